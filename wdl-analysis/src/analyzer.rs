@@ -30,6 +30,9 @@ use walkdir::WalkDir;
 use wdl_ast::Severity;
 use wdl_ast::SyntaxNode;
 
+use crate::CONSTANT_EVALUATION_RULE_ID;
+use crate::CustomRule;
+use crate::ImportsConfig;
 use crate::Rule;
 use crate::SyntaxNodeExt;
 use crate::UNNECESSARY_FUNCTION_CALL;
@@ -37,6 +40,7 @@ use crate::UNUSED_CALL_RULE_ID;
 use crate::UNUSED_DECL_RULE_ID;
 use crate::UNUSED_IMPORT_RULE_ID;
 use crate::UNUSED_INPUT_RULE_ID;
+use crate::ValidationCache;
 use crate::document::Document;
 use crate::graph::DocumentGraphNode;
 use crate::graph::ParseState;
@@ -320,6 +324,22 @@ pub struct DiagnosticsConfig {
     ///
     /// A value of `None` disables the diagnostic.
     pub unnecessary_function_call: Option<Severity>,
+    /// The severity for the "constant evaluation" diagnostics.
+    ///
+    /// Diagnostics that depend on the default value of an input are reported
+    /// with at most a warning severity.
+    ///
+    /// A value of `None` disables the diagnostics.
+    pub constant_evaluation: Option<Severity>,
+    /// The severities for the diagnostics of custom rules.
+    ///
+    /// Each entry is the identifier of a custom rule added to the validator
+    /// and the severity for the rule's diagnostics; a severity of `None`
+    /// disables the rule. Custom rules without an entry use the severity of
+    /// the rule.
+    ///
+    /// A value of `None` disables all custom rules.
+    pub custom_rules: Option<&'static [(&'static str, Option<Severity>)]>,
 }
 
 impl Default for DiagnosticsConfig {
@@ -329,6 +349,7 @@ impl Default for DiagnosticsConfig {
         let mut unused_declaration = None;
         let mut unused_call = None;
         let mut unnecessary_function_call = None;
+        let mut constant_evaluation = None;
 
         for rule in rules() {
             let rule = rule.as_ref();
@@ -338,6 +359,7 @@ impl Default for DiagnosticsConfig {
                 UNUSED_DECL_RULE_ID => unused_declaration = Some(rule.severity()),
                 UNUSED_CALL_RULE_ID => unused_call = Some(rule.severity()),
                 UNNECESSARY_FUNCTION_CALL => unnecessary_function_call = Some(rule.severity()),
+                CONSTANT_EVALUATION_RULE_ID => constant_evaluation = Some(rule.severity()),
                 _ => {
                     unreachable!("unknown rule ID: {}", rule.id());
                 }
@@ -350,6 +372,8 @@ impl Default for DiagnosticsConfig {
             unused_declaration,
             unused_call,
             unnecessary_function_call,
+            constant_evaluation,
+            custom_rules: Some(&[]),
         }
     }
 }
@@ -362,6 +386,7 @@ impl DiagnosticsConfig {
         let mut unused_declaration = None;
         let mut unused_call = None;
         let mut unnecessary_function_call = None;
+        let mut constant_evaluation = None;
 
         for rule in rules {
             let rule = rule.as_ref();
@@ -371,6 +396,7 @@ impl DiagnosticsConfig {
                 UNUSED_DECL_RULE_ID => unused_declaration = Some(rule.severity()),
                 UNUSED_CALL_RULE_ID => unused_call = Some(rule.severity()),
                 UNNECESSARY_FUNCTION_CALL => unnecessary_function_call = Some(rule.severity()),
+                CONSTANT_EVALUATION_RULE_ID => constant_evaluation = Some(rule.severity()),
                 _ => {}
             }
         }
@@ -381,6 +407,8 @@ impl DiagnosticsConfig {
             unused_declaration,
             unused_call,
             unnecessary_function_call,
+            constant_evaluation,
+            custom_rules: Some(&[]),
         }
     }
 
@@ -409,6 +437,10 @@ impl DiagnosticsConfig {
             self.unnecessary_function_call = None;
         }
 
+        if exceptions.contains(CONSTANT_EVALUATION_RULE_ID) {
+            self.constant_evaluation = None;
+        }
+
         self
     }
 
@@ -420,6 +452,19 @@ impl DiagnosticsConfig {
             unused_declaration: None,
             unused_call: None,
             unnecessary_function_call: None,
+            constant_evaluation: None,
+            custom_rules: None,
+        }
+    }
+
+    /// Gets the severity for the diagnostics of the given custom rule.
+    ///
+    /// Returns `None` if the custom rule is disabled.
+    pub fn custom_rule_severity(&self, rule: &CustomRule) -> Option<Severity> {
+        let rules = self.custom_rules?;
+        match rules.iter().find(|(id, _)| *id == rule.id()) {
+            Some((_, severity)) => *severity,
+            None => Some(rule.severity()),
         }
     }
 }
@@ -478,6 +523,76 @@ where
         progress: Progress,
         validator: Validator,
     ) -> Self
+    where
+        Progress: Fn(Context, ProgressKind, usize, usize) -> Return + Send + 'static,
+        Return: Future<Output = ()>,
+        Validator: Fn() -> crate::Validator + Send + Sync + 'static,
+    {
+        Self::new_with_imports(config, ImportsConfig::default(), progress, validator)
+    }
+
+    /// Constructs a new analyzer with the given diagnostics config, imports
+    /// config, and validator function.
+    ///
+    /// The imports config determines how imports are resolved and how remote
+    /// imports are verified.
+    ///
+    /// The provided progress callback will be invoked during analysis.
+    ///
+    /// This validator function will be called once per worker thread to
+    /// initialize a thread-local validator.
+    ///
+    /// The analyzer must be constructed from the context of a Tokio runtime.
+    pub fn new_with_imports<Progress, Return, Validator>(
+        config: DiagnosticsConfig,
+        imports: ImportsConfig,
+        progress: Progress,
+        validator: Validator,
+    ) -> Self
+    where
+        Progress: Fn(Context, ProgressKind, usize, usize) -> Return + Send + 'static,
+        Return: Future<Output = ()>,
+        Validator: Fn() -> crate::Validator + Send + Sync + 'static,
+    {
+        Self::create(config, imports, None, progress, validator)
+    }
+
+    /// Constructs a new analyzer with the given diagnostics config, imports
+    /// config, persistent validation cache, and validator function.
+    ///
+    /// Validation of a document that, along with its transitive imports, is
+    /// unchanged since it was last cached is skipped in favor of the cached
+    /// diagnostics; the document is still parsed and type checked.
+    ///
+    /// The provided progress callback will be invoked during analysis.
+    ///
+    /// This validator function will be called once per worker thread to
+    /// initialize a thread-local validator.
+    ///
+    /// The analyzer must be constructed from the context of a Tokio runtime.
+    pub fn new_with_validation_cache<Progress, Return, Validator>(
+        config: DiagnosticsConfig,
+        imports: ImportsConfig,
+        cache: ValidationCache,
+        progress: Progress,
+        validator: Validator,
+    ) -> Self
+    where
+        Progress: Fn(Context, ProgressKind, usize, usize) -> Return + Send + 'static,
+        Return: Future<Output = ()>,
+        Validator: Fn() -> crate::Validator + Send + Sync + 'static,
+    {
+        Self::create(config, imports, Some(cache), progress, validator)
+    }
+
+    /// Creates the analyzer and spawns its analysis queue.
+    fn create<Progress, Return, Validator>(
+        config: DiagnosticsConfig,
+        imports: ImportsConfig,
+        cache: Option<ValidationCache>,
+        progress: Progress,
+        validator: Validator,
+    ) -> Self
     where
         Progress: Fn(Context, ProgressKind, usize, usize) -> Return + Send + 'static,
         Return: Future<Output = ()>,
@@ -486,7 +601,7 @@ where
         let (tx, rx) = mpsc::unbounded_channel();
         let tokio = Handle::current();
         let handle = std::thread::spawn(move || {
-            let queue = AnalysisQueue::new(config, tokio, progress, validator);
+            let queue = AnalysisQueue::new(config, imports, cache, tokio, progress, validator);
             queue.run(rx);
         });
 
@@ -746,12 +861,107 @@ const _: () = {
 #[cfg(test)]
 mod test {
     use std::fs;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
 
     use tempfile::TempDir;
+    use wdl_ast::AstNode;
+    use wdl_ast::AstToken;
+    use wdl_ast::Diagnostic;
     use wdl_ast::Severity;
+    use wdl_ast::Span;
+    use wdl_ast::SupportedVersion;
+    use wdl_ast::v1::OutputSection;
+    use wdl_ast::v1::SectionParent;
 
     use super::*;
+    use crate::Diagnostics;
+    use crate::VisitReason;
+    use crate::Visitor;
+    use crate::eval::v1::ExportNodeKind;
+    use crate::eval::v1::WorkflowGraphExport;
     use crate::rules;
+    use crate::types::Optional;
+
+    /// A custom rule that disallows optional workflow outputs.
+    #[derive(Default)]
+    struct RequiredOutputsRule(Option<Document>);
+
+    impl Rule for RequiredOutputsRule {
+        fn id(&self) -> &'static str {
+            "RequiredWorkflowOutputs"
+        }
+
+        fn description(&self) -> &'static str {
+            "Ensures that workflow outputs are not optional."
+        }
+
+        fn explanation(&self) -> &'static str {
+            "Optional workflow outputs are not allowed."
+        }
+
+        fn deny(&mut self) {}
+
+        fn severity(&self) -> Severity {
+            Severity::Warning
+        }
+    }
+
+    impl Visitor for RequiredOutputsRule {
+        fn reset(&mut self) {
+            self.0 = None;
+        }
+
+        fn document(
+            &mut self,
+            _: &mut Diagnostics,
+            reason: VisitReason,
+            doc: &Document,
+            _: SupportedVersion,
+        ) {
+            if reason == VisitReason::Enter {
+                self.0 = Some(doc.clone());
+            }
+        }
+
+        fn output_section(
+            &mut self,
+            diagnostics: &mut Diagnostics,
+            reason: VisitReason,
+            section: &OutputSection,
+        ) {
+            if reason == VisitReason::Exit
+                || !matches!(section.parent(), SectionParent::Workflow(_))
+            {
+                return;
+            }
+
+            let Some(workflow) = self.0.as_ref().and_then(|d| d.workflow()) else {
+                return;
+            };
+
+            for decl in section.declarations() {
+                let name = decl.name();
+                if workflow
+                    .outputs()
+                    .get(name.text())
+                    .map(|o| o.ty().is_optional())
+                    .unwrap_or(false)
+                {
+                    diagnostics.exceptable_add(
+                        Diagnostic::warning(format!(
+                            "workflow output `{name}` is optional",
+                            name = name.text()
+                        ))
+                        .with_rule(self.id())
+                        .with_highlight(name.span()),
+                        decl.inner().clone().into(),
+                        &None,
+                    );
+                }
+            }
+        }
+    }
 
     #[tokio::test]
     async fn it_returns_empty_results() {
@@ -760,6 +970,521 @@ mod test {
         assert!(results.is_empty());
     }
 
+    #[tokio::test]
+    async fn it_resolves_remapped_and_searched_imports() {
+        let dir = TempDir::new().expect("failed to create temporary directory");
+        let vendor = dir.path().join("vendor");
+        let lib = dir.path().join("lib");
+        fs::create_dir_all(&vendor).expect("failed to create directory");
+        fs::create_dir_all(&lib).expect("failed to create directory");
+        fs::write(
+            vendor.join("remote.wdl"),
+            "version 1.1\n\ntask remote {\n    command <<<>>>\n}\n",
+        )
+        .expect("failed to create test file");
+        fs::write(
+            lib.join("util.wdl"),
+            "version 1.1\n\ntask util {\n    command <<<>>>\n}\n",
+        )
+        .expect("failed to create test file");
+
+        let path = dir.path().join("main.wdl");
+        fs::write(
+            &path,
+            r#"version 1.1
+
+import "https://example.com/org/repo/v1/remote.wdl"
+import "util.wdl"
+
+workflow main {
+    call remote.remote
+    call util.util
+}
+"#,
+        )
+        .expect("failed to create test file");
+
+        // Disallow downloads to ensure the remote import is remapped
+        let imports = ImportsConfig::default()
+            .with_offline(true)
+            .with_search_path(&lib)
+            .with_remapping(
+                "https://example.com/org/repo/v1/",
+                path_to_uri(&vendor).expect("should convert to URI"),
+            );
+        let analyzer = Analyzer::new_with_imports(
+            DiagnosticsConfig::except_all(),
+            imports,
+            |_: (), _, _, _| async {},
+            crate::Validator::default,
+        );
+        analyzer
+            .add_document(path_to_uri(&path).expect("should convert to URI"))
+            .await
+            .expect("should add document");
+
+        let results = analyzer.analyze(()).await.unwrap();
+        let result = results
+            .iter()
+            .find(|r| r.document.uri().as_ref() == &path_to_uri(&path).unwrap())
+            .expect("should have result");
+        assert!(
+            result.document.diagnostics().is_empty(),
+            "{diagnostics:?}",
+            diagnostics = result.document.diagnostics()
+        );
+        assert_eq!(
+            result
+                .document
+                .namespace("remote")
+                .expect("should have namespace")
+                .source()
+                .as_ref(),
+            &path_to_uri(vendor.join("remote.wdl")).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn it_runs_custom_rules() {
+        let dir = TempDir::new().expect("failed to create temporary directory");
+        let path = dir.path().join("foo.wdl");
+        fs::write(
+            &path,
+            r#"version 1.1
+
+workflow test {
+    output {
+        String? a = "a"
+        Int b = 1
+        #@ except: RequiredWorkflowOutputs
+        Int? c = 1
+    }
+}
+"#,
+        )
+        .expect("failed to create test file");
+
+        let analyze = async |custom_rules| {
+            let analyzer = Analyzer::new_with_validator(
+                DiagnosticsConfig {
+                    custom_rules,
+                    ..DiagnosticsConfig::except_all()
+                },
+                |_: (), _, _, _| async {},
+                || {
+                    let mut validator = crate::Validator::default();
+                    validator.add_custom_rule(RequiredOutputsRule::default);
+                    validator
+                },
+            );
+            analyzer
+                .add_document(path_to_uri(&path).expect("should convert to URI"))
+                .await
+                .expect("should add document");
+
+            let results = analyzer.analyze(()).await.unwrap();
+            assert_eq!(results.len(), 1);
+            results[0]
+                .document
+                .diagnostics()
+                .iter()
+                .map(|d| (d.severity(), d.message().to_string()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            analyze(Some(&[])).await,
+            [(
+                Severity::Warning,
+                "workflow output `a` is optional".to_string()
+            )]
+        );
+
+        assert_eq!(
+            analyze(Some(&[("RequiredWorkflowOutputs", Some(Severity::Error))])).await,
+            [(
+                Severity::Error,
+                "workflow output `a` is optional".to_string()
+            )]
+        );
+
+        assert!(
+            analyze(Some(&[("RequiredWorkflowOutputs", None)]))
+                .await
+                .is_empty()
+        );
+        assert!(analyze(None).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_uses_the_validation_cache() {
+        /// A custom rule that counts the documents it checks.
+        struct CountingRule(Arc<AtomicUsize>);
+
+        impl Rule for CountingRule {
+            fn id(&self) -> &'static str {
+                "Counting"
+            }
+
+            fn description(&self) -> &'static str {
+                "Counts the checked documents."
+            }
+
+            fn explanation(&self) -> &'static str {
+                "Every checked document is reported."
+            }
+
+            fn deny(&mut self) {}
+
+            fn severity(&self) -> Severity {
+                Severity::Note
+            }
+        }
+
+        impl Visitor for CountingRule {
+            fn reset(&mut self) {}
+
+            fn document(
+                &mut self,
+                diagnostics: &mut Diagnostics,
+                reason: VisitReason,
+                doc: &Document,
+                _: SupportedVersion,
+            ) {
+                if reason == VisitReason::Enter {
+                    self.0.fetch_add(1, Ordering::SeqCst);
+                    diagnostics.add(
+                        Diagnostic::note(format!(
+                            "checked `{name}`",
+                            name = doc.path().rsplit('/').next().unwrap()
+                        ))
+                        .with_rule(self.id())
+                        .with_highlight(Span::new(0, 7)),
+                    );
+                }
+            }
+        }
+
+        let dir = TempDir::new().expect("failed to create temporary directory");
+        let lib = dir.path().join("lib.wdl");
+        let main = dir.path().join("main.wdl");
+        fs::write(&lib, "version 1.1\n\ntask t {\n    command <<<>>>\n}\n")
+            .expect("failed to create test file");
+        fs::write(
+            &main,
+            "version 1.1\n\nimport \"lib.wdl\"\n\nworkflow w {\n    call lib.t\n}\n",
+        )
+        .expect("failed to create test file");
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let cache = ValidationCache::new(dir.path().join("cache"), "test");
+        let analyze = async || {
+            let count = count.clone();
+            let analyzer = Analyzer::new_with_validation_cache(
+                DiagnosticsConfig {
+                    custom_rules: Some(&[]),
+                    ..DiagnosticsConfig::except_all()
+                },
+                ImportsConfig::default(),
+                cache.clone(),
+                |_: (), _, _, _| async {},
+                move || {
+                    let count = count.clone();
+                    let mut validator = crate::Validator::default();
+                    validator.add_custom_rule(move || CountingRule(count.clone()));
+                    validator
+                },
+            );
+            analyzer
+                .add_document(path_to_uri(&main).expect("should convert to URI"))
+                .await
+                .expect("should add document");
+
+            let mut diagnostics = analyzer
+                .analyze(())
+                .await
+                .unwrap()
+                .iter()
+                .flat_map(|r| {
+                    r.document
+                        .diagnostics()
+                        .iter()
+                        .map(|d| {
+                            (
+                                d.rule().map(str::to_string),
+                                d.severity(),
+                                d.message().to_string(),
+                                d.labels().map(|l| l.span()).collect::<Vec<_>>(),
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            diagnostics.sort();
+            diagnostics
+        };
+
+        // The first analysis checks both documents
+        let expected = analyze().await;
+        assert_eq!(expected.len(), 2);
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+
+        // The second analysis uses the cached diagnostics
+        assert_eq!(analyze().await, expected);
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+
+        // Changing the import invalidates both entries
+        fs::write(&lib, "version 1.1\n\ntask t {\n    command <<< >>>\n}\n")
+            .expect("failed to update test file");
+        assert_eq!(analyze().await, expected);
+        assert_eq!(count.load(Ordering::SeqCst), 4);
+
+        // Clearing the cache causes both documents to be checked again
+        cache.clear().expect("should clear the cache");
+        assert!(!cache.dir().exists());
+        assert_eq!(analyze().await, expected);
+        assert_eq!(count.load(Ordering::SeqCst), 6);
+    }
+
+    #[tokio::test]
+    async fn it_exports_workflow_graphs() {
+        let dir = TempDir::new().expect("failed to create temporary directory");
+        fs::write(
+            dir.path().join("lib.wdl"),
+            r#"version 1.1
+
+workflow inner {
+    input {
+        Int x
+    }
+
+    output {
+        Int y = x
+    }
+}
+"#,
+        )
+        .expect("failed to create test file");
+
+        let path = dir.path().join("main.wdl");
+        fs::write(
+            &path,
+            r#"version 1.1
+
+import "lib.wdl"
+
+workflow main {
+    input {
+        Array[Int] xs
+    }
+
+    scatter (x in xs) {
+        if (x > 1) {
+            call lib.inner { input: x }
+        }
+    }
+
+    output {
+        Array[Int?] ys = inner.y
+    }
+}
+"#,
+        )
+        .expect("failed to create test file");
+
+        let analyzer = Analyzer::new(DiagnosticsConfig::except_all(), |_: (), _, _, _| async {});
+        analyzer
+            .add_document(path_to_uri(&path).expect("should convert to URI"))
+            .await
+            .expect("should add document");
+
+        let results = analyzer.analyze(()).await.unwrap();
+        let result = results
+            .iter()
+            .find(|r| r.document.uri().as_ref() == &path_to_uri(&path).unwrap())
+            .expect("should have result");
+        assert!(
+            result.document.diagnostics().is_empty(),
+            "{diagnostics:?}",
+            diagnostics = result.document.diagnostics()
+        );
+
+        let export = WorkflowGraphExport::new(&result.document, false).expect("should export");
+        assert_eq!(export.workflow(), "main");
+        let nodes = export
+            .nodes()
+            .iter()
+            .map(|n| (n.id(), n.kind(), n.label(), n.parent()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            nodes,
+            [
+                (0, ExportNodeKind::Input, "Array[Int] xs", None),
+                (1, ExportNodeKind::Scatter, "scatter (x in xs)", None),
+                (2, ExportNodeKind::Conditional, "if (x > 1)", Some(1)),
+                (3, ExportNodeKind::Call, "call lib.inner", Some(2)),
+                (4, ExportNodeKind::Output, "Array[Int?] ys", None),
+            ]
+        );
+        assert_eq!(
+            export
+                .edges()
+                .iter()
+                .map(|e| (e.from(), e.to()))
+                .collect::<Vec<_>>(),
+            [(0, 1), (1, 4)]
+        );
+
+        assert_eq!(
+            export.to_dot(),
+            r#"digraph "main" {
+    n0 [label="Array[Int] xs", shape=invhouse];
+    subgraph cluster_n1 {
+        style=dashed;
+        label="";
+        n1 [label="scatter (x in xs)", shape=hexagon];
+        subgraph cluster_n2 {
+            style=dashed;
+            label="";
+            n2 [label="if (x > 1)", shape=diamond];
+            n3 [label="call lib.inner", shape=box, style=rounded];
+        }
+    }
+    n4 [label="Array[Int?] ys", shape=house];
+    n0 -> n1;
+    n1 -> n4;
+}
+"#
+        );
+        assert_eq!(
+            export.to_mermaid(),
+            r#"flowchart TD
+    n0[/"Array[Int] xs"/]
+    subgraph s1 [" "]
+        n1{{"scatter (x in xs)"}}
+        subgraph s2 [" "]
+            n2{"if (x > 1)"}
+            n3("call lib.inner")
+        end
+    end
+    n4[\"Array[Int?] ys"\]
+    n0 --> n1
+    n1 --> n4
+"#
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&export.to_json()).expect("should be valid JSON");
+        assert_eq!(json["nodes"][3]["kind"], "call");
+        assert_eq!(json["nodes"][3]["target"], "lib.inner");
+        assert_eq!(json["edges"][1]["to"], 4);
+
+        // Expanding the call nests the sub-workflow's graph inside of the call
+        let export = WorkflowGraphExport::new(&result.document, true).expect("should export");
+        let nodes = export
+            .nodes()
+            .iter()
+            .map(|n| (n.id(), n.kind(), n.label(), n.parent()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            nodes[5..],
+            [
+                (5, ExportNodeKind::Input, "Int x", Some(3)),
+                (6, ExportNodeKind::Output, "Int y", Some(3)),
+            ]
+        );
+        assert!(export.edges().iter().any(|e| (e.from(), e.to()) == (5, 6)));
+    }
+
+    #[tokio::test]
+    async fn it_queries_expression_types() {
+        let dir = TempDir::new().expect("failed to create temporary directory");
+        let path = dir.path().join("foo.wdl");
+        let source = r#"version 1.1
+
+task t {
+    command <<<>>>
+
+    output {
+        Int out = 1
+    }
+}
+
+workflow test {
+    scatter (i in [1, 2]) {
+        call t
+        Int x = i + 1
+    }
+
+    output {
+        Array[Int] outs = t.out
+        Array[Int] xs = x
+    }
+}
+"#;
+        fs::write(&path, source).expect("failed to create test file");
+
+        let analyzer = Analyzer::new(DiagnosticsConfig::except_all(), |_: (), _, _, _| async {});
+        analyzer
+            .add_document(path_to_uri(&path).expect("should convert to URI"))
+            .await
+            .expect("should add document");
+
+        let results = analyzer.analyze(()).await.unwrap();
+        assert_eq!(results.len(), 1);
+        let document = &results[0].document;
+        assert!(document.diagnostics().is_empty());
+
+        let type_at = |text: &str| {
+            let position = source.find(text).expect("should find text");
+            document
+                .expr_type_at(position)
+                .map(|(span, ty)| (&source[span.start()..span.end()], ty.to_string()))
+        };
+
+        // The innermost expression is found
+        assert_eq!(type_at("i + 1"), Some(("i", "Int".to_string())));
+        assert_eq!(type_at("+ 1"), Some(("i + 1", "Int".to_string())));
+        assert_eq!(
+            type_at("[1, 2]"),
+            Some(("[1, 2]", "Array[Int]+".to_string()))
+        );
+
+        // Call outputs and names are promoted outside of the scatter
+        assert_eq!(type_at(".out"), Some(("t.out", "Array[Int]".to_string())));
+        assert_eq!(
+            type_at("t.out"),
+            Some(("t", "call to task `t`".to_string()))
+        );
+        assert_eq!(type_at("x\n"), Some(("x", "Array[Int]".to_string())));
+
+        // Positions outside of expressions have no type
+        assert_eq!(type_at("scatter"), None);
+
+        let names = |text: &str| {
+            let position = source.find(text).expect("should find text");
+            let mut names = document
+                .visible_names(position)
+                .into_iter()
+                .map(|(name, n)| format!("{name}: {ty}", ty = n.ty()))
+                .collect::<Vec<_>>();
+            names.sort();
+            names
+        };
+
+        assert_eq!(names("i + 1"), ["i: Int", "t: call to task `t`", "x: Int"]);
+        assert_eq!(
+            names("t.out"),
+            [
+                "outs: Array[Int]",
+                "t: call to task `t`",
+                "x: Array[Int]",
+                "xs: Array[Int]"
+            ]
+        );
+        assert!(names("version").is_empty());
+    }
+
     #[tokio::test]
     async fn it_analyzes_a_document() {
         let dir = TempDir::new().expect("failed to create temporary directory");
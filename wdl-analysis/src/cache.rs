@@ -0,0 +1,364 @@
+//! Persistent on-disk caching of validation diagnostics.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Context;
+use anyhow::Result;
+use petgraph::graph::NodeIndex;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+use tracing::warn;
+use url::Url;
+use wdl_ast::Applicability;
+use wdl_ast::AstNode;
+use wdl_ast::Diagnostic;
+use wdl_ast::Edit;
+use wdl_ast::Severity;
+use wdl_ast::Span;
+
+use crate::CustomRule;
+use crate::DiagnosticsConfig;
+use crate::content_hash;
+use crate::graph::DocumentGraph;
+
+/// The current version of the cache entry format.
+///
+/// This is incremented whenever the format of a cache entry changes.
+const CACHE_VERSION: u32 = 2;
+
+/// Represents a cached label of a diagnostic.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CachedLabel {
+    /// The message of the label.
+    message: String,
+    /// The start of the label's span.
+    start: usize,
+    /// The end of the label's span.
+    end: usize,
+}
+
+/// Represents a cached edit of a diagnostic.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CachedEdit {
+    /// The start of the edit's span.
+    start: usize,
+    /// The end of the edit's span.
+    end: usize,
+    /// The replacement text of the edit.
+    replacement: String,
+}
+
+/// Represents a cached diagnostic.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CachedDiagnostic {
+    /// The rule associated with the diagnostic.
+    rule: Option<String>,
+    /// The severity of the diagnostic (`error`, `warning`, or `note`).
+    severity: String,
+    /// The message of the diagnostic.
+    message: String,
+    /// The fix of the diagnostic.
+    fix: Option<String>,
+    /// The labels of the diagnostic.
+    labels: Vec<CachedLabel>,
+    /// The applicability of the diagnostic's edits (`safe` or `unsafe`).
+    applicability: Option<String>,
+    /// The edits of the diagnostic.
+    edits: Vec<CachedEdit>,
+}
+
+impl From<&Diagnostic> for CachedDiagnostic {
+    fn from(diagnostic: &Diagnostic) -> Self {
+        Self {
+            rule: diagnostic.rule().map(str::to_string),
+            severity: match diagnostic.severity() {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Note => "note",
+            }
+            .to_string(),
+            message: diagnostic.message().to_string(),
+            fix: diagnostic.fix().map(str::to_string),
+            labels: diagnostic
+                .labels()
+                .map(|l| CachedLabel {
+                    message: l.message().to_string(),
+                    start: l.span().start(),
+                    end: l.span().end(),
+                })
+                .collect(),
+            applicability: diagnostic.applicability().map(|a| a.to_string()),
+            edits: diagnostic
+                .edits()
+                .iter()
+                .map(|e| CachedEdit {
+                    start: e.span().start(),
+                    end: e.span().end(),
+                    replacement: e.replacement().to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl CachedDiagnostic {
+    /// Converts the cached diagnostic back into a diagnostic.
+    ///
+    /// Returns `None` if the cached severity or applicability is unknown.
+    fn into_diagnostic(self) -> Option<Diagnostic> {
+        let mut diagnostic = match self.severity.as_str() {
+            "error" => Diagnostic::error(self.message),
+            "warning" => Diagnostic::warning(self.message),
+            "note" => Diagnostic::note(self.message),
+            _ => return None,
+        };
+
+        if let Some(rule) = self.rule {
+            diagnostic = diagnostic.with_rule(rule);
+        }
+
+        if let Some(fix) = self.fix {
+            diagnostic = diagnostic.with_fix(fix);
+        }
+
+        for label in self.labels {
+            diagnostic = diagnostic.with_label(
+                label.message,
+                Span::new(label.start, label.end.checked_sub(label.start)?),
+            );
+        }
+
+        if let Some(applicability) = self.applicability {
+            let applicability = match applicability.as_str() {
+                "safe" => Applicability::Safe,
+                "unsafe" => Applicability::Unsafe,
+                _ => return None,
+            };
+
+            let edits = self
+                .edits
+                .into_iter()
+                .map(|e| {
+                    Some(Edit::new(
+                        Span::new(e.start, e.end.checked_sub(e.start)?),
+                        e.replacement,
+                    ))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            diagnostic = diagnostic.with_edits(applicability, edits);
+        }
+
+        Some(diagnostic)
+    }
+}
+
+/// Represents a cache entry for a single document.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CacheEntry {
+    /// The version of the cache entry format.
+    version: u32,
+    /// The key the entry was stored with.
+    key: String,
+    /// The cached diagnostics.
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+/// Represents a persistent on-disk cache of validation diagnostics.
+///
+/// The cache stores the diagnostics produced by validating a document (i.e.
+/// the diagnostics of the validator, including any lint rules, and of any
+/// custom analysis rules). When a document and every document it transitively
+/// imports are unchanged, validation of the document is skipped and the cached
+/// diagnostics are used instead.
+///
+/// Parsing and type checking are not cached: every document is still parsed
+/// and analyzed so that the analysis results contain the full document.
+///
+/// Each entry is keyed by a hash of:
+///
+/// * the version of the analyzer,
+/// * the diagnostics configuration,
+/// * the custom rules of the validator,
+/// * the cache's salt (see [`ValidationCache::new`]),
+/// * the content hash of the document, and
+/// * the URIs and content hashes of the documents it transitively imports.
+///
+/// Failures to read or write the cache are logged and otherwise ignored.
+#[derive(Debug, Clone)]
+pub struct ValidationCache {
+    /// The directory containing the cache entries.
+    dir: Arc<PathBuf>,
+    /// The salt to include in every cache key.
+    salt: Arc<String>,
+}
+
+impl ValidationCache {
+    /// Constructs a new validation cache that stores entries in the given
+    /// directory.
+    ///
+    /// The salt is included in every cache key and must identify the
+    /// validation visitors of the analyzer, such as the set of enabled lint
+    /// rules, their configuration, and their versions; the visitors of a
+    /// validator are otherwise not part of the cache key.
+    ///
+    /// The directory is created when the first entry is stored.
+    pub fn new(dir: impl Into<PathBuf>, salt: impl Into<String>) -> Self {
+        Self {
+            dir: Arc::new(dir.into()),
+            salt: Arc::new(salt.into()),
+        }
+    }
+
+    /// Gets the directory containing the cache entries.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Gets the salt included in every cache key.
+    pub fn salt(&self) -> &str {
+        &self.salt
+    }
+
+    /// Removes every entry from the cache.
+    pub fn clear(&self) -> Result<()> {
+        if !self.dir.exists() {
+            return Ok(());
+        }
+
+        fs::remove_dir_all(self.dir.as_path()).with_context(|| {
+            format!(
+                "failed to remove validation cache directory `{dir}`",
+                dir = self.dir.display()
+            )
+        })
+    }
+
+    /// Calculates the cache key for the given document graph node.
+    ///
+    /// Returns `None` if the document or one of its transitive imports was not
+    /// parsed.
+    pub(crate) fn key(
+        &self,
+        config: &DiagnosticsConfig,
+        rules: &[CustomRule],
+        graph: &DocumentGraph,
+        index: NodeIndex,
+    ) -> Option<String> {
+        let hash = |index: NodeIndex| {
+            graph
+                .get(index)
+                .root()
+                .map(|root| content_hash(&root.inner().text().to_string()))
+        };
+
+        // Collect the content hashes of the transitive imports, ordered by URI
+        let mut imports = BTreeMap::new();
+        let mut stack: Vec<_> = graph.dependencies(index).collect();
+        while let Some(dependency) = stack.pop() {
+            let uri = graph.get(dependency).uri().to_string();
+            if imports.contains_key(&uri) {
+                continue;
+            }
+
+            imports.insert(uri, hash(dependency)?);
+            stack.extend(graph.dependencies(dependency));
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(format!("version:{CACHE_VERSION}\n"));
+        hasher.update(format!("analyzer:{}\n", env!("CARGO_PKG_VERSION")));
+        hasher.update(format!("config:{config:?}\n"));
+        hasher.update(format!("rules:{rules:?}\n"));
+        hasher.update(format!("salt:{salt}\n", salt = self.salt));
+        hasher.update(format!(
+            "document:{uri} {hash}\n",
+            uri = graph.get(index).uri(),
+            hash = hash(index)?
+        ));
+        for (uri, hash) in imports {
+            hasher.update(format!("import:{uri} {hash}\n"));
+        }
+
+        Some(format!("{hash:x}", hash = hasher.finalize()))
+    }
+
+    /// Gets the path of the cache entry for the given document URI.
+    fn entry_path(&self, uri: &Url) -> PathBuf {
+        self.dir.join(format!(
+            "{hash:x}.json",
+            hash = Sha256::digest(uri.as_str().as_bytes())
+        ))
+    }
+
+    /// Loads the cached diagnostics for the given document URI.
+    ///
+    /// Returns `None` if there is no entry for the document or if the entry
+    /// was stored with a different key.
+    pub(crate) fn load(&self, uri: &Url, key: &str) -> Option<Vec<Diagnostic>> {
+        let contents = fs::read_to_string(self.entry_path(uri)).ok()?;
+        let entry: CacheEntry = match serde_json::from_str(&contents) {
+            Ok(entry) => entry,
+            Err(e) => {
+                warn!("ignoring invalid validation cache entry for `{uri}`: {e}");
+                return None;
+            }
+        };
+
+        if entry.version != CACHE_VERSION || entry.key != key {
+            return None;
+        }
+
+        entry
+            .diagnostics
+            .into_iter()
+            .map(CachedDiagnostic::into_diagnostic)
+            .collect()
+    }
+
+    /// Stores the diagnostics for the given document URI.
+    pub(crate) fn store(&self, uri: &Url, key: &str, diagnostics: &[Diagnostic]) -> Result<()> {
+        fs::create_dir_all(self.dir.as_path()).with_context(|| {
+            format!(
+                "failed to create validation cache directory `{dir}`",
+                dir = self.dir.display()
+            )
+        })?;
+
+        let entry = CacheEntry {
+            version: CACHE_VERSION,
+            key: key.to_string(),
+            diagnostics: diagnostics.iter().map(Into::into).collect(),
+        };
+
+        let contents =
+            serde_json::to_string(&entry).context("failed to serialize validation cache entry")?;
+
+        // Write to a temporary file first so that a partially written entry is never
+        // observed
+        let path = self.entry_path(uri);
+        let temp = path.with_extension(format!("{id}.tmp", id = uuid::Uuid::new_v4()));
+        fs::write(&temp, contents).with_context(|| {
+            format!(
+                "failed to write validation cache entry `{path}`",
+                path = temp.display()
+            )
+        })?;
+
+        fs::rename(&temp, &path).with_context(|| {
+            format!(
+                "failed to write validation cache entry `{path}`",
+                path = path.display()
+            )
+        })
+    }
+}
@@ -0,0 +1,472 @@
+//! Workspace-wide dead code analysis.
+//!
+//! Unlike the unused rules that operate on a single document, dead code
+//! analysis operates on the results of analyzing a set of documents: starting
+//! from a set of entrypoint documents, it follows calls, struct references, and
+//! imports to find the tasks, workflows, and structs that are never used and
+//! the documents that are never imported.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+use url::Url;
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::Diagnostic;
+use wdl_ast::Span;
+use wdl_ast::SyntaxNode;
+use wdl_ast::v1::DocumentItem;
+use wdl_ast::v1::LiteralStruct;
+use wdl_ast::v1::TypeRef;
+
+use crate::AnalysisResult;
+use crate::document::Document;
+use crate::types::CallKind;
+use crate::types::CompoundType;
+use crate::types::Type;
+
+/// The rule identifier for dead code diagnostics.
+pub const DEAD_CODE_RULE_ID: &str = "DeadCode";
+
+/// Represents the kind of a dead item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeadItemKind {
+    /// The item is a task that is never called.
+    Task,
+    /// The item is a workflow that is never called.
+    Workflow,
+    /// The item is a struct that is never referenced.
+    Struct,
+    /// The item is a document that is never imported.
+    Document,
+}
+
+impl fmt::Display for DeadItemKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Task => write!(f, "task"),
+            Self::Workflow => write!(f, "workflow"),
+            Self::Struct => write!(f, "struct"),
+            Self::Document => write!(f, "document"),
+        }
+    }
+}
+
+/// Represents an item that is not reachable from any entrypoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeadItem {
+    /// The URI of the document containing the item.
+    uri: Arc<Url>,
+    /// The kind of the item.
+    kind: DeadItemKind,
+    /// The name of the item.
+    ///
+    /// This is `None` for documents.
+    name: Option<String>,
+    /// The span of the item's name.
+    ///
+    /// This is `None` for documents.
+    span: Option<Span>,
+}
+
+impl DeadItem {
+    /// Gets the URI of the document containing the item.
+    pub fn uri(&self) -> &Arc<Url> {
+        &self.uri
+    }
+
+    /// Gets the kind of the item.
+    pub fn kind(&self) -> DeadItemKind {
+        self.kind
+    }
+
+    /// Gets the name of the item.
+    ///
+    /// Returns `None` for documents.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Gets the span of the item's name within its document.
+    ///
+    /// Returns `None` for documents.
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Converts the dead item into a diagnostic.
+    ///
+    /// The diagnostic is relative to the document containing the item.
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        let message = match (&self.kind, &self.name) {
+            (DeadItemKind::Document, _) | (_, None) => {
+                "document is never imported from an entrypoint".to_string()
+            }
+            (DeadItemKind::Struct, Some(name)) => {
+                format!("struct `{name}` is never referenced from an entrypoint")
+            }
+            (kind, Some(name)) => format!("{kind} `{name}` is never called from an entrypoint"),
+        };
+
+        let diagnostic = Diagnostic::warning(message)
+            .with_rule(DEAD_CODE_RULE_ID)
+            .with_fix("remove the unused item or add it to the set of entrypoints");
+
+        match self.span {
+            Some(span) => diagnostic.with_highlight(span),
+            None => diagnostic,
+        }
+    }
+}
+
+/// Gets the CST node of the definition of the given item in a document.
+fn definition(document: &Document, kind: DeadItemKind, name: &str) -> Option<SyntaxNode> {
+    document.root().ast().into_v1()?.items().find_map(|item| {
+        let (item_kind, item_name, node) = match item {
+            DocumentItem::Task(d) => (DeadItemKind::Task, d.name(), d.inner().clone()),
+            DocumentItem::Workflow(d) => (DeadItemKind::Workflow, d.name(), d.inner().clone()),
+            DocumentItem::Struct(d) => (DeadItemKind::Struct, d.name(), d.inner().clone()),
+            _ => return None,
+        };
+
+        (item_kind == kind && item_name.text() == name).then_some(node)
+    })
+}
+
+/// Resolves a struct name used in a document to the document that defines the
+/// struct and the name of the struct in that document.
+fn resolve_struct(document: &Document, name: &str) -> Option<(Document, String)> {
+    let mut document = document.clone();
+    let mut name = name.to_string();
+    loop {
+        let s = document.struct_by_name(&name)?;
+        let Some(ns) = s.namespace() else {
+            return Some((document, name));
+        };
+
+        // Imported structs may be aliased; the type retains the original name
+        let original = match s.ty()? {
+            Type::Compound(CompoundType::Struct(ty), _) => ty.name().to_string(),
+            _ => return None,
+        };
+
+        document = document.namespace(ns)?.document().clone();
+        name = original;
+    }
+}
+
+/// Finds the items that are not reachable from any of the given entrypoints.
+///
+/// Each entrypoint is the URI of an analyzed document; the workflow of an
+/// entrypoint document is considered used or, if the document has no
+/// workflow, all of its tasks are considered used. If no entrypoints are
+/// given, every document containing a workflow is an entrypoint.
+///
+/// From the entrypoints, calls and struct references are followed to the
+/// called tasks and workflows and referenced structs; any task, workflow, or
+/// struct definition not reached is reported as dead. Documents that are not
+/// imported, directly or transitively, from an entrypoint document are
+/// reported as a whole rather than reporting each of their items.
+///
+/// The returned items are in the order of the given analysis results.
+pub fn find_dead_code(results: &[AnalysisResult], entrypoints: &[Url]) -> Vec<DeadItem> {
+    let entrypoints = results
+        .iter()
+        .map(|r| r.document())
+        .filter(|d| {
+            if entrypoints.is_empty() {
+                d.workflow().is_some()
+            } else {
+                entrypoints.contains(d.uri())
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Find the documents that are imported from the entrypoints
+    let mut imported = HashSet::new();
+    let mut stack = entrypoints.iter().map(|d| (*d).clone()).collect::<Vec<_>>();
+    while let Some(document) = stack.pop() {
+        if !imported.insert(document.uri().clone()) {
+            continue;
+        }
+
+        stack.extend(document.namespaces().map(|(_, ns)| ns.document().clone()));
+    }
+
+    // Find the items that are reachable from the entrypoints
+    let mut live = HashSet::new();
+    let mut stack = Vec::new();
+    for document in entrypoints {
+        match document.workflow() {
+            Some(workflow) => stack.push((
+                document.clone(),
+                DeadItemKind::Workflow,
+                workflow.name().to_string(),
+            )),
+            None => stack.extend(
+                document
+                    .tasks()
+                    .map(|t| (document.clone(), DeadItemKind::Task, t.name().to_string())),
+            ),
+        }
+    }
+
+    while let Some((document, kind, name)) = stack.pop() {
+        if !live.insert((document.uri().clone(), kind, name.clone())) {
+            continue;
+        }
+
+        if kind == DeadItemKind::Workflow {
+            if let Some(workflow) = document.workflow().filter(|w| w.name() == name) {
+                for call in workflow.calls().values() {
+                    let target = match call.namespace() {
+                        Some(ns) => match document.namespace(ns) {
+                            Some(ns) => ns.document().clone(),
+                            None => continue,
+                        },
+                        None => document.clone(),
+                    };
+
+                    let kind = match call.kind() {
+                        CallKind::Task => DeadItemKind::Task,
+                        CallKind::Workflow => DeadItemKind::Workflow,
+                    };
+
+                    stack.push((target, kind, call.name().to_string()));
+                }
+            }
+        }
+
+        // Follow the struct references of the item's definition
+        let Some(node) = definition(&document, kind, &name) else {
+            continue;
+        };
+
+        for node in node.descendants() {
+            let referenced = match TypeRef::cast(node.clone()) {
+                Some(r) => r.name(),
+                None => match LiteralStruct::cast(node) {
+                    Some(l) => l.name(),
+                    None => continue,
+                },
+            };
+
+            if let Some((document, name)) = resolve_struct(&document, referenced.text()) {
+                stack.push((document, DeadItemKind::Struct, name));
+            }
+        }
+    }
+
+    let is_live = |document: &Document, kind, name: &str| {
+        live.contains(&(document.uri().clone(), kind, name.to_string()))
+    };
+
+    let mut dead = Vec::new();
+    for document in results.iter().map(|r| r.document()) {
+        if !imported.contains(document.uri()) {
+            dead.push(DeadItem {
+                uri: document.uri().clone(),
+                kind: DeadItemKind::Document,
+                name: None,
+                span: None,
+            });
+            continue;
+        }
+
+        let mut items = Vec::new();
+        items.extend(
+            document
+                .structs()
+                .filter(|(_, s)| s.namespace().is_none())
+                .map(|(name, s)| (DeadItemKind::Struct, name, s.span())),
+        );
+        items.extend(
+            document
+                .tasks()
+                .map(|t| (DeadItemKind::Task, t.name(), t.name_span())),
+        );
+        items.extend(
+            document
+                .workflow()
+                .map(|w| (DeadItemKind::Workflow, w.name(), w.name_span())),
+        );
+
+        // Report the items in the order they appear in the document
+        items.sort_by_key(|(_, _, span)| span.start());
+        dead.extend(
+            items
+                .into_iter()
+                .filter(|(kind, name, _)| !is_live(document, *kind, name))
+                .map(|(kind, name, span)| DeadItem {
+                    uri: document.uri().clone(),
+                    kind,
+                    name: Some(name.to_string()),
+                    span: Some(span),
+                }),
+        );
+    }
+
+    dead
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::Analyzer;
+    use crate::DiagnosticsConfig;
+    use crate::path_to_uri;
+
+    #[tokio::test]
+    async fn it_finds_dead_code() {
+        let dir = TempDir::new().expect("failed to create temporary directory");
+        let files = [
+            (
+                "main.wdl",
+                r#"version 1.1
+
+import "lib.wdl" as lib alias Config as LibConfig
+
+task local_unused {
+    command <<<>>>
+}
+
+task local_used {
+    input {
+        LibConfig config
+    }
+
+    command <<<>>>
+}
+
+workflow main {
+    input {
+        LibConfig config
+    }
+
+    call lib.used
+    call local_used { config }
+}
+"#,
+            ),
+            (
+                "lib.wdl",
+                r#"version 1.1
+
+struct Nested {
+    Int x
+}
+
+struct Config {
+    Nested nested
+}
+
+struct Unused {
+    Int x
+}
+
+task used {
+    command <<<>>>
+}
+
+task unused {
+    command <<<>>>
+}
+"#,
+            ),
+            (
+                "orphan.wdl",
+                r#"version 1.1
+
+task orphan {
+    command <<<>>>
+}
+"#,
+            ),
+        ];
+
+        for (name, source) in files {
+            fs::write(dir.path().join(name), source).expect("failed to create test file");
+        }
+
+        let analyzer = Analyzer::new(DiagnosticsConfig::except_all(), |_: (), _, _, _| async {});
+        analyzer
+            .add_directory(dir.path().to_path_buf())
+            .await
+            .expect("should add directory");
+        let mut results = analyzer.analyze(()).await.unwrap();
+        results.sort_by(|a, b| a.document().uri().cmp(b.document().uri()));
+
+        let uri = |name: &str| path_to_uri(dir.path().join(name)).unwrap();
+        let dead = |entrypoints: &[Url]| {
+            find_dead_code(&results, entrypoints)
+                .into_iter()
+                .map(|i| {
+                    (
+                        i.uri().as_ref().clone(),
+                        i.kind(),
+                        i.name().map(str::to_string),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            dead(&[]),
+            [
+                (
+                    uri("lib.wdl"),
+                    DeadItemKind::Struct,
+                    Some("Unused".to_string())
+                ),
+                (
+                    uri("lib.wdl"),
+                    DeadItemKind::Task,
+                    Some("unused".to_string())
+                ),
+                (
+                    uri("main.wdl"),
+                    DeadItemKind::Task,
+                    Some("local_unused".to_string())
+                ),
+                (uri("orphan.wdl"), DeadItemKind::Document, None),
+            ]
+        );
+
+        // A document without a workflow keeps all of its tasks
+        assert_eq!(
+            dead(&[uri("lib.wdl")]),
+            [
+                (
+                    uri("lib.wdl"),
+                    DeadItemKind::Struct,
+                    Some("Nested".to_string())
+                ),
+                (
+                    uri("lib.wdl"),
+                    DeadItemKind::Struct,
+                    Some("Config".to_string())
+                ),
+                (
+                    uri("lib.wdl"),
+                    DeadItemKind::Struct,
+                    Some("Unused".to_string())
+                ),
+                (uri("main.wdl"), DeadItemKind::Document, None),
+                (uri("orphan.wdl"), DeadItemKind::Document, None),
+            ]
+        );
+
+        let diagnostic = find_dead_code(&results, &[])[0].to_diagnostic();
+        assert_eq!(diagnostic.rule(), Some(DEAD_CODE_RULE_ID));
+        assert_eq!(
+            diagnostic.message(),
+            "struct `Unused` is never referenced from an entrypoint"
+        );
+    }
+}
@@ -12,6 +12,7 @@ use wdl_ast::TreeToken;
 use wdl_ast::Version;
 use wdl_ast::v1::PlaceholderOption;
 
+use crate::CONSTANT_EVALUATION_RULE_ID;
 use crate::UNNECESSARY_FUNCTION_CALL;
 use crate::UNUSED_CALL_RULE_ID;
 use crate::UNUSED_DECL_RULE_ID;
@@ -53,6 +54,10 @@ pub enum Context {
     Struct(Span),
     /// The name is a struct member name.
     StructMember(Span),
+    /// The name is an enum name.
+    Enum(Span),
+    /// The name is an enum variant name.
+    EnumVariant(Span),
     /// A name from a scope.
     Name(NameContext),
 }
@@ -65,6 +70,8 @@ impl Context {
             Self::Task(s) => *s,
             Self::Struct(s) => *s,
             Self::StructMember(s) => *s,
+            Self::Enum(s) => *s,
+            Self::EnumVariant(s) => *s,
             Self::Name(n) => n.span(),
         }
     }
@@ -77,6 +84,8 @@ impl fmt::Display for Context {
             Self::Task(_) => write!(f, "task"),
             Self::Struct(_) => write!(f, "struct"),
             Self::StructMember(_) => write!(f, "struct member"),
+            Self::Enum(_) => write!(f, "enum"),
+            Self::EnumVariant(_) => write!(f, "enum variant"),
             Self::Name(n) => n.fmt(f),
         }
     }
@@ -457,6 +466,15 @@ pub fn not_a_struct_member<T: TreeToken>(name: &str, member: &Ident<T>) -> Diagn
     .with_highlight(member.span())
 }
 
+/// Creates a "not an enum variant" diagnostic.
+pub fn not_an_enum_variant<T: TreeToken>(name: &str, variant: &Ident<T>) -> Diagnostic {
+    Diagnostic::error(format!(
+        "enum `{name}` does not have a variant named `{variant}`",
+        variant = variant.text()
+    ))
+    .with_highlight(variant.span())
+}
+
 /// Creates a "not a pair accessor" diagnostic.
 pub fn not_a_pair_accessor<T: TreeToken>(name: &Ident<T>) -> Diagnostic {
     Diagnostic::error(format!(
@@ -805,3 +823,59 @@ pub fn invalid_regex_pattern(
     ))
     .with_label("invalid regular expression", span)
 }
+
+/// Creates a "division by zero" diagnostic.
+pub fn division_by_zero(span: Span, divisor_span: Span) -> Diagnostic {
+    Diagnostic::error("attempt to divide by zero")
+        .with_rule(CONSTANT_EVALUATION_RULE_ID)
+        .with_highlight(span)
+        .with_label("this expression is always zero", divisor_span)
+}
+
+/// Creates an "array index out of range" diagnostic.
+pub fn array_index_out_of_range(
+    index: i64,
+    count: usize,
+    span: Span,
+    target_span: Span,
+) -> Diagnostic {
+    Diagnostic::error(format!("array index {index} is out of range"))
+        .with_rule(CONSTANT_EVALUATION_RULE_ID)
+        .with_highlight(span)
+        .with_label(
+            if count == 0 {
+                "this array is empty".to_string()
+            } else {
+                format!(
+                    "this array has only {count} element{s}",
+                    s = if count == 1 { "" } else { "s" }
+                )
+            },
+            target_span,
+        )
+}
+
+/// Creates an "invalid memory requirement" diagnostic.
+pub fn invalid_memory_requirement(value: &str, span: Span) -> Diagnostic {
+    Diagnostic::error(format!(
+        "task specifies an invalid `memory` requirement `{value}`"
+    ))
+    .with_rule(CONSTANT_EVALUATION_RULE_ID)
+    .with_label("this evaluates to an invalid memory value", span)
+    .with_fix(
+        "specify an integer followed by a unit of `B`, `KB`, `MB`, `GB`, `TB`, `KiB`, `MiB`, \
+             `GiB`, or `TiB`",
+    )
+}
+
+/// Creates an "invalid container requirement" diagnostic.
+pub fn invalid_container_requirement(value: &str, span: Span) -> Diagnostic {
+    Diagnostic::error(format!(
+        "task specifies an invalid `container` requirement `{value}`"
+    ))
+    .with_rule(CONSTANT_EVALUATION_RULE_ID)
+    .with_label(
+        "this evaluates to a value that is never a valid container",
+        span,
+    )
+}
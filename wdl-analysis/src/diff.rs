@@ -0,0 +1,782 @@
+//! Detection of breaking changes between two versions of a document.
+//!
+//! The public interface of a WDL document consists of its tasks, its workflow,
+//! and its struct definitions; the [`diff`] function compares the interfaces of
+//! two analyzed documents and classifies each change as either breaking or
+//! non-breaking for the users of the document.
+
+use std::fmt;
+
+use indexmap::IndexMap;
+
+use crate::document::Document;
+use crate::document::Input;
+use crate::document::Output;
+use crate::types::Coercible;
+use crate::types::CompoundType;
+use crate::types::Optional;
+use crate::types::Type;
+
+/// Represents the kind of a document item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemKind {
+    /// The item is a task.
+    Task,
+    /// The item is a workflow.
+    Workflow,
+    /// The item is a struct.
+    Struct,
+}
+
+impl fmt::Display for ItemKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Task => write!(f, "task"),
+            Self::Workflow => write!(f, "workflow"),
+            Self::Struct => write!(f, "struct"),
+        }
+    }
+}
+
+/// Represents a change to the interface of a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// An item was added.
+    Added {
+        /// The kind of the item.
+        kind: ItemKind,
+        /// The name of the item.
+        name: String,
+    },
+    /// An item was removed.
+    Removed {
+        /// The kind of the item.
+        kind: ItemKind,
+        /// The name of the item.
+        name: String,
+    },
+    /// An item was renamed.
+    ///
+    /// A removed item is considered renamed when an added item of the same
+    /// kind has an identical interface.
+    Renamed {
+        /// The kind of the item.
+        kind: ItemKind,
+        /// The old name of the item.
+        old: String,
+        /// The new name of the item.
+        new: String,
+    },
+    /// An input was added to a task or workflow.
+    InputAdded {
+        /// The kind of the item.
+        kind: ItemKind,
+        /// The name of the item.
+        item: String,
+        /// The name of the input.
+        name: String,
+        /// Whether or not the new input is required.
+        required: bool,
+    },
+    /// An input was removed from a task or workflow.
+    InputRemoved {
+        /// The kind of the item.
+        kind: ItemKind,
+        /// The name of the item.
+        item: String,
+        /// The name of the input.
+        name: String,
+    },
+    /// An optional input of a task or workflow is now required.
+    InputRequired {
+        /// The kind of the item.
+        kind: ItemKind,
+        /// The name of the item.
+        item: String,
+        /// The name of the input.
+        name: String,
+    },
+    /// A required input of a task or workflow is now optional.
+    InputOptional {
+        /// The kind of the item.
+        kind: ItemKind,
+        /// The name of the item.
+        item: String,
+        /// The name of the input.
+        name: String,
+    },
+    /// The type of an input of a task or workflow changed.
+    InputTypeChanged {
+        /// The kind of the item.
+        kind: ItemKind,
+        /// The name of the item.
+        item: String,
+        /// The name of the input.
+        name: String,
+        /// The old type of the input.
+        old: Type,
+        /// The new type of the input.
+        new: Type,
+    },
+    /// An output was added to a task or workflow.
+    OutputAdded {
+        /// The kind of the item.
+        kind: ItemKind,
+        /// The name of the item.
+        item: String,
+        /// The name of the output.
+        name: String,
+    },
+    /// An output was removed from a task or workflow.
+    OutputRemoved {
+        /// The kind of the item.
+        kind: ItemKind,
+        /// The name of the item.
+        item: String,
+        /// The name of the output.
+        name: String,
+    },
+    /// The type of an output of a task or workflow changed.
+    OutputTypeChanged {
+        /// The kind of the item.
+        kind: ItemKind,
+        /// The name of the item.
+        item: String,
+        /// The name of the output.
+        name: String,
+        /// The old type of the output.
+        old: Type,
+        /// The new type of the output.
+        new: Type,
+    },
+    /// A member was added to a struct.
+    MemberAdded {
+        /// The name of the struct.
+        item: String,
+        /// The name of the member.
+        name: String,
+        /// The type of the member.
+        ty: Type,
+    },
+    /// A member was removed from a struct.
+    MemberRemoved {
+        /// The name of the struct.
+        item: String,
+        /// The name of the member.
+        name: String,
+    },
+    /// The type of a struct member changed.
+    MemberTypeChanged {
+        /// The name of the struct.
+        item: String,
+        /// The name of the member.
+        name: String,
+        /// The old type of the member.
+        old: Type,
+        /// The new type of the member.
+        new: Type,
+    },
+}
+
+impl Change {
+    /// Determines if the change is a breaking change.
+    ///
+    /// A change is breaking if an existing user of the document may fail to
+    /// call the changed task or workflow, to provide its inputs, to consume
+    /// its outputs, or to construct the changed struct.
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            Self::Added { .. } | Self::InputOptional { .. } | Self::OutputAdded { .. } => false,
+            Self::Removed { .. }
+            | Self::Renamed { .. }
+            | Self::InputRemoved { .. }
+            | Self::InputRequired { .. }
+            | Self::OutputRemoved { .. }
+            | Self::MemberRemoved { .. } => true,
+            Self::InputAdded { required, .. } => *required,
+            // Existing input values must still be accepted
+            Self::InputTypeChanged { old, new, .. } => !old.is_coercible_to(new),
+            // Existing consumers of the output must still accept its values
+            Self::OutputTypeChanged { old, new, .. } => !new.is_coercible_to(old),
+            // Existing struct values must still be constructible
+            Self::MemberAdded { ty, .. } => !ty.is_optional(),
+            // Struct values flow both into and out of tasks and workflows
+            Self::MemberTypeChanged { old, new, .. } => {
+                !old.is_coercible_to(new) || !new.is_coercible_to(old)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added { kind, name } => write!(f, "{kind} `{name}` was added"),
+            Self::Removed { kind, name } => write!(f, "{kind} `{name}` was removed"),
+            Self::Renamed { kind, old, new } => {
+                write!(f, "{kind} `{old}` was renamed to `{new}`")
+            }
+            Self::InputAdded {
+                kind,
+                item,
+                name,
+                required,
+            } => write!(
+                f,
+                "{required} input `{name}` was added to {kind} `{item}`",
+                required = if *required { "required" } else { "optional" }
+            ),
+            Self::InputRemoved { kind, item, name } => {
+                write!(f, "input `{name}` was removed from {kind} `{item}`")
+            }
+            Self::InputRequired { kind, item, name } => {
+                write!(f, "input `{name}` of {kind} `{item}` is now required")
+            }
+            Self::InputOptional { kind, item, name } => {
+                write!(f, "input `{name}` of {kind} `{item}` is now optional")
+            }
+            Self::InputTypeChanged {
+                kind,
+                item,
+                name,
+                old,
+                new,
+            } => write!(
+                f,
+                "type of input `{name}` of {kind} `{item}` changed from `{old}` to `{new}`"
+            ),
+            Self::OutputAdded { kind, item, name } => {
+                write!(f, "output `{name}` was added to {kind} `{item}`")
+            }
+            Self::OutputRemoved { kind, item, name } => {
+                write!(f, "output `{name}` was removed from {kind} `{item}`")
+            }
+            Self::OutputTypeChanged {
+                kind,
+                item,
+                name,
+                old,
+                new,
+            } => write!(
+                f,
+                "type of output `{name}` of {kind} `{item}` changed from `{old}` to `{new}`"
+            ),
+            Self::MemberAdded { item, name, .. } => {
+                write!(f, "member `{name}` was added to struct `{item}`")
+            }
+            Self::MemberRemoved { item, name } => {
+                write!(f, "member `{name}` was removed from struct `{item}`")
+            }
+            Self::MemberTypeChanged {
+                item,
+                name,
+                old,
+                new,
+            } => write!(
+                f,
+                "type of member `{name}` of struct `{item}` changed from `{old}` to `{new}`"
+            ),
+        }
+    }
+}
+
+/// Represents the interface of a task or workflow.
+struct Callable<'a> {
+    /// The name of the task or workflow.
+    name: &'a str,
+    /// The inputs of the task or workflow.
+    inputs: &'a IndexMap<String, Input>,
+    /// The outputs of the task or workflow.
+    outputs: &'a IndexMap<String, Output>,
+}
+
+impl Callable<'_> {
+    /// Determines if two callables have identical interfaces.
+    fn same_interface(&self, other: &Self) -> bool {
+        self.inputs.len() == other.inputs.len()
+            && self.outputs.len() == other.outputs.len()
+            && self.inputs.iter().all(|(name, input)| {
+                other
+                    .inputs
+                    .get(name)
+                    .map(|i| i.ty() == input.ty() && i.required() == input.required())
+                    .unwrap_or(false)
+            })
+            && self.outputs.iter().all(|(name, output)| {
+                other
+                    .outputs
+                    .get(name)
+                    .map(|o| o.ty() == output.ty())
+                    .unwrap_or(false)
+            })
+    }
+
+    /// Compares the interface of this callable to a newer version of it.
+    fn diff(&self, kind: ItemKind, new: &Self, changes: &mut Vec<Change>) {
+        let item = || new.name.to_string();
+
+        for (name, old) in self.inputs {
+            let Some(new) = new.inputs.get(name) else {
+                changes.push(Change::InputRemoved {
+                    kind,
+                    item: item(),
+                    name: name.clone(),
+                });
+                continue;
+            };
+
+            if old.ty() != new.ty() {
+                changes.push(Change::InputTypeChanged {
+                    kind,
+                    item: item(),
+                    name: name.clone(),
+                    old: old.ty().clone(),
+                    new: new.ty().clone(),
+                });
+            }
+
+            match (old.required(), new.required()) {
+                (false, true) => changes.push(Change::InputRequired {
+                    kind,
+                    item: item(),
+                    name: name.clone(),
+                }),
+                (true, false) => changes.push(Change::InputOptional {
+                    kind,
+                    item: item(),
+                    name: name.clone(),
+                }),
+                _ => {}
+            }
+        }
+
+        for (name, input) in new.inputs {
+            if !self.inputs.contains_key(name) {
+                changes.push(Change::InputAdded {
+                    kind,
+                    item: item(),
+                    name: name.clone(),
+                    required: input.required(),
+                });
+            }
+        }
+
+        for (name, old) in self.outputs {
+            match new.outputs.get(name) {
+                Some(new) if old.ty() != new.ty() => changes.push(Change::OutputTypeChanged {
+                    kind,
+                    item: item(),
+                    name: name.clone(),
+                    old: old.ty().clone(),
+                    new: new.ty().clone(),
+                }),
+                Some(_) => {}
+                None => changes.push(Change::OutputRemoved {
+                    kind,
+                    item: item(),
+                    name: name.clone(),
+                }),
+            }
+        }
+
+        for name in new.outputs.keys() {
+            if !self.outputs.contains_key(name) {
+                changes.push(Change::OutputAdded {
+                    kind,
+                    item: item(),
+                    name: name.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Compares the callables of one kind between two documents.
+fn diff_callables(
+    kind: ItemKind,
+    old: Vec<Callable<'_>>,
+    new: Vec<Callable<'_>>,
+    changes: &mut Vec<Change>,
+) {
+    let mut removed = Vec::new();
+    for callable in &old {
+        match new.iter().find(|c| c.name == callable.name) {
+            Some(new) => callable.diff(kind, new, changes),
+            None => removed.push(callable),
+        }
+    }
+
+    let mut added = new
+        .iter()
+        .filter(|c| !old.iter().any(|o| o.name == c.name))
+        .collect::<Vec<_>>();
+
+    // Detect renames as a removed and an added callable with the same interface
+    for callable in removed {
+        match added.iter().position(|c| c.same_interface(callable)) {
+            Some(index) => {
+                let new = added.remove(index);
+                changes.push(Change::Renamed {
+                    kind,
+                    old: callable.name.to_string(),
+                    new: new.name.to_string(),
+                });
+            }
+            None => changes.push(Change::Removed {
+                kind,
+                name: callable.name.to_string(),
+            }),
+        }
+    }
+
+    changes.extend(added.into_iter().map(|c| Change::Added {
+        kind,
+        name: c.name.to_string(),
+    }));
+}
+
+/// Gets the members of the local structs of a document.
+fn struct_members(document: &Document) -> IndexMap<&str, &IndexMap<String, Type>> {
+    document
+        .structs()
+        .filter(|(_, s)| s.namespace().is_none())
+        .filter_map(|(name, s)| match s.ty()? {
+            Type::Compound(CompoundType::Struct(ty), _) => Some((name, ty.members())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Compares the local structs between two documents.
+fn diff_structs(old: &Document, new: &Document, changes: &mut Vec<Change>) {
+    let old = struct_members(old);
+    let new = struct_members(new);
+
+    for (name, members) in &old {
+        let Some(new_members) = new.get(name) else {
+            changes.push(Change::Removed {
+                kind: ItemKind::Struct,
+                name: name.to_string(),
+            });
+            continue;
+        };
+
+        for (member, old_ty) in members.iter() {
+            match new_members.get(member) {
+                Some(new_ty) if old_ty != new_ty => changes.push(Change::MemberTypeChanged {
+                    item: name.to_string(),
+                    name: member.clone(),
+                    old: old_ty.clone(),
+                    new: new_ty.clone(),
+                }),
+                Some(_) => {}
+                None => changes.push(Change::MemberRemoved {
+                    item: name.to_string(),
+                    name: member.clone(),
+                }),
+            }
+        }
+
+        for (member, ty) in new_members.iter() {
+            if !members.contains_key(member) {
+                changes.push(Change::MemberAdded {
+                    item: name.to_string(),
+                    name: member.clone(),
+                    ty: ty.clone(),
+                });
+            }
+        }
+    }
+
+    for name in new.keys() {
+        if !old.contains_key(name) {
+            changes.push(Change::Added {
+                kind: ItemKind::Struct,
+                name: name.to_string(),
+            });
+        }
+    }
+}
+
+/// Gets the interfaces of the tasks of a document.
+fn tasks(document: &Document) -> Vec<Callable<'_>> {
+    document
+        .tasks()
+        .map(|t| Callable {
+            name: t.name(),
+            inputs: t.inputs(),
+            outputs: t.outputs(),
+        })
+        .collect()
+}
+
+/// Gets the interface of the workflow of a document.
+fn workflows(document: &Document) -> Vec<Callable<'_>> {
+    document
+        .workflow()
+        .map(|w| Callable {
+            name: w.name(),
+            inputs: w.inputs(),
+            outputs: w.outputs(),
+        })
+        .into_iter()
+        .collect()
+}
+
+/// Compares the interfaces of two versions of a document.
+///
+/// The interface of a document consists of its tasks, its workflow, and the
+/// structs defined in the document; imported structs are not compared.
+///
+/// Returns the changes from the old document to the new document; use
+/// [`Change::is_breaking`] to classify each change.
+pub fn diff(old: &Document, new: &Document) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    diff_callables(ItemKind::Task, tasks(old), tasks(new), &mut changes);
+    diff_callables(
+        ItemKind::Workflow,
+        workflows(old),
+        workflows(new),
+        &mut changes,
+    );
+
+    diff_structs(old, new, &mut changes);
+    changes
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::Analyzer;
+    use crate::DiagnosticsConfig;
+    use crate::path_to_uri;
+
+    /// Analyzes the given old and new versions of a document.
+    async fn analyze(old: &str, new: &str) -> (Document, Document) {
+        let dir = TempDir::new().expect("failed to create temporary directory");
+        let analyzer = Analyzer::new(DiagnosticsConfig::except_all(), |_: (), _, _, _| async {});
+
+        let mut uris = Vec::new();
+        for (name, source) in [("old.wdl", old), ("new.wdl", new)] {
+            let path = dir.path().join(name);
+            fs::write(&path, source).expect("failed to create test file");
+            let uri = path_to_uri(&path).expect("should convert to URI");
+            analyzer
+                .add_document(uri.clone())
+                .await
+                .expect("should add document");
+            uris.push(uri);
+        }
+
+        let results = analyzer.analyze(()).await.unwrap();
+        let document = |uri| {
+            results
+                .iter()
+                .find(|r| r.document().uri().as_ref() == uri)
+                .expect("should have result")
+                .document()
+                .clone()
+        };
+
+        (document(&uris[0]), document(&uris[1]))
+    }
+
+    #[tokio::test]
+    async fn it_detects_changes() {
+        let (old, new) = analyze(
+            r#"version 1.1
+
+struct Sample {
+    String name
+    Int count
+}
+
+struct Removed {
+    Int x
+}
+
+task align {
+    input {
+        File reads
+        Int threads = 1
+        String? prefix
+        Int retries = 0
+    }
+
+    command <<<>>>
+
+    output {
+        File bam = "out.bam"
+        Int count = 1
+    }
+}
+
+task old_name {
+    input {
+        String s
+    }
+
+    command <<<>>>
+}
+
+workflow main {
+    input {
+        String sample
+    }
+
+    output {
+        Int n = 1
+    }
+}
+"#,
+            r#"version 1.1
+
+struct Sample {
+    String name
+    Float count
+    String? note
+}
+
+task align {
+    input {
+        File reads
+        Float threads = 1
+        String prefix
+        String reference
+        Boolean? verbose
+    }
+
+    command <<<>>>
+
+    output {
+        File bam = "out.bam"
+        String count = "1"
+        File bai = "out.bai"
+    }
+}
+
+task new_name {
+    input {
+        String s
+    }
+
+    command <<<>>>
+}
+
+task added {
+    command <<<>>>
+}
+
+workflow main {
+    input {
+        String? sample
+    }
+
+    output {
+        Float n = 1
+    }
+}
+"#,
+        )
+        .await;
+
+        let changes = diff(&old, &new)
+            .into_iter()
+            .map(|c| (c.is_breaking(), c.to_string()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            changes,
+            [
+                (
+                    false,
+                    "type of input `threads` of task `align` changed from `Int` to `Float`"
+                        .to_string()
+                ),
+                (
+                    true,
+                    "type of input `prefix` of task `align` changed from `String?` to `String`"
+                        .to_string()
+                ),
+                (
+                    true,
+                    "input `prefix` of task `align` is now required".to_string()
+                ),
+                (
+                    true,
+                    "input `retries` was removed from task `align`".to_string()
+                ),
+                (
+                    true,
+                    "required input `reference` was added to task `align`".to_string()
+                ),
+                (
+                    false,
+                    "optional input `verbose` was added to task `align`".to_string()
+                ),
+                (
+                    true,
+                    "type of output `count` of task `align` changed from `Int` to `String`"
+                        .to_string()
+                ),
+                (false, "output `bai` was added to task `align`".to_string()),
+                (
+                    true,
+                    "task `old_name` was renamed to `new_name`".to_string()
+                ),
+                (false, "task `added` was added".to_string()),
+                (
+                    false,
+                    "type of input `sample` of workflow `main` changed from `String` to \
+                     `String?`"
+                        .to_string()
+                ),
+                (
+                    false,
+                    "input `sample` of workflow `main` is now optional".to_string()
+                ),
+                (
+                    true,
+                    "type of output `n` of workflow `main` changed from `Int` to `Float`"
+                        .to_string()
+                ),
+                (
+                    true,
+                    "type of member `count` of struct `Sample` changed from `Int` to `Float`"
+                        .to_string()
+                ),
+                (
+                    false,
+                    "member `note` was added to struct `Sample`".to_string()
+                ),
+                (true, "struct `Removed` was removed".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_detects_no_changes() {
+        let source = r#"version 1.1
+
+task t {
+    input {
+        Int x
+    }
+
+    command <<<>>>
+}
+
+workflow w {
+    call t
+}
+"#;
+        let (old, new) = analyze(source, source).await;
+        assert!(diff(&old, &new).is_empty());
+    }
+}
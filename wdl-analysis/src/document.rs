@@ -1,6 +1,7 @@
 //! Representation of analyzed WDL documents.
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::path::Path;
 use std::str::FromStr;
@@ -94,6 +95,14 @@ pub struct Struct {
 }
 
 impl Struct {
+    /// Gets the span that introduced the struct.
+    ///
+    /// This is either the name of a struct definition (local) or an import's
+    /// URI or alias (imported).
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
     /// Gets the namespace that defines this struct.
     ///
     /// Returns `None` for structs defined in the containing document or `Some`
@@ -111,6 +120,37 @@ impl Struct {
     }
 }
 
+/// Represents an enum in a document.
+#[derive(Debug, Clone)]
+pub struct Enum {
+    /// The span that introduced the enum.
+    ///
+    /// This is either the name of an enum definition (local) or an import's
+    /// URI or alias (imported).
+    span: Span,
+    /// The namespace that defines the enum.
+    ///
+    /// This is `Some` only for imported enums.
+    namespace: Option<String>,
+    /// The type of the enum.
+    ty: Type,
+}
+
+impl Enum {
+    /// Gets the namespace that defines this enum.
+    ///
+    /// Returns `None` for enums defined in the containing document or `Some`
+    /// for an enum introduced by an import.
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Gets the type of the enum.
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
 /// Represents information about a name in a scope.
 #[derive(Debug, Clone)]
 pub struct Name {
@@ -196,7 +236,7 @@ impl<'a> ScopeRef<'a> {
     }
 
     /// Gets all of the names available at this scope.
-    pub fn names(&self) -> impl Iterator<Item = (&str, &Name)> + use<'_> {
+    pub fn names(&self) -> impl Iterator<Item = (&'a str, &'a Name)> + use<'a> {
         self.scopes[self.index.0]
             .names
             .iter()
@@ -445,6 +485,11 @@ struct DocumentData {
     workflow: Option<Workflow>,
     /// The structs in the document.
     structs: IndexMap<String, Struct>,
+    /// The enums in the document.
+    enums: IndexMap<String, Enum>,
+    /// The evaluated types of the expressions in the document, keyed by the
+    /// span of each expression.
+    expr_types: BTreeMap<Span, Type>,
     /// The diagnostics for the document.
     diagnostics: Vec<Diagnostic>,
 }
@@ -466,6 +511,8 @@ impl DocumentData {
             tasks: Default::default(),
             workflow: Default::default(),
             structs: Default::default(),
+            enums: Default::default(),
+            expr_types: Default::default(),
             diagnostics,
         }
     }
@@ -642,6 +689,16 @@ impl Document {
         self.data.structs.get(name)
     }
 
+    /// Gets the enums in the document.
+    pub fn enums(&self) -> impl Iterator<Item = (&str, &Enum)> {
+        self.data.enums.iter().map(|(n, e)| (n.as_str(), e))
+    }
+
+    /// Gets an enum in the document by name.
+    pub fn enum_by_name(&self, name: &str) -> Option<&Enum> {
+        self.data.enums.get(name)
+    }
+
     /// Gets the analysis diagnostics for the document.
     pub fn diagnostics(&self) -> &[Diagnostic] {
         &self.data.diagnostics
@@ -736,6 +793,74 @@ impl Document {
         None
     }
 
+    /// Gets the evaluated type of the expression with the given span.
+    ///
+    /// Returns `None` if there is no expression with the given span or if the
+    /// type of the expression could not be determined.
+    pub fn expr_type(&self, span: Span) -> Option<&Type> {
+        self.data.expr_types.get(&span)
+    }
+
+    /// Gets the evaluated type of the innermost expression containing the
+    /// given position.
+    ///
+    /// Returns the span of the expression and its type.
+    ///
+    /// Returns `None` if the position is not within an expression with a known
+    /// type.
+    pub fn expr_type_at(&self, position: usize) -> Option<(Span, &Type)> {
+        // Expressions containing the position are nested, so the innermost has
+        // the greatest start; for equal starts, the innermost has the least end
+        let mut found: Option<(Span, &Type)> = None;
+        for (span, ty) in self
+            .data
+            .expr_types
+            .range(..=Span::new(position, usize::MAX - position))
+            .rev()
+        {
+            if let Some((f, _)) = found {
+                if span.start() != f.start() {
+                    break;
+                }
+            }
+
+            if span.contains(position) {
+                found = Some((*span, ty));
+            }
+        }
+
+        found
+    }
+
+    /// Gets the evaluated types of every expression in the document.
+    ///
+    /// The expressions are in order of their spans.
+    pub fn expr_types(&self) -> impl Iterator<Item = (Span, &Type)> {
+        self.data.expr_types.iter().map(|(s, t)| (*s, t))
+    }
+
+    /// Gets the names visible at the given position within the document.
+    ///
+    /// The names from the innermost scope come first; the types of names
+    /// declared within scatter and conditional statements are their promoted
+    /// types when the position is outside of the statement.
+    ///
+    /// Returns an empty collection if the position is not within a task or
+    /// workflow.
+    pub fn visible_names(&self, position: usize) -> Vec<(&str, &Name)> {
+        let mut names: IndexMap<&str, &Name> = IndexMap::new();
+        let mut scope = self.find_scope_by_position(position);
+        while let Some(current) = scope {
+            for (name, n) in current.names() {
+                names.entry(name).or_insert(n);
+            }
+
+            scope = current.parent();
+        }
+
+        names.into_iter().collect()
+    }
+
     /// Determines if the document, or any documents transitively imported by
     /// this document, has errors.
     ///
@@ -25,6 +25,7 @@ use wdl_ast::v1::CommandPart;
 use wdl_ast::v1::ConditionalStatement;
 use wdl_ast::v1::Decl;
 use wdl_ast::v1::DocumentItem;
+use wdl_ast::v1::EnumDefinition;
 use wdl_ast::v1::Expr;
 use wdl_ast::v1::ImportStatement;
 use wdl_ast::v1::ScatterStatement;
@@ -35,6 +36,7 @@ use wdl_ast::version::V1;
 
 use super::Document;
 use super::DocumentData;
+use super::Enum;
 use super::Input;
 use super::Namespace;
 use super::Output;
@@ -45,6 +47,7 @@ use super::Struct;
 use super::TASK_VAR_NAME;
 use super::Task;
 use super::Workflow;
+use crate::CONSTANT_EVALUATION_RULE_ID;
 use crate::DiagnosticsConfig;
 use crate::SyntaxNodeExt;
 use crate::UNUSED_CALL_RULE_ID;
@@ -65,6 +68,7 @@ use crate::diagnostics::invalid_relative_import;
 use crate::diagnostics::missing_call_input;
 use crate::diagnostics::name_conflict;
 use crate::diagnostics::namespace_conflict;
+use crate::diagnostics::no_common_type;
 use crate::diagnostics::non_empty_array_assignment;
 use crate::diagnostics::only_one_namespace;
 use crate::diagnostics::recursive_struct;
@@ -87,12 +91,15 @@ use crate::eval::v1::TaskGraphBuilder;
 use crate::eval::v1::TaskGraphNode;
 use crate::eval::v1::WorkflowGraphBuilder;
 use crate::eval::v1::WorkflowGraphNode;
+use crate::eval::v1::check_task;
+use crate::eval::v1::check_workflow;
 use crate::graph::DocumentGraph;
 use crate::graph::ParseState;
 use crate::types::CallKind;
 use crate::types::CallType;
 use crate::types::Coercible;
 use crate::types::CompoundType;
+use crate::types::EnumType;
 use crate::types::Optional;
 use crate::types::PrimitiveType;
 use crate::types::PromotionKind;
@@ -204,9 +211,9 @@ pub(crate) fn populate_document(
         "expected a supported V1 version"
     );
 
-    // First start by processing imports and struct definitions
+    // First start by processing imports, struct definitions, and enum definitions
     // This needs to be performed before processing tasks and workflows as
-    // declarations might reference an imported or locally-defined struct
+    // declarations might reference an imported or locally-defined struct or enum
     for item in ast.items() {
         match item {
             DocumentItem::Import(import) => {
@@ -215,6 +222,9 @@ pub(crate) fn populate_document(
             DocumentItem::Struct(s) => {
                 add_struct(document, &s);
             }
+            DocumentItem::Enum(e) => {
+                add_enum(document, &e);
+            }
             DocumentItem::Task(_) | DocumentItem::Workflow(_) => {
                 continue;
             }
@@ -238,7 +248,7 @@ pub(crate) fn populate_document(
                     workflow = Some(w.clone());
                 }
             }
-            DocumentItem::Import(_) | DocumentItem::Struct(_) => {
+            DocumentItem::Import(_) | DocumentItem::Struct(_) | DocumentItem::Enum(_) => {
                 continue;
             }
         }
@@ -306,7 +316,9 @@ fn add_namespace(
         .aliases()
         .filter_map(|a| {
             let (from, to) = a.names();
-            if !imported.data.structs.contains_key(from.text()) {
+            if !imported.data.structs.contains_key(from.text())
+                && !imported.data.enums.contains_key(from.text())
+            {
                 document.diagnostics.push(struct_not_in_document(&from));
                 return None;
             }
@@ -360,6 +372,45 @@ fn add_namespace(
             }
         }
     }
+
+    // Insert the imported document's enum definitions
+    for (name, e) in &imported.data.enums {
+        let (span, aliased_name) = aliases
+            .get(name)
+            .map(|n| (n.span(), n.text()))
+            .unwrap_or_else(|| (span, name));
+        match document.enums.get(aliased_name) {
+            Some(prev) => {
+                // Importing the same enum more than once is not a conflict
+                if prev.ty != e.ty {
+                    document.diagnostics.push(name_conflict(
+                        aliased_name,
+                        Context::Enum(span),
+                        Context::Enum(prev.span),
+                    ));
+                }
+            }
+            None => {
+                if let Some(prev) = document.structs.get(aliased_name) {
+                    document.diagnostics.push(name_conflict(
+                        aliased_name,
+                        Context::Enum(span),
+                        Context::Struct(prev.span),
+                    ));
+                    continue;
+                }
+
+                document.enums.insert(
+                    aliased_name.to_string(),
+                    Enum {
+                        span,
+                        namespace: Some(ns.clone()),
+                        ty: e.ty.clone(),
+                    },
+                );
+            }
+        }
+    }
 }
 
 /// Compares two structs for structural equality.
@@ -401,6 +452,15 @@ fn add_struct(document: &mut DocumentData, definition: &StructDefinition) {
         return;
     }
 
+    if let Some(prev) = document.enums.get(name.text()) {
+        document.diagnostics.push(name_conflict(
+            name.text(),
+            Context::Struct(name.span()),
+            Context::Enum(prev.span),
+        ));
+        return;
+    }
+
     // Ensure there are no duplicate members
     let mut members = IndexMap::new();
     for decl in definition.members() {
@@ -431,6 +491,67 @@ fn add_struct(document: &mut DocumentData, definition: &StructDefinition) {
     );
 }
 
+/// Adds an enum to the document.
+fn add_enum(document: &mut DocumentData, definition: &EnumDefinition) {
+    let name = definition.name();
+    if let Some(prev) = document.enums.get(name.text()) {
+        document.diagnostics.push(name_conflict(
+            name.text(),
+            Context::Enum(name.span()),
+            Context::Enum(prev.span),
+        ));
+        return;
+    }
+
+    if let Some(prev) = document.structs.get(name.text()) {
+        document.diagnostics.push(name_conflict(
+            name.text(),
+            Context::Enum(name.span()),
+            Context::Struct(prev.span),
+        ));
+        return;
+    }
+
+    // Ensure there are no duplicate variants
+    let mut variants = IndexMap::new();
+    for variant in definition.variants() {
+        let name = variant.name();
+        match variants.get(name.text()) {
+            Some(prev_span) => {
+                document.diagnostics.push(name_conflict(
+                    name.text(),
+                    Context::EnumVariant(name.span()),
+                    Context::EnumVariant(*prev_span),
+                ));
+            }
+            _ => {
+                variants.insert(name.text().to_string(), name.span());
+            }
+        }
+    }
+
+    document.enums.insert(
+        name.text().to_string(),
+        Enum {
+            span: name.span(),
+            namespace: None,
+            ty: EnumType::new(name.text(), variants.into_keys()).into(),
+        },
+    );
+}
+
+/// Resolves the type of an enum in the document by name.
+///
+/// The namespace of an imported enum is marked as used.
+fn resolve_enum_type(document: &mut DocumentData, name: &str) -> Option<Type> {
+    let e = document.enums.get(name)?;
+    if let Some(ns) = &e.namespace {
+        document.namespaces[ns].used = true;
+    }
+
+    Some(e.ty.clone())
+}
+
 /// Converts an AST type to an analysis type.
 fn convert_ast_type(document: &mut DocumentData, ty: &wdl_ast::v1::Type) -> Type {
     /// Used to resolve a type name from a document.
@@ -449,6 +570,7 @@ fn convert_ast_type(document: &mut DocumentData, ty: &wdl_ast::v1::Type) -> Type
 
                     s.ty().expect("struct should have type").clone()
                 })
+                .or_else(|| resolve_enum_type(self.0, name))
                 .ok_or_else(|| unknown_type(name, span))
         }
     }
@@ -565,11 +687,13 @@ fn add_task(config: DiagnosticsConfig, document: &mut DocumentData, definition:
     };
 
     // Process the task in evaluation order
-    let graph = TaskGraphBuilder::default().build(
-        document.version.unwrap(),
-        definition,
-        &mut document.diagnostics,
-    );
+    let graph = TaskGraphBuilder::default()
+        .with_enums(document.enums.keys().cloned())
+        .build(
+            document.version.unwrap(),
+            definition,
+            &mut document.diagnostics,
+        );
 
     let mut task = Task {
         name_span: name.span(),
@@ -742,6 +866,18 @@ fn add_task(config: DiagnosticsConfig, document: &mut DocumentData, definition:
         }
     }
 
+    // Report any constant expressions that are guaranteed to fail at runtime
+    if let Some(severity) = config.constant_evaluation {
+        if !definition
+            .inner()
+            .is_rule_excepted(CONSTANT_EVALUATION_RULE_ID)
+        {
+            document
+                .diagnostics
+                .extend(check_task(definition, severity));
+        }
+    }
+
     // Sort the scopes
     sort_scopes(&mut task.scopes);
     document.tasks.insert(name.text().to_string(), task);
@@ -853,8 +989,9 @@ fn populate_workflow(
 
     // For static analysis, we don't need to provide inputs to the workflow graph
     // builder
-    let graph =
-        WorkflowGraphBuilder::default().build(workflow, &mut document.diagnostics, |_| false);
+    let graph = WorkflowGraphBuilder::default()
+        .with_enums(document.enums.keys().cloned())
+        .build(workflow, &mut document.diagnostics, |_| false);
 
     for index in toposort(&graph, None).expect("graph should be acyclic") {
         match graph[index].clone() {
@@ -1015,11 +1152,17 @@ fn populate_workflow(
                 }
             }
             WorkflowGraphNode::ExitConditional(statement) => {
-                let scope_index = scope_indexes
-                    .get(statement.inner())
-                    .copied()
-                    .expect("should have scope");
-                promote_scope(&mut scopes, scope_index, None, PromotionKind::Conditional);
+                // The statement is exhaustive if it ends with an `else` clause
+                let exhaustive = statement
+                    .else_clauses()
+                    .last()
+                    .map(|c| c.expr().is_none())
+                    .unwrap_or(false);
+                let branches = std::iter::once(statement.inner().clone())
+                    .chain(statement.else_clauses().map(|c| c.inner().clone()))
+                    .map(|n| scope_indexes.get(&n).copied().expect("should have scope"))
+                    .collect::<Vec<_>>();
+                promote_branches(document, &mut scopes, &branches, exhaustive);
             }
             WorkflowGraphNode::ExitScatter(statement) => {
                 let scope_index = scope_indexes
@@ -1037,6 +1180,18 @@ fn populate_workflow(
         }
     }
 
+    // Report any constant expressions that are guaranteed to fail at runtime
+    if let Some(severity) = config.constant_evaluation {
+        if !workflow
+            .inner()
+            .is_rule_excepted(CONSTANT_EVALUATION_RULE_ID)
+        {
+            document
+                .diagnostics
+                .extend(check_workflow(workflow, severity));
+        }
+    }
+
     // Sort the scopes
     sort_scopes(&mut scopes);
 
@@ -1078,6 +1233,36 @@ fn add_conditional_statement(
             .diagnostics
             .push(if_conditional_mismatch(&ty, expr.span()));
     }
+
+    // Each else clause introduces a scope of its own
+    for clause in statement.else_clauses() {
+        let scope_index = add_scope(
+            scopes,
+            Scope::new(
+                Some(parent),
+                clause
+                    .braced_scope_span()
+                    .expect("should have braced scope span"),
+            ),
+        );
+        scope_indexes.insert(clause.inner().clone(), scope_index);
+
+        // The expression of an `else if` clause is also expected to be a boolean
+        let Some(expr) = clause.expr() else {
+            continue;
+        };
+
+        let mut context =
+            EvaluationContext::new(document, ScopeRef::new(scopes, scope_index), config);
+        let mut evaluator = ExprTypeEvaluator::new(&mut context);
+        let ty = evaluator.evaluate_expr(&expr).unwrap_or(Type::Union);
+
+        if !ty.is_coercible_to(&PrimitiveType::Boolean.into()) {
+            document
+                .diagnostics
+                .push(if_conditional_mismatch(&ty, expr.span()));
+        }
+    }
 }
 
 /// Adds a scatter statement to the current scope.
@@ -1335,6 +1520,60 @@ fn promote_scope(scopes: &mut [Scope], index: ScopeIndex, skip: Option<&str>, ki
     }
 }
 
+/// Promotes the names in the branch scopes of a conditional statement to the
+/// parent scope.
+///
+/// A name declared in every branch of a conditional statement that ends with
+/// an `else` clause is promoted with the common type of its declarations;
+/// otherwise, the name is promoted as optional.
+fn promote_branches(
+    document: &mut DocumentData,
+    scopes: &mut [Scope],
+    branches: &[ScopeIndex],
+    exhaustive: bool,
+) {
+    // Merge the names from each of the branches, counting the number of branches
+    // that declare each name
+    let mut merged: IndexMap<String, (Name, usize)> = IndexMap::new();
+    for index in branches {
+        for (name, n) in scopes[index.0].names.iter() {
+            let Some((prev, count)) = merged.get_mut(name) else {
+                merged.insert(name.clone(), (n.clone(), 1));
+                continue;
+            };
+
+            *count += 1;
+            let ty = match (&prev.ty, &n.ty) {
+                (Type::Call(a), Type::Call(b)) => (a == b).then(|| prev.ty.clone()),
+                (a, b) => a.common_type(b),
+            };
+
+            match ty {
+                Some(ty) => prev.ty = ty,
+                None => document
+                    .diagnostics
+                    .push(no_common_type(&prev.ty, prev.span, &n.ty, n.span)),
+            }
+        }
+    }
+
+    let parent = scopes[branches[0].0]
+        .parent
+        .expect("should have a parent scope");
+    for (name, (Name { span, ty }, count)) in merged {
+        let ty = if exhaustive && count == branches.len() {
+            ty
+        } else {
+            ty.promote(PromotionKind::Conditional)
+        };
+
+        scopes[parent.0]
+            .names
+            .entry(name)
+            .or_insert(Name { span, ty });
+    }
+}
+
 /// Resolves an import to its document.
 fn resolve_import(
     graph: &DocumentGraph,
@@ -1353,7 +1592,7 @@ fn resolve_import(
         }
     };
 
-    let uri = match graph.get(importer_index).uri().join(text.text()) {
+    let uri = match graph.resolve_import(importer_index, text.text()) {
         Ok(uri) => uri,
         Err(e) => return Err(Some(invalid_relative_import(&e, span))),
     };
@@ -1425,6 +1664,9 @@ fn set_struct_types(document: &mut DocumentData) {
 
                     Ok(s.ty().cloned().unwrap_or(Type::Union))
                 }
+                _ if self.document.enums.contains_key(name) => {
+                    Ok(resolve_enum_type(self.document, name).expect("enum should exist"))
+                }
                 _ => {
                     self.document.diagnostics.push(unknown_type(
                         name,
@@ -1571,6 +1813,7 @@ impl crate::types::v1::EvaluationContext for EvaluationContext<'_> {
 
                 s.ty().expect("struct should have type").clone()
             })
+            .or_else(|| resolve_enum_type(self.document, name))
             .ok_or_else(|| unknown_type(name, span))
     }
 
@@ -1585,6 +1828,10 @@ impl crate::types::v1::EvaluationContext for EvaluationContext<'_> {
     fn add_diagnostic(&mut self, diagnostic: Diagnostic) {
         self.document.diagnostics.push(diagnostic);
     }
+
+    fn record_expr_type(&mut self, span: Span, ty: &Type) {
+        self.document.expr_types.insert(span, ty.clone());
+    }
 }
 
 /// Performs a type check of an expression.
@@ -0,0 +1,483 @@
+//! Workspace-wide duplicate task detection.
+//!
+//! Duplicate task detection operates on the results of analyzing a set of
+//! documents: each task is normalized by removing trivia (whitespace and
+//! comments) and its metadata sections and by renaming the task and the names
+//! it declares, and the normalized tasks are then compared to find tasks that
+//! are identical or highly similar to one another.
+
+use std::collections::HashMap;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use sha2::Digest;
+use sha2::Sha256;
+use url::Url;
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::Diagnostic;
+use wdl_ast::Span;
+use wdl_ast::SyntaxKind;
+use wdl_ast::v1::Decl;
+use wdl_ast::v1::DocumentItem;
+use wdl_ast::v1::TaskDefinition;
+
+use crate::AnalysisResult;
+
+/// The rule identifier for duplicate task diagnostics.
+pub const DUPLICATE_TASK_RULE_ID: &str = "DuplicateTask";
+
+/// The number of consecutive normalized tokens in a shingle.
+///
+/// Shingles are used to compute the similarity of two tasks that are not
+/// identical.
+const SHINGLE_SIZE: usize = 3;
+
+/// Represents the location of a task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskLocation {
+    /// The URI of the document containing the task.
+    uri: Arc<Url>,
+    /// The name of the task.
+    name: String,
+    /// The span of the task's name.
+    span: Span,
+}
+
+impl TaskLocation {
+    /// Gets the URI of the document containing the task.
+    pub fn uri(&self) -> &Arc<Url> {
+        &self.uri
+    }
+
+    /// Gets the name of the task.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the span of the task's name within its document.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// Represents a pair of tasks that are identical or highly similar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateTask {
+    /// The location of the task that appears first.
+    first: TaskLocation,
+    /// The location of the task that duplicates the first task.
+    second: TaskLocation,
+    /// The similarity of the two tasks, from `0.0` to `1.0`.
+    similarity: f64,
+    /// Whether or not the normalized tasks are identical.
+    exact: bool,
+}
+
+impl DuplicateTask {
+    /// Gets the location of the task that appears first.
+    pub fn first(&self) -> &TaskLocation {
+        &self.first
+    }
+
+    /// Gets the location of the task that duplicates the first task.
+    pub fn second(&self) -> &TaskLocation {
+        &self.second
+    }
+
+    /// Gets the similarity of the two tasks, from `0.0` to `1.0`.
+    ///
+    /// The similarity of identical tasks is `1.0`.
+    pub fn similarity(&self) -> f64 {
+        self.similarity
+    }
+
+    /// Determines if the two tasks are identical once normalized.
+    pub fn is_exact(&self) -> bool {
+        self.exact
+    }
+
+    /// Converts the duplicate into a diagnostic.
+    ///
+    /// The diagnostic is relative to the document containing the second task.
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        let first = if self.first.uri == self.second.uri {
+            format!("task `{name}`", name = self.first.name)
+        } else {
+            format!(
+                "task `{name}` in `{uri}`",
+                name = self.first.name,
+                uri = self.first.uri
+            )
+        };
+
+        let message = if self.exact {
+            format!(
+                "task `{name}` is a duplicate of {first}",
+                name = self.second.name
+            )
+        } else {
+            format!(
+                "task `{name}` is {percent:.0}% similar to {first}",
+                name = self.second.name,
+                percent = self.similarity * 100.0
+            )
+        };
+
+        let diagnostic = Diagnostic::warning(message)
+            .with_rule(DUPLICATE_TASK_RULE_ID)
+            .with_highlight(self.second.span)
+            .with_fix("remove the duplicate task and call or import the original task instead");
+
+        if self.first.uri == self.second.uri {
+            diagnostic.with_label("this task is duplicated", self.first.span)
+        } else {
+            diagnostic
+        }
+    }
+}
+
+/// Represents a task that has been normalized for comparison.
+struct NormalizedTask {
+    /// The location of the task.
+    location: TaskLocation,
+    /// The hash of the normalized tokens of the task.
+    fingerprint: [u8; 32],
+    /// The number of normalized tokens in the task.
+    len: usize,
+    /// The number of occurrences of each shingle of the task.
+    shingles: HashMap<u64, usize>,
+}
+
+impl NormalizedTask {
+    /// Normalizes the given task.
+    ///
+    /// Whitespace, comments, and the `meta` and `parameter_meta` sections are
+    /// removed; the task's name and the names of its declarations are replaced
+    /// by their order of first appearance; and the text of the command is split
+    /// into whitespace-separated words.
+    fn new(uri: &Arc<Url>, task: &TaskDefinition) -> Self {
+        let name = task.name();
+        let mut renames = HashMap::new();
+        renames.insert(name.text().to_string(), 0);
+        for node in task.inner().descendants() {
+            if let Some(decl) = Decl::cast(node) {
+                let next = renames.len();
+                renames
+                    .entry(decl.name().text().to_string())
+                    .or_insert(next);
+            }
+        }
+
+        let mut tokens = Vec::new();
+        for token in task
+            .inner()
+            .descendants_with_tokens()
+            .filter_map(|e| e.into_token())
+        {
+            if token.parent_ancestors().any(|n| {
+                matches!(
+                    n.kind(),
+                    SyntaxKind::MetadataSectionNode | SyntaxKind::ParameterMetadataSectionNode
+                )
+            }) {
+                continue;
+            }
+
+            match token.kind() {
+                SyntaxKind::Whitespace | SyntaxKind::Comment => {}
+                SyntaxKind::Ident => match renames.get(token.text()) {
+                    Some(index) => tokens.push(format!("${index}")),
+                    None => tokens.push(token.text().to_string()),
+                },
+                SyntaxKind::LiteralCommandText => {
+                    tokens.extend(token.text().split_whitespace().map(str::to_string))
+                }
+                _ => tokens.push(token.text().to_string()),
+            }
+        }
+
+        let mut hasher = Sha256::new();
+        for token in &tokens {
+            hasher.update(token.as_bytes());
+            hasher.update([0]);
+        }
+
+        let mut shingles = HashMap::new();
+        for window in tokens.windows(SHINGLE_SIZE.min(tokens.len()).max(1)) {
+            let mut hasher = DefaultHasher::new();
+            window.hash(&mut hasher);
+            *shingles.entry(hasher.finish()).or_default() += 1;
+        }
+
+        Self {
+            location: TaskLocation {
+                uri: uri.clone(),
+                name: name.text().to_string(),
+                span: name.span(),
+            },
+            fingerprint: hasher.finalize().into(),
+            len: tokens.len(),
+            shingles,
+        }
+    }
+
+    /// Computes the similarity of this task to another task.
+    ///
+    /// The similarity is the weighted Jaccard index of the shingles of the two
+    /// tasks.
+    fn similarity(&self, other: &Self) -> f64 {
+        let mut intersection = 0;
+        let mut union = 0;
+        for (shingle, count) in &self.shingles {
+            let other = other.shingles.get(shingle).copied().unwrap_or(0);
+            intersection += (*count).min(other);
+            union += (*count).max(other);
+        }
+
+        union += other
+            .shingles
+            .iter()
+            .filter(|(shingle, _)| !self.shingles.contains_key(*shingle))
+            .map(|(_, count)| *count)
+            .sum::<usize>();
+
+        if union == 0 {
+            return 1.0;
+        }
+
+        intersection as f64 / union as f64
+    }
+}
+
+/// Finds the tasks that are identical or highly similar to one another.
+///
+/// Every task of the given analysis results is compared with every other
+/// task, including the tasks of the same document. Tasks are compared after
+/// normalization: whitespace, comments, and metadata sections are ignored and
+/// the names of the tasks and their declarations are replaced so that tasks
+/// that differ only by the names they use are identical.
+///
+/// A pair of tasks is reported when the similarity of the tasks is at least
+/// the given threshold (from `0.0` to `1.0`); identical tasks always have a
+/// similarity of `1.0`.
+///
+/// The returned pairs are in the order of the given analysis results and the
+/// order of the tasks within each document; the first task of each pair
+/// appears before the second.
+pub fn find_duplicate_tasks(results: &[AnalysisResult], threshold: f64) -> Vec<DuplicateTask> {
+    let mut tasks = Vec::new();
+    for document in results.iter().map(|r| r.document()) {
+        let Some(ast) = document.root().ast().into_v1() else {
+            continue;
+        };
+
+        tasks.extend(ast.items().filter_map(|item| match item {
+            DocumentItem::Task(task) => Some(NormalizedTask::new(document.uri(), &task)),
+            _ => None,
+        }));
+    }
+
+    let mut duplicates = Vec::new();
+    for (i, first) in tasks.iter().enumerate() {
+        for second in &tasks[i + 1..] {
+            let (exact, similarity) = if first.fingerprint == second.fingerprint {
+                (true, 1.0)
+            } else {
+                // The similarity cannot exceed the ratio of the task sizes
+                let ratio = first.len.min(second.len) as f64 / first.len.max(second.len) as f64;
+                if ratio < threshold {
+                    continue;
+                }
+
+                (false, first.similarity(second))
+            };
+
+            if similarity >= threshold {
+                duplicates.push(DuplicateTask {
+                    first: first.location.clone(),
+                    second: second.location.clone(),
+                    similarity,
+                    exact,
+                });
+            }
+        }
+    }
+
+    duplicates
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::Analyzer;
+    use crate::DiagnosticsConfig;
+    use crate::path_to_uri;
+
+    #[tokio::test]
+    async fn it_finds_duplicate_tasks() {
+        let dir = TempDir::new().expect("failed to create temporary directory");
+        let files = [
+            (
+                "a.wdl",
+                r#"version 1.1
+
+task count_lines {
+    meta {
+        description: "Counts the lines of a file"
+    }
+
+    input {
+        File file
+    }
+
+    command <<<
+        wc -l < ~{file} > count.txt
+    >>>
+
+    output {
+        Int count = read_int("count.txt")
+    }
+}
+
+task sort_file {
+    input {
+        File file
+        Boolean reverse = false
+    }
+
+    command <<<
+        sort ~{if reverse then "-r" else ""} ~{file} > sorted.txt
+    >>>
+
+    output {
+        File sorted = "sorted.txt"
+    }
+
+    runtime {
+        container: "ubuntu:22.04"
+    }
+}
+"#,
+            ),
+            (
+                "b.wdl",
+                r#"version 1.1
+
+# A copy of `count_lines`
+task line_count {
+    input {
+        File input_file
+    }
+
+    command <<<
+        wc   -l < ~{input_file} > count.txt
+    >>>
+
+    output {
+        Int lines = read_int("count.txt")
+    }
+}
+
+task sort_lines {
+    input {
+        File file
+        Boolean reverse = false
+    }
+
+    command <<<
+        sort ~{if reverse then "-r" else ""} ~{file} > sorted.txt
+    >>>
+
+    output {
+        File sorted = "sorted.txt"
+    }
+
+    runtime {
+        container: "ubuntu:24.04"
+    }
+}
+
+task unrelated {
+    input {
+        String message
+    }
+
+    command <<<
+        echo "~{message}"
+    >>>
+
+    output {
+        String out = read_string(stdout())
+    }
+}
+"#,
+            ),
+        ];
+
+        for (name, source) in files {
+            fs::write(dir.path().join(name), source).expect("failed to create test file");
+        }
+
+        let analyzer = Analyzer::new(DiagnosticsConfig::except_all(), |_: (), _, _, _| async {});
+        analyzer
+            .add_directory(dir.path().to_path_buf())
+            .await
+            .expect("should add directory");
+        let mut results = analyzer.analyze(()).await.unwrap();
+        results.sort_by(|a, b| a.document().uri().cmp(b.document().uri()));
+
+        let uri = |name: &str| path_to_uri(dir.path().join(name)).unwrap();
+        let duplicates = find_duplicate_tasks(&results, 0.8);
+        assert_eq!(
+            duplicates
+                .iter()
+                .map(|d| {
+                    (
+                        d.first().uri().as_ref().clone(),
+                        d.first().name().to_string(),
+                        d.second().uri().as_ref().clone(),
+                        d.second().name().to_string(),
+                        d.is_exact(),
+                    )
+                })
+                .collect::<Vec<_>>(),
+            [
+                (
+                    uri("a.wdl"),
+                    "count_lines".to_string(),
+                    uri("b.wdl"),
+                    "line_count".to_string(),
+                    true
+                ),
+                (
+                    uri("a.wdl"),
+                    "sort_file".to_string(),
+                    uri("b.wdl"),
+                    "sort_lines".to_string(),
+                    false
+                ),
+            ]
+        );
+
+        assert_eq!(duplicates[0].similarity(), 1.0);
+        assert!(duplicates[1].similarity() >= 0.8 && duplicates[1].similarity() < 1.0);
+        assert_eq!(
+            duplicates[0].to_diagnostic().message(),
+            format!(
+                "task `line_count` is a duplicate of task `count_lines` in `{uri}`",
+                uri = uri("a.wdl")
+            )
+        );
+
+        // A threshold of one only reports identical tasks
+        assert_eq!(find_duplicate_tasks(&results, 1.0).len(), 1);
+    }
+}
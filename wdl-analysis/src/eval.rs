@@ -2,5 +2,8 @@
 //!
 //! Evaluation graphs are used to process the items in a task or workflow in
 //! topological order.
+//!
+//! The module also implements the static evaluation of constant expressions
+//! and the exporting of workflow evaluation graphs.
 
 pub mod v1;
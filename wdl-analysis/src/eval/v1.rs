@@ -1,6 +1,7 @@
 //! Evaluation graphs for WDL 1.x.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt;
 
 use petgraph::algo::DfsSpace;
@@ -17,6 +18,7 @@ use wdl_ast::SyntaxKind;
 use wdl_ast::SyntaxNode;
 use wdl_ast::TokenText;
 use wdl_ast::TreeNode;
+use wdl_ast::v1::AccessExpr;
 use wdl_ast::v1::CallStatement;
 use wdl_ast::v1::CommandPart;
 use wdl_ast::v1::CommandSection;
@@ -44,6 +46,24 @@ use crate::diagnostics::unknown_name;
 use crate::diagnostics::workflow_reference_cycle;
 use crate::document::TASK_VAR_NAME;
 
+mod constant;
+mod export;
+
+pub use constant::*;
+pub use export::*;
+
+/// Determines if a name reference is the target of an access expression.
+///
+/// An unknown name that is the target of an access expression may refer to an
+/// enum (e.g. `Color.Red`).
+fn is_access_target<N: TreeNode>(r: &NameRefExpr<N>) -> bool {
+    r.inner()
+        .parent()
+        .and_then(AccessExpr::cast)
+        .map(|a| a.operands().0.inner() == r.inner())
+        .unwrap_or(false)
+}
+
 /// Represents a node in an task evaluation graph.
 #[derive(Debug, Clone)]
 pub enum TaskGraphNode<N: TreeNode = SyntaxNode> {
@@ -114,11 +134,22 @@ pub struct TaskGraphBuilder<N: TreeNode = SyntaxNode> {
     requirements: Option<NodeIndex>,
     /// The hints node index.
     hints: Option<NodeIndex>,
+    /// The names of the enums that may be referenced by expressions.
+    enums: HashSet<String>,
     /// Space for DFS operations when building the graph.
     space: DfsSpace<NodeIndex, <DiGraph<TaskGraphNode<N>, ()> as Visitable>::Map>,
 }
 
 impl<N: TreeNode> TaskGraphBuilder<N> {
+    /// Sets the names of the enums that may be referenced by expressions.
+    ///
+    /// A name that is the target of an access expression and is not a
+    /// declaration is not reported as unknown if it is an enum name.
+    pub fn with_enums(mut self, enums: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.enums = enums.into_iter().map(Into::into).collect();
+        self
+    }
+
     /// Builds a new task evaluation graph.
     ///
     /// The nodes are [`TaskGraphNode`] and the edges represent a reverse
@@ -295,6 +326,7 @@ impl<N: TreeNode> TaskGraphBuilder<N> {
                 Some(to) => {
                     graph.update_edge(*to, from, false);
                 }
+                _ if is_access_target(&r) && self.enums.contains(name.text()) => continue,
                 _ => {
                     if name.text() != TASK_VAR_NAME || !allow_task_var {
                         diagnostics.push(unknown_name(name.text(), name.span()));
@@ -415,6 +447,7 @@ impl<N: TreeNode> TaskGraphBuilder<N> {
 
                     graph.update_edge(*to, from, false);
                 }
+                _ if is_access_target(&r) && self.enums.contains(name.text()) => continue,
                 _ => {
                     if name.text() != TASK_VAR_NAME || !allow_task_var {
                         diagnostics.push(unknown_name(name.text(), name.span()));
@@ -433,6 +466,7 @@ impl<N: TreeNode> Default for TaskGraphBuilder<N> {
             runtime: Default::default(),
             requirements: Default::default(),
             hints: Default::default(),
+            enums: Default::default(),
             space: Default::default(),
         }
     }
@@ -543,6 +577,12 @@ impl<N: TreeNode> fmt::Display for WorkflowGraphNode<N> {
 pub struct WorkflowGraphBuilder<N: TreeNode = SyntaxNode> {
     /// The map of declaration names to node indexes in the graph.
     names: HashMap<TokenText<N::Token>, NodeIndex>,
+    /// A map of conditional branch nodes to the names declared in the branch.
+    ///
+    /// A name may be declared in more than one branch of a conditional
+    /// statement with `else` clauses; the first declaration is stored in
+    /// `names` and the subsequent declarations are stored here.
+    branch_names: HashMap<N, HashMap<TokenText<N::Token>, NodeIndex>>,
     /// A stack of scatter variable names.
     variables: Vec<Ident<N::Token>>,
     /// A map of AST syntax nodes to their entry and exit nodes in the graph.
@@ -554,9 +594,20 @@ pub struct WorkflowGraphBuilder<N: TreeNode = SyntaxNode> {
     space: DfsSpace<NodeIndex, <DiGraph<WorkflowGraphNode<N>, ()> as Visitable>::Map>,
     /// The common ancestor finder used when building the graph.
     ancestor_finder: CommonAncestorFinder<N>,
+    /// The names of the enums that may be referenced by expressions.
+    enums: HashSet<String>,
 }
 
 impl<N: TreeNode> WorkflowGraphBuilder<N> {
+    /// Sets the names of the enums that may be referenced by expressions.
+    ///
+    /// A name that is the target of an access expression and is not a
+    /// declaration is not reported as unknown if it is an enum name.
+    pub fn with_enums(mut self, enums: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.enums = enums.into_iter().map(Into::into).collect();
+        self
+    }
+
     /// Builds a new workflow evaluation graph.
     ///
     /// The nodes are [`WorkflowGraphNode`] and the edges represent a reverse
@@ -661,11 +712,22 @@ impl<N: TreeNode> WorkflowGraphBuilder<N> {
                 self.entry_exits
                     .insert(statement.inner().clone(), (entry, exit));
 
-                // Add all of the statement's statements
+                // Add all of the statement's statements, including those of any else clauses
                 for statement in statement.statements() {
                     self.add_workflow_statement(statement, Some((entry, exit)), graph, diagnostics);
                 }
 
+                for clause in statement.else_clauses() {
+                    for statement in clause.statements() {
+                        self.add_workflow_statement(
+                            statement,
+                            Some((entry, exit)),
+                            graph,
+                            diagnostics,
+                        );
+                    }
+                }
+
                 Some((entry, exit))
             }
             WorkflowStatement::Scatter(statement) => {
@@ -756,20 +818,22 @@ impl<N: TreeNode> WorkflowGraphBuilder<N> {
         diagnostics: &mut Vec<Diagnostic>,
     ) -> Option<NodeIndex> {
         // Check for a conflicting name, either from a declaration or from a scatter
-        // variable
-        let (context, cont) = match self.names.get(name.text()) {
-            Some(existing) => {
+        // variable; declarations in different branches of a conditional statement do
+        // not conflict
+        let mut branch = None;
+        let (context, cont) = match self.find_conflicting_node(name.text(), node.inner(), graph) {
+            Ok(Some(b)) => {
+                branch = Some(b);
+                (None, true)
+            }
+            Err(existing) => {
                 // Conflict with a declaration
                 (
-                    Some(
-                        graph[*existing]
-                            .context()
-                            .expect("node should have context"),
-                    ),
+                    Some(graph[existing].context().expect("node should have context")),
                     false,
                 )
             }
-            _ => {
+            Ok(None) => {
                 match self.variables.iter().find(|i| i.text() == name.text()) {
                     Some(existing) => {
                         // Conflict with a scatter variable; we continue to add the node so that any
@@ -805,10 +869,62 @@ impl<N: TreeNode> WorkflowGraphBuilder<N> {
         }
 
         let index = graph.add_node(node);
-        self.names.insert(name.hashable(), index);
+        match branch {
+            Some(branch) => {
+                self.branch_names
+                    .entry(branch)
+                    .or_default()
+                    .insert(name.hashable(), index);
+            }
+            None => {
+                self.names.insert(name.hashable(), index);
+            }
+        }
+
         Some(index)
     }
 
+    /// Finds an existing node that conflicts with a new node of the given name.
+    ///
+    /// Returns `Err` with the conflicting node if there is a conflict.
+    ///
+    /// Returns `Ok(Some)` with the branch to store the name in if the name is
+    /// only declared in other branches of a conditional statement.
+    ///
+    /// Returns `Ok(None)` if the name has not been declared.
+    fn find_conflicting_node(
+        &self,
+        name: &str,
+        node: &N,
+        graph: &DiGraph<WorkflowGraphNode<N>, ()>,
+    ) -> Result<Option<N>, NodeIndex> {
+        let branches = conditional_branches(node);
+        let existing = branches
+            .iter()
+            .rev()
+            .filter_map(|(_, b)| self.branch_names.get(b)?.get(name))
+            .chain(self.names.get(name));
+
+        // Use the innermost branch that separates the new node from an existing node
+        let mut result: Option<(usize, N)> = None;
+        for index in existing {
+            let other = conditional_branches(graph[*index].inner());
+            let Some(depth) = branches
+                .iter()
+                .zip(other.iter())
+                .position(|((c1, b1), (c2, b2))| c1 == c2 && b1 != b2)
+            else {
+                return Err(*index);
+            };
+
+            if result.as_ref().map(|(d, _)| depth > *d).unwrap_or(true) {
+                result = Some((depth, branches[depth].1.clone()));
+            }
+        }
+
+        Ok(result.map(|(_, b)| b))
+    }
+
     /// Adds name reference edges to the graph.
     fn add_reference_edges(
         &mut self,
@@ -836,6 +952,11 @@ impl<N: TreeNode> WorkflowGraphBuilder<N> {
                 }
                 WorkflowGraphNode::Conditional(statement, _) => {
                     self.add_expr_edges(from, statement.expr(), graph, diagnostics);
+                    for clause in statement.else_clauses() {
+                        if let Some(expr) = clause.expr() {
+                            self.add_expr_edges(from, expr, graph, diagnostics);
+                        }
+                    }
                 }
                 WorkflowGraphNode::Scatter(statement, _) => {
                     self.add_expr_edges(from, statement.expr(), graph, diagnostics);
@@ -850,9 +971,11 @@ impl<N: TreeNode> WorkflowGraphBuilder<N> {
                                 self.add_expr_edges(from, expr, graph, diagnostics);
                             }
                             _ => {
-                                if let Some(to) =
-                                    self.find_node_by_name(name.text(), input.inner().clone())
-                                {
+                                if let Some(to) = self.find_node_by_name(
+                                    name.text(),
+                                    input.inner().clone(),
+                                    graph,
+                                ) {
                                     // Check for a dependency cycle
                                     if has_path_connecting(
                                         graph as &_,
@@ -881,7 +1004,8 @@ impl<N: TreeNode> WorkflowGraphBuilder<N> {
                     // Add edges to other the requested calls
                     for after in statement.after() {
                         let name = after.name();
-                        if let Some(to) = self.find_node_by_name(name.text(), after.inner().clone())
+                        if let Some(to) =
+                            self.find_node_by_name(name.text(), after.inner().clone(), graph)
                         {
                             // Check for a dependency cycle
                             if has_path_connecting(graph as &_, from, to, Some(&mut self.space)) {
@@ -920,7 +1044,7 @@ impl<N: TreeNode> WorkflowGraphBuilder<N> {
             let name = r.name();
 
             // Only add an edge if the name is known
-            match self.find_node_by_name(name.text(), expr.inner().clone()) {
+            match self.find_node_by_name(name.text(), expr.inner().clone(), graph) {
                 Some(to) => {
                     // Check to see if the node is self-referential
                     if to == from {
@@ -951,6 +1075,7 @@ impl<N: TreeNode> WorkflowGraphBuilder<N> {
 
                     self.add_dependency_edge(from, to, graph);
                 }
+                _ if is_access_target(&r) && self.enums.contains(name.text()) => continue,
                 _ => {
                     diagnostics.push(unknown_name(name.text(), name.span()));
                 }
@@ -1015,10 +1140,32 @@ impl<N: TreeNode> WorkflowGraphBuilder<N> {
     /// Finds a node in the graph by name for the referencing expression.
     ///
     /// This takes into account finding a scatter variable that's in scope.
-    fn find_node_by_name(&self, name: &str, expr: N) -> Option<NodeIndex> {
+    fn find_node_by_name(
+        &self,
+        name: &str,
+        expr: N,
+        graph: &DiGraph<WorkflowGraphNode<N>, ()>,
+    ) -> Option<NodeIndex> {
+        // Check for a declaration in an enclosing conditional branch first
+        let branches = conditional_branches(&expr);
+        if let Some(index) = branches
+            .iter()
+            .rev()
+            .find_map(|(_, b)| self.branch_names.get(b)?.get(name))
+        {
+            return Some(*index);
+        }
+
         // If the name came from a declaration or call, return the node
+        // A name declared in a branch of a conditional statement is not visible from
+        // another branch of the same statement
         if let Some(index) = self.names.get(name) {
-            return Some(*index);
+            let visible = conditional_branches(graph[*index].inner())
+                .iter()
+                .all(|(c, b)| branches.iter().all(|(ec, eb)| ec != c || eb == b));
+            if visible {
+                return Some(*index);
+            }
         }
 
         // Otherwise, we need to walk up the parent chain looking for a scatter variable
@@ -1045,12 +1192,45 @@ impl<N: TreeNode> Default for WorkflowGraphBuilder<N> {
     fn default() -> Self {
         Self {
             names: Default::default(),
+            branch_names: Default::default(),
             variables: Default::default(),
             entry_exits: Default::default(),
             space: Default::default(),
             ancestor_finder: Default::default(),
+            enums: Default::default(),
+        }
+    }
+}
+
+/// Gets the conditional statement branches enclosing the given node.
+///
+/// Each branch is represented as the conditional statement node paired with
+/// the branch node; the branch node is either the conditional statement node
+/// itself (for the `if` body) or an `else` clause node.
+///
+/// The branches are ordered from outermost to innermost.
+fn conditional_branches<N: TreeNode>(node: &N) -> Vec<(N, N)> {
+    let mut branches = Vec::new();
+    let mut child = node.clone();
+    while let Some(parent) = child.parent() {
+        match parent.kind() {
+            SyntaxKind::ConditionalStatementNode
+                if child.kind() == SyntaxKind::ConditionalElseClauseNode =>
+            {
+                branches.push((parent.clone(), child.clone()));
+            }
+            SyntaxKind::ConditionalStatementNode => {
+                branches.push((parent.clone(), parent.clone()));
+            }
+            SyntaxKind::WorkflowDefinitionNode => break,
+            _ => {}
         }
+
+        child = parent;
     }
+
+    branches.reverse();
+    branches
 }
 
 /// A helper for finding the children of a common ancestor in the AST.
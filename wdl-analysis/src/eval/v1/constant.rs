@@ -0,0 +1,982 @@
+//! Static evaluation of constant expressions for WDL 1.x.
+//!
+//! The constant evaluator folds expressions composed of literals, references
+//! to declarations with constant values, and a small subset of the standard
+//! library. It is used during analysis to report expressions that are
+//! guaranteed to fail at runtime.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::Diagnostic;
+use wdl_ast::Severity;
+use wdl_ast::Span;
+use wdl_ast::v1::CallStatement;
+use wdl_ast::v1::Decl;
+use wdl_ast::v1::Expr;
+use wdl_ast::v1::LiteralExpr;
+use wdl_ast::v1::LiteralString;
+use wdl_ast::v1::Placeholder;
+use wdl_ast::v1::PlaceholderOption;
+use wdl_ast::v1::PrimitiveTypeKind;
+use wdl_ast::v1::StringPart;
+use wdl_ast::v1::StrippedStringPart;
+use wdl_ast::v1::TASK_REQUIREMENT_CONTAINER;
+use wdl_ast::v1::TASK_REQUIREMENT_CONTAINER_ALIAS;
+use wdl_ast::v1::TASK_REQUIREMENT_MEMORY;
+use wdl_ast::v1::TaskDefinition;
+use wdl_ast::v1::Type;
+use wdl_ast::v1::WorkflowDefinition;
+use wdl_ast::v1::WorkflowStatement;
+
+use crate::diagnostics::array_index_out_of_range;
+use crate::diagnostics::division_by_zero;
+use crate::diagnostics::invalid_container_requirement;
+use crate::diagnostics::invalid_memory_requirement;
+use crate::units::convert_unit_string;
+
+/// The maximum number of elements of an array produced by constant
+/// evaluation.
+///
+/// Calls to `range` producing larger arrays are not evaluated.
+const MAX_ARRAY_LEN: i64 = 10_000;
+
+/// Represents the value of a constant expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantValue {
+    /// The value is `None`.
+    None,
+    /// The value is a `Boolean`.
+    Boolean(bool),
+    /// The value is an `Int`.
+    Integer(i64),
+    /// The value is a `Float`.
+    Float(f64),
+    /// The value is a `String` (or a `File` or `Directory`).
+    String(Arc<str>),
+    /// The value is an `Array`.
+    Array(Arc<[ConstantValue]>),
+}
+
+impl ConstantValue {
+    /// Gets the value as a `Boolean`.
+    ///
+    /// Returns `None` if the value is not a `Boolean`.
+    pub fn as_boolean(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Gets the value as an `Int`.
+    ///
+    /// Returns `None` if the value is not an `Int`.
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            Self::Integer(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Gets the value as a `Float`, coercing from `Int` if necessary.
+    ///
+    /// Returns `None` if the value is not numeric.
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Self::Integer(v) => Some(*v as f64),
+            Self::Float(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Gets the value as a string.
+    ///
+    /// Returns `None` if the value is not a `String`.
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            Self::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Gets the value as an array.
+    ///
+    /// Returns `None` if the value is not an `Array`.
+    pub fn as_array(&self) -> Option<&[ConstantValue]> {
+        match self {
+            Self::Array(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Determines if the value is a primitive value.
+    fn is_primitive(&self) -> bool {
+        matches!(
+            self,
+            Self::Boolean(_) | Self::Integer(_) | Self::Float(_) | Self::String(_)
+        )
+    }
+}
+
+impl fmt::Display for ConstantValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Boolean(v) => write!(f, "{v}"),
+            Self::Integer(v) => write!(f, "{v}"),
+            Self::Float(v) => write!(f, "{v:.6?}"),
+            Self::String(v) => write!(f, "{v}"),
+            Self::Array(elements) => {
+                write!(f, "[")?;
+                for (i, e) in elements.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    match e {
+                        Self::String(v) => write!(f, "{v:?}")?,
+                        _ => write!(f, "{e}")?,
+                    }
+                }
+
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+/// Represents a declaration known to the constant evaluator.
+#[derive(Debug, Clone)]
+struct Binding {
+    /// The expression of the declaration.
+    ///
+    /// This is `None` for an unbound optional input, which is implicitly
+    /// `None` when not specified.
+    expr: Option<Expr>,
+    /// Whether or not the declared type is `Float`.
+    ///
+    /// Used to coerce `Int` values to `Float`.
+    float: bool,
+    /// The span of the input name if the declaration is an input.
+    ///
+    /// The value of an input is only its default; it may be overridden.
+    input: Option<Span>,
+}
+
+/// Represents the result of evaluating a declaration.
+#[derive(Debug, Clone)]
+struct Evaluated {
+    /// The constant value of the declaration.
+    value: Option<ConstantValue>,
+    /// The span of an input name if the value depends on the default value of
+    /// that input.
+    assumed: Option<Span>,
+}
+
+/// Evaluates constant expressions.
+///
+/// The evaluator is given the declarations of a task or workflow; references
+/// to declarations are evaluated lazily and the results are memoized.
+///
+/// Inputs are evaluated to their default values; any diagnostic that depends
+/// on the default value of an input is reported as a warning as the input may
+/// be overridden at runtime.
+///
+/// Expressions that may not be evaluated at runtime (e.g. the branches of an
+/// `if` expression with a non-constant condition) are not evaluated.
+#[derive(Debug, Default)]
+pub struct ConstantEvaluator {
+    /// The known declarations.
+    bindings: HashMap<String, Binding>,
+    /// The memoized results of evaluating declarations.
+    cache: HashMap<String, Evaluated>,
+    /// The set of declarations currently being evaluated.
+    ///
+    /// Used to guard against reference cycles.
+    evaluating: HashSet<String>,
+    /// The span of the first input whose default value was assumed while
+    /// evaluating the current expression.
+    assumed: Option<Span>,
+    /// The current placeholder nesting depth.
+    ///
+    /// Diagnostics are not reported within placeholders as a placeholder that
+    /// evaluates `None` may suppress an evaluation error.
+    placeholders: usize,
+    /// The start offsets of expressions already diagnosed.
+    diagnosed: HashSet<usize>,
+    /// The diagnostics reported by the evaluator.
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl ConstantEvaluator {
+    /// Constructs a new constant evaluator with no known declarations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an input declaration to the evaluator.
+    ///
+    /// The value of the input is assumed to be its default value; an optional
+    /// input without a default is assumed to be `None`.
+    pub fn add_input(&mut self, decl: &Decl) {
+        let ty = decl.ty();
+        let expr = decl.expr();
+        if expr.is_none() && !ty.is_optional() {
+            return;
+        }
+
+        let name = decl.name();
+        self.bindings.insert(
+            name.text().to_string(),
+            Binding {
+                expr,
+                float: is_float(&ty),
+                input: Some(name.span()),
+            },
+        );
+    }
+
+    /// Adds a private (i.e. non-input) declaration to the evaluator.
+    pub fn add_decl(&mut self, decl: &Decl) {
+        let Some(expr) = decl.expr() else {
+            return;
+        };
+
+        let name = decl.name();
+        self.bindings.insert(
+            name.text().to_string(),
+            Binding {
+                expr: Some(expr),
+                float: is_float(&decl.ty()),
+                input: None,
+            },
+        );
+    }
+
+    /// Evaluates the given expression.
+    ///
+    /// Returns `None` if the expression is not constant.
+    pub fn evaluate(&mut self, expr: &Expr) -> Option<ConstantValue> {
+        let saved = self.assumed.take();
+        let value = self.evaluate_expr(expr);
+        self.assumed = saved;
+        value
+    }
+
+    /// Evaluates the declaration with the given name.
+    ///
+    /// Returns `None` if the name is unknown or its value is not constant.
+    pub fn evaluate_name(&mut self, name: &str) -> Option<ConstantValue> {
+        let saved = self.assumed.take();
+        let value = self.resolve(name);
+        self.assumed = saved;
+        value
+    }
+
+    /// Checks the value of a `runtime` or `requirements` section item.
+    ///
+    /// Reports invalid `memory` and `container` values.
+    pub fn check_requirement(&mut self, name: &str, expr: &Expr) {
+        let saved = self.assumed.take();
+        let value = self.evaluate_expr(expr);
+        match (name, value) {
+            (n, Some(ConstantValue::String(s)))
+                if n == TASK_REQUIREMENT_MEMORY && !is_valid_memory(&s) =>
+            {
+                self.report(invalid_memory_requirement(&s, expr.span()));
+            }
+            (n, Some(value))
+                if n == TASK_REQUIREMENT_CONTAINER || n == TASK_REQUIREMENT_CONTAINER_ALIAS =>
+            {
+                let invalid = match &value {
+                    ConstantValue::String(s) => (!is_valid_container(s)).then(|| s.to_string()),
+                    ConstantValue::Array(elements) => elements.iter().find_map(|e| {
+                        e.as_string()
+                            .filter(|s| !is_valid_container(s))
+                            .map(str::to_string)
+                    }),
+                    _ => None,
+                };
+
+                if let Some(invalid) = invalid {
+                    self.report(invalid_container_requirement(&invalid, expr.span()));
+                }
+            }
+            _ => {}
+        }
+
+        self.assumed = saved;
+    }
+
+    /// Gets the diagnostics reported by the evaluator.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Consumes the evaluator and returns the reported diagnostics.
+    pub fn into_diagnostics(self) -> Vec<Diagnostic> {
+        self.diagnostics
+    }
+
+    /// Reports a diagnostic.
+    ///
+    /// If the diagnostic depends on the default value of an input, it is
+    /// reported as a warning.
+    fn report(&mut self, diagnostic: Diagnostic) {
+        if self.placeholders > 0 {
+            return;
+        }
+
+        let Some(span) = diagnostic.labels().next().map(|l| l.span()) else {
+            return;
+        };
+
+        if !self.diagnosed.insert(span.start()) {
+            return;
+        }
+
+        let diagnostic = match self.assumed {
+            Some(input) => diagnostic
+                .with_severity(Severity::Warning)
+                .with_label("this assumes the default value of this input", input),
+            None => diagnostic,
+        };
+
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// Resolves the value of a declaration by name.
+    fn resolve(&mut self, name: &str) -> Option<ConstantValue> {
+        if let Some(evaluated) = self.cache.get(name) {
+            if let Some(span) = evaluated.assumed {
+                self.assumed.get_or_insert(span);
+            }
+
+            return evaluated.value.clone();
+        }
+
+        let binding = self.bindings.get(name)?.clone();
+        if !self.evaluating.insert(name.to_string()) {
+            return None;
+        }
+
+        let saved = std::mem::replace(&mut self.assumed, binding.input);
+        let value = match &binding.expr {
+            Some(expr) => self.evaluate_expr(expr).map(|v| match v {
+                ConstantValue::Integer(v) if binding.float => ConstantValue::Float(v as f64),
+                v => v,
+            }),
+            None => Some(ConstantValue::None),
+        };
+
+        let assumed = std::mem::replace(&mut self.assumed, saved);
+        if let Some(span) = assumed {
+            self.assumed.get_or_insert(span);
+        }
+
+        self.evaluating.remove(name);
+        self.cache.insert(
+            name.to_string(),
+            Evaluated {
+                value: value.clone(),
+                assumed,
+            },
+        );
+
+        value
+    }
+
+    /// Evaluates an expression.
+    fn evaluate_expr(&mut self, expr: &Expr) -> Option<ConstantValue> {
+        match expr {
+            Expr::Literal(expr) => self.evaluate_literal(expr),
+            Expr::NameRef(r) => self.resolve(r.name().text()),
+            Expr::Parenthesized(expr) => self.evaluate_expr(&expr.expr()),
+            Expr::If(expr) => {
+                let (cond, true_expr, false_expr) = expr.exprs();
+                // Only the selected branch is evaluated at runtime
+                match self.evaluate_expr(&cond)?.as_boolean()? {
+                    true => self.evaluate_expr(&true_expr),
+                    false => self.evaluate_expr(&false_expr),
+                }
+            }
+            Expr::LogicalNot(expr) => Some(ConstantValue::Boolean(
+                !self.evaluate_expr(&expr.operand())?.as_boolean()?,
+            )),
+            Expr::Negation(expr) => match self.evaluate_expr(&expr.operand())? {
+                ConstantValue::Integer(v) => v.checked_neg().map(ConstantValue::Integer),
+                ConstantValue::Float(v) => Some(ConstantValue::Float(-v)),
+                _ => None,
+            },
+            Expr::LogicalOr(expr) => {
+                let (lhs, rhs) = expr.operands();
+                // The right-hand side is only evaluated if the left-hand side is false
+                match self.evaluate_expr(&lhs)?.as_boolean()? {
+                    true => Some(ConstantValue::Boolean(true)),
+                    false => Some(ConstantValue::Boolean(
+                        self.evaluate_expr(&rhs)?.as_boolean()?,
+                    )),
+                }
+            }
+            Expr::LogicalAnd(expr) => {
+                let (lhs, rhs) = expr.operands();
+                // The right-hand side is only evaluated if the left-hand side is true
+                match self.evaluate_expr(&lhs)?.as_boolean()? {
+                    true => Some(ConstantValue::Boolean(
+                        self.evaluate_expr(&rhs)?.as_boolean()?,
+                    )),
+                    false => Some(ConstantValue::Boolean(false)),
+                }
+            }
+            Expr::Equality(expr) => {
+                let (lhs, rhs) = expr.operands();
+                let (left, right) = self.evaluate_operands(&lhs, &rhs);
+                equals(&left?, &right?).map(ConstantValue::Boolean)
+            }
+            Expr::Inequality(expr) => {
+                let (lhs, rhs) = expr.operands();
+                let (left, right) = self.evaluate_operands(&lhs, &rhs);
+                equals(&left?, &right?).map(|v| ConstantValue::Boolean(!v))
+            }
+            Expr::Less(expr) => {
+                let (lhs, rhs) = expr.operands();
+                self.evaluate_comparison(&lhs, &rhs, |o| o.is_lt())
+            }
+            Expr::LessEqual(expr) => {
+                let (lhs, rhs) = expr.operands();
+                self.evaluate_comparison(&lhs, &rhs, |o| o.is_le())
+            }
+            Expr::Greater(expr) => {
+                let (lhs, rhs) = expr.operands();
+                self.evaluate_comparison(&lhs, &rhs, |o| o.is_gt())
+            }
+            Expr::GreaterEqual(expr) => {
+                let (lhs, rhs) = expr.operands();
+                self.evaluate_comparison(&lhs, &rhs, |o| o.is_ge())
+            }
+            Expr::Addition(e) => {
+                let (lhs, rhs) = e.operands();
+                let (left, right) = self.evaluate_operands(&lhs, &rhs);
+                match (left?, right?) {
+                    (ConstantValue::String(l), r)
+                        if r.is_primitive() && r.as_boolean().is_none() =>
+                    {
+                        Some(ConstantValue::String(format!("{l}{r}").into()))
+                    }
+                    (l, ConstantValue::String(r))
+                        if l.is_primitive() && l.as_boolean().is_none() =>
+                    {
+                        Some(ConstantValue::String(format!("{l}{r}").into()))
+                    }
+                    (l, r) => numeric(&l, &r, i64::checked_add, |l, r| l + r),
+                }
+            }
+            Expr::Subtraction(e) => {
+                let (lhs, rhs) = e.operands();
+                let (left, right) = self.evaluate_operands(&lhs, &rhs);
+                numeric(&left?, &right?, i64::checked_sub, |l, r| l - r)
+            }
+            Expr::Multiplication(e) => {
+                let (lhs, rhs) = e.operands();
+                let (left, right) = self.evaluate_operands(&lhs, &rhs);
+                numeric(&left?, &right?, i64::checked_mul, |l, r| l * r)
+            }
+            Expr::Division(e) => {
+                let (lhs, rhs) = e.operands();
+                self.evaluate_division(e.span(), &lhs, &rhs, i64::checked_div, |l, r| l / r)
+            }
+            Expr::Modulo(e) => {
+                let (lhs, rhs) = e.operands();
+                self.evaluate_division(e.span(), &lhs, &rhs, i64::checked_rem, |l, r| l % r)
+            }
+            Expr::Exponentiation(e) => {
+                let (lhs, rhs) = e.operands();
+                let (left, right) = self.evaluate_operands(&lhs, &rhs);
+                numeric(
+                    &left?,
+                    &right?,
+                    |l, r| l.checked_pow(r.try_into().ok()?),
+                    f64::powf,
+                )
+            }
+            Expr::Call(expr) => {
+                let target = expr.target();
+                let arguments = expr
+                    .arguments()
+                    .map(|a| self.evaluate_expr(&a))
+                    .collect::<Vec<_>>();
+                call(target.text(), &arguments)
+            }
+            Expr::Index(expr) => {
+                let (target, index) = expr.operands();
+                let (array, i) = self.evaluate_operands(&target, &index);
+                let array = array?;
+                let array = array.as_array()?;
+                let i = i?.as_integer()?;
+                match usize::try_from(i).ok().and_then(|i| array.get(i)) {
+                    Some(v) => Some(v.clone()),
+                    None => {
+                        self.report(array_index_out_of_range(
+                            i,
+                            array.len(),
+                            index.span(),
+                            target.span(),
+                        ));
+                        None
+                    }
+                }
+            }
+            Expr::Access(expr) => {
+                self.evaluate_expr(&expr.operands().0);
+                None
+            }
+        }
+    }
+
+    /// Evaluates both operands of a binary expression.
+    ///
+    /// Both operands are always evaluated so that errors in either are
+    /// reported.
+    fn evaluate_operands(
+        &mut self,
+        lhs: &Expr,
+        rhs: &Expr,
+    ) -> (Option<ConstantValue>, Option<ConstantValue>) {
+        let left = self.evaluate_expr(lhs);
+        let right = self.evaluate_expr(rhs);
+        (left, right)
+    }
+
+    /// Evaluates a comparison expression.
+    fn evaluate_comparison(
+        &mut self,
+        lhs: &Expr,
+        rhs: &Expr,
+        op: fn(std::cmp::Ordering) -> bool,
+    ) -> Option<ConstantValue> {
+        let (left, right) = self.evaluate_operands(lhs, rhs);
+        let ordering = match (left?, right?) {
+            (ConstantValue::Boolean(l), ConstantValue::Boolean(r)) => l.cmp(&r),
+            (ConstantValue::Integer(l), ConstantValue::Integer(r)) => l.cmp(&r),
+            (ConstantValue::String(l), ConstantValue::String(r)) => l.cmp(&r),
+            (l, r) => l.as_float()?.partial_cmp(&r.as_float()?)?,
+        };
+
+        Some(ConstantValue::Boolean(op(ordering)))
+    }
+
+    /// Evaluates a division or modulo expression.
+    ///
+    /// Reports a division by zero for integer operands.
+    fn evaluate_division(
+        &mut self,
+        span: Span,
+        lhs: &Expr,
+        rhs: &Expr,
+        int: fn(i64, i64) -> Option<i64>,
+        float: fn(f64, f64) -> f64,
+    ) -> Option<ConstantValue> {
+        let (left, right) = self.evaluate_operands(lhs, rhs);
+        let (left, right) = (left?, right?);
+        if let (ConstantValue::Integer(_), ConstantValue::Integer(0)) = (&left, &right) {
+            self.report(division_by_zero(span, rhs.span()));
+            return None;
+        }
+
+        numeric(&left, &right, int, float)
+    }
+
+    /// Evaluates a literal expression.
+    fn evaluate_literal(&mut self, expr: &LiteralExpr) -> Option<ConstantValue> {
+        match expr {
+            LiteralExpr::Boolean(v) => Some(ConstantValue::Boolean(v.value())),
+            LiteralExpr::Integer(v) => v.value().map(ConstantValue::Integer),
+            LiteralExpr::Float(v) => v.value().map(ConstantValue::Float),
+            LiteralExpr::String(s) => self
+                .evaluate_string(s)
+                .map(|s| ConstantValue::String(s.into())),
+            LiteralExpr::Array(a) => {
+                let elements = a
+                    .elements()
+                    .map(|e| self.evaluate_expr(&e))
+                    .collect::<Vec<_>>();
+                Some(ConstantValue::Array(
+                    elements.into_iter().collect::<Option<Vec<_>>>()?.into(),
+                ))
+            }
+            LiteralExpr::None(_) => Some(ConstantValue::None),
+            LiteralExpr::Pair(p) => {
+                let (left, right) = p.exprs();
+                self.evaluate_operands(&left, &right);
+                None
+            }
+            LiteralExpr::Map(m) => {
+                for item in m.items() {
+                    let (key, value) = item.key_value();
+                    self.evaluate_operands(&key, &value);
+                }
+
+                None
+            }
+            LiteralExpr::Object(o) => {
+                for item in o.items() {
+                    self.evaluate_expr(&item.name_value().1);
+                }
+
+                None
+            }
+            LiteralExpr::Struct(s) => {
+                for item in s.items() {
+                    self.evaluate_expr(&item.name_value().1);
+                }
+
+                None
+            }
+            LiteralExpr::Hints(_) | LiteralExpr::Input(_) | LiteralExpr::Output(_) => None,
+        }
+    }
+
+    /// Evaluates a literal string.
+    fn evaluate_string(&mut self, s: &LiteralString) -> Option<String> {
+        let mut result = String::new();
+        let mut constant = true;
+        match s.strip_whitespace() {
+            Some(parts) => {
+                for part in parts {
+                    match part {
+                        StrippedStringPart::Text(t) => result.push_str(&t),
+                        StrippedStringPart::Placeholder(p) => {
+                            // Continue evaluating placeholders even if one is not constant
+                            constant &= self.evaluate_placeholder(&p, &mut result).is_some();
+                        }
+                    }
+                }
+            }
+            None => {
+                for part in s.parts() {
+                    match part {
+                        StringPart::Text(t) => t.unescape_to(&mut result),
+                        StringPart::Placeholder(p) => {
+                            constant &= self.evaluate_placeholder(&p, &mut result).is_some();
+                        }
+                    }
+                }
+            }
+        }
+
+        constant.then_some(result)
+    }
+
+    /// Evaluates a placeholder, appending its value to the given buffer.
+    fn evaluate_placeholder(
+        &mut self,
+        placeholder: &Placeholder,
+        buffer: &mut String,
+    ) -> Option<()> {
+        self.placeholders += 1;
+        let value = self.evaluate_expr(&placeholder.expr());
+        self.placeholders -= 1;
+
+        match (value?, placeholder.option()) {
+            (ConstantValue::None, Some(PlaceholderOption::Default(o))) => {
+                buffer.push_str(&self.evaluate_string(&o.value())?);
+            }
+            (ConstantValue::None, _) => {}
+            (ConstantValue::Boolean(v), Some(PlaceholderOption::TrueFalse(o))) => {
+                let (t, f) = o.values();
+                buffer.push_str(&self.evaluate_string(if v { &t } else { &f })?);
+            }
+            (ConstantValue::Array(elements), Some(PlaceholderOption::Sep(o))) => {
+                let sep = self.evaluate_string(&o.separator())?;
+                for (i, e) in elements.iter().enumerate() {
+                    if i > 0 {
+                        buffer.push_str(&sep);
+                    }
+
+                    match e {
+                        ConstantValue::None => {}
+                        e if e.is_primitive() => buffer.push_str(&e.to_string()),
+                        _ => return None,
+                    }
+                }
+            }
+            (v, _) if v.is_primitive() => buffer.push_str(&v.to_string()),
+            _ => return None,
+        }
+
+        Some(())
+    }
+}
+
+/// Determines if the given declared type is `Float` or `Float?`.
+fn is_float(ty: &Type) -> bool {
+    matches!(ty, Type::Primitive(ty) if ty.kind() == PrimitiveTypeKind::Float)
+}
+
+/// Determines if two constant values are equal.
+///
+/// Returns `None` if the values cannot be compared.
+fn equals(left: &ConstantValue, right: &ConstantValue) -> Option<bool> {
+    match (left, right) {
+        (ConstantValue::None, ConstantValue::None) => Some(true),
+        (ConstantValue::None, _) | (_, ConstantValue::None) => Some(false),
+        (ConstantValue::Boolean(l), ConstantValue::Boolean(r)) => Some(l == r),
+        (ConstantValue::Integer(l), ConstantValue::Integer(r)) => Some(l == r),
+        (ConstantValue::String(l), ConstantValue::String(r)) => Some(l == r),
+        (ConstantValue::Array(l), ConstantValue::Array(r)) => {
+            if l.len() != r.len() {
+                return Some(false);
+            }
+
+            for (l, r) in l.iter().zip(r.iter()) {
+                if !equals(l, r)? {
+                    return Some(false);
+                }
+            }
+
+            Some(true)
+        }
+        (l, r) => Some(l.as_float()? == r.as_float()?),
+    }
+}
+
+/// Performs a numeric operation on two constant values.
+///
+/// Integer operations that overflow are not constant.
+fn numeric(
+    left: &ConstantValue,
+    right: &ConstantValue,
+    int: impl Fn(i64, i64) -> Option<i64>,
+    float: impl Fn(f64, f64) -> f64,
+) -> Option<ConstantValue> {
+    match (left, right) {
+        (ConstantValue::Integer(l), ConstantValue::Integer(r)) => {
+            int(*l, *r).map(ConstantValue::Integer)
+        }
+        (l, r) => Some(ConstantValue::Float(float(l.as_float()?, r.as_float()?))),
+    }
+}
+
+/// Evaluates a call to a standard library function.
+///
+/// Only a subset of the standard library is supported; calls to other
+/// functions are not constant.
+fn call(name: &str, arguments: &[Option<ConstantValue>]) -> Option<ConstantValue> {
+    match (name, arguments) {
+        ("range", [Some(ConstantValue::Integer(n))]) if (0..=MAX_ARRAY_LEN).contains(n) => Some(
+            ConstantValue::Array((0..*n).map(ConstantValue::Integer).collect()),
+        ),
+        ("select_first", [Some(ConstantValue::Array(elements)), rest @ ..]) => elements
+            .iter()
+            .find(|e| **e != ConstantValue::None)
+            .cloned()
+            .or_else(|| rest.first().cloned().flatten()),
+        ("defined", [Some(v)]) => Some(ConstantValue::Boolean(*v != ConstantValue::None)),
+        ("length", [Some(ConstantValue::Array(elements))]) => {
+            Some(ConstantValue::Integer(elements.len().try_into().ok()?))
+        }
+        ("length", [Some(ConstantValue::String(s))]) => {
+            Some(ConstantValue::Integer(s.chars().count().try_into().ok()?))
+        }
+        ("floor", [Some(v)]) => Some(ConstantValue::Integer(v.as_float()?.floor() as i64)),
+        ("ceil", [Some(v)]) => Some(ConstantValue::Integer(v.as_float()?.ceil() as i64)),
+        ("round", [Some(v)]) => Some(ConstantValue::Integer(v.as_float()?.round() as i64)),
+        ("min", [Some(l), Some(r)]) => match (l, r) {
+            (ConstantValue::Integer(l), ConstantValue::Integer(r)) => {
+                Some(ConstantValue::Integer(*l.min(r)))
+            }
+            _ => Some(ConstantValue::Float(l.as_float()?.min(r.as_float()?))),
+        },
+        ("max", [Some(l), Some(r)]) => match (l, r) {
+            (ConstantValue::Integer(l), ConstantValue::Integer(r)) => {
+                Some(ConstantValue::Integer(*l.max(r)))
+            }
+            _ => Some(ConstantValue::Float(l.as_float()?.max(r.as_float()?))),
+        },
+        _ => None,
+    }
+}
+
+/// Determines if a `memory` requirement string is valid.
+///
+/// A valid string is a non-negative integer followed by a storage unit (e.g.
+/// `2 GiB`) that does not exceed the maximum number of bytes.
+fn is_valid_memory(s: &str) -> bool {
+    convert_unit_string(s).is_some()
+}
+
+/// Determines if a `container` requirement string may be valid.
+///
+/// A container can never be valid if it is empty or contains whitespace.
+fn is_valid_container(s: &str) -> bool {
+    !s.is_empty() && !s.chars().any(char::is_whitespace)
+}
+
+/// Applies the configured severity of the constant evaluation rule to the
+/// given diagnostics.
+///
+/// Diagnostics that were reported as warnings because they depend on the
+/// default value of an input are never made more severe.
+fn with_severity(diagnostics: Vec<Diagnostic>, severity: Severity) -> Vec<Diagnostic> {
+    diagnostics
+        .into_iter()
+        .map(|d| {
+            let severity = d.severity().max(severity);
+            d.with_severity(severity)
+        })
+        .collect()
+}
+
+/// Checks the constant expressions of a task.
+///
+/// Returns the diagnostics, with the given severity, for any expression that
+/// is guaranteed to fail at runtime.
+pub(crate) fn check_task(definition: &TaskDefinition, severity: Severity) -> Vec<Diagnostic> {
+    let mut evaluator = ConstantEvaluator::new();
+    if let Some(section) = definition.input() {
+        for decl in section.declarations() {
+            evaluator.add_input(&decl);
+        }
+    }
+
+    for decl in definition.declarations() {
+        evaluator.add_decl(&Decl::Bound(decl));
+    }
+
+    if let Some(section) = definition.input() {
+        for decl in section.declarations() {
+            evaluator.evaluate_name(decl.name().text());
+        }
+    }
+
+    for decl in definition.declarations() {
+        evaluator.evaluate_name(decl.name().text());
+    }
+
+    if let Some(section) = definition.runtime() {
+        for item in section.items() {
+            evaluator.check_requirement(item.name().text(), &item.expr());
+        }
+    }
+
+    if let Some(section) = definition.requirements() {
+        for item in section.items() {
+            evaluator.check_requirement(item.name().text(), &item.expr());
+        }
+    }
+
+    if let Some(section) = definition.output() {
+        for decl in section.declarations() {
+            evaluator.evaluate(&decl.expr());
+        }
+    }
+
+    with_severity(evaluator.into_diagnostics(), severity)
+}
+
+/// Checks the constant expressions of a workflow.
+///
+/// Returns the diagnostics, with the given severity, for any expression that
+/// is guaranteed to fail at runtime.
+///
+/// The statements of a conditional clause that is never taken are not checked.
+pub(crate) fn check_workflow(
+    definition: &WorkflowDefinition,
+    severity: Severity,
+) -> Vec<Diagnostic> {
+    /// Checks the expressions of the given workflow statements.
+    ///
+    /// Only the declarations at the root of the workflow are known to the
+    /// evaluator as nested declarations are promoted to different types outside
+    /// of their enclosing statement.
+    fn check_statements(
+        evaluator: &mut ConstantEvaluator,
+        statements: impl Iterator<Item = WorkflowStatement>,
+        root: bool,
+    ) {
+        for statement in statements {
+            match statement {
+                WorkflowStatement::Conditional(statement) => {
+                    // A clause is never taken if its condition is constant `false` or if the
+                    // condition of a preceding clause is constant `true`
+                    let condition = evaluator
+                        .evaluate(&statement.expr())
+                        .and_then(|v| v.as_boolean());
+                    let mut taken = condition == Some(true);
+                    if condition != Some(false) {
+                        check_statements(evaluator, statement.statements(), false);
+                    }
+
+                    for clause in statement.else_clauses() {
+                        if taken {
+                            break;
+                        }
+
+                        let condition = match clause.expr() {
+                            Some(expr) => evaluator.evaluate(&expr).and_then(|v| v.as_boolean()),
+                            None => Some(true),
+                        };
+
+                        taken = condition == Some(true);
+                        if condition != Some(false) {
+                            check_statements(evaluator, clause.statements(), false);
+                        }
+                    }
+                }
+                WorkflowStatement::Scatter(statement) => {
+                    evaluator.evaluate(&statement.expr());
+                    check_statements(evaluator, statement.statements(), false);
+                }
+                WorkflowStatement::Call(statement) => check_call(evaluator, &statement),
+                WorkflowStatement::Declaration(decl) => {
+                    if root {
+                        evaluator.evaluate_name(decl.name().text());
+                    } else {
+                        evaluator.evaluate(&decl.expr());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Checks the input expressions of a call statement.
+    fn check_call(evaluator: &mut ConstantEvaluator, statement: &CallStatement) {
+        for input in statement.inputs() {
+            if let Some(expr) = input.expr() {
+                evaluator.evaluate(&expr);
+            }
+        }
+    }
+
+    let mut evaluator = ConstantEvaluator::new();
+    if let Some(section) = definition.input() {
+        for decl in section.declarations() {
+            evaluator.add_input(&decl);
+        }
+    }
+
+    for decl in definition.declarations() {
+        evaluator.add_decl(&Decl::Bound(decl));
+    }
+
+    if let Some(section) = definition.input() {
+        for decl in section.declarations() {
+            evaluator.evaluate_name(decl.name().text());
+        }
+    }
+
+    check_statements(&mut evaluator, definition.statements(), true);
+
+    if let Some(section) = definition.output() {
+        for decl in section.declarations() {
+            evaluator.evaluate(&decl.expr());
+        }
+    }
+
+    with_severity(evaluator.into_diagnostics(), severity)
+}
@@ -0,0 +1,543 @@
+//! Exporting of workflow evaluation graphs.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use petgraph::graph::NodeIndex;
+use serde::Serialize;
+use url::Url;
+use wdl_ast::Ast;
+use wdl_ast::AstToken;
+use wdl_ast::SyntaxNode;
+use wdl_ast::v1::CallStatement;
+use wdl_ast::v1::WorkflowDefinition;
+
+use super::WorkflowGraphBuilder;
+use super::WorkflowGraphNode;
+use crate::Document;
+
+/// Represents the kind of a node in an exported workflow graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportNodeKind {
+    /// The node is a workflow input.
+    Input,
+    /// The node is a private declaration.
+    Decl,
+    /// The node is a workflow output.
+    Output,
+    /// The node is a call statement.
+    Call,
+    /// The node is a scatter statement.
+    Scatter,
+    /// The node is a conditional statement.
+    Conditional,
+}
+
+/// Represents a node in an exported workflow graph.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportNode {
+    /// The identifier of the node.
+    id: usize,
+    /// The kind of the node.
+    kind: ExportNodeKind,
+    /// The name introduced by the node, if any.
+    name: Option<String>,
+    /// The display label of the node.
+    label: String,
+    /// The identifier of the node containing this node, if any.
+    parent: Option<usize>,
+    /// The target of the call, if the node is a call.
+    target: Option<String>,
+}
+
+impl ExportNode {
+    /// Gets the identifier of the node.
+    ///
+    /// Identifiers are unique within an exported graph.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Gets the kind of the node.
+    pub fn kind(&self) -> ExportNodeKind {
+        self.kind
+    }
+
+    /// Gets the name introduced by the node.
+    ///
+    /// Returns `None` for conditional statements.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Gets the display label of the node.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Gets the identifier of the node containing this node.
+    ///
+    /// The parent of a node is the scatter or conditional statement it is
+    /// nested in or, for an expanded sub-workflow, the call to the
+    /// sub-workflow.
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+
+    /// Gets the target of the call (e.g. `lib.task`).
+    ///
+    /// Returns `None` if the node is not a call.
+    pub fn target(&self) -> Option<&str> {
+        self.target.as_deref()
+    }
+}
+
+/// Represents an edge in an exported workflow graph.
+///
+/// An edge from `A` to `B` means that `B` depends on `A`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct ExportEdge {
+    /// The identifier of the node being depended on.
+    from: usize,
+    /// The identifier of the dependent node.
+    to: usize,
+}
+
+impl ExportEdge {
+    /// Gets the identifier of the node being depended on.
+    pub fn from(&self) -> usize {
+        self.from
+    }
+
+    /// Gets the identifier of the dependent node.
+    pub fn to(&self) -> usize {
+        self.to
+    }
+}
+
+/// Represents an exported workflow evaluation graph.
+///
+/// The graph is built from the workflow evaluation graph of an analyzed
+/// document; the exit nodes of scatter and conditional statements are merged
+/// into the statements and the nesting of nodes is represented by the parent
+/// of each node rather than by edges.
+///
+/// The graph may be exported as Graphviz DOT, Mermaid, or a JSON list of nodes
+/// and edges.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowGraphExport {
+    /// The name of the workflow.
+    workflow: String,
+    /// The nodes of the graph.
+    nodes: Vec<ExportNode>,
+    /// The edges of the graph.
+    edges: Vec<ExportEdge>,
+}
+
+impl WorkflowGraphExport {
+    /// Builds an exported graph of the workflow in the given analyzed
+    /// document.
+    ///
+    /// If `expand` is `true`, the graph of a sub-workflow is nested inside of
+    /// each call to the sub-workflow.
+    ///
+    /// Returns `None` if the document does not contain a WDL 1.x workflow.
+    pub fn new(document: &Document, expand: bool) -> Option<Self> {
+        let definition = workflow_definition(document)?;
+        let mut export = Self {
+            workflow: definition.name().text().to_string(),
+            nodes: Default::default(),
+            edges: Default::default(),
+        };
+
+        let mut edges = BTreeSet::new();
+        let mut stack = vec![document.uri().as_ref().clone()];
+        export.add_workflow(document, &definition, None, expand, &mut stack, &mut edges);
+        export.edges = edges.into_iter().collect();
+        Some(export)
+    }
+
+    /// Gets the name of the workflow.
+    pub fn workflow(&self) -> &str {
+        &self.workflow
+    }
+
+    /// Gets the nodes of the graph.
+    pub fn nodes(&self) -> &[ExportNode] {
+        &self.nodes
+    }
+
+    /// Gets the edges of the graph.
+    pub fn edges(&self) -> &[ExportEdge] {
+        &self.edges
+    }
+
+    /// Exports the graph as Graphviz DOT.
+    ///
+    /// Scatter and conditional statements and expanded calls are rendered as
+    /// clusters containing their nested nodes.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        writeln!(
+            dot,
+            "digraph \"{name}\" {{",
+            name = escape_dot(&self.workflow)
+        )
+        .unwrap();
+        for node in self.nodes.iter().filter(|n| n.parent.is_none()) {
+            self.write_dot_node(&mut dot, node, 1);
+        }
+
+        for edge in &self.edges {
+            writeln!(dot, "    n{from} -> n{to};", from = edge.from, to = edge.to).unwrap();
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Exports the graph as a Mermaid flowchart.
+    ///
+    /// Scatter and conditional statements and expanded calls are rendered as
+    /// subgraphs containing their nested nodes.
+    pub fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("flowchart TD\n");
+        for node in self.nodes.iter().filter(|n| n.parent.is_none()) {
+            self.write_mermaid_node(&mut mermaid, node, 1);
+        }
+
+        for edge in &self.edges {
+            writeln!(
+                mermaid,
+                "    n{from} --> n{to}",
+                from = edge.from,
+                to = edge.to
+            )
+            .unwrap();
+        }
+
+        mermaid
+    }
+
+    /// Exports the graph as a JSON object with `workflow`, `nodes`, and
+    /// `edges` fields.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("graph should serialize")
+    }
+
+    /// Adds the nodes and edges of a workflow to the graph.
+    fn add_workflow(
+        &mut self,
+        document: &Document,
+        definition: &WorkflowDefinition,
+        parent: Option<usize>,
+        expand: bool,
+        stack: &mut Vec<Url>,
+        edges: &mut BTreeSet<ExportEdge>,
+    ) {
+        // Diagnostics were already reported when the document was analyzed
+        let mut diagnostics = Vec::new();
+        let graph = WorkflowGraphBuilder::default()
+            .with_enums(document.enums().map(|(n, _)| n))
+            .build(definition, &mut diagnostics, |_| false);
+
+        // Map each graph node to a node identifier, merging exit nodes into their
+        // statements
+        let mut ids: HashMap<NodeIndex, usize> = HashMap::new();
+        let mut containers: HashMap<SyntaxNode, usize> = HashMap::new();
+        for index in graph.node_indices() {
+            let (kind, name, label, target) = match &graph[index] {
+                WorkflowGraphNode::Input(decl) => (
+                    ExportNodeKind::Input,
+                    Some(decl.name().text().to_string()),
+                    format!("{ty} {name}", ty = decl.ty(), name = decl.name().text()),
+                    None,
+                ),
+                WorkflowGraphNode::Decl(decl) => (
+                    ExportNodeKind::Decl,
+                    Some(decl.name().text().to_string()),
+                    format!("{ty} {name}", ty = decl.ty(), name = decl.name().text()),
+                    None,
+                ),
+                WorkflowGraphNode::Output(decl) => (
+                    ExportNodeKind::Output,
+                    Some(decl.name().text().to_string()),
+                    format!("{ty} {name}", ty = decl.ty(), name = decl.name().text()),
+                    None,
+                ),
+                WorkflowGraphNode::Call(statement) => {
+                    let target = call_target(statement);
+                    let name = statement
+                        .alias()
+                        .map(|a| a.name())
+                        .or_else(|| statement.target().names().last())
+                        .expect("call should have a name");
+                    let label = match statement.alias() {
+                        Some(alias) => {
+                            format!("call {target} as {alias}", alias = alias.name().text())
+                        }
+                        None => format!("call {target}"),
+                    };
+                    (
+                        ExportNodeKind::Call,
+                        Some(name.text().to_string()),
+                        label,
+                        Some(target),
+                    )
+                }
+                WorkflowGraphNode::Scatter(statement, _) => (
+                    ExportNodeKind::Scatter,
+                    Some(statement.variable().text().to_string()),
+                    format!(
+                        "scatter ({variable} in {expr})",
+                        variable = statement.variable().text(),
+                        expr = statement.expr().inner().text()
+                    ),
+                    None,
+                ),
+                WorkflowGraphNode::Conditional(statement, _) => {
+                    let mut label = format!("if ({expr})", expr = statement.expr().inner().text());
+                    for clause in statement.else_clauses() {
+                        match clause.expr() {
+                            Some(expr) => {
+                                write!(label, " / else if ({expr})", expr = expr.inner().text())
+                                    .unwrap()
+                            }
+                            None => label.push_str(" / else"),
+                        }
+                    }
+
+                    (ExportNodeKind::Conditional, None, label, None)
+                }
+                WorkflowGraphNode::ExitConditional(_) | WorkflowGraphNode::ExitScatter(_) => {
+                    continue;
+                }
+            };
+
+            let id = self.nodes.len();
+            ids.insert(index, id);
+            if matches!(kind, ExportNodeKind::Scatter | ExportNodeKind::Conditional) {
+                containers.insert(graph[index].inner().clone(), id);
+            }
+
+            self.nodes.push(ExportNode {
+                id,
+                kind,
+                name,
+                label,
+                parent,
+                target,
+            });
+        }
+
+        for index in graph.node_indices() {
+            match &graph[index] {
+                WorkflowGraphNode::Conditional(_, exit) | WorkflowGraphNode::Scatter(_, exit) => {
+                    ids.insert(*exit, ids[&index]);
+                }
+                _ => {}
+            }
+        }
+
+        // Set the parent of nodes nested in scatter and conditional statements
+        for (index, id) in &ids {
+            if let Some(container) = graph[*index]
+                .inner()
+                .ancestors()
+                .skip(1)
+                .find_map(|n| containers.get(&n))
+            {
+                self.nodes[*id].parent = Some(*container);
+            }
+        }
+
+        // Add the dependency edges, excluding those between a statement and the nodes
+        // nested in it
+        for edge in graph.raw_edges() {
+            let from = ids[&edge.source()];
+            let to = ids[&edge.target()];
+            if from == to || self.is_ancestor(from, to) || self.is_ancestor(to, from) {
+                continue;
+            }
+
+            edges.insert(ExportEdge { from, to });
+        }
+
+        if !expand {
+            return;
+        }
+
+        // Expand the calls to sub-workflows
+        for index in graph.node_indices() {
+            let WorkflowGraphNode::Call(statement) = &graph[index] else {
+                continue;
+            };
+
+            let Some((document, definition)) = sub_workflow(document, statement) else {
+                continue;
+            };
+
+            // Guard against expanding a workflow inside of itself
+            if stack.contains(document.uri()) {
+                continue;
+            }
+
+            stack.push(document.uri().as_ref().clone());
+            self.add_workflow(
+                document,
+                &definition,
+                Some(ids[&index]),
+                expand,
+                stack,
+                edges,
+            );
+            stack.pop();
+        }
+    }
+
+    /// Determines if the first node is an ancestor of the second node.
+    fn is_ancestor(&self, ancestor: usize, node: usize) -> bool {
+        let mut current = self.nodes[node].parent;
+        while let Some(parent) = current {
+            if parent == ancestor {
+                return true;
+            }
+
+            current = self.nodes[parent].parent;
+        }
+
+        false
+    }
+
+    /// Writes a node and its nested nodes as DOT.
+    fn write_dot_node(&self, dot: &mut String, node: &ExportNode, depth: usize) {
+        let indent = "    ".repeat(depth);
+        let shape = match node.kind {
+            ExportNodeKind::Input => "shape=invhouse",
+            ExportNodeKind::Decl => "shape=box",
+            ExportNodeKind::Output => "shape=house",
+            ExportNodeKind::Call => "shape=box, style=rounded",
+            ExportNodeKind::Scatter => "shape=hexagon",
+            ExportNodeKind::Conditional => "shape=diamond",
+        };
+
+        let children: Vec<_> = self
+            .nodes
+            .iter()
+            .filter(|n| n.parent == Some(node.id))
+            .collect();
+
+        if !children.is_empty() {
+            writeln!(dot, "{indent}subgraph cluster_n{id} {{", id = node.id).unwrap();
+            writeln!(dot, "{indent}    style=dashed;").unwrap();
+            writeln!(dot, "{indent}    label=\"\";").unwrap();
+        }
+
+        let inner = if children.is_empty() {
+            indent.clone()
+        } else {
+            format!("{indent}    ")
+        };
+
+        writeln!(
+            dot,
+            "{inner}n{id} [label=\"{label}\", {shape}];",
+            id = node.id,
+            label = escape_dot(&node.label)
+        )
+        .unwrap();
+
+        if !children.is_empty() {
+            for child in children {
+                self.write_dot_node(dot, child, depth + 1);
+            }
+
+            writeln!(dot, "{indent}}}").unwrap();
+        }
+    }
+
+    /// Writes a node and its nested nodes as Mermaid.
+    fn write_mermaid_node(&self, mermaid: &mut String, node: &ExportNode, depth: usize) {
+        let indent = "    ".repeat(depth);
+        let label = escape_mermaid(&node.label);
+        let shape = match node.kind {
+            ExportNodeKind::Input => format!("[/\"{label}\"/]"),
+            ExportNodeKind::Decl => format!("[\"{label}\"]"),
+            ExportNodeKind::Output => format!("[\\\"{label}\"\\]"),
+            ExportNodeKind::Call => format!("(\"{label}\")"),
+            ExportNodeKind::Scatter => format!("{{{{\"{label}\"}}}}"),
+            ExportNodeKind::Conditional => format!("{{\"{label}\"}}"),
+        };
+
+        let children: Vec<_> = self
+            .nodes
+            .iter()
+            .filter(|n| n.parent == Some(node.id))
+            .collect();
+
+        if children.is_empty() {
+            writeln!(mermaid, "{indent}n{id}{shape}", id = node.id).unwrap();
+            return;
+        }
+
+        writeln!(mermaid, "{indent}subgraph s{id} [\" \"]", id = node.id).unwrap();
+        writeln!(mermaid, "{indent}    n{id}{shape}", id = node.id).unwrap();
+        for child in children {
+            self.write_mermaid_node(mermaid, child, depth + 1);
+        }
+
+        writeln!(mermaid, "{indent}end").unwrap();
+    }
+}
+
+/// Gets the definition of the workflow in the given document.
+fn workflow_definition(document: &Document) -> Option<WorkflowDefinition> {
+    match document.root().ast() {
+        Ast::V1(ast) => ast.workflows().next(),
+        _ => None,
+    }
+}
+
+/// Gets the target of a call statement as written (e.g. `lib.task`).
+fn call_target(statement: &CallStatement) -> String {
+    statement
+        .target()
+        .names()
+        .map(|n| n.text().to_string())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Gets the document and definition of the sub-workflow called by the given
+/// call statement.
+///
+/// Returns `None` if the call is not to a workflow of an imported document.
+fn sub_workflow<'a>(
+    document: &'a Document,
+    statement: &CallStatement,
+) -> Option<(&'a Document, WorkflowDefinition)> {
+    let target = statement.target();
+    let mut names = target.names();
+    let namespace = names.next()?;
+    let name = names.next()?;
+    let document = document.namespace(namespace.text())?.document();
+    if document.workflow()?.name() != name.text() {
+        return None;
+    }
+
+    Some((document, workflow_definition(document)?))
+}
+
+/// Escapes a string for use in a quoted DOT identifier.
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Escapes a string for use in a quoted Mermaid label.
+fn escape_mermaid(s: &str) -> String {
+    s.replace('"', "#quot;").replace('\n', " ")
+}
@@ -31,6 +31,7 @@ use wdl_ast::AstNode;
 use wdl_ast::Diagnostic;
 use wdl_ast::SyntaxNode;
 
+use crate::ImportsConfig;
 use crate::IncrementalChange;
 use crate::document::Document;
 
@@ -240,7 +241,15 @@ impl DocumentGraphNode {
     /// If a parse is not necessary, the current parse state is returned.
     ///
     /// Otherwise, the new parse state is returned.
-    pub fn parse(&self, tokio: &Handle, client: &Client) -> Result<ParseState> {
+    ///
+    /// Remote documents are downloaded and verified according to the given
+    /// imports configuration.
+    pub fn parse(
+        &self,
+        tokio: &Handle,
+        client: &Client,
+        imports: &ImportsConfig,
+    ) -> Result<ParseState> {
         if !self.needs_parse() {
             return Ok(self.parse_state.clone());
         }
@@ -251,7 +260,7 @@ impl DocumentGraphNode {
         }
 
         // Otherwise, fall back to a full parse.
-        self.full_parse(tokio, client)
+        self.full_parse(tokio, client, imports)
     }
 
     /// Performs an incremental parse of the document.
@@ -276,14 +285,25 @@ impl DocumentGraphNode {
     }
 
     /// Performs a full parse of the node.
-    fn full_parse(&self, tokio: &Handle, client: &Client) -> Result<ParseState> {
+    fn full_parse(
+        &self,
+        tokio: &Handle,
+        client: &Client,
+        imports: &ImportsConfig,
+    ) -> Result<ParseState> {
         let (version, source, lines) = match &self.change {
             None => {
                 // Fetch the source
                 let result = match self.uri.to_file_path() {
                     Ok(path) => fs::read_to_string(path).map_err(Into::into),
                     Err(_) => match self.uri.scheme() {
-                        "https" | "http" => Self::download_source(tokio, client, &self.uri),
+                        "https" | "http" => imports
+                            .check_download(&self.uri)
+                            .and_then(|_| Self::download_source(tokio, client, &self.uri))
+                            .and_then(|source| {
+                                imports.verify(&self.uri, &source)?;
+                                Ok(source)
+                            }),
                         scheme => Err(anyhow!("unsupported URI scheme `{scheme}`")),
                     },
                 };
@@ -389,6 +409,8 @@ impl DocumentGraphNode {
 /// Represents a graph of WDL analyzed documents.
 #[derive(Debug, Default)]
 pub struct DocumentGraph {
+    /// The configuration for resolving imports.
+    imports: ImportsConfig,
     /// The inner directional graph.
     ///
     /// Edges in the graph denote inverse dependency relationships (i.e. "is
@@ -416,6 +438,28 @@ pub struct DocumentGraph {
 }
 
 impl DocumentGraph {
+    /// Constructs a new document graph with the given imports configuration.
+    pub fn new(imports: ImportsConfig) -> Self {
+        Self {
+            imports,
+            ..Default::default()
+        }
+    }
+
+    /// Gets the configuration for resolving imports.
+    pub fn imports(&self) -> &ImportsConfig {
+        &self.imports
+    }
+
+    /// Resolves the URI of an import from the given importing document.
+    pub fn resolve_import(
+        &self,
+        importer: NodeIndex,
+        import: &str,
+    ) -> Result<Url, url::ParseError> {
+        self.imports.resolve(self.get(importer).uri(), import)
+    }
+
     /// Add a node to the document graph.
     pub fn add_node(&mut self, uri: Url, rooted: bool) -> NodeIndex {
         let index = match self.indexes.get(&uri) {
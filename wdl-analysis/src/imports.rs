@@ -0,0 +1,409 @@
+//! Configuration for resolving and pinning imported documents.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+use url::Url;
+
+use crate::path_to_uri;
+
+/// The prefix of the content hashes stored in an import lockfile.
+const HASH_PREFIX: &str = "sha256:";
+
+/// The current version of the import lockfile format.
+const LOCKFILE_VERSION: u32 = 1;
+
+/// Calculates the content hash of an imported document's source.
+pub fn content_hash(source: &str) -> String {
+    format!(
+        "{HASH_PREFIX}{hash:x}",
+        hash = Sha256::digest(source.as_bytes())
+    )
+}
+
+/// Represents a lockfile that pins the content hashes of remote imports.
+///
+/// The lockfile is serialized as JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ImportLockfile {
+    /// The version of the lockfile format.
+    version: u32,
+    /// The map of remote import URI to content hash.
+    imports: BTreeMap<String, String>,
+}
+
+impl Default for ImportLockfile {
+    fn default() -> Self {
+        Self {
+            version: LOCKFILE_VERSION,
+            imports: Default::default(),
+        }
+    }
+}
+
+impl ImportLockfile {
+    /// Reads an import lockfile from the given path.
+    pub fn read(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).with_context(|| {
+            format!(
+                "failed to read import lockfile `{path}`",
+                path = path.display()
+            )
+        })?;
+
+        let lockfile: Self = serde_json::from_str(&contents).with_context(|| {
+            format!(
+                "failed to deserialize import lockfile `{path}`",
+                path = path.display()
+            )
+        })?;
+
+        if lockfile.version != LOCKFILE_VERSION {
+            bail!(
+                "import lockfile `{path}` has unsupported version {version}",
+                path = path.display(),
+                version = lockfile.version
+            );
+        }
+
+        Ok(lockfile)
+    }
+
+    /// Writes the import lockfile to the given path.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut contents =
+            serde_json::to_string_pretty(self).context("failed to serialize import lockfile")?;
+        contents.push('\n');
+        fs::write(path, contents).with_context(|| {
+            format!(
+                "failed to write import lockfile `{path}`",
+                path = path.display()
+            )
+        })
+    }
+
+    /// Gets the pinned content hash of the given remote import.
+    pub fn get(&self, uri: &Url) -> Option<&str> {
+        self.imports.get(uri.as_str()).map(String::as_str)
+    }
+
+    /// Pins the content hash of the given remote import.
+    pub fn insert(&mut self, uri: &Url, hash: String) {
+        self.imports.insert(uri.to_string(), hash);
+    }
+
+    /// Iterates over the pinned remote imports and their content hashes.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.imports.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+/// Configuration for resolving imports.
+///
+/// By default, imports are resolved relative to the importing document and
+/// remote imports are downloaded on every analysis.
+///
+/// Clones of the configuration share the same import lockfile; the lockfile
+/// may be retrieved with [`ImportsConfig::lockfile`] after an analysis to
+/// persist any newly pinned imports.
+#[derive(Debug, Clone, Default)]
+pub struct ImportsConfig {
+    /// The directories to search for imports that cannot be found relative to
+    /// the importing document.
+    search_paths: Vec<PathBuf>,
+    /// The import URI prefix remappings.
+    ///
+    /// Each remapping is a pair of prefix and replacement URI.
+    remappings: Vec<(String, Url)>,
+    /// The import lockfile to verify remote imports against.
+    lockfile: Option<Arc<Mutex<ImportLockfile>>>,
+    /// Whether or not a remote import missing from the lockfile is an error.
+    ///
+    /// If `false`, the content hash of a missing import is added to the
+    /// lockfile.
+    frozen: bool,
+    /// Whether or not remote imports may be downloaded.
+    offline: bool,
+}
+
+impl ImportsConfig {
+    /// Adds a directory to search for imports that cannot be found relative to
+    /// the importing document.
+    ///
+    /// Search paths are consulted in the order they were added.
+    pub fn with_search_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.search_paths.push(path.into());
+        self
+    }
+
+    /// Adds a remapping of import URIs starting with the given prefix.
+    ///
+    /// The prefix of a matching import URI is replaced by the given
+    /// replacement URI, such as the file URI of a local checkout of a remote
+    /// repository.
+    ///
+    /// When multiple prefixes match, the longest prefix is used.
+    pub fn with_remapping(mut self, prefix: impl Into<String>, mut replacement: Url) -> Self {
+        // The replacement is treated as a directory
+        if !replacement.path().ends_with('/') {
+            replacement.set_path(&format!("{path}/", path = replacement.path()));
+        }
+
+        let prefix = prefix.into();
+        let index = self
+            .remappings
+            .partition_point(|(p, _)| p.len() >= prefix.len());
+        self.remappings.insert(index, (prefix, replacement));
+        self
+    }
+
+    /// Sets the lockfile used to verify the content of remote imports.
+    ///
+    /// If `frozen` is `true`, a remote import that is not in the lockfile is
+    /// an error; otherwise, its content hash is added to the lockfile.
+    pub fn with_lockfile(mut self, lockfile: ImportLockfile, frozen: bool) -> Self {
+        self.lockfile = Some(Arc::new(Mutex::new(lockfile)));
+        self.frozen = frozen;
+        self
+    }
+
+    /// Sets whether or not remote imports may be downloaded.
+    ///
+    /// When offline, remote imports must be remapped to local documents.
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Gets a snapshot of the import lockfile, if one was configured.
+    pub fn lockfile(&self) -> Option<ImportLockfile> {
+        self.lockfile.as_ref().map(|l| l.lock().clone())
+    }
+
+    /// Resolves an import from the given importing document URI.
+    pub fn resolve(&self, importer: &Url, import: &str) -> Result<Url, url::ParseError> {
+        let uri = importer.join(import)?;
+
+        for (prefix, replacement) in &self.remappings {
+            if let Some(rest) = uri.as_str().strip_prefix(prefix.as_str()) {
+                return replacement.join(rest);
+            }
+        }
+
+        // Consult the search paths only for relative imports that do not exist
+        let relative = matches!(
+            Url::parse(import),
+            Err(url::ParseError::RelativeUrlWithoutBase)
+        );
+        let exists = uri.to_file_path().map(|p| p.exists()).unwrap_or(true);
+        if relative && !exists {
+            if let Some(uri) = self
+                .search_paths
+                .iter()
+                .map(|dir| dir.join(import))
+                .find(|path| path.is_file())
+                .and_then(path_to_uri)
+            {
+                return Ok(uri);
+            }
+        }
+
+        Ok(uri)
+    }
+
+    /// Determines if the given remote import may be downloaded.
+    pub(crate) fn check_download(&self, uri: &Url) -> Result<()> {
+        if self.offline {
+            bail!("remote import `{uri}` cannot be downloaded as remote imports are disabled");
+        }
+
+        if self.frozen {
+            if let Some(lockfile) = &self.lockfile {
+                if lockfile.lock().get(uri).is_none() {
+                    bail!("remote import `{uri}` is not pinned in the import lockfile");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the downloaded source of a remote import against the lockfile.
+    ///
+    /// If the import is not pinned in the lockfile, it is added.
+    pub(crate) fn verify(&self, uri: &Url, source: &str) -> Result<()> {
+        let Some(lockfile) = &self.lockfile else {
+            return Ok(());
+        };
+
+        let actual = content_hash(source);
+        let mut lockfile = lockfile.lock();
+        match lockfile.get(uri) {
+            Some(expected) if expected != actual => {
+                bail!(
+                    "the content of remote import `{uri}` has changed: expected hash \
+                     `{expected}` but found `{actual}`"
+                );
+            }
+            Some(_) => Ok(()),
+            None if self.frozen => {
+                bail!("remote import `{uri}` is not pinned in the import lockfile")
+            }
+            None => {
+                lockfile.insert(uri, actual);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn it_resolves_imports() {
+        let dir = TempDir::new().expect("failed to create temporary directory");
+        let vendor = dir.path().join("vendor");
+        let lib = dir.path().join("lib");
+        fs::create_dir_all(vendor.join("tasks")).unwrap();
+        fs::create_dir_all(&lib).unwrap();
+        fs::write(lib.join("util.wdl"), "version 1.1").unwrap();
+        fs::write(dir.path().join("local.wdl"), "version 1.1").unwrap();
+
+        let config = ImportsConfig::default()
+            .with_search_path(&lib)
+            .with_remapping(
+                "https://example.com/org/repo/v1/",
+                path_to_uri(&vendor).unwrap(),
+            )
+            .with_remapping(
+                "https://example.com/org/repo/v1/tasks/",
+                path_to_uri(vendor.join("tasks")).unwrap(),
+            );
+
+        let importer = path_to_uri(dir.path().join("main.wdl")).unwrap();
+
+        // Relative imports that exist are not searched for
+        assert_eq!(
+            config.resolve(&importer, "local.wdl").unwrap(),
+            path_to_uri(dir.path().join("local.wdl")).unwrap()
+        );
+
+        // Relative imports that don't exist are searched for
+        assert_eq!(
+            config.resolve(&importer, "util.wdl").unwrap(),
+            path_to_uri(lib.join("util.wdl")).unwrap()
+        );
+        assert_eq!(
+            config.resolve(&importer, "missing.wdl").unwrap(),
+            path_to_uri(dir.path().join("missing.wdl")).unwrap()
+        );
+
+        // The longest remapped prefix is used
+        assert_eq!(
+            config
+                .resolve(&importer, "https://example.com/org/repo/v1/a.wdl")
+                .unwrap(),
+            path_to_uri(vendor.join("a.wdl")).unwrap()
+        );
+        assert_eq!(
+            config
+                .resolve(&importer, "https://example.com/org/repo/v1/tasks/b.wdl")
+                .unwrap(),
+            path_to_uri(vendor.join("tasks").join("b.wdl")).unwrap()
+        );
+
+        // Relative imports from a remote document are also remapped
+        let importer = Url::parse("https://example.com/org/repo/v1/tasks/b.wdl").unwrap();
+        assert_eq!(
+            config.resolve(&importer, "../a.wdl").unwrap(),
+            path_to_uri(vendor.join("a.wdl")).unwrap()
+        );
+
+        // Other remote imports are unchanged
+        assert_eq!(
+            config
+                .resolve(&importer, "https://example.com/other.wdl")
+                .unwrap()
+                .as_str(),
+            "https://example.com/other.wdl"
+        );
+    }
+
+    #[test]
+    fn it_verifies_remote_imports() {
+        let uri = Url::parse("https://example.com/a.wdl").unwrap();
+        let other = Url::parse("https://example.com/b.wdl").unwrap();
+
+        let mut lockfile = ImportLockfile::default();
+        lockfile.insert(&uri, content_hash("version 1.1"));
+
+        // An unfrozen lockfile pins new imports
+        let config = ImportsConfig::default().with_lockfile(lockfile.clone(), false);
+        config.check_download(&other).unwrap();
+        config.verify(&uri, "version 1.1").unwrap();
+        config.verify(&other, "version 1.2").unwrap();
+        assert_eq!(
+            config.lockfile().unwrap().get(&other),
+            Some(content_hash("version 1.2").as_str())
+        );
+
+        let e = config.verify(&uri, "version 1.2").unwrap_err();
+        assert!(e.to_string().contains("has changed"), "{e}");
+
+        // A frozen lockfile rejects new imports
+        let config = ImportsConfig::default().with_lockfile(lockfile, true);
+        config.check_download(&uri).unwrap();
+        let e = config.check_download(&other).unwrap_err();
+        assert!(e.to_string().contains("is not pinned"), "{e}");
+
+        // Offline configurations reject all downloads
+        let config = ImportsConfig::default().with_offline(true);
+        assert!(config.check_download(&uri).is_err());
+    }
+
+    #[test]
+    fn it_round_trips_lockfiles() {
+        let dir = TempDir::new().expect("failed to create temporary directory");
+        let path = dir.path().join("wdl.lock");
+
+        let mut lockfile = ImportLockfile::default();
+        lockfile.insert(
+            &Url::parse("https://example.com/a.wdl").unwrap(),
+            content_hash(""),
+        );
+        lockfile.write(&path).unwrap();
+
+        let read = ImportLockfile::read(&path).unwrap();
+        assert_eq!(read, lockfile);
+        assert_eq!(
+            read.iter().collect::<Vec<_>>(),
+            [(
+                "https://example.com/a.wdl",
+                "sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+            )]
+        );
+
+        fs::write(&path, r#"{ "version": 2, "imports": {} }"#).unwrap();
+        let e = ImportLockfile::read(&path).unwrap_err();
+        assert!(e.to_string().contains("unsupported version"), "{e}");
+    }
+}
@@ -39,20 +39,29 @@ use wdl_ast::SyntaxNode;
 use wdl_ast::SyntaxToken;
 
 mod analyzer;
+mod cache;
+pub mod dead_code;
 pub mod diagnostics;
+pub mod diff;
 pub mod document;
+pub mod duplicates;
 pub mod eval;
 mod graph;
+mod imports;
+pub mod metrics;
 mod queue;
 mod rayon;
 mod rules;
 pub mod stdlib;
 pub mod types;
+pub mod units;
 mod validation;
 mod visitor;
 
 pub use analyzer::*;
+pub use cache::*;
 pub use document::Document;
+pub use imports::*;
 pub use rules::*;
 pub use validation::*;
 pub use visitor::*;
@@ -0,0 +1,508 @@
+//! Complexity and size metrics of WDL documents.
+//!
+//! Metrics are computed from the abstract syntax tree of a document and do not
+//! require the document to be analyzed; the metrics of workflows are computed
+//! from the workflow evaluation graph (see
+//! [`WorkflowGraphBuilder`](crate::eval::v1::WorkflowGraphBuilder)).
+//!
+//! The metrics of a document may be exported as JSON or CSV.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use petgraph::Direction;
+use petgraph::algo::toposort;
+use petgraph::graph::NodeIndex;
+use serde::Serialize;
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::Span;
+use wdl_ast::SyntaxKind;
+use wdl_ast::v1::CommandPart;
+use wdl_ast::v1::CommandSection;
+use wdl_ast::v1::StrippedCommandPart;
+use wdl_ast::v1::TaskDefinition;
+use wdl_ast::v1::WorkflowDefinition;
+
+use crate::eval::v1::WorkflowGraphBuilder;
+use crate::eval::v1::WorkflowGraphNode;
+
+/// The header row of metrics exported as CSV.
+const CSV_HEADER: &str = "kind,name,imports,inputs,outputs,command_lines,placeholders,\
+                          runtime_keys,calls,max_nesting_depth,max_fan_out,critical_path_length";
+
+/// Represents the metrics of a task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TaskMetrics {
+    /// The name of the task.
+    name: String,
+    /// The span of the task's name.
+    #[serde(skip)]
+    span: Span,
+    /// The number of inputs of the task.
+    inputs: usize,
+    /// The number of outputs of the task.
+    outputs: usize,
+    /// The number of non-blank lines of the task's command.
+    command_lines: usize,
+    /// The number of placeholders in the task's command.
+    placeholders: usize,
+    /// The number of distinct keys of the task's `runtime` and `requirements`
+    /// sections.
+    runtime_keys: usize,
+}
+
+impl TaskMetrics {
+    /// Computes the metrics of the given task.
+    pub fn new(task: &TaskDefinition) -> Self {
+        let name = task.name();
+        let (command_lines, placeholders) = task
+            .command()
+            .map(|c| {
+                let placeholders = c
+                    .parts()
+                    .filter(|p| matches!(p, CommandPart::Placeholder(_)))
+                    .count();
+                (command_lines(&c), placeholders)
+            })
+            .unwrap_or_default();
+
+        let mut keys = BTreeSet::new();
+        if let Some(section) = task.runtime() {
+            keys.extend(section.items().map(|i| i.name().text().to_string()));
+        }
+
+        if let Some(section) = task.requirements() {
+            keys.extend(section.items().map(|i| i.name().text().to_string()));
+        }
+
+        Self {
+            name: name.text().to_string(),
+            span: name.span(),
+            inputs: task
+                .input()
+                .map(|s| s.declarations().count())
+                .unwrap_or_default(),
+            outputs: task
+                .output()
+                .map(|s| s.declarations().count())
+                .unwrap_or_default(),
+            command_lines,
+            placeholders,
+            runtime_keys: keys.len(),
+        }
+    }
+
+    /// Gets the name of the task.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the span of the task's name.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Gets the number of inputs of the task.
+    pub fn inputs(&self) -> usize {
+        self.inputs
+    }
+
+    /// Gets the number of outputs of the task.
+    pub fn outputs(&self) -> usize {
+        self.outputs
+    }
+
+    /// Gets the number of non-blank lines of the task's command.
+    ///
+    /// Each placeholder is counted as part of the line it appears on.
+    pub fn command_lines(&self) -> usize {
+        self.command_lines
+    }
+
+    /// Gets the number of placeholders in the task's command.
+    pub fn placeholders(&self) -> usize {
+        self.placeholders
+    }
+
+    /// Gets the number of distinct keys of the task's `runtime` and
+    /// `requirements` sections.
+    pub fn runtime_keys(&self) -> usize {
+        self.runtime_keys
+    }
+}
+
+/// Represents the metrics of a workflow.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WorkflowMetrics {
+    /// The name of the workflow.
+    name: String,
+    /// The span of the workflow's name.
+    #[serde(skip)]
+    span: Span,
+    /// The number of call statements in the workflow.
+    calls: usize,
+    /// The maximum nesting depth of scatter and conditional statements.
+    max_nesting_depth: usize,
+    /// The maximum number of calls that directly depend on a single call.
+    max_fan_out: usize,
+    /// The number of calls in the longest chain of dependent calls.
+    critical_path_length: usize,
+}
+
+impl WorkflowMetrics {
+    /// Computes the metrics of the given workflow.
+    pub fn new(workflow: &WorkflowDefinition) -> Self {
+        let name = workflow.name();
+        let mut calls = 0;
+        let mut max_nesting_depth = 0;
+        for node in workflow.inner().descendants() {
+            match node.kind() {
+                SyntaxKind::CallStatementNode => calls += 1,
+                SyntaxKind::ScatterStatementNode | SyntaxKind::ConditionalStatementNode => {
+                    let depth = node
+                        .ancestors()
+                        .filter(|n| {
+                            matches!(
+                                n.kind(),
+                                SyntaxKind::ScatterStatementNode
+                                    | SyntaxKind::ConditionalStatementNode
+                            )
+                        })
+                        .count();
+                    max_nesting_depth = max_nesting_depth.max(depth);
+                }
+                _ => {}
+            }
+        }
+
+        // Diagnostics are reported when the document is analyzed
+        let mut diagnostics = Vec::new();
+        let graph = WorkflowGraphBuilder::default().build(workflow, &mut diagnostics, |_| false);
+        let is_call = |index: NodeIndex| matches!(graph[index], WorkflowGraphNode::Call(_));
+
+        // The fan-out of a call is the number of calls reachable from the call without
+        // passing through another call
+        let mut max_fan_out = 0;
+        for call in graph.node_indices().filter(|i| is_call(*i)) {
+            let mut dependents = BTreeSet::new();
+            let mut visited = BTreeSet::new();
+            let mut stack = graph
+                .neighbors_directed(call, Direction::Outgoing)
+                .collect::<Vec<_>>();
+            while let Some(index) = stack.pop() {
+                if !visited.insert(index) {
+                    continue;
+                }
+
+                if is_call(index) {
+                    dependents.insert(index);
+                } else {
+                    stack.extend(graph.neighbors_directed(index, Direction::Outgoing));
+                }
+            }
+
+            max_fan_out = max_fan_out.max(dependents.len());
+        }
+
+        // The graph builder does not add edges that would form a cycle
+        let mut critical_path_length = 0;
+        let mut lengths: HashMap<NodeIndex, usize> = HashMap::new();
+        for index in toposort(&graph, None).unwrap_or_default() {
+            let length = graph
+                .neighbors_directed(index, Direction::Incoming)
+                .map(|i| lengths.get(&i).copied().unwrap_or_default())
+                .max()
+                .unwrap_or_default()
+                + usize::from(is_call(index));
+            critical_path_length = critical_path_length.max(length);
+            lengths.insert(index, length);
+        }
+
+        Self {
+            name: name.text().to_string(),
+            span: name.span(),
+            calls,
+            max_nesting_depth,
+            max_fan_out,
+            critical_path_length,
+        }
+    }
+
+    /// Gets the name of the workflow.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the span of the workflow's name.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Gets the number of call statements in the workflow.
+    pub fn calls(&self) -> usize {
+        self.calls
+    }
+
+    /// Gets the maximum nesting depth of scatter and conditional statements.
+    ///
+    /// The depth of a workflow without scatter or conditional statements is
+    /// zero.
+    pub fn max_nesting_depth(&self) -> usize {
+        self.max_nesting_depth
+    }
+
+    /// Gets the maximum number of calls that directly depend on a single call.
+    ///
+    /// A call depends on another call if it references the other call's
+    /// outputs, either directly or through declarations, or if it is nested in
+    /// a scatter or conditional statement that depends on the other call.
+    pub fn max_fan_out(&self) -> usize {
+        self.max_fan_out
+    }
+
+    /// Gets the number of calls in the longest chain of dependent calls.
+    ///
+    /// This is the minimum number of calls that must run one after another to
+    /// evaluate the workflow.
+    pub fn critical_path_length(&self) -> usize {
+        self.critical_path_length
+    }
+}
+
+/// Represents the metrics of a document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DocumentMetrics {
+    /// The number of import statements in the document.
+    imports: usize,
+    /// The metrics of the document's tasks.
+    tasks: Vec<TaskMetrics>,
+    /// The metrics of the document's workflow.
+    workflow: Option<WorkflowMetrics>,
+}
+
+impl DocumentMetrics {
+    /// Computes the metrics of the given document.
+    ///
+    /// Returns `None` if the document is not a WDL 1.x document.
+    pub fn new(document: &wdl_ast::Document) -> Option<Self> {
+        let ast = document.ast().into_v1()?;
+        Some(Self {
+            imports: ast.imports().count(),
+            tasks: ast.tasks().map(|t| TaskMetrics::new(&t)).collect(),
+            workflow: ast.workflows().next().map(|w| WorkflowMetrics::new(&w)),
+        })
+    }
+
+    /// Gets the number of import statements in the document.
+    pub fn imports(&self) -> usize {
+        self.imports
+    }
+
+    /// Gets the metrics of the document's tasks.
+    pub fn tasks(&self) -> &[TaskMetrics] {
+        &self.tasks
+    }
+
+    /// Gets the metrics of the document's workflow.
+    pub fn workflow(&self) -> Option<&WorkflowMetrics> {
+        self.workflow.as_ref()
+    }
+
+    /// Exports the metrics as a JSON object with `imports`, `tasks`, and
+    /// `workflow` fields.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("metrics should serialize")
+    }
+
+    /// Exports the metrics as CSV.
+    ///
+    /// The first row is a header; it is followed by a `document` row, a
+    /// `task` row for each task, and a `workflow` row if the document has a
+    /// workflow. Columns that do not apply to the kind of a row are empty.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        writeln!(csv, "{CSV_HEADER}").unwrap();
+        writeln!(csv, "document,,{imports},,,,,,,,,", imports = self.imports).unwrap();
+        for task in &self.tasks {
+            writeln!(
+                csv,
+                "task,{name},,{inputs},{outputs},{lines},{placeholders},{keys},,,,",
+                name = task.name,
+                inputs = task.inputs,
+                outputs = task.outputs,
+                lines = task.command_lines,
+                placeholders = task.placeholders,
+                keys = task.runtime_keys
+            )
+            .unwrap();
+        }
+
+        if let Some(workflow) = &self.workflow {
+            writeln!(
+                csv,
+                "workflow,{name},,,,,,,{calls},{depth},{fan_out},{path}",
+                name = workflow.name,
+                calls = workflow.calls,
+                depth = workflow.max_nesting_depth,
+                fan_out = workflow.max_fan_out,
+                path = workflow.critical_path_length
+            )
+            .unwrap();
+        }
+
+        csv
+    }
+}
+
+/// Counts the non-blank lines of a command section.
+fn command_lines(command: &CommandSection) -> usize {
+    let mut text = String::new();
+    match command.strip_whitespace() {
+        Some(parts) => {
+            for part in parts {
+                match part {
+                    StrippedCommandPart::Text(t) => text.push_str(&t),
+                    StrippedCommandPart::Placeholder(_) => text.push_str("~{}"),
+                }
+            }
+        }
+        None => {
+            for part in command.parts() {
+                match part {
+                    CommandPart::Text(t) => text.push_str(t.text()),
+                    CommandPart::Placeholder(_) => text.push_str("~{}"),
+                }
+            }
+        }
+    }
+
+    text.lines().filter(|l| !l.trim().is_empty()).count()
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use wdl_ast::Document;
+
+    use super::*;
+
+    #[test]
+    fn it_computes_metrics() {
+        let (document, diagnostics) = Document::parse(
+            r#"version 1.1
+
+import "a.wdl"
+import "b.wdl" as b
+
+task align {
+    input {
+        File reads
+        File reference
+        Int threads = 4
+    }
+
+    command <<<
+        aligner \
+            --threads ~{threads} \
+            ~{reference} ~{reads} > out.bam
+
+        echo done
+    >>>
+
+    output {
+        File bam = "out.bam"
+    }
+
+    runtime {
+        container: "aligner:1.0"
+        cpu: threads
+    }
+
+    requirements {
+        cpu: threads
+        memory: "4 GiB"
+    }
+}
+
+task noop {
+    command <<<>>>
+}
+
+workflow main {
+    input {
+        Array[File] samples
+        File reference
+        Boolean index = true
+    }
+
+    call noop as first
+
+    scatter (sample in samples) {
+        if (index) {
+            call align { input: reads = sample, reference }
+        }
+
+        call noop as second after first
+    }
+
+    Array[File?] bams = align.bam
+
+    call noop as third after first
+    call noop as fourth after third
+    call noop as fifth after fourth
+
+    output {
+        Array[File?] out = bams
+    }
+}
+"#,
+        );
+        assert!(diagnostics.is_empty());
+
+        let metrics = DocumentMetrics::new(&document).expect("should have metrics");
+        assert_eq!(metrics.imports(), 2);
+        assert_eq!(metrics.tasks().len(), 2);
+
+        let align = &metrics.tasks()[0];
+        assert_eq!(align.name(), "align");
+        assert_eq!(align.inputs(), 3);
+        assert_eq!(align.outputs(), 1);
+        assert_eq!(align.command_lines(), 4);
+        assert_eq!(align.placeholders(), 3);
+        assert_eq!(align.runtime_keys(), 3);
+
+        let noop = &metrics.tasks()[1];
+        assert_eq!(noop.inputs(), 0);
+        assert_eq!(noop.command_lines(), 0);
+        assert_eq!(noop.placeholders(), 0);
+        assert_eq!(noop.runtime_keys(), 0);
+
+        let workflow = metrics.workflow().expect("should have a workflow");
+        assert_eq!(workflow.name(), "main");
+        assert_eq!(workflow.calls(), 6);
+        assert_eq!(workflow.max_nesting_depth(), 2);
+        assert_eq!(workflow.max_fan_out(), 3);
+        assert_eq!(workflow.critical_path_length(), 4);
+
+        assert_eq!(
+            metrics.to_csv(),
+            "kind,name,imports,inputs,outputs,command_lines,placeholders,runtime_keys,calls,\
+             max_nesting_depth,max_fan_out,critical_path_length
+document,,2,,,,,,,,,
+task,align,,3,1,4,3,3,,,,
+task,noop,,0,0,0,0,0,,,,
+workflow,main,,,,,,,6,2,3,4
+"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&metrics.to_json()).expect("should be valid JSON");
+        assert_eq!(json["imports"], 2);
+        assert_eq!(json["tasks"][0]["name"], "align");
+        assert_eq!(json["tasks"][0]["command_lines"], 4);
+        assert_eq!(json["workflow"]["critical_path_length"], 4);
+        assert!(json["tasks"][0].get("span").is_none());
+    }
+}
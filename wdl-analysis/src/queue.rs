@@ -6,6 +6,8 @@ use std::ops::Range;
 use std::panic;
 use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::time::Instant;
 
 use anyhow::Context;
@@ -27,16 +29,22 @@ use tracing::error;
 use tracing::info;
 use url::Url;
 use wdl_ast::Ast;
+use wdl_ast::AstNode;
 use wdl_ast::AstToken;
+use wdl_ast::Diagnostic;
 use wdl_ast::Node;
 use wdl_ast::Severity;
 use wdl_format::Formatter;
 use wdl_format::element::node::AstNodeFormatExt as _;
 
 use crate::AnalysisResult;
+use crate::CustomRule;
 use crate::DiagnosticsConfig;
+use crate::ImportsConfig;
 use crate::IncrementalChange;
 use crate::ProgressKind;
+use crate::SyntaxNodeExt;
+use crate::ValidationCache;
 use crate::document::Document;
 use crate::graph::DfsSpace;
 use crate::graph::DocumentGraph;
@@ -128,12 +136,22 @@ enum Cancelable<T> {
     Canceled,
 }
 
+/// The identifier of the next analysis queue to be created.
+static NEXT_QUEUE_ID: AtomicUsize = AtomicUsize::new(0);
+
 /// Represents the analysis queue.
 pub struct AnalysisQueue<Progress, Context, Return, Validator> {
+    /// The unique identifier of the analysis queue.
+    ///
+    /// This is used to associate the thread-local validators with the queue
+    /// that created them.
+    id: usize,
     /// The document graph maintained by the analysis queue.
     graph: Arc<RwLock<DocumentGraph>>,
     /// The diagnostics configuration to use.
     config: DiagnosticsConfig,
+    /// The persistent validation cache to use, if any.
+    cache: Option<ValidationCache>,
     /// The handle to the tokio runtime for blocking on async tasks.
     tokio: Handle,
     /// The HTTP client to use for fetching documents.
@@ -156,13 +174,17 @@ where
     /// Constructs a new analysis queue.
     pub fn new(
         config: DiagnosticsConfig,
+        imports: ImportsConfig,
+        cache: Option<ValidationCache>,
         tokio: Handle,
         progress: Progress,
         validator: Validator,
     ) -> Self {
         Self {
-            graph: Default::default(),
+            id: NEXT_QUEUE_ID.fetch_add(1, Ordering::Relaxed),
+            graph: Arc::new(RwLock::new(DocumentGraph::new(imports))),
             config,
+            cache,
             tokio,
             progress: Arc::new(progress),
             marker: PhantomData,
@@ -456,18 +478,31 @@ where
                             return None;
                         }
 
+                        let id = self.id;
                         let graph = self.graph.clone();
                         let config = self.config;
+                        let cache = self.cache.clone();
                         let validator = self.validator.clone();
                         Some(RayonHandle::spawn(move || {
                             thread_local! {
-                                static VALIDATOR: RefCell<Option<crate::Validator>> = const { RefCell::new(None) };
+                                static VALIDATOR: RefCell<Option<(usize, crate::Validator)>> = const { RefCell::new(None) };
                             }
 
                             let result = panic::catch_unwind(AssertUnwindSafe(|| {
                                 VALIDATOR.with_borrow_mut(|v| {
-                                    let validator = v.get_or_insert_with(|| validator());
-                                    Self::analyze_node(config, graph.clone(), index, validator)
+                                    // Recreate the validator if it was created by another queue
+                                    if v.as_ref().map(|(i, _)| *i) != Some(id) {
+                                        *v = Some((id, validator()));
+                                    }
+
+                                    let (_, validator) = v.as_mut().expect("should have validator");
+                                    Self::analyze_node(
+                                        config,
+                                        cache.as_ref(),
+                                        graph.clone(),
+                                        index,
+                                        validator,
+                                    )
                                 })
                             }));
 
@@ -607,7 +642,7 @@ where
         RayonHandle::spawn(move || {
             let graph = graph.read();
             let node = graph.get(index);
-            let state = node.parse(&tokio, &client);
+            let state = node.parse(&tokio, &client, graph.imports());
             (index, state)
         })
     }
@@ -645,7 +680,7 @@ where
                             None => continue,
                         };
 
-                        let import_uri = match graph.get(index).uri().join(text.text()) {
+                        let import_uri = match graph.resolve_import(index, text.text()) {
                             Ok(uri) => uri,
                             Err(_) => continue,
                         };
@@ -694,9 +729,61 @@ where
         Ok(())
     }
 
+    /// Runs the enabled custom rules on an analyzed document.
+    ///
+    /// Returns the diagnostics of the custom rules with the configured
+    /// severities applied.
+    fn check_custom_rules(
+        config: &DiagnosticsConfig,
+        rules: &[CustomRule],
+        document: &Document,
+    ) -> Vec<Diagnostic> {
+        // Apply any document-wide exceptions from the version statement
+        let exceptions = match document.root().version_statement() {
+            Some(stmt) => stmt.inner().rule_exceptions(),
+            None => return Vec::new(),
+        };
+
+        let rules: Vec<_> = rules
+            .iter()
+            .filter(|r| !exceptions.contains(r.id()))
+            .filter_map(|r| Some((r, config.custom_rule_severity(r)?)))
+            .collect();
+        if rules.is_empty() {
+            return Vec::new();
+        }
+
+        let mut validator = crate::Validator::empty();
+        validator.add_visitors(rules.iter().map(|(r, _)| r.instantiate()));
+
+        // The validator is dropped before returning so that the rules release any
+        // references to the document
+        let Err(diagnostics) = validator.validate(document) else {
+            return Vec::new();
+        };
+
+        diagnostics
+            .into_iter()
+            .map(|diagnostic| {
+                let severity = diagnostic
+                    .rule()
+                    .and_then(|id| rules.iter().find(|(r, _)| r.id() == id))
+                    .map(|(_, severity)| *severity);
+                match severity {
+                    Some(severity) => diagnostic.with_severity(severity),
+                    None => diagnostic,
+                }
+            })
+            .collect()
+    }
+
     /// Analyzes a node in the document graph.
+    ///
+    /// If a cache is given and the document and its transitive imports are
+    /// unchanged, validation is skipped in favor of the cached diagnostics.
     fn analyze_node(
         config: DiagnosticsConfig,
+        cache: Option<&ValidationCache>,
         graph: Arc<RwLock<DocumentGraph>>,
         index: NodeIndex,
         validator: &mut crate::Validator,
@@ -707,8 +794,39 @@ where
 
         match &graph.get(index).parse_state() {
             ParseState::Parsed { diagnostics, .. } if diagnostics.is_empty() => {
-                if let Err(new_diagnostics) = validator.validate(&document) {
-                    document.extend_diagnostics(new_diagnostics);
+                let uri = graph.get(index).uri();
+                let cache = cache.and_then(|c| {
+                    Some((c, c.key(&config, validator.custom_rules(), &graph, index)?))
+                });
+                match cache.as_ref().and_then(|(cache, key)| cache.load(uri, key)) {
+                    Some(cached) => {
+                        debug!("using cached validation diagnostics for `{uri}`");
+                        if !cached.is_empty() {
+                            document.extend_diagnostics(cached);
+                        }
+                    }
+                    None => {
+                        let mut new_diagnostics = match validator.validate(&document) {
+                            Ok(()) => Vec::new(),
+                            Err(diagnostics) => diagnostics,
+                        };
+
+                        new_diagnostics.extend(Self::check_custom_rules(
+                            &config,
+                            validator.custom_rules(),
+                            &document,
+                        ));
+
+                        if let Some((cache, key)) = &cache {
+                            if let Err(e) = cache.store(uri, key, &new_diagnostics) {
+                                error!("failed to store validation cache entry for `{uri}`: {e:#}");
+                            }
+                        }
+
+                        if !new_diagnostics.is_empty() {
+                            document.extend_diagnostics(new_diagnostics);
+                        }
+                    }
                 }
             }
             _ => {}
@@ -1,7 +1,12 @@
 //! Implementation of analysis rules.
 
+use std::fmt;
+use std::sync::Arc;
+
 use wdl_ast::Severity;
 
+use crate::Visitor;
+
 /// The rule identifier for unused import warnings.
 pub const UNUSED_IMPORT_RULE_ID: &str = "UnusedImport";
 
@@ -17,6 +22,9 @@ pub const UNUSED_CALL_RULE_ID: &str = "UnusedCall";
 /// The rule identifier for unnecessary function call warnings.
 pub const UNNECESSARY_FUNCTION_CALL: &str = "UnnecessaryFunctionCall";
 
+/// The rule identifier for constant evaluation failures.
+pub const CONSTANT_EVALUATION_RULE_ID: &str = "ConstantEvaluation";
+
 /// A trait implemented by analysis rules.
 pub trait Rule: Send + Sync {
     /// The unique identifier for the rule.
@@ -31,6 +39,22 @@ pub trait Rule: Send + Sync {
     /// Get the long-form explanation of the rule.
     fn explanation(&self) -> &'static str;
 
+    /// Gets examples of WDL documents that violate the rule.
+    ///
+    /// Each example is a complete document for which the rule emits at least
+    /// one diagnostic.
+    fn bad_examples(&self) -> &[&'static str] {
+        &[]
+    }
+
+    /// Gets examples of WDL documents that conform to the rule.
+    ///
+    /// Each example is a complete document for which the rule emits no
+    /// diagnostics.
+    fn good_examples(&self) -> &[&'static str] {
+        &[]
+    }
+
     /// Denies the rule.
     ///
     /// Denying the rule treats any diagnostics it emits as an error.
@@ -40,6 +64,81 @@ pub trait Rule: Send + Sync {
     fn severity(&self) -> Severity;
 }
 
+/// Represents a custom analysis rule.
+///
+/// A custom rule is a [`Visitor`] that also implements [`Rule`]; custom rules
+/// are added to a [`Validator`](crate::Validator) and run on every document
+/// after it has been analyzed.
+///
+/// As the document passed to [`Visitor::document`] is the analyzed document, a
+/// custom rule has access to the types and scopes of the document in addition
+/// to its syntax.
+///
+/// The diagnostics a custom rule adds should be attributed to the rule's
+/// identifier with [`Diagnostic::with_rule`](wdl_ast::Diagnostic::with_rule);
+/// the severity of such diagnostics is set according to
+/// [`DiagnosticsConfig::custom_rules`](crate::DiagnosticsConfig::custom_rules).
+#[derive(Clone)]
+pub struct CustomRule {
+    /// The identifier of the rule.
+    id: &'static str,
+    /// The short description of the rule.
+    description: &'static str,
+    /// The default severity of the rule.
+    severity: Severity,
+    /// The function used to create a new instance of the rule.
+    factory: Arc<dyn Fn() -> Box<dyn Visitor> + Send + Sync>,
+}
+
+impl CustomRule {
+    /// Creates a new custom rule from the given rule factory function.
+    pub(crate) fn new<R, F>(factory: F) -> Self
+    where
+        R: Rule + Visitor + 'static,
+        F: Fn() -> R + Send + Sync + 'static,
+    {
+        let rule = factory();
+        Self {
+            id: rule.id(),
+            description: rule.description(),
+            severity: rule.severity(),
+            factory: Arc::new(move || Box::new(factory())),
+        }
+    }
+
+    /// Gets the identifier of the rule.
+    pub fn id(&self) -> &'static str {
+        self.id
+    }
+
+    /// Gets the short description of the rule.
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Gets the default severity of the rule.
+    ///
+    /// The severity used for the rule's diagnostics may be overridden with
+    /// [`DiagnosticsConfig::custom_rules`](crate::DiagnosticsConfig::custom_rules).
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Creates a new instance of the rule's visitor.
+    pub(crate) fn instantiate(&self) -> Box<dyn Visitor> {
+        (self.factory)()
+    }
+}
+
+impl fmt::Debug for CustomRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomRule")
+            .field("id", &self.id)
+            .field("severity", &self.severity)
+            .finish_non_exhaustive()
+    }
+}
+
 /// Gets the list of all analysis rules.
 pub fn rules() -> Vec<Box<dyn Rule>> {
     let rules: Vec<Box<dyn Rule>> = vec![
@@ -48,6 +147,7 @@ pub fn rules() -> Vec<Box<dyn Rule>> {
         Box::<UnusedDeclarationRule>::default(),
         Box::<UnusedCallRule>::default(),
         Box::<UnnecessaryFunctionCall>::default(),
+        Box::<ConstantEvaluationRule>::default(),
     ];
 
     // Ensure all the rule ids are unique and pascal case
@@ -101,6 +201,27 @@ impl Rule for UnusedImportRule {
          impact parsing and evaluation performance."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+import "lib.wdl"
+
+workflow hello {
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+import "lib.wdl"
+
+workflow hello {
+    call lib.greet { name = "world" }
+}
+"#]
+    }
+
     fn deny(&mut self) {
         self.0 = Severity::Error;
     }
@@ -141,6 +262,36 @@ impl Rule for UnusedInputRule {
          file inputs in tasks can also cause unnecessary file localizations."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    input {
+        String name
+    }
+
+    command <<<
+        echo "Hello!"
+    >>>
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    input {
+        String name
+    }
+
+    command <<<
+        echo "Hello, ~{name}!"
+    >>>
+}
+"#]
+    }
+
     fn deny(&mut self) {
         self.0 = Severity::Error;
     }
@@ -182,6 +333,32 @@ impl Rule for UnusedDeclarationRule {
          code."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    String greeting = "Hello"
+
+    command <<<
+        echo "Hello!"
+    >>>
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    String greeting = "Hello"
+
+    command <<<
+        echo "~{greeting}!"
+    >>>
+}
+"#]
+    }
+
     fn deny(&mut self) {
         self.0 = Severity::Error;
     }
@@ -221,6 +398,48 @@ impl Rule for UnusedCallRule {
         "Unused calls may cause unnecessary consumption of compute resources."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        echo "Hello!"
+    >>>
+
+    output {
+        String greeting = read_string(stdout())
+    }
+}
+
+workflow hello {
+    call say_hello
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        echo "Hello!"
+    >>>
+
+    output {
+        String greeting = read_string(stdout())
+    }
+}
+
+workflow hello {
+    call say_hello
+
+    output {
+        String greeting = say_hello.greeting
+    }
+}
+"#]
+    }
+
     fn deny(&mut self) {
         self.0 = Severity::Error;
     }
@@ -260,6 +479,102 @@ impl Rule for UnnecessaryFunctionCall {
         "Unnecessary function calls may impact evaluation performance."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    input {
+        String name
+    }
+
+    String greeting = "Hello, ~{select_first([name])}!"
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    input {
+        String? name
+    }
+
+    String greeting = "Hello, ~{select_first([name, "world"])}!"
+}
+"#]
+    }
+
+    fn deny(&mut self) {
+        self.0 = Severity::Error;
+    }
+
+    fn severity(&self) -> Severity {
+        self.0
+    }
+}
+
+/// Represents the constant evaluation rule.
+#[derive(Debug, Clone, Copy)]
+pub struct ConstantEvaluationRule(Severity);
+
+impl ConstantEvaluationRule {
+    /// Creates a new constant evaluation rule.
+    pub fn new() -> Self {
+        Self(Severity::Warning)
+    }
+}
+
+impl Default for ConstantEvaluationRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for ConstantEvaluationRule {
+    fn id(&self) -> &'static str {
+        CONSTANT_EVALUATION_RULE_ID
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures that constant expressions do not fail when evaluated."
+    }
+
+    fn explanation(&self) -> &'static str {
+        "Expressions that only depend on literal values, private declarations, and the default \
+         values of inputs can be evaluated without running a task or workflow. Such an \
+         expression that divides by zero, indexes past the end of an array, or specifies a \
+         `memory` or `container` requirement that is never valid will fail at runtime. A failure \
+         that depends on the default value of an input is reported as a warning as the input may \
+         be given a different value at runtime."
+    }
+
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    Array[String] names = []
+
+    output {
+        String name = names[0]
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    Array[String] names = ["world"]
+
+    output {
+        String name = names[0]
+    }
+}
+"#]
+    }
+
     fn deny(&mut self) {
         self.0 = Severity::Error;
     }
@@ -48,6 +48,7 @@ impl Constraint for SizeableConstraint {
                     type_is_sizable(ty.key_type()) | type_is_sizable(ty.value_type())
                 }
                 CompoundType::Struct(s) => s.members().values().any(type_is_sizable),
+                CompoundType::Enum(_) => false,
             }
         }
 
@@ -126,6 +127,8 @@ impl Constraint for JsonSerializableConstraint {
                         && type_is_serializable(ty.value_type())
                 }
                 CompoundType::Struct(s) => s.members().values().all(type_is_serializable),
+                // Enums are serialized as the name of the variant
+                CompoundType::Enum(_) => true,
             }
         }
 
@@ -233,6 +233,16 @@ impl Type {
         }
     }
 
+    /// Converts the type to an enum type.
+    ///
+    /// Returns `None` if the type is not an enum type.
+    pub fn as_enum(&self) -> Option<&EnumType> {
+        match self {
+            Self::Compound(CompoundType::Enum(ty), _) => Some(ty),
+            _ => None,
+        }
+    }
+
     /// Determines if the type is `Union`.
     pub fn is_union(&self) -> bool {
         matches!(self, Type::Union)
@@ -391,6 +401,12 @@ impl Coercible for Type {
                 src.is_coercible_to(target)
             }
 
+            // Enum -> String, Enum -> String?, Enum? -> String?
+            (
+                Self::Compound(CompoundType::Enum(_), src_opt),
+                Self::Primitive(PrimitiveType::String, target_opt),
+            ) => !*src_opt || *target_opt,
+
             // Object -> Object, Object -> Object?, Object? -> Object?
             (Self::Object, Self::Object)
             | (Self::Object, Self::OptionalObject)
@@ -474,6 +490,12 @@ impl From<StructType> for Type {
     }
 }
 
+impl From<EnumType> for Type {
+    fn from(value: EnumType) -> Self {
+        Self::Compound(value.into(), false)
+    }
+}
+
 impl From<CallType> for Type {
     fn from(value: CallType) -> Self {
         Self::Call(value)
@@ -491,6 +513,8 @@ pub enum CompoundType {
     Map(Arc<MapType>),
     /// The type is a struct (e.g. `Foo`).
     Struct(Arc<StructType>),
+    /// The type is an enum (e.g. `Color`).
+    Enum(Arc<EnumType>),
 }
 
 impl CompoundType {
@@ -534,6 +558,16 @@ impl CompoundType {
         }
     }
 
+    /// Converts the compound type to an enum type.
+    ///
+    /// Returns `None` if the compound type is not an enum type.
+    pub fn as_enum(&self) -> Option<&EnumType> {
+        match self {
+            Self::Enum(ty) => Some(ty),
+            _ => None,
+        }
+    }
+
     /// Calculates a common type between two compound types.
     ///
     /// This method does not attempt coercion; it only attempts to find common
@@ -568,6 +602,7 @@ impl fmt::Display for CompoundType {
             Self::Pair(ty) => ty.fmt(f),
             Self::Map(ty) => ty.fmt(f),
             Self::Struct(ty) => ty.fmt(f),
+            Self::Enum(ty) => ty.fmt(f),
         }
     }
 }
@@ -591,6 +626,9 @@ impl Coercible for CompoundType {
             // members with identical names and compatible types)
             (Self::Struct(src), Self::Struct(target)) => src.is_coercible_to(target),
 
+            // Enum -> Enum, Enum -> Enum?, Enum? -> Enum? (if the two enum types are the same)
+            (Self::Enum(src), Self::Enum(target)) => src == target,
+
             // Map[String, X] -> Struct, Map[String, X] -> Struct?, Map[String, X]? -> Struct? (if
             // `Map` keys match struct member name and all struct member types are coercible from X)
             (Self::Map(src), Self::Struct(target)) => {
@@ -659,6 +697,12 @@ impl From<StructType> for CompoundType {
     }
 }
 
+impl From<EnumType> for CompoundType {
+    fn from(value: EnumType) -> Self {
+        Self::Enum(value.into())
+    }
+}
+
 /// Represents the type of an `Array`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ArrayType {
@@ -873,6 +917,51 @@ impl Coercible for StructType {
     }
 }
 
+/// Represents the type of an enum.
+///
+/// Enums are only available in the `development` version of WDL.
+#[derive(Debug, PartialEq, Eq)]
+pub struct EnumType {
+    /// The name of the enum.
+    name: Arc<String>,
+    /// The names of the variants of the enum.
+    variants: Vec<String>,
+}
+
+impl EnumType {
+    /// Constructs a new enum type definition.
+    pub fn new<V>(name: impl Into<String>, variants: impl IntoIterator<Item = V>) -> Self
+    where
+        V: Into<String>,
+    {
+        Self {
+            name: Arc::new(name.into()),
+            variants: variants.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Gets the name of the enum.
+    pub fn name(&self) -> &Arc<String> {
+        &self.name
+    }
+
+    /// Gets the names of the variants of the enum.
+    pub fn variants(&self) -> &[String] {
+        &self.variants
+    }
+
+    /// Determines if the enum has a variant with the given name.
+    pub fn has_variant(&self, name: &str) -> bool {
+        self.variants.iter().any(|v| v == name)
+    }
+}
+
+impl fmt::Display for EnumType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{name}", name = self.name)
+    }
+}
+
 /// The kind of call for a call type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CallKind {
@@ -1851,6 +1940,30 @@ mod test {
         assert!(!a.eq(&b));
     }
 
+    #[test]
+    fn enum_type_coercion() {
+        let a: Type = EnumType::new("Color", ["Red", "Green"]).into();
+        let b: Type = EnumType::new("Color", ["Red", "Green"]).into();
+        assert!(a.is_coercible_to(&b));
+        assert!(a.is_coercible_to(&b.optional()));
+        assert!(!a.optional().is_coercible_to(&b));
+
+        // Enum -> String
+        assert!(a.is_coercible_to(&PrimitiveType::String.into()));
+        assert!(a.is_coercible_to(&Type::from(PrimitiveType::String).optional()));
+        assert!(
+            !a.optional()
+                .is_coercible_to(&Type::from(PrimitiveType::String))
+        );
+
+        // String -> Enum
+        assert!(!Type::from(PrimitiveType::String).is_coercible_to(&a));
+
+        let b: Type = EnumType::new("Shape", ["Red", "Green"]).into();
+        assert!(!a.is_coercible_to(&b));
+        assert_eq!(a.to_string(), "Color");
+    }
+
     #[test]
     fn object_equality() {
         assert!(Type::Object.eq(&Type::Object));
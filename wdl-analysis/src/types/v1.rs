@@ -112,6 +112,7 @@ use crate::diagnostics::not_a_pair_accessor;
 use crate::diagnostics::not_a_struct;
 use crate::diagnostics::not_a_struct_member;
 use crate::diagnostics::not_a_task_member;
+use crate::diagnostics::not_an_enum_variant;
 use crate::diagnostics::numeric_mismatch;
 use crate::diagnostics::string_concat_mismatch;
 use crate::diagnostics::too_few_arguments;
@@ -518,6 +519,13 @@ pub trait EvaluationContext {
 
     /// Adds a diagnostic.
     fn add_diagnostic(&mut self, diagnostic: Diagnostic);
+
+    /// Records the evaluated type of the expression with the given span.
+    ///
+    /// The default implementation does nothing.
+    fn record_expr_type(&mut self, span: Span, ty: &Type) {
+        let _ = (span, ty);
+    }
 }
 
 /// Represents an evaluator of expression types.
@@ -546,8 +554,18 @@ impl<'a, C: EvaluationContext> ExprTypeEvaluator<'a, C> {
 
     /// Evaluates the type of the given expression in the given scope.
     ///
+    /// The type of the expression, and of each of its subexpressions, is
+    /// recorded with the evaluation context.
+    ///
     /// Returns `None` if the type of the expression is indeterminate.
     pub fn evaluate_expr<N: TreeNode + SyntaxNodeExt>(&mut self, expr: &Expr<N>) -> Option<Type> {
+        let ty = self.evaluate_expr_type(expr)?;
+        self.context.record_expr_type(expr.span(), &ty);
+        Some(ty)
+    }
+
+    /// Evaluates the type of the given expression without recording it.
+    fn evaluate_expr_type<N: TreeNode + SyntaxNodeExt>(&mut self, expr: &Expr<N>) -> Option<Type> {
         match expr {
             Expr::Literal(expr) => self.evaluate_literal_expr(expr),
             Expr::NameRef(r) => {
@@ -1650,6 +1668,35 @@ impl<'a, C: EvaluationContext> ExprTypeEvaluator<'a, C> {
         expr: &AccessExpr<N>,
     ) -> Option<Type> {
         let (target, name) = expr.operands();
+
+        // Check for an enum variant (e.g. `Color.Red`); names in scope take
+        // precedence over enum names
+        if let Expr::NameRef(r) = &target {
+            let ident = r.name();
+            let ty = self
+                .context
+                .resolve_type_name(ident.text(), ident.span())
+                .ok()
+                .filter(|ty| ty.as_enum().is_some());
+
+            if let Some(ty) = ty {
+                if self
+                    .context
+                    .resolve_name(ident.text(), ident.span())
+                    .is_none()
+                {
+                    let e = ty.as_enum().expect("type should be an enum");
+                    if !e.has_variant(name.text()) {
+                        self.context
+                            .add_diagnostic(not_an_enum_variant(e.name(), &name));
+                        return None;
+                    }
+
+                    return Some(ty);
+                }
+            }
+        }
+
         let ty = self.evaluate_expr(&target)?;
 
         if matches!(ty, Type::Task) {
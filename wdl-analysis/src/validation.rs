@@ -2,6 +2,7 @@
 
 use wdl_ast::Comment;
 use wdl_ast::Diagnostic;
+use wdl_ast::Severity;
 use wdl_ast::SupportedVersion;
 use wdl_ast::SyntaxElement;
 use wdl_ast::SyntaxKind;
@@ -9,6 +10,8 @@ use wdl_ast::VersionStatement;
 use wdl_ast::Whitespace;
 use wdl_ast::v1;
 
+use crate::CustomRule;
+use crate::Rule;
 use crate::SyntaxNodeExt;
 use crate::VisitReason;
 use crate::Visitor;
@@ -73,6 +76,22 @@ impl Diagnostics {
         self.0.is_empty()
     }
 
+    /// Returns the number of diagnostics in the collection.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Changes the severity of every diagnostic in the collection starting at
+    /// the given index.
+    ///
+    /// This is useful for remapping the severity of diagnostics added by a
+    /// particular visitor.
+    pub fn set_severity_from(&mut self, start: usize, severity: Severity) {
+        for diagnostic in self.0.iter_mut().skip(start) {
+            *diagnostic = diagnostic.clone().with_severity(severity);
+        }
+    }
+
     /// Sorts the diagnostics in the collection.
     pub fn sort(&mut self) {
         self.0.sort();
@@ -88,6 +107,11 @@ impl Diagnostics {
 pub struct Validator {
     /// The set of validation visitors.
     visitors: Vec<Box<dyn Visitor>>,
+    /// The registered custom rules.
+    ///
+    /// Custom rules are run on documents after they have been analyzed rather
+    /// than as part of validation.
+    custom_rules: Vec<CustomRule>,
 }
 
 impl Validator {
@@ -95,6 +119,7 @@ impl Validator {
     pub const fn empty() -> Self {
         Self {
             visitors: Vec::new(),
+            custom_rules: Vec::new(),
         }
     }
 
@@ -108,6 +133,31 @@ impl Validator {
         self.visitors.extend(visitors)
     }
 
+    /// Adds a custom rule to the validator.
+    ///
+    /// The given function is called to create a new instance of the rule for
+    /// each analyzed document; the rule's diagnostics default to the severity
+    /// of the rule unless overridden by
+    /// [`DiagnosticsConfig::custom_rules`](crate::DiagnosticsConfig::custom_rules).
+    ///
+    /// A previously added custom rule with the same identifier is replaced.
+    ///
+    /// See [`CustomRule`] for more information.
+    pub fn add_custom_rule<R, F>(&mut self, factory: F)
+    where
+        R: Rule + Visitor + 'static,
+        F: Fn() -> R + Send + Sync + 'static,
+    {
+        let rule = CustomRule::new(factory);
+        self.custom_rules.retain(|r| r.id() != rule.id());
+        self.custom_rules.push(rule);
+    }
+
+    /// Gets the custom rules added to the validator.
+    pub fn custom_rules(&self) -> &[CustomRule] {
+        &self.custom_rules
+    }
+
     /// Validates the given document and returns the validation errors upon
     /// failure.
     pub fn validate(&mut self, document: &Document) -> Result<(), Vec<Diagnostic>> {
@@ -140,6 +190,7 @@ impl Default for Validator {
                 Box::<imports::ImportsVisitor>::default(),
                 Box::<env::EnvVisitor>::default(),
             ],
+            custom_rules: Vec::new(),
         }
     }
 }
@@ -208,6 +259,17 @@ impl Visitor for Validator {
         }
     }
 
+    fn enum_definition(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        def: &v1::EnumDefinition,
+    ) {
+        for visitor in self.visitors.iter_mut() {
+            visitor.enum_definition(diagnostics, reason, def);
+        }
+    }
+
     fn task_definition(
         &mut self,
         diagnostics: &mut Diagnostics,
@@ -435,6 +497,17 @@ impl Visitor for Validator {
         }
     }
 
+    fn conditional_else_clause(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        clause: &v1::ConditionalElseClause,
+    ) {
+        for visitor in self.visitors.iter_mut() {
+            visitor.conditional_else_clause(diagnostics, reason, clause);
+        }
+    }
+
     fn scatter_statement(
         &mut self,
         diagnostics: &mut Diagnostics,
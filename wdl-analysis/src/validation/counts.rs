@@ -13,6 +13,7 @@ use wdl_ast::SyntaxNode;
 use wdl_ast::TokenText;
 use wdl_ast::v1::CommandKeyword;
 use wdl_ast::v1::CommandSection;
+use wdl_ast::v1::EnumDefinition;
 use wdl_ast::v1::HintsKeyword;
 use wdl_ast::v1::InputKeyword;
 use wdl_ast::v1::InputSection;
@@ -212,6 +213,8 @@ pub struct CountingVisitor {
     has_workflow: bool,
     /// Whether or not the document has at least one struct.
     has_struct: bool,
+    /// Whether or not the document has at least one enum.
+    has_enum: bool,
     /// The span of the first command section in the task.
     command: Option<Span>,
     /// The span of the first input section in the task or workflow.
@@ -253,6 +256,7 @@ impl Visitor for CountingVisitor {
         self.soft_reset();
         self.has_workflow = false;
         self.has_struct = false;
+        self.has_enum = false;
     }
 
     fn document(
@@ -266,7 +270,7 @@ impl Visitor for CountingVisitor {
             return;
         }
 
-        if !self.has_workflow && self.tasks_seen.is_empty() && !self.has_struct {
+        if !self.has_workflow && self.tasks_seen.is_empty() && !self.has_struct && !self.has_enum {
             diagnostics.add(at_least_one_definition());
         }
     }
@@ -322,6 +326,12 @@ impl Visitor for CountingVisitor {
         self.has_struct = true;
     }
 
+    fn enum_definition(&mut self, _: &mut Diagnostics, reason: VisitReason, _: &EnumDefinition) {
+        if reason == VisitReason::Enter {
+            self.has_enum = true;
+        }
+    }
+
     fn command_section(
         &mut self,
         diagnostics: &mut Diagnostics,
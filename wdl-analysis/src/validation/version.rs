@@ -6,6 +6,7 @@ use wdl_ast::Diagnostic;
 use wdl_ast::Span;
 use wdl_ast::SupportedVersion;
 use wdl_ast::v1;
+use wdl_ast::v1::ElseKeyword;
 use wdl_ast::v1::Exponentiation;
 use wdl_ast::v1::Expr;
 use wdl_ast::v1::HintsKeyword;
@@ -68,6 +69,12 @@ fn env_var_requirement(span: Span) -> Diagnostic {
         .with_highlight(span)
 }
 
+/// Creates an "else clause requirement" diagnostic.
+fn else_clause_requirement(span: Span) -> Diagnostic {
+    Diagnostic::error("use of `else` clauses requires WDL version `development`")
+        .with_highlight(span)
+}
+
 /// An AST visitor that ensures the syntax present in the document matches the
 /// document's declared version.
 #[derive(Debug, Default)]
@@ -296,4 +303,26 @@ impl Visitor for VersionVisitor {
             }
         }
     }
+
+    fn conditional_else_clause(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        clause: &v1::ConditionalElseClause,
+    ) {
+        if reason == VisitReason::Exit {
+            return;
+        }
+
+        if let Some(version) = self.version {
+            if version < SupportedVersion::V1(V1::Development) {
+                diagnostics.add(else_clause_requirement(
+                    clause
+                        .token::<ElseKeyword<_>>()
+                        .expect("should have keyword")
+                        .span(),
+                ));
+            }
+        }
+    }
 }
@@ -35,7 +35,9 @@ use wdl_ast::v1::BoundDecl;
 use wdl_ast::v1::CallStatement;
 use wdl_ast::v1::CommandSection;
 use wdl_ast::v1::CommandText;
+use wdl_ast::v1::ConditionalElseClause;
 use wdl_ast::v1::ConditionalStatement;
+use wdl_ast::v1::EnumDefinition;
 use wdl_ast::v1::Expr;
 use wdl_ast::v1::ImportStatement;
 use wdl_ast::v1::InputSection;
@@ -129,6 +131,15 @@ pub trait Visitor {
     ) {
     }
 
+    /// Visits an enum definition node.
+    fn enum_definition(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        def: &EnumDefinition,
+    ) {
+    }
+
     /// Visits a task definition node.
     fn task_definition(
         &mut self,
@@ -305,6 +316,15 @@ pub trait Visitor {
     ) {
     }
 
+    /// Visits an `else if` or `else` clause node of a conditional statement.
+    fn conditional_else_clause(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        clause: &ConditionalElseClause,
+    ) {
+    }
+
     /// Visits a scatter statement node in a workflow.
     fn scatter_statement(
         &mut self,
@@ -367,6 +387,14 @@ pub(crate) fn visit<V: Visitor>(
                 reason,
                 &StructDefinition::cast(element.into_node().unwrap()).expect("should cast"),
             ),
+            SyntaxKind::EnumDefinitionNode => visitor.enum_definition(
+                diagnostics,
+                reason,
+                &EnumDefinition::cast(element.into_node().unwrap()).expect("should cast"),
+            ),
+            SyntaxKind::EnumVariantNode => {
+                // Skip these nodes as they're part of an enum definition
+            }
             SyntaxKind::TaskDefinitionNode => visitor.task_definition(
                 diagnostics,
                 reason,
@@ -538,6 +566,11 @@ pub(crate) fn visit<V: Visitor>(
                 reason,
                 &ConditionalStatement::cast(element.into_node().unwrap()).expect("should cast"),
             ),
+            SyntaxKind::ConditionalElseClauseNode => visitor.conditional_else_clause(
+                diagnostics,
+                reason,
+                &ConditionalElseClause::cast(element.into_node().unwrap()).expect("should cast"),
+            ),
             SyntaxKind::ScatterStatementNode => visitor.scatter_statement(
                 diagnostics,
                 reason,
@@ -145,12 +145,18 @@ pub enum Node<N: TreeNode = SyntaxNode> {
     CallTarget(CallTarget<N>),
     /// A command section.
     CommandSection(CommandSection<N>),
+    /// An `else if` or `else` clause of a conditional statement.
+    ConditionalElseClause(ConditionalElseClause<N>),
     /// A conditional statement.
     ConditionalStatement(ConditionalStatement<N>),
     /// The `default` placeholder option.
     DefaultOption(DefaultOption<N>),
     /// A division expression.
     DivisionExpr(DivisionExpr<N>),
+    /// An enum definition.
+    EnumDefinition(EnumDefinition<N>),
+    /// An enum variant.
+    EnumVariant(EnumVariant<N>),
     /// An equality expression.
     EqualityExpr(EqualityExpr<N>),
     /// An exponentiation expression.
@@ -314,9 +320,12 @@ ast_element_impl!(
         call_statement(): CallStatementNode => CallStatement => CallStatement,
         call_target(): CallTargetNode => CallTarget => CallTarget,
         command_section(): CommandSectionNode => CommandSection => CommandSection,
+        conditional_else_clause(): ConditionalElseClauseNode => ConditionalElseClause => ConditionalElseClause,
         conditional_statement(): ConditionalStatementNode => ConditionalStatement => ConditionalStatement,
         default_option(): PlaceholderDefaultOptionNode => DefaultOption => DefaultOption,
         division_expr(): DivisionExprNode => DivisionExpr => DivisionExpr,
+        enum_definition(): EnumDefinitionNode => EnumDefinition => EnumDefinition,
+        enum_variant(): EnumVariantNode => EnumVariant => EnumVariant,
         equality_expr(): EqualityExprNode => EqualityExpr => EqualityExpr,
         exponentiation_expr(): ExponentiationExprNode => ExponentiationExpr => ExponentiationExpr,
         greater_equal_expr(): GreaterEqualExprNode => GreaterEqualExpr => GreaterEqualExpr,
@@ -439,6 +448,8 @@ pub enum Token<T: TreeToken = SyntaxToken> {
     DoubleQuote(DoubleQuote<T>),
     /// The `else` keyword.
     ElseKeyword(ElseKeyword<T>),
+    /// The `enum` keyword.
+    EnumKeyword(EnumKeyword<T>),
     /// The `env` keyword.
     EnvKeyword(EnvKeyword<T>),
     /// The `==` symbol.
@@ -579,6 +590,7 @@ ast_element_impl!(
         dot(): Dot => Dot => Dot,
         double_quote(): DoubleQuote => DoubleQuote => DoubleQuote,
         else_keyword(): ElseKeyword => ElseKeyword => ElseKeyword,
+        enum_keyword(): EnumKeyword => EnumKeyword => EnumKeyword,
         env_keyword(): EnvKeyword => EnvKeyword => EnvKeyword,
         equal(): Equal => Equal => Equal,
         exclamation(): Exclamation => Exclamation => Exclamation,
@@ -38,7 +38,9 @@ use v1::CloseBrace;
 use v1::CloseHeredoc;
 use v1::OpenBrace;
 use v1::OpenHeredoc;
+pub use wdl_grammar::Applicability;
 pub use wdl_grammar::Diagnostic;
+pub use wdl_grammar::Edit;
 pub use wdl_grammar::Label;
 pub use wdl_grammar::Severity;
 pub use wdl_grammar::Span;
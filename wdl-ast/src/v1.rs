@@ -6,6 +6,7 @@ use crate::SyntaxNode;
 use crate::TreeNode;
 
 mod decls;
+mod r#enum;
 mod expr;
 mod import;
 mod r#struct;
@@ -14,6 +15,7 @@ mod tokens;
 mod workflow;
 
 pub use decls::*;
+pub use r#enum::*;
 pub use expr::*;
 pub use import::*;
 pub use r#struct::*;
@@ -53,6 +55,11 @@ impl<N: TreeNode> Ast<N> {
         self.children()
     }
 
+    /// Gets the enum definitions in the AST.
+    pub fn enums(&self) -> impl Iterator<Item = EnumDefinition<N>> + use<'_, N> {
+        self.children()
+    }
+
     /// Gets the task definitions in the AST.
     pub fn tasks(&self) -> impl Iterator<Item = TaskDefinition<N>> + use<'_, N> {
         self.children()
@@ -88,6 +95,8 @@ pub enum DocumentItem<N: TreeNode = SyntaxNode> {
     Import(ImportStatement<N>),
     /// The item is a struct definition.
     Struct(StructDefinition<N>),
+    /// The item is an enum definition.
+    Enum(EnumDefinition<N>),
     /// The item is a task definition.
     Task(TaskDefinition<N>),
     /// The item is a workflow definition.
@@ -105,6 +114,7 @@ impl<N: TreeNode> DocumentItem<N> {
             kind,
             SyntaxKind::ImportStatementNode
                 | SyntaxKind::StructDefinitionNode
+                | SyntaxKind::EnumDefinitionNode
                 | SyntaxKind::TaskDefinitionNode
                 | SyntaxKind::WorkflowDefinitionNode
         )
@@ -121,6 +131,9 @@ impl<N: TreeNode> DocumentItem<N> {
             SyntaxKind::StructDefinitionNode => Some(Self::Struct(
                 StructDefinition::cast(inner).expect("struct definition to cast"),
             )),
+            SyntaxKind::EnumDefinitionNode => Some(Self::Enum(
+                EnumDefinition::cast(inner).expect("enum definition to cast"),
+            )),
             SyntaxKind::TaskDefinitionNode => Some(Self::Task(
                 TaskDefinition::cast(inner).expect("task definition to cast"),
             )),
@@ -136,6 +149,7 @@ impl<N: TreeNode> DocumentItem<N> {
         match self {
             Self::Import(e) => e.inner(),
             Self::Struct(e) => e.inner(),
+            Self::Enum(e) => e.inner(),
             Self::Task(e) => e.inner(),
             Self::Workflow(e) => e.inner(),
         }
@@ -189,6 +203,30 @@ impl<N: TreeNode> DocumentItem<N> {
         }
     }
 
+    /// Attempts to get a reference to the inner [`EnumDefinition`].
+    ///
+    /// * If `self` is a [`DocumentItem::Enum`], then a reference to the inner
+    ///   [`EnumDefinition`] is returned wrapped in [`Some`].
+    /// * Else, [`None`] is returned.
+    pub fn as_enum_definition(&self) -> Option<&EnumDefinition<N>> {
+        match self {
+            Self::Enum(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// Consumes `self` and attempts to return the inner [`EnumDefinition`].
+    ///
+    /// * If `self` is a [`DocumentItem::Enum`], then the inner
+    ///   [`EnumDefinition`] is returned wrapped in [`Some`].
+    /// * Else, [`None`] is returned.
+    pub fn into_enum_definition(self) -> Option<EnumDefinition<N>> {
+        match self {
+            Self::Enum(i) => Some(i),
+            _ => None,
+        }
+    }
+
     /// Attempts to get a reference to the inner [`TaskDefinition`].
     ///
     /// * If `self` is a [`DocumentItem::Task`], then a reference to the inner
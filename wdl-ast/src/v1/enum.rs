@@ -0,0 +1,118 @@
+//! V1 AST representation for enum definitions.
+
+use super::EnumKeyword;
+use crate::AstNode;
+use crate::Ident;
+use crate::SyntaxKind;
+use crate::SyntaxNode;
+use crate::TreeNode;
+
+/// Represents an enum definition.
+///
+/// Enum definitions are only supported in the `development` version of WDL.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnumDefinition<N: TreeNode = SyntaxNode>(N);
+
+impl<N: TreeNode> EnumDefinition<N> {
+    /// Gets the name of the enum.
+    pub fn name(&self) -> Ident<N::Token> {
+        self.token().expect("enum should have a name")
+    }
+
+    /// Gets the `enum` keyword of the enum definition.
+    pub fn keyword(&self) -> EnumKeyword<N::Token> {
+        self.token().expect("enum should have a keyword")
+    }
+
+    /// Gets the variants of the enum.
+    pub fn variants(&self) -> impl Iterator<Item = EnumVariant<N>> + use<'_, N> {
+        self.children()
+    }
+}
+
+impl<N: TreeNode> AstNode<N> for EnumDefinition<N> {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::EnumDefinitionNode
+    }
+
+    fn cast(inner: N) -> Option<Self> {
+        match inner.kind() {
+            SyntaxKind::EnumDefinitionNode => Some(Self(inner)),
+            _ => None,
+        }
+    }
+
+    fn inner(&self) -> &N {
+        &self.0
+    }
+}
+
+/// Represents a variant of an enum definition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnumVariant<N: TreeNode = SyntaxNode>(N);
+
+impl<N: TreeNode> EnumVariant<N> {
+    /// Gets the name of the variant.
+    pub fn name(&self) -> Ident<N::Token> {
+        self.token().expect("enum variant should have a name")
+    }
+}
+
+impl<N: TreeNode> AstNode<N> for EnumVariant<N> {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::EnumVariantNode
+    }
+
+    fn cast(inner: N) -> Option<Self> {
+        match inner.kind() {
+            SyntaxKind::EnumVariantNode => Some(Self(inner)),
+            _ => None,
+        }
+    }
+
+    fn inner(&self) -> &N {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use crate::AstToken;
+    use crate::Document;
+
+    #[test]
+    fn enum_definitions() {
+        let (document, diagnostics) = Document::parse(
+            r#"
+version development
+
+enum Empty {}
+
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+"#,
+        );
+        assert!(diagnostics.is_empty());
+        let ast = document.ast();
+        let ast = ast.as_v1().expect("should be a V1 AST");
+        let enums: Vec<_> = ast.enums().collect();
+        assert_eq!(enums.len(), 2);
+
+        // First enum definition
+        assert_eq!(enums[0].name().text(), "Empty");
+        assert_eq!(enums[0].variants().count(), 0);
+
+        // Second enum definition
+        assert_eq!(enums[1].name().text(), "Color");
+        let variants: Vec<_> = enums[1]
+            .variants()
+            .map(|v| v.name().text().to_string())
+            .collect();
+        assert_eq!(variants, ["Red", "Green", "Blue"]);
+    }
+}
@@ -77,6 +77,7 @@ define_token!(
 define_token!(Dot, "the `.` symbol", ".");
 define_token!(DoubleQuote, "the `\"` symbol", "\"");
 define_token!(ElseKeyword, "the `else` keyword", "else");
+define_token!(EnumKeyword, "the `enum` keyword", "enum");
 define_token!(EnvKeyword, "the `env` keyword", "env");
 define_token!(Equal, "the `=` symbol", "=");
 define_token!(Exclamation, "the `!` symbol", "!");
@@ -5,6 +5,7 @@ use wdl_grammar::SupportedVersion;
 use wdl_grammar::version::V1;
 
 use super::BoundDecl;
+use super::ElseKeyword;
 use super::Expr;
 use super::InputSection;
 use super::LiteralBoolean;
@@ -86,7 +87,7 @@ impl<N: TreeNode> WorkflowDefinition<N> {
             SupportedVersion::V1(V1::One) => {
                 // Fall through to below
             }
-            SupportedVersion::V1(V1::Two) => {
+            SupportedVersion::V1(V1::Two | V1::Development) => {
                 // Check the hints section
                 let allow = self.hints().and_then(|s| {
                     s.items().find_map(|i| {
@@ -699,9 +700,19 @@ impl<N: TreeNode> ConditionalStatement<N> {
     }
 
     /// Gets the statements of the conditional body.
+    ///
+    /// This does not include the statements of any `else if` or `else`
+    /// clauses.
     pub fn statements(&self) -> impl Iterator<Item = WorkflowStatement<N>> + use<'_, N> {
         WorkflowStatement::children(&self.0)
     }
+
+    /// Gets the `else if` and `else` clauses of the conditional statement.
+    ///
+    /// Else clauses are only supported in the `development` version of WDL.
+    pub fn else_clauses(&self) -> impl Iterator<Item = ConditionalElseClause<N>> + use<'_, N> {
+        self.children()
+    }
 }
 
 impl<N: TreeNode> AstNode<N> for ConditionalStatement<N> {
@@ -721,6 +732,47 @@ impl<N: TreeNode> AstNode<N> for ConditionalStatement<N> {
     }
 }
 
+/// Represents an `else if` or `else` clause of a workflow conditional
+/// statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConditionalElseClause<N: TreeNode = SyntaxNode>(N);
+
+impl<N: TreeNode> ConditionalElseClause<N> {
+    /// Gets the `else` keyword of the clause.
+    pub fn keyword(&self) -> ElseKeyword<N::Token> {
+        self.token().expect("else clause should have a keyword")
+    }
+
+    /// Gets the expression of an `else if` clause.
+    ///
+    /// Returns `None` for an `else` clause.
+    pub fn expr(&self) -> Option<Expr<N>> {
+        Expr::child(&self.0)
+    }
+
+    /// Gets the statements of the clause body.
+    pub fn statements(&self) -> impl Iterator<Item = WorkflowStatement<N>> + use<'_, N> {
+        WorkflowStatement::children(&self.0)
+    }
+}
+
+impl<N: TreeNode> AstNode<N> for ConditionalElseClause<N> {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::ConditionalElseClauseNode
+    }
+
+    fn cast(inner: N) -> Option<Self> {
+        match inner.kind() {
+            SyntaxKind::ConditionalElseClauseNode => Some(Self(inner)),
+            _ => None,
+        }
+    }
+
+    fn inner(&self) -> &N {
+        &self.0
+    }
+}
+
 /// Represents a workflow scatter statement.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ScatterStatement<N: TreeNode = SyntaxNode>(N);
@@ -1541,4 +1593,51 @@ workflow test {
             "private"
         );
     }
+
+    #[test]
+    fn conditional_else_clauses() {
+        let (document, diagnostics) = Document::parse(
+            r#"
+version development
+
+workflow test {
+    if (a) {
+        Int x = 1
+    } else if (b) {
+        Int x = 2
+        Int y = 3
+    } else {
+        Int x = 4
+    }
+}
+"#,
+        );
+
+        assert!(diagnostics.is_empty());
+        let ast = document.ast();
+        let ast = ast.as_v1().expect("should be a V1 AST");
+        let workflows: Vec<_> = ast.workflows().collect();
+        assert_eq!(workflows.len(), 1);
+
+        let statements: Vec<_> = workflows[0].statements().collect();
+        assert_eq!(statements.len(), 1);
+        let conditional = statements[0].clone().unwrap_conditional();
+        assert_eq!(conditional.expr().unwrap_name_ref().name().text(), "a");
+        assert_eq!(conditional.statements().count(), 1);
+
+        let clauses: Vec<_> = conditional.else_clauses().collect();
+        assert_eq!(clauses.len(), 2);
+        assert_eq!(
+            clauses[0]
+                .expr()
+                .expect("should have an expression")
+                .unwrap_name_ref()
+                .name()
+                .text(),
+            "b"
+        );
+        assert_eq!(clauses[0].statements().count(), 2);
+        assert!(clauses[1].expr().is_none());
+        assert_eq!(clauses[1].statements().count(), 1);
+    }
 }
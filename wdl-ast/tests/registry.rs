@@ -82,6 +82,7 @@ static REGISTRY: LazyLock<HashMap<&'static str, Box<[SyntaxKind]>>> = LazyLock::
         v1::CommandKeyword::register(),
         v1::CommandSection::register(),
         v1::CommandText::register(),
+        v1::ConditionalElseClause::register(),
         v1::ConditionalStatement::register(),
         v1::DefaultOption::register(),
         v1::DirectoryTypeKeyword::register(),
@@ -89,6 +90,9 @@ static REGISTRY: LazyLock<HashMap<&'static str, Box<[SyntaxKind]>>> = LazyLock::
         v1::Dot::register(),
         v1::DoubleQuote::register(),
         v1::ElseKeyword::register(),
+        v1::EnumDefinition::register(),
+        v1::EnumKeyword::register(),
+        v1::EnumVariant::register(),
         v1::EnvKeyword::register(),
         v1::Equal::register(),
         v1::EqualityExpr::register(),
@@ -1,17 +1,22 @@
 //! Facilities for performing a typical analysis using the `wdl-*` crates.
 
 use std::collections::HashSet;
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use anyhow::Context;
 use anyhow::Error;
 use futures::future::BoxFuture;
 use nonempty::NonEmpty;
 use tracing::warn;
 use wdl_analysis::Analyzer;
 use wdl_analysis::DiagnosticsConfig;
+use wdl_analysis::ImportsConfig;
 use wdl_analysis::ProgressKind;
+use wdl_analysis::ValidationCache;
 use wdl_analysis::Validator;
 use wdl_lint::Linter;
+use wdl_lint::config::LintConfig;
 
 mod results;
 mod source;
@@ -39,6 +44,17 @@ pub struct Analysis {
     /// Whether or not to enable linting.
     lint: bool,
 
+    /// The lint configuration to use, if any.
+    ///
+    /// If `None`, the configuration is discovered from the current directory.
+    lint_config: Option<LintConfig>,
+
+    /// The configuration for resolving imports.
+    imports: ImportsConfig,
+
+    /// The directory of the persistent validation cache to use, if any.
+    cache: Option<PathBuf>,
+
     /// The initialization callback.
     init: InitCb,
 
@@ -77,6 +93,33 @@ impl Analysis {
         self
     }
 
+    /// Sets the lint configuration to use when linting is enabled.
+    ///
+    /// If a configuration is not set, a [`CONFIG_FILE_NAME`] file in the
+    /// current directory or one of its ancestors is used if it exists.
+    ///
+    /// Rules that are excepted are disabled regardless of the configuration.
+    ///
+    /// [`CONFIG_FILE_NAME`]: wdl_lint::config::CONFIG_FILE_NAME
+    pub fn lint_config(mut self, config: LintConfig) -> Self {
+        self.lint_config = Some(config);
+        self
+    }
+
+    /// Sets the configuration for resolving imports.
+    pub fn imports(mut self, imports: ImportsConfig) -> Self {
+        self.imports = imports;
+        self
+    }
+
+    /// Sets the directory of the persistent validation cache to use.
+    ///
+    /// The cache is salted with the linting configuration of the analysis.
+    pub fn validation_cache(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache = Some(dir.into());
+        self
+    }
+
     /// Sets the initialization callback.
     pub fn init<F>(mut self, init: F) -> Self
     where
@@ -100,24 +143,52 @@ impl Analysis {
         warn_unknown_rules(&self.exceptions);
         let config = get_diagnostics_config(&self.exceptions);
 
+        let lint_config = match (self.lint, self.lint_config) {
+            (false, _) => None,
+            (true, Some(config)) => Some(config),
+            (true, None) => discover_lint_config().map_err(|e| NonEmpty::new(Arc::new(e)))?,
+        }
+        .map(|config| Arc::new(get_lint_config(config, &self.exceptions)));
+
         (self.init)();
 
+        // Salt the cache with the linting configuration as it affects validation
+        let cache = self.cache.map(|dir| {
+            let mut exceptions = self.exceptions.iter().collect::<Vec<_>>();
+            exceptions.sort();
+            let salt = format!(
+                "wdl-cli:{version} lint:{lint} exceptions:{exceptions:?} config:{lint_config:?}",
+                version = env!("CARGO_PKG_VERSION"),
+                lint = self.lint,
+            );
+            ValidationCache::new(dir, salt)
+        });
+
         let validator = Box::new(move || {
             let mut validator = Validator::default();
 
             if self.lint {
-                let visitor = get_lint_visitor(&self.exceptions);
+                let visitor = match &lint_config {
+                    Some(config) => Linter::with_config(config.clone()),
+                    None => get_lint_visitor(&self.exceptions),
+                };
                 validator.add_visitor(visitor);
             }
 
             validator
         });
 
-        let mut analyzer = Analyzer::new_with_validator(
-            config,
-            move |_, kind, count, total| (self.progress)(kind, count, total),
-            validator,
-        );
+        let progress = move |_, kind, count, total| (self.progress)(kind, count, total);
+        let mut analyzer = match cache {
+            Some(cache) => Analyzer::new_with_validation_cache(
+                config,
+                self.imports,
+                cache,
+                progress,
+                validator,
+            ),
+            None => Analyzer::new_with_imports(config, self.imports, progress, validator),
+        };
 
         for source in self.sources {
             if let Err(error) = source.register(&mut analyzer).await {
@@ -140,6 +211,9 @@ impl Default for Analysis {
             sources: Default::default(),
             exceptions: Default::default(),
             lint: Default::default(),
+            lint_config: None,
+            imports: Default::default(),
+            cache: None,
             init: Box::new(|| {}),
             progress: Box::new(|_, _, _| Box::pin(async {})),
         }
@@ -182,6 +256,26 @@ fn get_diagnostics_config(exceptions: &HashSet<String>) -> DiagnosticsConfig {
     }))
 }
 
+/// Discovers the lint configuration from the current directory.
+fn discover_lint_config() -> Result<Option<LintConfig>, Error> {
+    let dir = std::env::current_dir().context("failed to determine the current directory")?;
+    LintConfig::discover(dir)
+}
+
+/// Gets the given lint configuration with the excepted rules disabled.
+fn get_lint_config(mut config: LintConfig, exceptions: &HashSet<String>) -> LintConfig {
+    for rule in wdl_lint::rules() {
+        if exceptions
+            .iter()
+            .any(|exception| exception.eq_ignore_ascii_case(rule.id()))
+        {
+            config.disable_rule(rule.id());
+        }
+    }
+
+    config
+}
+
 /// Gets a lint visitor with the excepted rules removed.
 fn get_lint_visitor(exceptions: &HashSet<String>) -> Linter {
     Linter::new(wdl_lint::rules().into_iter().filter(|rule| {
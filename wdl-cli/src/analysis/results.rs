@@ -6,6 +6,7 @@ use std::sync::Arc;
 use anyhow::Error;
 use nonempty::NonEmpty;
 use wdl_analysis::AnalysisResult;
+use wdl_analysis::duplicates::find_duplicate_tasks;
 use wdl_ast::AstNode as _;
 use wdl_ast::Diagnostic;
 
@@ -78,6 +79,35 @@ impl AnalysisResults {
                 .map(move |diagnostic| (path.clone(), source.clone(), diagnostic))
         })
     }
+
+    /// Finds the tasks within the analysis result set that are identical or
+    /// at least `threshold` (from `0.0` to `1.0`) similar to another task.
+    ///
+    /// The return type is an iterator that yields tuples that contain the
+    /// following:
+    ///
+    /// - The path to the file containing the duplicate task.
+    /// - The source of the file containing the duplicate task.
+    /// - A diagnostic describing the duplicate task.
+    pub fn duplicate_tasks(
+        &self,
+        threshold: f64,
+    ) -> impl Iterator<Item = (Rc<String>, Rc<String>, Diagnostic)> + '_ {
+        find_duplicate_tasks(&self.0, threshold)
+            .into_iter()
+            .filter_map(|duplicate| {
+                let result = self
+                    .0
+                    .iter()
+                    .find(|r| r.document().uri() == duplicate.second().uri())?;
+
+                Some((
+                    Rc::new(result.document().path().to_string()),
+                    Rc::new(result.document().root().text().to_string()),
+                    duplicate.to_diagnostic(),
+                ))
+            })
+    }
 }
 
 impl IntoIterator for AnalysisResults {
@@ -4,8 +4,10 @@
 pub mod analysis;
 pub mod eval;
 pub mod inputs;
+pub mod migration;
 
 pub use analysis::Analysis;
 pub use eval::Evaluator;
 pub use inputs::Input;
 pub use inputs::Inputs;
+pub use migration::Migration;
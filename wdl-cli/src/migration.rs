@@ -0,0 +1,232 @@
+//! Facilities for migrating WDL documents to newer versions.
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use wdl_ast::SupportedVersion;
+use wdl_ast::version::V1;
+use wdl_format::Config;
+use wdl_format::migrate::MigratedDocument;
+use wdl_format::migrate::Migrator;
+
+/// The result of migrating a single document.
+#[derive(Debug, Clone)]
+pub struct MigrationResult {
+    /// The path of the migrated document.
+    path: PathBuf,
+    /// The migrated document.
+    document: MigratedDocument,
+}
+
+impl MigrationResult {
+    /// Gets the path of the migrated document.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Gets the migrated document.
+    pub fn document(&self) -> &MigratedDocument {
+        &self.document
+    }
+
+    /// Gets a unified diff of the changes made to the document.
+    ///
+    /// Returns an empty string if the document was not changed.
+    pub fn diff(&self) -> String {
+        self.document.diff(&self.path.to_string_lossy())
+    }
+}
+
+/// A migration of WDL documents to a newer version.
+pub struct Migration {
+    /// The files or directories containing the documents to migrate.
+    paths: Vec<PathBuf>,
+
+    /// The version to migrate the documents to.
+    target: SupportedVersion,
+
+    /// Whether or not to format the migrated documents.
+    format: bool,
+
+    /// Whether or not to write the migrated documents in place.
+    write: bool,
+}
+
+impl Migration {
+    /// Adds a file or directory to the migration.
+    ///
+    /// Directories are searched recursively for WDL documents.
+    pub fn add_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.paths.push(path.into());
+        self
+    }
+
+    /// Adds multiple files or directories to the migration.
+    pub fn extend_paths(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        self.paths.extend(paths.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets the version to migrate the documents to.
+    ///
+    /// Defaults to WDL 1.2.
+    pub fn target(mut self, target: SupportedVersion) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Sets whether or not to format the migrated documents.
+    pub fn format(mut self, value: bool) -> Self {
+        self.format = value;
+        self
+    }
+
+    /// Sets whether or not to write the migrated documents in place.
+    ///
+    /// When `false`, the documents are not modified.
+    pub fn write(mut self, value: bool) -> Self {
+        self.write = value;
+        self
+    }
+
+    /// Runs the migration and returns the result for each document, ordered
+    /// by path.
+    pub fn run(self) -> Result<Vec<MigrationResult>> {
+        let mut migrator = Migrator::new(self.target);
+        if self.format {
+            migrator = migrator.with_format(Config::default());
+        }
+
+        let mut files = Vec::new();
+        for path in &self.paths {
+            if path.is_dir() {
+                find_documents(path, &mut files)?;
+            } else {
+                files.push(path.clone());
+            }
+        }
+
+        files.sort();
+        files.dedup();
+
+        let mut results = Vec::with_capacity(files.len());
+        for path in files {
+            let source = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read `{path}`", path = path.display()))?;
+
+            let document = match migrator.migrate(&source) {
+                Ok(document) => document,
+                Err(diagnostics) => bail!(
+                    "failed to migrate `{path}`: {messages}",
+                    path = path.display(),
+                    messages = diagnostics
+                        .iter()
+                        .map(|d| d.message())
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                ),
+            };
+
+            if self.write && document.is_changed() {
+                fs::write(&path, document.migrated())
+                    .with_context(|| format!("failed to write `{path}`", path = path.display()))?;
+            }
+
+            results.push(MigrationResult { path, document });
+        }
+
+        Ok(results)
+    }
+}
+
+impl Default for Migration {
+    fn default() -> Self {
+        Self {
+            paths: Default::default(),
+            target: SupportedVersion::V1(V1::Two),
+            format: Default::default(),
+            write: Default::default(),
+        }
+    }
+}
+
+/// Recursively finds the WDL documents in a directory.
+fn find_documents(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    let entries = fs::read_dir(dir)
+        .with_context(|| format!("failed to read directory `{dir}`", dir = dir.display()))?;
+
+    for entry in entries {
+        let path = entry
+            .with_context(|| format!("failed to read directory `{dir}`", dir = dir.display()))?
+            .path();
+
+        if path.is_dir() {
+            find_documents(&path, files)?;
+        } else if path.extension().and_then(|e| e.to_str()) == Some("wdl") {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn migrates_directories() {
+        let dir = TempDir::new().unwrap();
+        let nested = dir.path().join("nested");
+        fs::create_dir_all(&nested).unwrap();
+
+        let source = "version 1.0\n\ntask t {\n    command {\n        echo ${1}\n    }\n}\n";
+        fs::write(dir.path().join("a.wdl"), source).unwrap();
+        fs::write(
+            nested.join("b.wdl"),
+            "version 1.2\n\ntask t {\n    command <<<>>>\n}\n",
+        )
+        .unwrap();
+        fs::write(nested.join("c.txt"), "not a document").unwrap();
+
+        let error = Migration::default()
+            .add_path(dir.path())
+            .target(SupportedVersion::V1(V1::One))
+            .run()
+            .unwrap_err();
+        assert!(
+            error.to_string().contains("to the older version 1.1"),
+            "{error}"
+        );
+
+        let results = Migration::default().add_path(dir.path()).run().unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].path().ends_with("a.wdl"));
+        assert!(results[0].document().is_changed());
+        assert!(results[0].diff().contains("+        echo ~{1}"));
+        assert!(results[1].path().ends_with("b.wdl"));
+        assert!(!results[1].document().is_changed());
+        assert!(results[1].diff().is_empty());
+
+        // The documents are only modified when writing
+        assert_eq!(
+            fs::read_to_string(dir.path().join("a.wdl")).unwrap(),
+            source
+        );
+        Migration::default()
+            .add_path(dir.path())
+            .write(true)
+            .run()
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.path().join("a.wdl")).unwrap(),
+            "version 1.2\n\ntask t {\n    command <<<\n        echo ~{1}\n    >>>\n}\n"
+        );
+    }
+}
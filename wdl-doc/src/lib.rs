@@ -11,6 +11,7 @@ pub mod callable;
 pub mod docs_tree;
 pub mod meta;
 pub mod parameter;
+pub mod rule_docs;
 pub mod r#struct;
 
 use std::path::Path;
@@ -34,6 +35,7 @@ use maud::html;
 use pathdiff::diff_paths;
 use pulldown_cmark::Options;
 use pulldown_cmark::Parser;
+pub use rule_docs::document_rules;
 use wdl_analysis::Analyzer;
 use wdl_analysis::DiagnosticsConfig;
 use wdl_analysis::rules;
@@ -343,7 +345,7 @@ pub async fn document_workspace(
                     docs_tree.add_page(path.clone(), page.clone());
                     local_pages.push((diff_paths(path, &cur_dir).unwrap(), page));
                 }
-                DocumentItem::Import(_) => {}
+                DocumentItem::Import(_) | DocumentItem::Enum(_) => {}
             }
         }
         let name = rel_wdl_path.file_stem().unwrap().to_str().unwrap();
@@ -0,0 +1,153 @@
+//! Generation of HTML documentation for lint and analysis rules.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::path::absolute;
+
+use anyhow::Result;
+use maud::Markup;
+use maud::html;
+use wdl_lint::docs::RuleDocumentation;
+use wdl_lint::docs::documentation;
+
+use crate::Markdown;
+use crate::full_page;
+
+/// The name of the stylesheet copied into the rules directory.
+const STYLESHEET: &str = "style.css";
+
+/// Renders the page of a single rule.
+fn render_rule(doc: &RuleDocumentation) -> Markup {
+    let tags = doc.tags();
+    html! {
+        div class="" {
+            p { a href="index.html" { "All rules" } }
+            h1 class="" { code { (doc.id()) } }
+            p { (doc.description()) }
+            ul {
+                li { b { "Kind: " } (doc.kind().to_string()) }
+                @if !tags.is_empty() {
+                    li { b { "Tags: " } (tags.join(", ")) }
+                }
+                @if let Some(url) = doc.url() {
+                    li { b { "More information: " } a href=(url) { (url) } }
+                }
+            }
+            h2 class="" { "Explanation" }
+            (Markdown(doc.explanation()))
+            @if let Some(options) = doc.example_options() {
+                h2 class="" { "Options" }
+                p { "The examples below are linted with the following options:" }
+                pre { code class="language-toml" {
+                    (format!("[rules.{id}]\n{options}", id = doc.id(), options = options.trim()))
+                } }
+            }
+            @for (heading, examples) in [
+                ("Bad examples", doc.bad_examples()),
+                ("Good examples", doc.good_examples()),
+            ] {
+                @if !examples.is_empty() {
+                    h2 class="" { (heading) }
+                    @for example in examples {
+                        pre { code class="language-wdl" { (example.trim()) } }
+                    }
+                }
+            }
+            @if !doc.related_rules().is_empty() {
+                h2 class="" { "Related rules" }
+                ul {
+                    @for id in doc.related_rules() {
+                        li { a href=(format!("{id}.html")) { code { (id) } } }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Renders the index page listing every rule.
+fn render_index(docs: &[RuleDocumentation]) -> Markup {
+    html! {
+        div class="" {
+            h1 class="" { "Rules" }
+            table class="border" {
+                thead class="border" { tr {
+                    th class="" { "Name" }
+                    th class="" { "Kind" }
+                    th class="" { "Tags" }
+                    th class="" { "Description" }
+                }}
+                tbody class="border" {
+                    @for doc in docs {
+                        tr class="border" {
+                            td class="border" {
+                                a href=(format!("{id}.html", id = doc.id())) { code { (doc.id()) } }
+                            }
+                            td class="border" { (doc.kind().to_string()) }
+                            td class="border" { (doc.tags().join(", ")) }
+                            td class="border" { (doc.description()) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate HTML documentation for the lint and analysis rules.
+///
+/// An `index.html` page listing every rule and a page for each rule (e.g.
+/// `ShellCheck.html`) are written to the given directory, which is created if
+/// it does not exist. If a stylesheet is given, it is copied into the
+/// directory and linked from every page.
+pub fn document_rules(
+    dir: impl AsRef<Path>,
+    stylesheet: Option<impl AsRef<Path>>,
+) -> Result<PathBuf> {
+    let dir = absolute(dir)?;
+    std::fs::create_dir_all(&dir)?;
+
+    let stylesheet = match stylesheet {
+        Some(ss) => {
+            std::fs::copy(absolute(ss.as_ref())?, dir.join(STYLESHEET))?;
+            Some(STYLESHEET)
+        }
+        None => None,
+    };
+
+    let docs = documentation();
+    for doc in &docs {
+        let html = full_page(doc.id(), render_rule(doc), stylesheet);
+        std::fs::write(
+            dir.join(format!("{id}.html", id = doc.id())),
+            html.into_string(),
+        )?;
+    }
+
+    let html = full_page("Rules", render_index(&docs), stylesheet);
+    std::fs::write(dir.join("index.html"), html.into_string())?;
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_rule() {
+        let docs = documentation();
+        let doc = docs
+            .iter()
+            .find(|d| d.id() == "RequiredResources")
+            .expect("should have rule");
+        let html = render_rule(doc).into_string();
+        assert!(html.contains("<h1 class=\"\"><code>RequiredResources</code></h1>"));
+        assert!(html.contains("<h2 class=\"\">Bad examples</h2>"));
+        assert!(html.contains("<code class=\"language-toml\">[rules.RequiredResources]\n"));
+        assert!(html.contains("<code class=\"language-wdl\">version 1.2\n"));
+
+        let html = render_index(&docs).into_string();
+        assert!(html.contains("<a href=\"UnusedImport.html\"><code>UnusedImport</code></a>"));
+    }
+}
@@ -31,6 +31,7 @@ use wdl_analysis::diagnostics::not_a_pair_accessor;
 use wdl_analysis::diagnostics::not_a_struct;
 use wdl_analysis::diagnostics::not_a_struct_member;
 use wdl_analysis::diagnostics::not_a_task_member;
+use wdl_analysis::diagnostics::not_an_enum_variant;
 use wdl_analysis::diagnostics::numeric_mismatch;
 use wdl_analysis::diagnostics::too_few_arguments;
 use wdl_analysis::diagnostics::too_many_arguments;
@@ -90,6 +91,7 @@ use wdl_ast::version::V1;
 use crate::Array;
 use crate::Coercible;
 use crate::CompoundValue;
+use crate::EnumValue;
 use crate::EvaluationContext;
 use crate::Map;
 use crate::Object;
@@ -1417,6 +1419,20 @@ impl<C: EvaluationContext> ExprEvaluator<C> {
     ) -> Result<Value, Diagnostic> {
         let (target, name) = expr.operands();
 
+        // Check for an enum variant (e.g. `Color.Red`) where the target is a type name
+        if let Expr::NameRef(r) = &target {
+            let n = r.name();
+            if self.context.resolve_name(n.text(), n.span()).is_err() {
+                if let Ok(ty) = self.context.resolve_type_name(n.text(), n.span()) {
+                    if let Some(enum_ty) = ty.as_enum() {
+                        return EnumValue::new(ty.clone(), name.text())
+                            .map(Into::into)
+                            .map_err(|_| not_an_enum_variant(enum_ty.name(), &name));
+                    }
+                }
+            }
+        }
+
         match self.evaluate_expr(&target).await? {
             Value::Compound(CompoundValue::Pair(pair)) => match name.text() {
                 "left" => Ok(pair.left().clone()),
@@ -614,7 +614,9 @@ impl TaskEvaluator {
 
         // Build an evaluation graph for the task
         let mut diagnostics = Vec::new();
-        let graph = TaskGraphBuilder::default().build(version, &definition, &mut diagnostics);
+        let graph = TaskGraphBuilder::default()
+            .with_enums(document.enums().map(|(n, _)| n))
+            .build(version, &definition, &mut diagnostics);
         assert!(
             diagnostics.is_empty(),
             "task evaluation graph should have no diagnostics"
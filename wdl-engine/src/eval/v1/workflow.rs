@@ -6,6 +6,7 @@ use std::collections::HashSet;
 use std::fmt::Write;
 use std::fs;
 use std::future::Future;
+use std::iter::once;
 use std::mem;
 use std::path::Path;
 use std::path::PathBuf;
@@ -50,6 +51,8 @@ use wdl_ast::AstToken;
 use wdl_ast::Diagnostic;
 use wdl_ast::Span;
 use wdl_ast::SupportedVersion;
+use wdl_ast::SyntaxKind;
+use wdl_ast::TreeNode;
 use wdl_ast::v1::CallKeyword;
 use wdl_ast::v1::CallStatement;
 use wdl_ast::v1::ConditionalStatement;
@@ -541,6 +544,34 @@ impl Subgraph {
         subgraphs
     }
 
+    /// Creates a new subgraph containing only the nodes of this subgraph for
+    /// which the given predicate returns `true`.
+    ///
+    /// The indegrees of the retained nodes exclude edges from the nodes that
+    /// were filtered out.
+    fn filter(
+        &self,
+        graph: &DiGraph<WorkflowGraphNode<SyntaxNode>, ()>,
+        mut predicate: impl FnMut(NodeIndex) -> bool,
+    ) -> Self {
+        let retained: HashSet<_> = self.0.keys().copied().filter(|n| predicate(*n)).collect();
+        Self(
+            self.0
+                .iter()
+                .filter(|(node, _)| retained.contains(node))
+                .map(|(node, indegree)| {
+                    let removed = graph
+                        .edges_directed(*node, Direction::Incoming)
+                        .filter(|e| {
+                            self.0.contains_key(&e.source()) && !retained.contains(&e.source())
+                        })
+                        .count();
+                    (*node, indegree - removed)
+                })
+                .collect(),
+        )
+    }
+
     /// Removes the given node from the subgraph.
     ///
     /// # Panics
@@ -563,6 +594,29 @@ impl Subgraph {
     }
 }
 
+/// Gets the branch of a conditional statement that contains the given node.
+///
+/// The branch is either the conditional statement node itself (i.e. the `if`
+/// branch) or one of its `else` clause nodes.
+///
+/// Returns `None` if the node is not contained within the statement.
+fn conditional_branch(node: &SyntaxNode, statement: &SyntaxNode) -> Option<SyntaxNode> {
+    let mut child = node.clone();
+    while let Some(parent) = child.parent() {
+        if &parent == statement {
+            return Some(if child.kind() == SyntaxKind::ConditionalElseClauseNode {
+                child
+            } else {
+                parent
+            });
+        }
+
+        child = parent;
+    }
+
+    None
+}
+
 /// Represents workflow evaluation state.
 struct State {
     /// The evaluation configuration to use.
@@ -753,6 +807,7 @@ impl WorkflowEvaluator {
         // We need to provide inputs to the workflow graph builder to avoid adding
         // dependency edges from the default expressions if a value was provided
         let graph = WorkflowGraphBuilder::default()
+            .with_enums(document.enums().map(|(n, _)| n))
             .build(&definition, &mut diagnostics, |name| inputs.contains(name));
         assert!(
             diagnostics.is_empty(),
@@ -1253,21 +1308,39 @@ impl WorkflowEvaluator {
             "evaluating conditional statement",
         );
 
-        // Evaluate the conditional expression
-        let value = Self::evaluate_expr(&state, parent, &expr)
-            .await
-            .map_err(|d| EvaluationError::new(state.document.clone(), d))?;
+        // Evaluate the conditional expressions in order to determine the branch to
+        // take; the `if` branch is represented by the statement node itself
+        let mut branch = None;
+        let branches = once((stmt.inner().clone(), Some(expr)))
+            .chain(stmt.else_clauses().map(|c| (c.inner().clone(), c.expr())));
+        for (node, expr) in branches {
+            let taken = match expr {
+                Some(expr) => {
+                    let value = Self::evaluate_expr(&state, parent, &expr)
+                        .await
+                        .map_err(|d| EvaluationError::new(state.document.clone(), d))?;
+
+                    value
+                        .coerce(&PrimitiveType::Boolean.into())
+                        .map_err(|e| {
+                            EvaluationError::new(
+                                state.document.clone(),
+                                if_conditional_mismatch(e, &value.ty(), expr.span()),
+                            )
+                        })?
+                        .unwrap_boolean()
+                }
+                // An `else` clause is always taken
+                None => true,
+            };
 
-        if value
-            .coerce(&PrimitiveType::Boolean.into())
-            .map_err(|e| {
-                EvaluationError::new(
-                    state.document.clone(),
-                    if_conditional_mismatch(e, &value.ty(), expr.span()),
-                )
-            })?
-            .unwrap_boolean()
-        {
+            if taken {
+                branch = Some(node);
+                break;
+            }
+        }
+
+        if let Some(branch) = &branch {
             debug!(
                 workflow_id = id.as_str(),
                 workflow_name = state.document.workflow().unwrap().name(),
@@ -1278,22 +1351,39 @@ impl WorkflowEvaluator {
             // Intentionally drop the write lock before evaluating the subgraph
             let scope = { state.scopes.write().await.alloc(parent) };
 
-            // Evaluate the subgraph
+            // Evaluate only the part of the subgraph belonging to the branch
+            let subgraph = state.subgraphs[&entry].filter(&state.graph, |node| {
+                conditional_branch(state.graph[node].inner(), stmt.inner()).as_ref() == Some(branch)
+            });
+
             Self::evaluate_subgraph(
                 state.clone(),
                 scope,
-                state.subgraphs[&entry].clone(),
+                subgraph,
                 max_concurrency,
                 id,
                 progress.clone(),
             )
             .await?;
 
-            // Promote all values in the scope to the parent scope as optional
+            // Promote all values in the scope to the parent scope; values are made
+            // optional unless every branch of the statement introduces the name
+            let statement_scope = state
+                .document
+                .find_scope_by_position(stmt.span().start())
+                .expect("should have scope");
             let mut scopes = state.scopes.write().await;
             let (parent, child) = scopes.parent_mut(scope);
             for (name, value) in child.local() {
-                parent.insert(name.to_string(), value.clone_as_optional());
+                let optional = statement_scope
+                    .lookup(name)
+                    .map(|n| n.ty().is_optional())
+                    .unwrap_or(true);
+                if optional {
+                    parent.insert(name.to_string(), value.clone_as_optional());
+                } else {
+                    parent.insert(name.to_string(), value.clone());
+                }
             }
 
             scopes.free(scope);
@@ -1304,21 +1394,32 @@ impl WorkflowEvaluator {
                 document = state.document.uri().as_str(),
                 "conditional statement branch was not taken and subgraph will be skipped"
             );
+        }
+
+        // Set the names expected from the branches not taken to `None` in the parent
+        // scope
+        let mut scopes = state.scopes.write().await;
+        let parent = scopes.get_mut(parent);
+        let spans = once(stmt.braced_scope_span())
+            .chain(stmt.else_clauses().map(|c| c.braced_scope_span()));
+        let nodes =
+            once(stmt.inner().clone()).chain(stmt.else_clauses().map(|c| c.inner().clone()));
+        for (node, span) in nodes.zip(spans) {
+            if branch.as_ref() == Some(&node) {
+                continue;
+            }
 
-            // Conditional evaluated to false; set the expected names to `None` in the
-            // parent scope
-            let mut scopes = state.scopes.write().await;
-            let parent = scopes.get_mut(parent);
             let scope = state
                 .document
-                .find_scope_by_position(
-                    stmt.braced_scope_span()
-                        .expect("should have braced scope span")
-                        .start(),
-                )
+                .find_scope_by_position(span.expect("should have braced scope span").start())
                 .expect("should have scope");
 
             for (name, n) in scope.names() {
+                // Skip names already introduced by the branch taken
+                if parent.get_mut(name).is_some() {
+                    continue;
+                }
+
                 if let Type::Call(ty) = n.ty() {
                     parent.insert(
                         name.to_string(),
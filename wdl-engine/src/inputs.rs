@@ -8,6 +8,7 @@ use std::path::Path;
 
 use anyhow::Context;
 use anyhow::Result;
+use anyhow::anyhow;
 use anyhow::bail;
 use indexmap::IndexMap;
 use serde::Serialize;
@@ -275,12 +276,18 @@ impl TaskInputs {
                 })?;
 
                 let actual = value.ty();
-                if !actual.is_coercible_to(input.ty()) {
-                    bail!(
-                        "expected type `{expected}` for input `{path}`, but found type `{actual}`",
-                        expected = input.ty()
-                    );
-                }
+                let value = if actual.is_coercible_to(input.ty()) {
+                    value
+                } else {
+                    // Enum values are specified by the names of their variants
+                    value.coerce(input.ty()).map_err(|_| {
+                        anyhow!(
+                            "expected type `{expected}` for input `{path}`, but found type \
+                             `{actual}`",
+                            expected = input.ty()
+                        )
+                    })?
+                };
                 self.inputs.insert(path.to_string(), value);
                 Ok(())
             }
@@ -595,11 +602,17 @@ impl WorkflowInputs {
 
                 let expected = input.ty();
                 let actual = value.ty();
-                if !actual.is_coercible_to(expected) {
-                    bail!(
-                        "expected type `{expected}` for input `{path}`, but found type `{actual}`"
-                    );
-                }
+                let value = if actual.is_coercible_to(expected) {
+                    value
+                } else {
+                    // Enum values are specified by the names of their variants
+                    value.coerce(expected).map_err(|_| {
+                        anyhow!(
+                            "expected type `{expected}` for input `{path}`, but found type \
+                             `{actual}`"
+                        )
+                    })?
+                };
                 self.inputs.insert(path.to_string(), value);
                 Ok(())
             }
@@ -10,7 +10,6 @@ mod outputs;
 pub mod path;
 mod stdlib;
 pub(crate) mod tree;
-mod units;
 mod value;
 
 use std::sync::LazyLock;
@@ -22,13 +21,13 @@ pub use outputs::*;
 use sysinfo::CpuRefreshKind;
 use sysinfo::MemoryRefreshKind;
 use sysinfo::System;
-pub use units::*;
 pub use value::*;
 use wdl_analysis::Document;
 use wdl_analysis::diagnostics::unknown_type;
 use wdl_analysis::types::Type;
 use wdl_analysis::types::TypeNameResolver;
 use wdl_analysis::types::v1::AstTypeConverter;
+pub use wdl_analysis::units::*;
 use wdl_ast::Diagnostic;
 use wdl_ast::Span;
 use wdl_ast::TreeNode;
@@ -46,6 +45,7 @@ fn resolve_type_name(document: &Document, name: &str, span: Span) -> Result<Type
     document
         .struct_by_name(name)
         .map(|s| s.ty().expect("struct should have type").clone())
+        .or_else(|| document.enum_by_name(name).map(|e| e.ty().clone()))
         .ok_or_else(|| unknown_type(name, span))
 }
 
@@ -196,6 +196,7 @@ async fn compound_disk_size(
 
             Ok(size)
         }
+        CompoundValue::Enum(_) => Ok(0.0),
     }
 }
 
@@ -396,6 +396,28 @@ impl Value {
         }
     }
 
+    /// Gets the value as an enum.
+    ///
+    /// Returns `None` if the value is not an enum.
+    pub fn as_enum(&self) -> Option<&EnumValue> {
+        match self {
+            Self::Compound(CompoundValue::Enum(v)) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Unwraps the value into an enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not an enum.
+    pub fn unwrap_enum(self) -> EnumValue {
+        match self {
+            Self::Compound(CompoundValue::Enum(v)) => v,
+            _ => panic!("value is not an enum"),
+        }
+    }
+
     /// Gets the value as a task.
     ///
     /// Returns `None` if the value is not a task.
@@ -577,6 +599,16 @@ impl Coercible for Value {
                     bail!("cannot coerce `None` to non-optional type `{target}`");
                 }
             }
+            // String -> Enum
+            Self::Primitive(PrimitiveValue::String(s)) if target.as_enum().is_some() => {
+                EnumValue::new(target.clone(), s).map(Into::into)
+            }
+            // Enum -> String
+            Self::Compound(CompoundValue::Enum(v))
+                if target.as_primitive() == Some(PrimitiveType::String) =>
+            {
+                Ok(PrimitiveValue::String(v.variant.clone()).into())
+            }
             Self::Primitive(v) => v.coerce(target).map(Self::Primitive),
             Self::Compound(v) => v.coerce(target).map(Self::Compound),
             Self::Task(_) => {
@@ -698,6 +730,12 @@ impl From<Struct> for Value {
     }
 }
 
+impl From<EnumValue> for Value {
+    fn from(value: EnumValue) -> Self {
+        Self::Compound(value.into())
+    }
+}
+
 impl From<TaskValue> for Value {
     fn from(value: TaskValue) -> Self {
         Self::Task(value)
@@ -2024,6 +2062,64 @@ impl fmt::Display for Struct {
     }
 }
 
+/// Represents an enum value.
+///
+/// Enum values are cheap to clone.
+#[derive(Debug, Clone)]
+pub struct EnumValue {
+    /// The type of the enum value.
+    ty: Type,
+    /// The name of the enum variant.
+    variant: Arc<String>,
+}
+
+impl EnumValue {
+    /// Creates a new enum value.
+    ///
+    /// Returns an error if the enum type does not contain a variant of the
+    /// given name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given type is not an enum type.
+    pub fn new(ty: impl Into<Type>, variant: &str) -> Result<Self> {
+        let ty = ty.into();
+        let enum_ty = ty
+            .as_enum()
+            .unwrap_or_else(|| panic!("type `{ty}` is not an enum type"));
+
+        if !enum_ty.has_variant(variant) {
+            bail!("enum `{ty}` does not have a variant named `{variant}`");
+        }
+
+        Ok(Self {
+            ty,
+            variant: Arc::new(variant.to_string()),
+        })
+    }
+
+    /// Gets the type of the enum value.
+    pub fn ty(&self) -> Type {
+        self.ty.clone()
+    }
+
+    /// Gets the name of the enum variant.
+    pub fn variant(&self) -> &Arc<String> {
+        &self.variant
+    }
+}
+
+impl fmt::Display for EnumValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{name}.{variant}",
+            name = self.ty.as_enum().expect("should be an enum type").name(),
+            variant = self.variant
+        )
+    }
+}
+
 /// Represents a compound value.
 ///
 /// Compound values are cheap to clone.
@@ -2039,6 +2135,8 @@ pub enum CompoundValue {
     Object(Object),
     /// The value is a struct.
     Struct(Struct),
+    /// The value is an enum.
+    Enum(EnumValue),
 }
 
 impl CompoundValue {
@@ -2050,6 +2148,7 @@ impl CompoundValue {
             CompoundValue::Map(v) => v.ty(),
             CompoundValue::Object(v) => v.ty(),
             CompoundValue::Struct(v) => v.ty(),
+            CompoundValue::Enum(v) => v.ty(),
         }
     }
 
@@ -2163,6 +2262,28 @@ impl CompoundValue {
         }
     }
 
+    /// Gets the value as an enum.
+    ///
+    /// Returns `None` if the value is not an enum.
+    pub fn as_enum(&self) -> Option<&EnumValue> {
+        match self {
+            Self::Enum(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Unwraps the value into an enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not an enum.
+    pub fn unwrap_enum(self) -> EnumValue {
+        match self {
+            Self::Enum(v) => v,
+            _ => panic!("value is not an enum"),
+        }
+    }
+
     /// Compares two compound values for equality based on the WDL
     /// specification.
     ///
@@ -2218,6 +2339,9 @@ impl CompoundValue {
                         None => false,
                     }),
             ),
+            (CompoundValue::Enum(left), CompoundValue::Enum(right)) => {
+                Some(left.variant == right.variant)
+            }
             _ => None,
         }
     }
@@ -2269,6 +2393,7 @@ impl CompoundValue {
                     v.visit_paths(ty.members()[n].is_optional(), cb)?;
                 }
             }
+            Self::Enum(_) => {}
         }
 
         Ok(())
@@ -2354,6 +2479,7 @@ impl CompoundValue {
                     v.visit_paths_mut(ty.members()[n].is_optional(), cb)?;
                 }
             }
+            Self::Enum(_) => {}
         }
 
         Ok(())
@@ -2380,6 +2506,10 @@ impl CompoundValue {
                 name: v.name.clone(),
                 members: v.members.clone(),
             }),
+            Self::Enum(v) => Self::Enum(EnumValue {
+                ty: v.ty.require(),
+                variant: v.variant.clone(),
+            }),
         }
     }
 
@@ -2404,6 +2534,10 @@ impl CompoundValue {
                 name: v.name.clone(),
                 members: v.members.clone(),
             }),
+            Self::Enum(v) => Self::Enum(EnumValue {
+                ty: v.ty.optional(),
+                variant: v.variant.clone(),
+            }),
         }
     }
 }
@@ -2416,6 +2550,7 @@ impl fmt::Display for CompoundValue {
             Self::Map(v) => v.fmt(f),
             Self::Object(v) => v.fmt(f),
             Self::Struct(v) => v.fmt(f),
+            Self::Enum(v) => v.fmt(f),
         }
     }
 }
@@ -2594,6 +2729,15 @@ impl Coercible for CompoundValue {
                         ),
                     }));
                 }
+                // Enum -> Enum
+                (Self::Enum(v), CompoundType::Enum(target_ty))
+                    if v.ty.as_enum().expect("should be an enum type") == target_ty.as_ref() =>
+                {
+                    return Ok(Self::Enum(EnumValue {
+                        ty: target.clone(),
+                        variant: v.variant.clone(),
+                    }));
+                }
                 _ => {}
             }
         }
@@ -2665,6 +2809,12 @@ impl From<Struct> for CompoundValue {
     }
 }
 
+impl From<EnumValue> for CompoundValue {
+    fn from(value: EnumValue) -> Self {
+        Self::Enum(value)
+    }
+}
+
 impl serde::Serialize for CompoundValue {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -2718,6 +2868,8 @@ impl serde::Serialize for CompoundValue {
 
                 s.end()
             }
+            // Enums are serialized as the name of the variant
+            Self::Enum(v) => serializer.serialize_str(&v.variant),
         }
     }
 }
@@ -2,6 +2,7 @@
 
 pub mod config;
 pub mod element;
+pub mod migrate;
 mod token;
 pub mod v1;
 
@@ -68,11 +69,16 @@ impl Writable for &FormatElement {
                 }
                 AstNode::CallTarget(_) => v1::workflow::call::format_call_target(self, stream),
                 AstNode::CommandSection(_) => v1::task::format_command_section(self, stream),
+                AstNode::ConditionalElseClause(_) => {
+                    v1::workflow::format_conditional_else_clause(self, stream)
+                }
                 AstNode::ConditionalStatement(_) => {
                     v1::workflow::format_conditional_statement(self, stream)
                 }
                 AstNode::DefaultOption(_) => v1::expr::format_default_option(self, stream),
                 AstNode::DivisionExpr(_) => v1::expr::format_division_expr(self, stream),
+                AstNode::EnumDefinition(_) => v1::r#struct::format_enum_definition(self, stream),
+                AstNode::EnumVariant(_) => v1::r#struct::format_enum_variant(self, stream),
                 AstNode::EqualityExpr(_) => v1::expr::format_equality_expr(self, stream),
                 AstNode::ExponentiationExpr(_) => {
                     v1::expr::format_exponentiation_expr(self, stream)
@@ -0,0 +1,798 @@
+//! Migration of WDL documents to newer versions.
+//!
+//! A migration rewrites the concrete syntax tree of a document to use the
+//! constructs of the target version in place of deprecated ones:
+//!
+//! * the version statement is updated to the target version.
+//! * brace-delimited command sections become heredoc command sections.
+//! * `${}` command placeholders become `~{}` placeholders.
+//! * the `sep`, `true`/`false`, and `default` placeholder options are replaced
+//!   with equivalent expressions; a `true`/`false` option whose expression may
+//!   be optional becomes an expression that evaluates to an empty string when
+//!   the value is undefined.
+//! * for WDL 1.2 and later, `runtime` sections are split into `requirements`
+//!   and `hints` sections; otherwise, the `docker` runtime key is renamed to
+//!   `container`. Sections with both a `docker` and a `container` key are not
+//!   migrated.
+//! * `Object` declarations initialized with an object literal of string values
+//!   become `Map[String, String]` declarations, unless a member of the
+//!   declaration is accessed.
+//!
+//! As the rewrite is performed on the source text, comments and the formatting
+//! of unchanged lines are preserved. Uses of deprecated constructs that cannot
+//! be migrated automatically are reported as notes.
+
+use std::fmt::Write;
+
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::Diagnostic;
+use wdl_ast::Document;
+use wdl_ast::Edit;
+use wdl_ast::Node;
+use wdl_ast::Severity;
+use wdl_ast::Span;
+use wdl_ast::SupportedVersion;
+use wdl_ast::SyntaxElement;
+use wdl_ast::SyntaxKind;
+use wdl_ast::SyntaxNode;
+use wdl_ast::TreeNode;
+use wdl_ast::TreeToken;
+use wdl_ast::v1::AccessExpr;
+use wdl_ast::v1::BoundDecl;
+use wdl_ast::v1::CommandSection;
+use wdl_ast::v1::Decl;
+use wdl_ast::v1::Expr;
+use wdl_ast::v1::LiteralExpr;
+use wdl_ast::v1::LiteralObject;
+use wdl_ast::v1::ObjectType;
+use wdl_ast::v1::Placeholder;
+use wdl_ast::v1::PlaceholderOption;
+use wdl_ast::v1::RuntimeSection;
+use wdl_ast::v1::SectionParent;
+use wdl_ast::v1::Type;
+use wdl_ast::version::V1;
+
+use crate::Config;
+use crate::Formatter;
+use crate::element::node::AstNodeFormatExt as _;
+
+/// The maximum number of rewrite passes performed by a migration.
+///
+/// Rewrites of nested constructs (e.g. a placeholder within a runtime section
+/// being split) are performed in subsequent passes.
+const MAX_PASSES: usize = 16;
+
+/// The number of context lines to include around each hunk of a diff.
+const DIFF_CONTEXT: usize = 3;
+
+/// The `runtime` keys that become `requirements` in WDL 1.2, along with the
+/// name of the requirement.
+const REQUIREMENTS: &[(&str, &str)] = &[
+    ("container", "container"),
+    ("docker", "container"),
+    ("cpu", "cpu"),
+    ("memory", "memory"),
+    ("gpu", "gpu"),
+    ("fpga", "fpga"),
+    ("disks", "disks"),
+    ("maxRetries", "max_retries"),
+    ("max_retries", "max_retries"),
+    ("returnCodes", "return_codes"),
+    ("return_codes", "return_codes"),
+];
+
+/// Represents a document that was migrated to a newer version.
+#[derive(Debug, Clone)]
+pub struct MigratedDocument {
+    /// The original source of the document.
+    original: String,
+    /// The migrated source of the document.
+    migrated: String,
+    /// The notes about constructs that were not migrated.
+    notes: Vec<Diagnostic>,
+}
+
+impl MigratedDocument {
+    /// Gets the original source of the document.
+    pub fn original(&self) -> &str {
+        &self.original
+    }
+
+    /// Gets the migrated source of the document.
+    pub fn migrated(&self) -> &str {
+        &self.migrated
+    }
+
+    /// Gets the notes about deprecated constructs that could not be migrated
+    /// automatically.
+    ///
+    /// The spans of the notes are relative to the migrated source.
+    pub fn notes(&self) -> &[Diagnostic] {
+        &self.notes
+    }
+
+    /// Determines if the migration changed the document.
+    pub fn is_changed(&self) -> bool {
+        self.original != self.migrated
+    }
+
+    /// Gets a unified diff of the changes made by the migration.
+    ///
+    /// The given path is used in the header of the diff.
+    ///
+    /// Returns an empty string if the document was not changed.
+    pub fn diff(&self, path: &str) -> String {
+        if !self.is_changed() {
+            return String::new();
+        }
+
+        // Collect the lines of the diff along with the (zero-based) line numbers of each
+        // side
+        let lines: Vec<_> = diff::lines(&self.original, &self.migrated);
+        let mut ops = Vec::with_capacity(lines.len());
+        let (mut old, mut new) = (0, 0);
+        for line in lines {
+            match line {
+                diff::Result::Both(l, _) => {
+                    ops.push((' ', l, old, new));
+                    old += 1;
+                    new += 1;
+                }
+                diff::Result::Left(l) => {
+                    ops.push(('-', l, old, new));
+                    old += 1;
+                }
+                diff::Result::Right(l) => {
+                    ops.push(('+', l, old, new));
+                    new += 1;
+                }
+            }
+        }
+
+        let mut diff = format!("--- a/{path}\n+++ b/{path}\n");
+        let mut i = 0;
+        while i < ops.len() {
+            // Find the next change
+            let Some(first) = ops[i..].iter().position(|(op, ..)| *op != ' ') else {
+                break;
+            };
+
+            // Extend the hunk until there are more than twice the context of unchanged lines
+            let start = (i + first).saturating_sub(DIFF_CONTEXT);
+            let mut end = i + first;
+            let mut unchanged = 0;
+            for (index, (op, ..)) in ops.iter().enumerate().skip(i + first) {
+                if *op == ' ' {
+                    unchanged += 1;
+                    if unchanged > DIFF_CONTEXT * 2 {
+                        break;
+                    }
+                } else {
+                    unchanged = 0;
+                    end = index;
+                }
+            }
+
+            let end = (end + DIFF_CONTEXT + 1).min(ops.len());
+            let hunk = &ops[start..end];
+            let old_count = hunk.iter().filter(|(op, ..)| *op != '+').count();
+            let new_count = hunk.iter().filter(|(op, ..)| *op != '-').count();
+            let (_, _, old_start, new_start) = hunk[0];
+            writeln!(
+                diff,
+                "@@ -{old} +{new} @@",
+                old = hunk_range(old_start, old_count),
+                new = hunk_range(new_start, new_count)
+            )
+            .unwrap();
+
+            for (op, line, ..) in hunk {
+                writeln!(diff, "{op}{line}").unwrap();
+            }
+
+            i = end;
+        }
+
+        diff
+    }
+}
+
+/// Formats the range of a hunk in a unified diff.
+fn hunk_range(start: usize, count: usize) -> String {
+    // An empty range refers to the line before the hunk
+    let start = if count == 0 { start } else { start + 1 };
+    if count == 1 {
+        start.to_string()
+    } else {
+        format!("{start},{count}")
+    }
+}
+
+/// Represents a migrator of WDL documents.
+#[derive(Debug, Clone, Copy)]
+pub struct Migrator {
+    /// The version to migrate documents to.
+    target: SupportedVersion,
+    /// The formatting configuration to use for formatting migrated documents.
+    ///
+    /// If `None`, migrated documents are not formatted.
+    format: Option<Config>,
+}
+
+impl Migrator {
+    /// Constructs a new migrator that migrates documents to the given
+    /// version.
+    pub fn new(target: SupportedVersion) -> Self {
+        Self {
+            target,
+            format: None,
+        }
+    }
+
+    /// Sets the formatting configuration used to format migrated documents.
+    ///
+    /// By default, migrated documents are not formatted.
+    pub fn with_format(mut self, config: Config) -> Self {
+        self.format = Some(config);
+        self
+    }
+
+    /// Gets the version documents are migrated to.
+    pub fn target(&self) -> SupportedVersion {
+        self.target
+    }
+
+    /// Migrates the given document source.
+    ///
+    /// Returns the error diagnostics if the document could not be parsed or
+    /// if the document's version is newer than the target version.
+    pub fn migrate(&self, source: &str) -> Result<MigratedDocument, Vec<Diagnostic>> {
+        let (document, diagnostics) = Document::parse(source);
+        check_diagnostics(diagnostics)?;
+
+        let statement = document.version_statement().ok_or_else(|| {
+            vec![Diagnostic::error(
+                "document does not contain a version statement",
+            )]
+        })?;
+        let version = statement.version();
+        let current = version.text().parse::<SupportedVersion>().map_err(|_| {
+            vec![
+                Diagnostic::error(format!(
+                    "unsupported WDL version `{version}`",
+                    version = version.text()
+                ))
+                .with_highlight(version.span()),
+            ]
+        })?;
+
+        if current > self.target {
+            return Err(vec![
+                Diagnostic::error(format!(
+                    "cannot migrate a WDL {current} document to the older version {target}",
+                    target = self.target
+                ))
+                .with_highlight(version.span()),
+            ]);
+        }
+
+        let mut migrated = source.to_string();
+        if current != self.target {
+            migrated.replace_range(
+                version.span().start()..version.span().end(),
+                &self.target.to_string(),
+            );
+        }
+
+        for _ in 0..MAX_PASSES {
+            let (document, diagnostics) = Document::parse(&migrated);
+            check_diagnostics(diagnostics)?;
+
+            let edits = self.edits(&migrated, document.inner());
+            if edits.is_empty() {
+                break;
+            }
+
+            migrated = apply(&migrated, edits);
+        }
+
+        if let Some(config) = self.format {
+            let (document, diagnostics) = Document::parse(&migrated);
+            check_diagnostics(diagnostics)?;
+            if let Some(ast) = document.ast().into_v1() {
+                let element = Node::Ast(ast).into_format_element();
+                migrated = Formatter::new(config).format(&element).map_err(|_| {
+                    vec![Diagnostic::error("failed to format the migrated document")]
+                })?;
+            }
+        }
+
+        let (document, _) = Document::parse(&migrated);
+        Ok(MigratedDocument {
+            original: source.to_string(),
+            notes: self.notes(document.inner()),
+            migrated,
+        })
+    }
+
+    /// Determines if the target version is WDL 1.2 or later.
+    fn targets_requirements(&self) -> bool {
+        self.target >= SupportedVersion::V1(V1::Two)
+    }
+
+    /// Collects the edits of a single migration pass.
+    fn edits(&self, source: &str, root: &SyntaxNode) -> Vec<Edit> {
+        let mut edits = Vec::new();
+        for node in root.descendants() {
+            match node.kind() {
+                SyntaxKind::PlaceholderNode => {
+                    let placeholder = Placeholder::cast(node).expect("node should cast");
+                    placeholder_edits(&placeholder, &mut edits);
+                }
+                SyntaxKind::CommandSectionNode => {
+                    let section = CommandSection::cast(node).expect("node should cast");
+                    command_edits(&section, &mut edits);
+                }
+                SyntaxKind::RuntimeSectionNode => {
+                    let section = RuntimeSection::cast(node).expect("node should cast");
+                    if self.targets_requirements() {
+                        if let Some(edit) = split_runtime(source, &section) {
+                            edits.push(edit);
+                        }
+                    } else if let Some(item) = section
+                        .items()
+                        .find(|i| i.name().text() == "docker")
+                        .filter(|_| !has_container(&section))
+                    {
+                        edits.push(Edit::new(item.name().span(), "container"));
+                    }
+                }
+                SyntaxKind::BoundDeclNode => {
+                    let decl = BoundDecl::cast(node).expect("node should cast");
+                    object_edits(&decl, &mut edits);
+                }
+                _ => {}
+            }
+        }
+
+        edits
+    }
+
+    /// Collects the notes about deprecated constructs remaining in a document.
+    fn notes(&self, root: &SyntaxNode) -> Vec<Diagnostic> {
+        let mut notes = Vec::new();
+        for node in root.descendants() {
+            if let Some(ty) = ObjectType::cast(node.clone()) {
+                notes.push(
+                    Diagnostic::note("use of the deprecated `Object` type was not migrated")
+                        .with_highlight(ty.span())
+                        .with_fix("replace the `Object` type with a struct or a `Map`"),
+                );
+            } else if let Some(object) = LiteralObject::cast(node.clone()) {
+                notes.push(
+                    Diagnostic::note("use of a deprecated object literal was not migrated")
+                        .with_highlight(object.span())
+                        .with_fix(
+                            "replace the object literal with a struct literal or a map literal",
+                        ),
+                );
+            } else if let Some(section) = RuntimeSection::cast(node) {
+                if self.targets_requirements() {
+                    notes.push(
+                        Diagnostic::note(
+                            "use of the deprecated `runtime` section was not migrated",
+                        )
+                        .with_highlight(section.span())
+                        .with_fix("replace the `runtime` section with `requirements` and `hints`"),
+                    );
+                } else if let Some(item) = section
+                    .items()
+                    .find(|i| i.name().text() == "docker")
+                    .filter(|_| self.target >= SupportedVersion::V1(V1::One))
+                {
+                    notes.push(
+                        Diagnostic::note(
+                            "use of the deprecated `docker` runtime key was not migrated",
+                        )
+                        .with_highlight(item.name().span())
+                        .with_fix("remove the `docker` key in favor of the `container` key"),
+                    );
+                }
+            }
+        }
+
+        notes
+    }
+}
+
+/// Determines if a runtime section has a `container` key.
+fn has_container(section: &RuntimeSection) -> bool {
+    section.items().any(|i| i.name().text() == "container")
+}
+
+/// Converts the error diagnostics of parsing into a result.
+fn check_diagnostics(diagnostics: Vec<Diagnostic>) -> Result<(), Vec<Diagnostic>> {
+    let errors: Vec<_> = diagnostics
+        .into_iter()
+        .filter(|d| d.severity() == Severity::Error)
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Applies the given edits to the source.
+///
+/// An edit that overlaps a preceding edit is not applied; it will be collected
+/// again in a subsequent pass.
+fn apply(source: &str, mut edits: Vec<Edit>) -> String {
+    edits.sort_by(|a, b| {
+        a.span()
+            .start()
+            .cmp(&b.span().start())
+            .then(b.span().end().cmp(&a.span().end()))
+    });
+
+    let mut result = String::with_capacity(source.len());
+    let mut offset = 0;
+    for edit in edits {
+        let span = edit.span();
+        if span.start() < offset {
+            continue;
+        }
+
+        result.push_str(&source[offset..span.start()]);
+        result.push_str(edit.replacement());
+        offset = span.end();
+    }
+
+    result.push_str(&source[offset..]);
+    result
+}
+
+/// Collects the edits for a placeholder.
+fn placeholder_edits(placeholder: &Placeholder, edits: &mut Vec<Edit>) {
+    // Replace `${` with `~{` in command sections
+    if !placeholder.has_tilde()
+        && placeholder
+            .inner()
+            .ancestors()
+            .any(|n| n.kind() == SyntaxKind::CommandSectionNode)
+    {
+        let open = placeholder
+            .inner()
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|t| t.kind() == SyntaxKind::PlaceholderOpen)
+            .expect("should have a placeholder open token");
+        edits.push(Edit::new(open.span(), "~{"));
+    }
+
+    let Some(option) = placeholder.option() else {
+        return;
+    };
+
+    let expr = placeholder.expr();
+    let text = expr.text().to_string();
+    let replacement = match &option {
+        PlaceholderOption::Sep(option) => format!(
+            "sep({separator}, {text})",
+            separator = option.separator().inner().text()
+        ),
+        PlaceholderOption::TrueFalse(option) => {
+            let (t, f) = option.values();
+            let (t, f) = (t.inner().text(), f.inner().text());
+            if is_required(&expr) {
+                format!("if {text} then {t} else {f}")
+            } else {
+                // An undefined value previously evaluated to an empty string
+                format!(
+                    "if defined({text}) then (if select_first([{text}]) then {t} else {f}) else \"\""
+                )
+            }
+        }
+        PlaceholderOption::Default(option) => format!(
+            "if defined({text}) then \"~{{{text}}}\" else {value}",
+            value = option.value().inner().text()
+        ),
+    };
+
+    let span = option.inner().span();
+    let end = expr.span().end();
+    edits.push(Edit::new(
+        Span::new(span.start(), end - span.start()),
+        replacement,
+    ));
+}
+
+/// Determines if the given expression is known to not be optional.
+///
+/// As migration is performed without type checking, only literals, logical and
+/// comparison expressions, and references to non-optional declarations of the
+/// enclosing task or workflow are known to not be optional.
+fn is_required(expr: &Expr) -> bool {
+    match expr {
+        Expr::Literal(LiteralExpr::Boolean(_))
+        | Expr::LogicalNot(_)
+        | Expr::LogicalOr(_)
+        | Expr::LogicalAnd(_)
+        | Expr::Equality(_)
+        | Expr::Inequality(_)
+        | Expr::Less(_)
+        | Expr::LessEqual(_)
+        | Expr::Greater(_)
+        | Expr::GreaterEqual(_) => true,
+        Expr::Parenthesized(expr) => is_required(&expr.expr()),
+        Expr::NameRef(name) => {
+            let Some(definition) = name.inner().ancestors().find(|n| {
+                matches!(
+                    n.kind(),
+                    SyntaxKind::TaskDefinitionNode | SyntaxKind::WorkflowDefinitionNode
+                )
+            }) else {
+                return false;
+            };
+
+            // Declarations within a conditional or scatter statement are optional or
+            // arrays when referenced outside of the statement
+            definition
+                .descendants()
+                .filter_map(Decl::cast)
+                .find(|d| d.name().text() == name.name().text())
+                .is_some_and(|d| {
+                    !d.ty().is_optional()
+                        && !d.inner().ancestors().any(|n| {
+                            matches!(
+                                n.kind(),
+                                SyntaxKind::ConditionalStatementNode
+                                    | SyntaxKind::ScatterStatementNode
+                            )
+                        })
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Collects the edits for a command section.
+fn command_edits(section: &CommandSection, edits: &mut Vec<Edit>) {
+    // A brace command can only become a heredoc command if it does not contain the
+    // heredoc close delimiter
+    if section.is_heredoc()
+        || section
+            .inner()
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .any(|t| t.kind() == SyntaxKind::LiteralCommandText && t.text().contains(">>>"))
+    {
+        return;
+    }
+
+    for token in section
+        .inner()
+        .children_with_tokens()
+        .filter_map(SyntaxElement::into_token)
+    {
+        match token.kind() {
+            SyntaxKind::OpenBrace => edits.push(Edit::new(token.span(), "<<<")),
+            SyntaxKind::CloseBrace => edits.push(Edit::new(token.span(), ">>>")),
+            _ => {}
+        }
+    }
+}
+
+/// Represents an item of a runtime section being split.
+#[derive(Debug, Default)]
+struct SplitItem {
+    /// The comments preceding the item.
+    leading: Vec<String>,
+    /// The text of the item.
+    text: String,
+    /// The comment following the item on the same line.
+    trailing: Option<String>,
+}
+
+/// Splits a runtime section into `requirements` and `hints` sections.
+///
+/// Returns `None` if the section cannot be split.
+fn split_runtime(source: &str, section: &RuntimeSection) -> Option<Edit> {
+    // Both `docker` and `container` would become a `container` requirement
+    if has_container(section) && section.items().any(|i| i.name().text() == "docker") {
+        return None;
+    }
+
+    // The task must not already have a requirements or hints section
+    let SectionParent::Task(task) = section.parent() else {
+        return None;
+    };
+
+    if task.requirements().is_some() || task.hints().is_some() {
+        return None;
+    }
+
+    let span = section.span();
+    let indent = line_indent(source, span.start());
+    let item_indent = section
+        .items()
+        .next()
+        .map(|i| line_indent(source, i.span().start()))
+        .unwrap_or_else(|| format!("{indent}    "));
+
+    let mut requirements: Vec<SplitItem> = Vec::new();
+    let mut hints: Vec<SplitItem> = Vec::new();
+    let mut pending = Vec::new();
+    let mut last_requirement = None;
+    let mut newline = true;
+    for child in section.inner().children_with_tokens() {
+        match child {
+            SyntaxElement::Token(token) if token.kind() == SyntaxKind::Whitespace => {
+                newline |= token.text().contains('\n');
+            }
+            SyntaxElement::Token(token) if token.kind() == SyntaxKind::Comment => {
+                let items = match last_requirement {
+                    Some(true) => &mut requirements,
+                    Some(false) => &mut hints,
+                    None => {
+                        pending.push(token.text().to_string());
+                        continue;
+                    }
+                };
+
+                match items.last_mut() {
+                    Some(item) if !newline && item.trailing.is_none() => {
+                        item.trailing = Some(token.text().to_string());
+                    }
+                    _ => pending.push(token.text().to_string()),
+                }
+            }
+            SyntaxElement::Node(node) if node.kind() == SyntaxKind::RuntimeItemNode => {
+                let item = wdl_ast::v1::RuntimeItem::cast(node).expect("node should cast");
+                let name = item.name();
+                let requirement = REQUIREMENTS
+                    .iter()
+                    .find(|(k, _)| *k == name.text())
+                    .map(|(_, r)| *r);
+                let text = format!(
+                    "{name}: {expr}",
+                    name = requirement.unwrap_or(name.text()),
+                    expr = item.expr().text()
+                );
+
+                let items = if requirement.is_some() {
+                    &mut requirements
+                } else {
+                    &mut hints
+                };
+
+                items.push(SplitItem {
+                    leading: std::mem::take(&mut pending),
+                    text,
+                    trailing: None,
+                });
+                last_requirement = Some(requirement.is_some());
+                newline = false;
+            }
+            _ => {}
+        }
+    }
+
+    if requirements.is_empty() && hints.is_empty() {
+        return None;
+    }
+
+    let mut text = String::new();
+    for (name, items) in [("requirements", &requirements), ("hints", &hints)] {
+        if items.is_empty() {
+            continue;
+        }
+
+        if !text.is_empty() {
+            write!(text, "\n\n{indent}").unwrap();
+        }
+
+        writeln!(text, "{name} {{").unwrap();
+        for item in items {
+            for comment in &item.leading {
+                writeln!(text, "{item_indent}{comment}").unwrap();
+            }
+
+            write!(text, "{item_indent}{item}", item = item.text).unwrap();
+            if let Some(comment) = &item.trailing {
+                write!(text, "  {comment}").unwrap();
+            }
+
+            text.push('\n');
+        }
+
+        // Place any remaining comments at the end of the last section
+        let last = std::ptr::eq(items, &hints) || hints.is_empty();
+        if last {
+            for comment in pending.drain(..) {
+                writeln!(text, "{item_indent}{comment}").unwrap();
+            }
+        }
+
+        write!(text, "{indent}}}").unwrap();
+    }
+
+    Some(Edit::new(span, text))
+}
+
+/// Collects the edits for an `Object` declaration.
+///
+/// Only declarations initialized with an object literal of string literal
+/// values are migrated; as member access of a `Map` fails at runtime,
+/// declarations that are the target of a member access are not migrated.
+fn object_edits(decl: &BoundDecl, edits: &mut Vec<Edit>) {
+    let Type::Object(ty) = decl.ty() else {
+        return;
+    };
+
+    if is_member_accessed(decl) {
+        return;
+    }
+
+    let Expr::Literal(LiteralExpr::Object(object)) = decl.expr() else {
+        return;
+    };
+
+    let mut items = Vec::new();
+    for item in object.items() {
+        let (name, value) = item.name_value();
+        let Expr::Literal(LiteralExpr::String(value)) = value else {
+            return;
+        };
+
+        items.push(format!(
+            "\"{name}\": {value}",
+            name = name.text(),
+            value = value.inner().text()
+        ));
+    }
+
+    edits.push(Edit::new(
+        ty.span(),
+        format!(
+            "Map[String, String]{optional}",
+            optional = if ty.is_optional() { "?" } else { "" }
+        ),
+    ));
+    edits.push(Edit::new(
+        object.span(),
+        format!("{{{items}}}", items = items.join(", ")),
+    ));
+}
+
+/// Determines if a member of the given declaration is accessed anywhere in the
+/// document.
+///
+/// Accesses of a call output of the same name are also considered as the
+/// declaration may be a task output.
+fn is_member_accessed(decl: &BoundDecl) -> bool {
+    let name = decl.name();
+    let root = decl
+        .inner()
+        .ancestors()
+        .last()
+        .expect("should have a root node");
+    root.descendants()
+        .filter_map(AccessExpr::cast)
+        .any(|access| match access.operands().0 {
+            Expr::NameRef(target) => target.name().text() == name.text(),
+            Expr::Access(target) => target.operands().1.text() == name.text(),
+            _ => false,
+        })
+}
+
+/// Gets the indentation of the line containing the given offset.
+fn line_indent(source: &str, offset: usize) -> String {
+    let start = source[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    source[start..offset]
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect()
+}
@@ -74,6 +74,73 @@ pub fn format_struct_definition(element: &FormatElement, stream: &mut TokenStrea
     stream.end_line();
 }
 
+/// Formats an [`EnumDefinition`](wdl_ast::v1::EnumDefinition).
+pub fn format_enum_definition(element: &FormatElement, stream: &mut TokenStream<PreToken>) {
+    let mut children = element.children().expect("enum definition children");
+
+    let enum_keyword = children.next().expect("enum keyword");
+    assert!(enum_keyword.element().kind() == SyntaxKind::EnumKeyword);
+    (&enum_keyword).write(stream);
+    stream.end_word();
+
+    let name = children.next().expect("enum name");
+    assert!(name.element().kind() == SyntaxKind::Ident);
+    (&name).write(stream);
+    stream.end_word();
+
+    let open_brace = children.next().expect("open brace");
+    assert!(open_brace.element().kind() == SyntaxKind::OpenBrace);
+    (&open_brace).write(stream);
+    stream.end_line();
+    stream.increment_indent();
+
+    let mut variants = Vec::new();
+    let mut commas = Vec::new();
+    let mut close_brace = None;
+
+    for child in children {
+        match child.element().kind() {
+            SyntaxKind::EnumVariantNode => {
+                variants.push(child.clone());
+            }
+            SyntaxKind::Comma => {
+                commas.push(child.clone());
+            }
+            SyntaxKind::CloseBrace => {
+                close_brace = Some(child.clone());
+            }
+            _ => {
+                unreachable!(
+                    "unexpected child in enum definition: {:?}",
+                    child.element().kind()
+                );
+            }
+        }
+    }
+
+    let mut commas = commas.iter();
+    for variant in variants {
+        (&variant).write(stream);
+        if let Some(comma) = commas.next() {
+            (comma).write(stream);
+        } else {
+            stream.push_literal(",".to_string(), SyntaxKind::Comma);
+        }
+        stream.end_line();
+    }
+
+    stream.decrement_indent();
+    (&close_brace.expect("enum definition close brace")).write(stream);
+    stream.end_line();
+}
+
+/// Formats an [`EnumVariant`](wdl_ast::v1::EnumVariant).
+pub fn format_enum_variant(element: &FormatElement, stream: &mut TokenStream<PreToken>) {
+    for child in element.children().expect("enum variant children") {
+        (&child).write(stream);
+    }
+}
+
 /// Formats a [`LiteralStructItem`](wdl_ast::v1::LiteralStructItem).
 pub fn format_literal_struct_item(element: &FormatElement, stream: &mut TokenStream<PreToken>) {
     let mut children = element.children().expect("literal struct item children");
@@ -36,13 +36,56 @@ pub fn format_conditional_statement(element: &FormatElement, stream: &mut TokenS
     (&open_brace).write(stream);
     stream.increment_indent();
 
+    for child in children {
+        match child.element().kind() {
+            SyntaxKind::CloseBrace => stream.decrement_indent(),
+            SyntaxKind::ConditionalElseClauseNode => stream.end_word(),
+            _ => {}
+        }
+        (&child).write(stream);
+    }
+    stream.end_line();
+}
+
+/// Formats a [`ConditionalElseClause`](wdl_ast::v1::ConditionalElseClause).
+pub fn format_conditional_else_clause(element: &FormatElement, stream: &mut TokenStream<PreToken>) {
+    let mut children = element.children().expect("else clause children");
+
+    let else_keyword = children.next().expect("else keyword");
+    assert!(else_keyword.element().kind() == SyntaxKind::ElseKeyword);
+    (&else_keyword).write(stream);
+    stream.end_word();
+
+    let mut next = children.next().expect("else clause child");
+    if next.element().kind() == SyntaxKind::IfKeyword {
+        (&next).write(stream);
+        stream.end_word();
+
+        let open_paren = children.next().expect("open paren");
+        assert!(open_paren.element().kind() == SyntaxKind::OpenParen);
+        (&open_paren).write(stream);
+
+        for child in children.by_ref() {
+            (&child).write(stream);
+            if child.element().kind() == SyntaxKind::CloseParen {
+                stream.end_word();
+                break;
+            }
+        }
+
+        next = children.next().expect("open brace");
+    }
+
+    assert!(next.element().kind() == SyntaxKind::OpenBrace);
+    (&next).write(stream);
+    stream.increment_indent();
+
     for child in children {
         if child.element().kind() == SyntaxKind::CloseBrace {
             stream.decrement_indent();
         }
         (&child).write(stream);
     }
-    stream.end_line();
 }
 
 /// Formats a [`ScatterStatement`](wdl_ast::v1::ScatterStatement).
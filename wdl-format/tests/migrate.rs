@@ -0,0 +1,245 @@
+//! The migration file tests.
+//!
+//! This test looks for directories in `tests/migrate`.
+//!
+//! Each directory is expected to contain:
+//!
+//! * `source.wdl` - the test input source to migrate.
+//! * `source.migrated.wdl` - the expected migrated output.
+//! * `source.diff` - the expected diff of the migration.
+//! * `source.notes` - the expected notes of the migration.
+//!
+//! A directory may also contain a `target` file containing the version to
+//! migrate to; the default target version is 1.2.
+//!
+//! The expected files may be automatically generated or updated by setting the
+//! `BLESS` environment variable when running this test.
+
+use std::collections::HashSet;
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::exit;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use codespan_reporting::files::SimpleFile;
+use codespan_reporting::term;
+use codespan_reporting::term::Config;
+use codespan_reporting::term::termcolor::Buffer;
+use colored::Colorize;
+use pretty_assertions::StrComparison;
+use rayon::prelude::*;
+use wdl_ast::Diagnostic;
+use wdl_ast::SupportedVersion;
+use wdl_format::migrate::Migrator;
+
+/// Normalizes a result.
+fn normalize(s: &str) -> String {
+    // Just normalize line endings
+    s.replace("\r\n", "\n")
+}
+
+/// Find all the tests in the `tests/migrate` directory.
+fn find_tests() -> Vec<PathBuf> {
+    // Check for filter arguments consisting of test names
+    let mut filter = HashSet::new();
+    for arg in std::env::args().skip_while(|a| a != "--").skip(1) {
+        if !arg.starts_with('-') {
+            filter.insert(arg);
+        }
+    }
+
+    let mut tests: Vec<PathBuf> = Vec::new();
+    for entry in Path::new("tests/migrate").read_dir().unwrap() {
+        let entry = entry.expect("failed to read directory");
+        let path = entry.path();
+        if !path.is_dir()
+            || (!filter.is_empty()
+                && !filter.contains(entry.file_name().to_str().expect("name should be UTF-8")))
+        {
+            continue;
+        }
+
+        tests.push(path);
+    }
+
+    tests.sort();
+    tests
+}
+
+/// Format a list of diagnostics.
+fn format_diagnostics(diagnostics: &[Diagnostic], path: &Path, source: &str) -> String {
+    let file = SimpleFile::new(path.as_os_str().to_str().unwrap(), source);
+    let mut buffer = Buffer::no_color();
+    for diagnostic in diagnostics {
+        term::emit(
+            &mut buffer,
+            &Config::default(),
+            &file,
+            &diagnostic.to_codespan(()),
+        )
+        .expect("should emit");
+    }
+
+    String::from_utf8(buffer.into_inner()).expect("should be UTF-8")
+}
+
+/// Compare the result of a test to the expected result.
+fn compare_result(path: &Path, result: &str) -> Result<(), String> {
+    let result = normalize(result);
+    if env::var_os("BLESS").is_some() {
+        fs::write(path, &result).map_err(|e| {
+            format!(
+                "failed to write result file `{path}`: {e}",
+                path = path.display()
+            )
+        })?;
+        return Ok(());
+    }
+
+    let expected = fs::read_to_string(path)
+        .map_err(|e| {
+            format!(
+                "failed to read result file `{path}`: {e}",
+                path = path.display()
+            )
+        })?
+        .replace("\r\n", "\n");
+
+    if expected != result {
+        return Err(format!(
+            "result from `{path}` is not as expected:\n{diff}",
+            path = path.display(),
+            diff = StrComparison::new(&expected, &result),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Run a test.
+fn run_test(test: &Path, ntests: &AtomicUsize) -> Result<(), String> {
+    let path = test.join("source.wdl");
+    let source = std::fs::read_to_string(&path).map_err(|e| {
+        format!(
+            "failed to read source file `{path}`: {e}",
+            path = path.display()
+        )
+    })?;
+
+    let target = match fs::read_to_string(test.join("target")) {
+        Ok(target) => target
+            .trim()
+            .parse::<SupportedVersion>()
+            .map_err(|_| format!("invalid target version `{target}`"))?,
+        Err(_) => "1.2".parse().expect("version should parse"),
+    };
+
+    let migrator = Migrator::new(target);
+    let migrated = migrator.migrate(&source).map_err(|diagnostics| {
+        format!(
+            "failed to migrate `{path}`:\n{diagnostics}",
+            path = path.display(),
+            diagnostics = format_diagnostics(&diagnostics, &path, &source)
+        )
+    })?;
+
+    compare_result(&path.with_extension("migrated.wdl"), migrated.migrated())?;
+    compare_result(&path.with_extension("diff"), &migrated.diff("source.wdl"))?;
+    compare_result(
+        &path.with_extension("notes"),
+        &format_diagnostics(migrated.notes(), &path, migrated.migrated()),
+    )?;
+
+    // Test idempotency by migrating the migrated document
+    let twice = migrator
+        .migrate(migrated.migrated())
+        .map_err(|diagnostics| {
+            format!(
+                "failed to migrate the migrated `{path}`:\n{diagnostics}",
+                path = path.display(),
+                diagnostics = format_diagnostics(&diagnostics, &path, migrated.migrated())
+            )
+        })?;
+
+    if twice.is_changed() {
+        return Err(format!(
+            "migrating `{path}` again changed the document:\n{diff}",
+            path = path.display(),
+            diff = twice.diff("source.migrated.wdl")
+        ));
+    }
+
+    ntests.fetch_add(1, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Run all the tests.
+fn main() {
+    let tests = find_tests();
+    println!("\nrunning {} tests\n", tests.len());
+
+    let ntests = AtomicUsize::new(0);
+    let errors = tests
+        .par_iter()
+        .filter_map(|test| {
+            let test_name = test.file_stem().and_then(OsStr::to_str).unwrap();
+            match std::panic::catch_unwind(|| {
+                match run_test(test, &ntests)
+                    .map_err(|e| format!("failed to run test `{path}`: {e}", path = test.display()))
+                    .err()
+                {
+                    Some(e) => {
+                        println!("test {test_name} ... {failed}", failed = "failed".red());
+                        Some((test_name, e))
+                    }
+                    None => {
+                        println!("test {test_name} ... {ok}", ok = "ok".green());
+                        None
+                    }
+                }
+            }) {
+                Ok(result) => result,
+                Err(e) => {
+                    println!(
+                        "test {test_name} ... {panicked}",
+                        panicked = "panicked".red()
+                    );
+                    Some((
+                        test_name,
+                        format!(
+                            "test panicked: {e:?}",
+                            e = e
+                                .downcast_ref::<String>()
+                                .map(|s| s.as_str())
+                                .or_else(|| e.downcast_ref::<&str>().copied())
+                                .unwrap_or("no panic message")
+                        ),
+                    ))
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if !errors.is_empty() {
+        eprintln!(
+            "\n{count} test(s) {failed}:",
+            count = errors.len(),
+            failed = "failed".red()
+        );
+
+        for (name, msg) in errors.iter() {
+            eprintln!("{name}: {msg}", msg = msg.red());
+        }
+
+        exit(1);
+    }
+
+    println!(
+        "\ntest result: ok. {} passed\n",
+        ntests.load(Ordering::SeqCst)
+    );
+}
@@ -105,6 +105,78 @@ pub enum Severity {
     Note,
 }
 
+/// Represents whether the edits of a diagnostic are safe to apply
+/// automatically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Applicability {
+    /// The edits do not change the meaning of the document and may be applied
+    /// automatically.
+    Safe,
+    /// The edits may change the meaning of the document and should be reviewed
+    /// before being applied.
+    Unsafe,
+}
+
+impl fmt::Display for Applicability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Safe => write!(f, "safe"),
+            Self::Unsafe => write!(f, "unsafe"),
+        }
+    }
+}
+
+/// Represents a machine-applicable edit of a document's source.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Edit {
+    /// The span of source being replaced.
+    span: Span,
+    /// The replacement text.
+    replacement: String,
+}
+
+impl Edit {
+    /// Creates a new edit that replaces the given span with the given text.
+    pub fn new(span: impl Into<Span>, replacement: impl Into<String>) -> Self {
+        Self {
+            span: span.into(),
+            replacement: replacement.into(),
+        }
+    }
+
+    /// Creates a new edit that inserts the given text at the given offset.
+    pub fn insert(offset: usize, text: impl Into<String>) -> Self {
+        Self::new(Span::new(offset, 0), text)
+    }
+
+    /// Creates a new edit that deletes the given span.
+    pub fn delete(span: impl Into<Span>) -> Self {
+        Self::new(span, String::new())
+    }
+
+    /// Gets the span of source being replaced.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Gets the replacement text.
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+}
+
+/// Represents the machine-applicable edits that fix a diagnostic.
+///
+/// This is boxed by the diagnostic so that diagnostics without edits remain
+/// small.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Edits {
+    /// The edits that fix the diagnostic.
+    edits: Box<[Edit]>,
+    /// The applicability of the edits.
+    applicability: Applicability,
+}
+
 /// Represents a diagnostic to display to the user.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Diagnostic {
@@ -120,6 +192,8 @@ pub struct Diagnostic {
     ///
     /// The first label in the collection is considered the primary label.
     labels: Vec<Label>,
+    /// The machine-applicable edits that fix the diagnostic.
+    edits: Option<Box<Edits>>,
 }
 
 impl Ord for Diagnostic {
@@ -144,7 +218,12 @@ impl Ord for Diagnostic {
             ord => return ord,
         }
 
-        self.fix.cmp(&other.fix)
+        match self.fix.cmp(&other.fix) {
+            Ordering::Equal => {}
+            ord => return ord,
+        }
+
+        self.edits.cmp(&other.edits)
     }
 }
 
@@ -163,6 +242,7 @@ impl Diagnostic {
             message: message.into(),
             fix: None,
             labels: Default::default(),
+            edits: None,
         }
     }
 
@@ -174,6 +254,7 @@ impl Diagnostic {
             message: message.into(),
             fix: None,
             labels: Default::default(),
+            edits: None,
         }
     }
 
@@ -185,6 +266,7 @@ impl Diagnostic {
             message: message.into(),
             fix: None,
             labels: Default::default(),
+            edits: None,
         }
     }
 
@@ -200,6 +282,27 @@ impl Diagnostic {
         self
     }
 
+    /// Sets the machine-applicable edits that fix the diagnostic.
+    ///
+    /// The spans of the edits are expected to be for the same file as the
+    /// diagnostic and must not overlap.
+    pub fn with_edits(
+        mut self,
+        applicability: Applicability,
+        edits: impl IntoIterator<Item = Edit>,
+    ) -> Self {
+        let edits: Box<[Edit]> = edits.into_iter().collect();
+        self.edits = if edits.is_empty() {
+            None
+        } else {
+            Some(Box::new(Edits {
+                edits,
+                applicability,
+            }))
+        };
+        self
+    }
+
     /// Adds a highlight to the diagnostic.
     ///
     /// This is equivalent to adding a label with an empty message.
@@ -250,6 +353,21 @@ impl Diagnostic {
         self.fix.as_deref()
     }
 
+    /// Gets the machine-applicable edits that fix the diagnostic.
+    pub fn edits(&self) -> &[Edit] {
+        self.edits
+            .as_ref()
+            .map(|e| e.edits.as_ref())
+            .unwrap_or_default()
+    }
+
+    /// Gets the applicability of the diagnostic's edits.
+    ///
+    /// Returns `None` if the diagnostic has no edits.
+    pub fn applicability(&self) -> Option<Applicability> {
+        self.edits.as_ref().map(|e| e.applicability)
+    }
+
     /// Gets the labels of the diagnostic.
     pub fn labels(&self) -> impl Iterator<Item = &Label> {
         self.labels.iter()
@@ -88,9 +88,9 @@ pub fn document(source: &str, mut parser: PreambleParser<'_>) -> (Vec<Event>, Ve
                     let version = &source[span.start()..span.end()];
 
                     match version.parse::<SupportedVersion>() {
-                        Ok(_) => {
+                        Ok(SupportedVersion::V1(version)) => {
                             let mut parser = parser.morph();
-                            v1::items(&mut parser);
+                            v1::items(&mut parser, version);
                             root.complete(&mut parser, SyntaxKind::RootNode);
                             let output = parser.finish();
                             return (output.events, output.diagnostics);
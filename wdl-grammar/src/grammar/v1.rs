@@ -24,6 +24,7 @@ use crate::parser::unterminated_braced_command;
 use crate::parser::unterminated_heredoc;
 use crate::parser::unterminated_string;
 use crate::tree::SyntaxKind;
+use crate::version::V1;
 
 /// The parser type for the V1 grammar.
 pub type Parser<'a> = parser::Parser<'a, Token>;
@@ -44,6 +45,22 @@ const TOP_EXPECTED_NAMES: &[&str] = &[
     "workflow definition",
 ];
 
+/// The names of the expected top-level items of a `development` document.
+const DEVELOPMENT_TOP_EXPECTED_NAMES: &[&str] = &[
+    "import statement",
+    "struct definition",
+    "enum definition",
+    "task definition",
+    "workflow definition",
+];
+
+/// The contextual keyword that starts an enum definition in a `development`
+/// document.
+///
+/// `enum` is lexed as an identifier so that it remains a valid name in
+/// released versions of WDL.
+const ENUM_KEYWORD: &str = "enum";
+
 /// The recovery set for top-level.
 const TOP_RECOVERY_SET: TokenSet = TOP_EXPECTED_SET;
 
@@ -73,6 +90,10 @@ const STRUCT_ITEM_RECOVERY_SET: TokenSet = TYPE_EXPECTED_SET.union(TokenSet::new
     Token::CloseBrace as u8,
 ]));
 
+/// The recovery set for enum variants.
+const ENUM_VARIANT_RECOVERY_SET: TokenSet =
+    TokenSet::new(&[Token::Ident as u8, Token::CloseBrace as u8]);
+
 /// The recovery set for input items.
 const INPUT_ITEM_RECOVERY_SET: TokenSet =
     TYPE_EXPECTED_SET.union(TokenSet::new(&[Token::CloseBrace as u8]));
@@ -417,12 +438,12 @@ macro_rules! paren {
 /// Parses the top-level items of a V1 document.
 ///
 /// It is expected that the version statement has already been parsed.
-pub fn items(parser: &mut Parser<'_>) {
+pub fn items(parser: &mut Parser<'_>, version: V1) {
     parser.push_recovery_set(TOP_RECOVERY_SET);
 
     while parser.peek().is_some() {
         let marker = parser.start();
-        if let Err((marker, e)) = item(parser, marker) {
+        if let Err((marker, e)) = item(parser, marker, version) {
             parser.recover(e);
             marker.abandon(parser);
         }
@@ -435,17 +456,27 @@ pub fn items(parser: &mut Parser<'_>) {
 }
 
 /// Parses a single top-level item in a WDL document.
-fn item(parser: &mut Parser<'_>, marker: Marker) -> Result<(), (Marker, Diagnostic)> {
+fn item(parser: &mut Parser<'_>, marker: Marker, version: V1) -> Result<(), (Marker, Diagnostic)> {
     match parser.peek() {
         Some((Token::ImportKeyword, _)) => import_statement(parser, marker),
         Some((Token::StructKeyword, _)) => struct_definition(parser, marker),
+        Some((Token::Ident, span))
+            if version >= V1::Development && parser.source(span) == ENUM_KEYWORD =>
+        {
+            enum_definition(parser, marker)
+        }
         Some((Token::TaskKeyword, _)) => task_definition(parser, marker),
         Some((Token::WorkflowKeyword, _)) => workflow_definition(parser, marker),
         found => {
             let (found, span) = found
                 .map(|(t, s)| (Some(t.describe()), s))
                 .unwrap_or_else(|| (None, parser.span()));
-            Err((marker, expected_one_of(TOP_EXPECTED_NAMES, found, span)))
+            let names = if version >= V1::Development {
+                DEVELOPMENT_TOP_EXPECTED_NAMES
+            } else {
+                TOP_EXPECTED_NAMES
+            };
+            Err((marker, expected_one_of(names, found, span)))
         }
     }
 }
@@ -515,6 +546,29 @@ fn struct_member_decl(parser: &mut Parser<'_>, marker: Marker) -> Result<(), (Ma
     Ok(())
 }
 
+/// Parses an enum definition.
+fn enum_definition(parser: &mut Parser<'_>, marker: Marker) -> Result<(), (Marker, Diagnostic)> {
+    parser.require(Token::Ident);
+    parser.update_last_token_kind(SyntaxKind::EnumKeyword);
+    expected!(parser, marker, Token::Ident, "enum name");
+    braced_items!(
+        parser,
+        marker,
+        Some(Token::Comma),
+        ENUM_VARIANT_RECOVERY_SET,
+        enum_variant
+    );
+    marker.complete(parser, SyntaxKind::EnumDefinitionNode);
+    Ok(())
+}
+
+/// Parses a variant in an enum definition.
+fn enum_variant(parser: &mut Parser<'_>, marker: Marker) -> Result<(), (Marker, Diagnostic)> {
+    expected!(parser, marker, Token::Ident, "enum variant name");
+    marker.complete(parser, SyntaxKind::EnumVariantNode);
+    Ok(())
+}
+
 /// Parses a task definition.
 fn task_definition(parser: &mut Parser<'_>, marker: Marker) -> Result<(), (Marker, Diagnostic)> {
     parser.require(Token::TaskKeyword);
@@ -1750,10 +1804,39 @@ fn conditional_statement(
         WORKFLOW_STATEMENT_RECOVERY_SET,
         workflow_statement
     );
+
+    while let Some((Token::ElseKeyword, _)) = parser.peek() {
+        expected_fn!(parser, marker, conditional_else_clause);
+    }
+
     marker.complete(parser, SyntaxKind::ConditionalStatementNode);
     Ok(())
 }
 
+/// Parses an `else if` or `else` clause of a conditional statement.
+fn conditional_else_clause(
+    parser: &mut Parser<'_>,
+    marker: Marker,
+) -> Result<(), (Marker, Diagnostic)> {
+    parser.require(Token::ElseKeyword);
+    if parser.next_if(Token::IfKeyword) {
+        paren!(parser, marker, |parser, _| {
+            expected_fn!(parser, expr);
+            Ok(())
+        });
+    }
+
+    braced_items!(
+        parser,
+        marker,
+        None,
+        WORKFLOW_STATEMENT_RECOVERY_SET,
+        workflow_statement
+    );
+    marker.complete(parser, SyntaxKind::ConditionalElseClauseNode);
+    Ok(())
+}
+
 /// Parses a scatter statement in a workflow.
 fn scatter_statement(parser: &mut Parser<'_>, marker: Marker) -> Result<(), (Marker, Diagnostic)> {
     parser.require(Token::ScatterKeyword);
@@ -132,6 +132,8 @@ pub enum SyntaxKind {
     HintsKeyword,
     /// The 1.2 `requirements` keyword token.
     RequirementsKeyword,
+    /// The development `enum` keyword token.
+    EnumKeyword,
     /// The `{` symbol token.
     OpenBrace,
     /// The `}` symbol token.
@@ -210,6 +212,10 @@ pub enum SyntaxKind {
     ImportAliasNode,
     /// Represents a struct definition node.
     StructDefinitionNode,
+    /// Represents an enum definition node.
+    EnumDefinitionNode,
+    /// Represents an enum variant node.
+    EnumVariantNode,
     /// Represents a task definition node.
     TaskDefinitionNode,
     /// Represents a workflow definition node.
@@ -362,6 +368,9 @@ pub enum SyntaxKind {
     PlaceholderTrueFalseOptionNode,
     /// Represents a conditional statement node.
     ConditionalStatementNode,
+    /// Represents an `else if` or `else` clause node in a conditional
+    /// statement.
+    ConditionalElseClauseNode,
     /// Represents a scatter statement node.
     ScatterStatementNode,
     /// Represents a call statement node.
@@ -446,6 +455,7 @@ impl SyntaxKind {
             Self::DirectoryTypeKeyword => "`Directory` type keyword",
             Self::HintsKeyword => "`hints` keyword",
             Self::RequirementsKeyword => "`requirements` keyword",
+            Self::EnumKeyword => "`enum` keyword",
             Self::OpenBrace => "`{` symbol",
             Self::CloseBrace => "`}` symbol",
             Self::OpenBracket => "`[` symbol",
@@ -481,6 +491,8 @@ impl SyntaxKind {
             Self::ImportStatementNode => "import statement",
             Self::ImportAliasNode => "import alias",
             Self::StructDefinitionNode => "struct definition",
+            Self::EnumDefinitionNode => "enum definition",
+            Self::EnumVariantNode => "enum variant",
             Self::TaskDefinitionNode => "task definition",
             Self::WorkflowDefinitionNode => "workflow definition",
             Self::UnboundDeclNode => "declaration without assignment",
@@ -555,6 +567,7 @@ impl SyntaxKind {
             Self::PlaceholderDefaultOptionNode => "placeholder `default` option",
             Self::PlaceholderTrueFalseOptionNode => "placeholder `true`/`false` option",
             Self::ConditionalStatementNode => "conditional statement",
+            Self::ConditionalElseClauseNode => "else clause",
             Self::ScatterStatementNode => "scatter statement",
             Self::CallStatementNode => "call statement",
             Self::CallTargetNode => "call target",
@@ -13,6 +13,12 @@ pub enum V1 {
     One,
     /// The document version is 1.2.
     Two,
+    /// The document version is `development`.
+    ///
+    /// The development version is an opt-in version that enables features of
+    /// the upcoming WDL specification; it is ordered after all released 1.x
+    /// versions.
+    Development,
 }
 
 impl std::fmt::Display for V1 {
@@ -21,6 +27,7 @@ impl std::fmt::Display for V1 {
             V1::Zero => write!(f, "1.0"),
             V1::One => write!(f, "1.1"),
             V1::Two => write!(f, "1.2"),
+            V1::Development => write!(f, "development"),
         }
     }
 }
@@ -50,6 +57,7 @@ impl FromStr for SupportedVersion {
             "1.0" => Ok(Self::V1(V1::Zero)),
             "1.1" => Ok(Self::V1(V1::One)),
             "1.2" => Ok(Self::V1(V1::Two)),
+            "development" => Ok(Self::V1(V1::Development)),
             _ => Err(()),
         }
     }
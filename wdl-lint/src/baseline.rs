@@ -0,0 +1,417 @@
+//! Baselines of existing lint diagnostics.
+//!
+//! A baseline records the diagnostics present in a set of documents so that
+//! lint rules can be adopted incrementally: when linting with a baseline, only
+//! diagnostics that are not in the baseline are reported.
+//!
+//! Diagnostics are identified by their rule, the path of their document, and a
+//! fingerprint of their message and the normalized source of the lines they
+//! highlight. The fingerprint does not include line numbers, so baseline
+//! entries remain valid when unrelated lines are added or removed.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+use wdl_analysis::Document;
+use wdl_ast::AstNode;
+use wdl_ast::Diagnostic;
+
+/// The default file name of a lint baseline.
+pub const BASELINE_FILE_NAME: &str = "wdl-lint-baseline.json";
+
+/// The current version of the baseline format.
+const BASELINE_VERSION: u32 = 1;
+
+/// The number of hexadecimal digits of a fingerprint.
+const FINGERPRINT_LEN: usize = 16;
+
+/// Calculates the fingerprint of a diagnostic.
+///
+/// The fingerprint is a hash of the diagnostic's rule, its message, and the
+/// lines of the source highlighted by its first label with whitespace
+/// normalized.
+fn fingerprint(rule: &str, diagnostic: &Diagnostic, source: &str) -> String {
+    let snippet = diagnostic
+        .labels()
+        .next()
+        .map(|label| {
+            let span = label.span();
+            let start = source[..span.start().min(source.len())]
+                .rfind('\n')
+                .map(|i| i + 1)
+                .unwrap_or(0);
+            let end = source[span.end().min(source.len())..]
+                .find('\n')
+                .map(|i| span.end() + i)
+                .unwrap_or(source.len());
+            source[start..end.max(start)]
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .unwrap_or_default();
+
+    let mut hasher = Sha256::new();
+    hasher.update(format!("rule:{rule}\n"));
+    hasher.update(format!(
+        "message:{message}\n",
+        message = diagnostic.message()
+    ));
+    hasher.update(format!("snippet:{snippet}\n"));
+    let mut fingerprint = format!("{hash:x}", hash = hasher.finalize());
+    fingerprint.truncate(FINGERPRINT_LEN);
+    fingerprint
+}
+
+/// Gets the path of a document to record in a baseline.
+///
+/// The path is relative to the given root directory and uses `/` as the
+/// separator; documents that are not files within the root directory are
+/// identified by their URI.
+fn document_path(root: &Path, document: &Document) -> String {
+    document
+        .uri()
+        .to_file_path()
+        .ok()
+        .and_then(|path| {
+            path.strip_prefix(root).ok().map(|p| {
+                p.components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/")
+            })
+        })
+        .unwrap_or_else(|| document.uri().to_string())
+}
+
+/// Represents an entry in a lint baseline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BaselineEntry {
+    /// The path of the document containing the diagnostics.
+    pub path: String,
+    /// The rule of the diagnostics.
+    pub rule: String,
+    /// The fingerprint of the diagnostics.
+    pub fingerprint: String,
+    /// The message of the diagnostics.
+    ///
+    /// The message is recorded to make the baseline readable; it is not used
+    /// to match diagnostics.
+    pub message: String,
+    /// The number of diagnostics with the fingerprint.
+    pub count: usize,
+}
+
+/// Represents a baseline of lint diagnostics.
+///
+/// The baseline is serialized as JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Baseline {
+    /// The version of the baseline format.
+    version: u32,
+    /// The entries of the baseline, sorted by path, rule, and fingerprint.
+    entries: Vec<BaselineEntry>,
+}
+
+impl Default for Baseline {
+    fn default() -> Self {
+        Self {
+            version: BASELINE_VERSION,
+            entries: Default::default(),
+        }
+    }
+}
+
+impl Baseline {
+    /// Reads a baseline from the given path.
+    pub fn read(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).with_context(|| {
+            format!(
+                "failed to read lint baseline `{path}`",
+                path = path.display()
+            )
+        })?;
+
+        let baseline: Self = serde_json::from_str(&contents).with_context(|| {
+            format!(
+                "failed to deserialize lint baseline `{path}`",
+                path = path.display()
+            )
+        })?;
+
+        if baseline.version != BASELINE_VERSION {
+            bail!(
+                "lint baseline `{path}` has unsupported version {version}",
+                path = path.display(),
+                version = baseline.version
+            );
+        }
+
+        Ok(baseline)
+    }
+
+    /// Writes the baseline to the given path.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut contents =
+            serde_json::to_string_pretty(self).context("failed to serialize lint baseline")?;
+        contents.push('\n');
+        fs::write(path, contents).with_context(|| {
+            format!(
+                "failed to write lint baseline `{path}`",
+                path = path.display()
+            )
+        })
+    }
+
+    /// Gets the entries of the baseline.
+    pub fn entries(&self) -> &[BaselineEntry] {
+        &self.entries
+    }
+
+    /// Finds the index of the entry with the given path, rule, and
+    /// fingerprint.
+    ///
+    /// If the entry is not found, the index at which it would be inserted is
+    /// returned as the error.
+    fn find(&self, path: &str, rule: &str, fingerprint: &str) -> Result<usize, usize> {
+        self.entries.binary_search_by(|e| {
+            (e.path.as_str(), e.rule.as_str(), e.fingerprint.as_str()).cmp(&(
+                path,
+                rule,
+                fingerprint,
+            ))
+        })
+    }
+
+    /// Adds the diagnostics of a document to the baseline.
+    ///
+    /// Only diagnostics with a rule are recorded.
+    pub fn add(&mut self, path: &str, source: &str, diagnostics: &[Diagnostic]) {
+        for diagnostic in diagnostics {
+            let Some(rule) = diagnostic.rule() else {
+                continue;
+            };
+
+            let fingerprint = fingerprint(rule, diagnostic, source);
+            match self.find(path, rule, &fingerprint) {
+                Ok(index) => self.entries[index].count += 1,
+                Err(index) => self.entries.insert(
+                    index,
+                    BaselineEntry {
+                        path: path.to_string(),
+                        rule: rule.to_string(),
+                        fingerprint,
+                        message: diagnostic.message().to_string(),
+                        count: 1,
+                    },
+                ),
+            }
+        }
+    }
+
+    /// Adds the diagnostics of an analyzed document to the baseline.
+    ///
+    /// The document's path is recorded relative to the given root directory.
+    pub fn add_document(&mut self, root: impl AsRef<Path>, document: &Document) {
+        self.add(
+            &document_path(root.as_ref(), document),
+            &document.root().text().to_string(),
+            document.diagnostics(),
+        );
+    }
+
+    /// Starts checking diagnostics against the baseline.
+    pub fn check(&self) -> BaselineCheck<'_> {
+        BaselineCheck {
+            baseline: self,
+            remaining: self.entries.iter().map(|e| e.count).collect(),
+        }
+    }
+}
+
+/// Checks the diagnostics of documents against a baseline.
+///
+/// Each diagnostic in the baseline is matched at most once; the baseline
+/// entries that remain unmatched once all documents have been checked are
+/// stale.
+#[derive(Debug)]
+pub struct BaselineCheck<'a> {
+    /// The baseline being checked against.
+    baseline: &'a Baseline,
+    /// The number of unmatched diagnostics for each baseline entry.
+    remaining: Vec<usize>,
+}
+
+impl BaselineCheck<'_> {
+    /// Filters the diagnostics of a document, returning only the diagnostics
+    /// that are not in the baseline.
+    ///
+    /// Diagnostics without a rule are always returned.
+    pub fn filter(
+        &mut self,
+        path: &str,
+        source: &str,
+        diagnostics: impl IntoIterator<Item = Diagnostic>,
+    ) -> Vec<Diagnostic> {
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| {
+                let Some(rule) = diagnostic.rule() else {
+                    return true;
+                };
+
+                let fingerprint = fingerprint(rule, diagnostic, source);
+                match self.baseline.find(path, rule, &fingerprint) {
+                    Ok(index) if self.remaining[index] > 0 => {
+                        self.remaining[index] -= 1;
+                        false
+                    }
+                    _ => true,
+                }
+            })
+            .collect()
+    }
+
+    /// Filters the diagnostics of an analyzed document, returning only the
+    /// diagnostics that are not in the baseline.
+    ///
+    /// The document's path is relative to the given root directory.
+    pub fn filter_document(
+        &mut self,
+        root: impl AsRef<Path>,
+        document: &Document,
+    ) -> Vec<Diagnostic> {
+        self.filter(
+            &document_path(root.as_ref(), document),
+            &document.root().text().to_string(),
+            document.diagnostics().iter().cloned(),
+        )
+    }
+
+    /// Finishes the check and returns the stale baseline entries.
+    ///
+    /// The count of each stale entry is the number of its diagnostics that
+    /// were not matched.
+    ///
+    /// Entries for documents that were not checked are also stale.
+    pub fn stale(self) -> Vec<BaselineEntry> {
+        self.baseline
+            .entries
+            .iter()
+            .zip(self.remaining)
+            .filter(|(_, count)| *count > 0)
+            .map(|(e, count)| BaselineEntry { count, ..e.clone() })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use wdl_ast::Span;
+
+    use super::*;
+
+    /// Creates a diagnostic for the given rule highlighting the first
+    /// occurrence of the given text in the source.
+    fn diagnostic(rule: &'static str, source: &str, text: &str) -> Diagnostic {
+        let start = source.find(text).expect("text should be in source");
+        Diagnostic::note(format!("{rule} diagnostic"))
+            .with_rule(rule)
+            .with_highlight(Span::new(start, text.len()))
+    }
+
+    #[test]
+    fn it_filters_baselined_diagnostics() {
+        let source = "version 1.1\n\ntask foo {\n    command <<<>>>\n}\n\ntask bar {}\n";
+        let mut baseline = Baseline::default();
+        baseline.add(
+            "foo.wdl",
+            source,
+            &[
+                diagnostic("A", source, "task foo"),
+                diagnostic("B", source, "task bar"),
+                Diagnostic::error("not a lint diagnostic"),
+            ],
+        );
+        assert_eq!(baseline.entries().len(), 2);
+
+        // Shift every line and reformat the `foo` task's line
+        let shifted = "version 1.1\n\n# a new comment\n\ntask   foo {\n    command <<<>>>\n}\n\ntask bar {}\n";
+        let mut check = baseline.check();
+        let new = check.filter(
+            "foo.wdl",
+            shifted,
+            [
+                diagnostic("A", shifted, "task   foo"),
+                diagnostic("C", shifted, "task bar"),
+                Diagnostic::error("not a lint diagnostic"),
+            ],
+        );
+
+        // The diagnostic for `A` is the same despite the line shift, whereas the
+        // diagnostic for `C` is new and the one for `B` is now stale
+        assert_eq!(
+            new.iter().map(|d| d.message()).collect::<Vec<_>>(),
+            ["C diagnostic", "not a lint diagnostic"]
+        );
+        let stale = check.stale();
+        assert_eq!(stale.len(), 1);
+        assert_eq!(stale[0].rule, "B");
+        assert_eq!(stale[0].count, 1);
+    }
+
+    #[test]
+    fn it_counts_duplicate_diagnostics() {
+        let source = "version 1.1\nworkflow w {}\n";
+        let mut baseline = Baseline::default();
+        let d = diagnostic("A", source, "workflow");
+        baseline.add("w.wdl", source, &[d.clone(), d.clone()]);
+        assert_eq!(baseline.entries().len(), 1);
+        assert_eq!(baseline.entries()[0].count, 2);
+
+        let mut check = baseline.check();
+        assert_eq!(
+            check
+                .filter("w.wdl", source, [d.clone(), d.clone(), d.clone()])
+                .len(),
+            1
+        );
+        assert!(check.stale().is_empty());
+
+        // Diagnostics in other documents don't match the baseline
+        let mut check = baseline.check();
+        assert_eq!(check.filter("other.wdl", source, [d.clone()]).len(), 1);
+        assert_eq!(check.stale()[0].count, 2);
+    }
+
+    #[test]
+    fn it_round_trips() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = dir.path().join(BASELINE_FILE_NAME);
+        let source = "version 1.1\nworkflow w {}\n";
+        let mut baseline = Baseline::default();
+        baseline.add("w.wdl", source, &[diagnostic("A", source, "workflow")]);
+        baseline.write(&path).unwrap();
+        assert_eq!(Baseline::read(&path).unwrap(), baseline);
+
+        fs::write(&path, r#"{"version": 0, "entries": []}"#).unwrap();
+        assert!(
+            Baseline::read(&path)
+                .unwrap_err()
+                .to_string()
+                .contains("unsupported version 0")
+        );
+    }
+}
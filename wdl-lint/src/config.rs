@@ -0,0 +1,611 @@
+//! Configuration of lint rules.
+//!
+//! A lint configuration is typically loaded from a `wdl-lint.toml` file at the
+//! root of a project:
+//!
+//! ```toml
+//! [rules.LineWidth]
+//! max_width = 120
+//!
+//! [rules.SnakeCase]
+//! severity = "error"
+//!
+//! [rules.ShellCheck]
+//! severity = "off"
+//!
+//! [[overrides]]
+//! paths = ["legacy/**/*.wdl"]
+//!
+//! [overrides.rules.LineWidth]
+//! severity = "off"
+//! ```
+//!
+//! Each entry in the `rules` table is keyed by the identifier of a lint rule.
+//! The optional `severity` key remaps the severity of the diagnostics produced
+//! by the rule (or disables the rule with `off`); all other keys are options
+//! specific to the rule.
+//!
+//! Overrides apply to documents with a path, relative to the directory
+//! containing the configuration file, that matches any of the given glob
+//! patterns. Matching overrides are applied in order on top of the top-level
+//! rule configuration.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use convert_case::Boundary;
+use convert_case::Case;
+use convert_case::Converter;
+use glob::Pattern;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use wdl_ast::Severity;
+
+use crate::Rule;
+use crate::find_nearest_rule;
+use crate::rules;
+
+/// The name of the file a lint configuration is discovered from.
+pub const CONFIG_FILE_NAME: &str = "wdl-lint.toml";
+
+/// Represents the case a name is expected to be in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NamingCase {
+    /// Names are expected to be `snake_case`.
+    #[serde(rename = "snake_case")]
+    Snake,
+    /// Names are expected to be `camelCase`.
+    #[serde(rename = "camelCase")]
+    Camel,
+    /// Names are expected to be `PascalCase`.
+    #[serde(rename = "PascalCase")]
+    Pascal,
+    /// Names are expected to be `SCREAMING_SNAKE_CASE`.
+    #[serde(rename = "SCREAMING_SNAKE_CASE")]
+    ScreamingSnake,
+}
+
+impl NamingCase {
+    /// Converts the given name to this case.
+    pub fn convert(&self, name: &str) -> String {
+        let case = match self {
+            Self::Snake => Case::Snake,
+            Self::Camel => Case::Camel,
+            Self::Pascal => Case::Pascal,
+            Self::ScreamingSnake => Case::UpperSnake,
+        };
+
+        Converter::new()
+            .remove_boundaries(&[Boundary::DIGIT_LOWER, Boundary::LOWER_DIGIT])
+            .to_case(case)
+            .convert(name)
+    }
+}
+
+impl fmt::Display for NamingCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Snake => write!(f, "snake_case"),
+            Self::Camel => write!(f, "camelCase"),
+            Self::Pascal => write!(f, "PascalCase"),
+            Self::ScreamingSnake => write!(f, "SCREAMING_SNAKE_CASE"),
+        }
+    }
+}
+
+/// Represents the configured severity of a lint rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSeverity {
+    /// The rule is disabled.
+    Off,
+    /// The rule's diagnostics are reported as notes.
+    Note,
+    /// The rule's diagnostics are reported as warnings.
+    Warning,
+    /// The rule's diagnostics are reported as errors.
+    Error,
+}
+
+impl RuleSeverity {
+    /// Gets the diagnostic severity to use for the rule.
+    ///
+    /// Returns `None` if the rule is disabled.
+    pub fn severity(&self) -> Option<Severity> {
+        match self {
+            Self::Off => None,
+            Self::Note => Some(Severity::Note),
+            Self::Warning => Some(Severity::Warning),
+            Self::Error => Some(Severity::Error),
+        }
+    }
+}
+
+/// Represents the configuration of a single lint rule.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuleConfig {
+    /// The severity of the rule.
+    ///
+    /// If `None`, the rule reports diagnostics with their default severity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<RuleSeverity>,
+    /// The rule-specific options.
+    #[serde(flatten)]
+    pub options: toml::Table,
+}
+
+impl RuleConfig {
+    /// Merges another rule configuration on top of this one.
+    fn merge(&mut self, other: &RuleConfig) {
+        if other.severity.is_some() {
+            self.severity = other.severity;
+        }
+
+        for (key, value) in &other.options {
+            self.options.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+/// Represents a lint configuration override for a set of paths.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Override {
+    /// The glob patterns of the paths the override applies to.
+    pub paths: Vec<String>,
+    /// The rule configuration of the override.
+    #[serde(default)]
+    pub rules: IndexMap<String, RuleConfig>,
+    /// The compiled glob patterns.
+    #[serde(skip)]
+    patterns: Vec<Pattern>,
+}
+
+impl Override {
+    /// Determines if the override applies to the given relative path.
+    fn matches(&self, path: &Path) -> bool {
+        self.patterns.iter().any(|p| p.matches_path(path))
+    }
+}
+
+/// Represents the contents of a lint configuration file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    /// The configuration of the lint rules, keyed by rule identifier.
+    #[serde(default)]
+    rules: IndexMap<String, RuleConfig>,
+    /// The path-specific overrides of the configuration.
+    #[serde(default)]
+    overrides: Vec<Override>,
+}
+
+/// Represents a validated lint configuration.
+///
+/// Use [`Linter::with_config`](crate::Linter::with_config) to lint documents
+/// with a configuration.
+#[derive(Clone, Debug, Default)]
+pub struct LintConfig {
+    /// The configuration of the lint rules, keyed by rule identifier.
+    rules: IndexMap<String, RuleConfig>,
+    /// The path-specific overrides of the configuration.
+    overrides: Vec<Override>,
+    /// The directory that override paths are relative to.
+    root: PathBuf,
+    /// The identifiers of the rules that are disabled regardless of the
+    /// configuration.
+    disabled: BTreeSet<String>,
+}
+
+impl LintConfig {
+    /// Parses a lint configuration from the given TOML source.
+    ///
+    /// Override paths are relative to the given root directory.
+    ///
+    /// Returns an error if the configuration is malformed or references
+    /// unknown rules or options.
+    pub fn parse(source: &str, root: impl AsRef<Path>) -> Result<Self> {
+        let root = root.as_ref();
+        let file: ConfigFile = toml::from_str(source).context("invalid lint configuration")?;
+        let mut config = Self {
+            rules: file.rules,
+            overrides: file.overrides,
+            root: std::path::absolute(root).with_context(|| {
+                format!(
+                    "failed to determine absolute path of `{root}`",
+                    root = root.display()
+                )
+            })?,
+            disabled: Default::default(),
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Loads a lint configuration from the given file.
+    ///
+    /// Override paths are relative to the directory containing the file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).with_context(|| {
+            format!(
+                "failed to read lint configuration `{path}`",
+                path = path.display()
+            )
+        })?;
+
+        let root = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        Self::parse(&source, root).with_context(|| {
+            format!(
+                "failed to load lint configuration `{path}`",
+                path = path.display()
+            )
+        })
+    }
+
+    /// Discovers the lint configuration for the given directory.
+    ///
+    /// The directory and each of its ancestors is searched for a
+    /// [`CONFIG_FILE_NAME`] file; the first one found is loaded.
+    ///
+    /// Returns `Ok(None)` if no configuration file was found.
+    pub fn discover(dir: impl AsRef<Path>) -> Result<Option<Self>> {
+        for dir in dir.as_ref().ancestors() {
+            let path = dir.join(CONFIG_FILE_NAME);
+            if path.is_file() {
+                return Self::from_file(path).map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Disables the lint rule with the given identifier.
+    ///
+    /// The rule is disabled for every document regardless of the severity it
+    /// is configured with, including by overrides.
+    pub fn disable_rule(&mut self, id: impl Into<String>) {
+        self.disabled.insert(id.into());
+    }
+
+    /// Gets the top-level rule configuration.
+    pub fn rules(&self) -> &IndexMap<String, RuleConfig> {
+        &self.rules
+    }
+
+    /// Gets the path-specific overrides.
+    pub fn overrides(&self) -> &[Override] {
+        &self.overrides
+    }
+
+    /// Gets the directory that override paths are relative to.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Gets the indexes of the overrides that apply to the given document path.
+    ///
+    /// If `path` is `None` or is not within the configuration root, no
+    /// overrides apply.
+    pub(crate) fn matching_overrides(&self, path: Option<&Path>) -> Vec<usize> {
+        let Some(relative) = path.and_then(|p| p.strip_prefix(&self.root).ok()) else {
+            return Vec::new();
+        };
+
+        self.overrides
+            .iter()
+            .enumerate()
+            .filter(|(_, o)| o.matches(relative))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Constructs the configured lint rules with the given overrides applied.
+    ///
+    /// Disabled rules are omitted; each rule is paired with the severity its
+    /// diagnostics should be remapped to.
+    pub(crate) fn configured_rules(
+        &self,
+        overrides: &[usize],
+    ) -> Vec<(Box<dyn Rule>, Option<Severity>)> {
+        let mut effective = self.rules.clone();
+        for index in overrides {
+            for (id, config) in &self.overrides[*index].rules {
+                effective.entry(id.clone()).or_default().merge(config);
+            }
+        }
+
+        rules()
+            .into_iter()
+            .filter_map(|rule| {
+                if self.disabled.contains(rule.id()) {
+                    return None;
+                }
+
+                let Some(config) = effective.get(rule.id()) else {
+                    return Some((rule, None));
+                };
+
+                // A rule with a severity of `off` is omitted
+                let severity = match config.severity {
+                    Some(severity) => Some(severity.severity()?),
+                    None => None,
+                };
+
+                let mut rule = rule;
+                rule.configure(&config.options)
+                    .expect("rule options should have been validated");
+                Some((rule, severity))
+            })
+            .collect()
+    }
+
+    /// Validates the configuration and compiles the override patterns.
+    fn validate(&mut self) -> Result<()> {
+        let mut known = rules();
+
+        validate_rules(&mut known, &self.rules, "rules")?;
+        for (i, o) in self.overrides.iter_mut().enumerate() {
+            if o.paths.is_empty() {
+                bail!("override {i} must specify at least one path", i = i + 1);
+            }
+
+            o.patterns = o
+                .paths
+                .iter()
+                .map(|p| {
+                    Pattern::new(p).with_context(|| {
+                        format!("invalid path pattern `{p}` in override {i}", i = i + 1)
+                    })
+                })
+                .collect::<Result<_>>()?;
+
+            validate_rules(&mut known, &o.rules, &format!("override {i}", i = i + 1))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Validates the given rule configuration.
+///
+/// The options of each configured rule are validated by configuring the
+/// matching rule of the given known rules.
+///
+/// The `context` is used to describe where the configuration came from in
+/// errors.
+fn validate_rules(
+    known: &mut [Box<dyn Rule>],
+    rules: &IndexMap<String, RuleConfig>,
+    context: &str,
+) -> Result<()> {
+    for (id, config) in rules {
+        let Some(rule) = known.iter_mut().find(|r| r.id() == id) else {
+            match find_nearest_rule(id).filter(|r| known.iter().any(|k| k.id() == *r)) {
+                Some(nearest) => {
+                    bail!("unknown lint rule `{id}` in {context}; did you mean `{nearest}`?")
+                }
+                None => bail!("unknown lint rule `{id}` in {context}"),
+            }
+        };
+
+        rule.configure(&config.options)
+            .with_context(|| format!("in {context}"))?;
+    }
+
+    Ok(())
+}
+
+/// Parses the options of a lint rule.
+pub(crate) fn parse_options<T: DeserializeOwned>(id: &str, options: &toml::Table) -> Result<T> {
+    toml::Value::Table(options.clone())
+        .try_into()
+        .with_context(|| format!("invalid options for lint rule `{id}`"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_eq;
+    use wdl_analysis::Analyzer;
+    use wdl_analysis::DiagnosticsConfig;
+    use wdl_analysis::Validator;
+
+    use super::*;
+    use crate::Linter;
+
+    /// Parses the given configuration source and returns the error message.
+    fn parse_error(source: &str) -> String {
+        format!(
+            "{e:#}",
+            e = LintConfig::parse(source, ".").expect_err("configuration should be invalid")
+        )
+    }
+
+    #[test]
+    fn it_rejects_invalid_configuration() {
+        assert_eq!(
+            parse_error("[rules.LineWdth]\nmax_width = 100\n"),
+            "unknown lint rule `LineWdth` in rules; did you mean `LineWidth`?"
+        );
+
+        let error = parse_error("[rules.LineWidth]\nmax_widht = 100\n");
+        assert!(
+            error.starts_with(
+                "in rules: invalid options for lint rule `LineWidth`: unknown field `max_widht`, \
+                 expected `max_width`"
+            ),
+            "unexpected error: {error}"
+        );
+
+        assert_eq!(
+            parse_error("[rules.DoubleQuotes]\nquote = \"single\"\n"),
+            "in rules: lint rule `DoubleQuotes` does not accept options, but option `quote` was \
+             given"
+        );
+
+        let error = parse_error("[rules.ContainerPolicy]\nallowed_repositories = [\"[\"]\n");
+        assert!(
+            error.starts_with(
+                "in rules: invalid options for lint rule `ContainerPolicy`: invalid repository \
+                 pattern `[`"
+            ),
+            "unexpected error: {error}"
+        );
+
+        let error = parse_error("[rules.ResourceBounds]\nmax_memory = \"lots\"\n");
+        assert!(
+            error.starts_with(
+                "in rules: invalid options for lint rule `ResourceBounds`: invalid `max_memory` \
+                 value `lots`"
+            ),
+            "unexpected error: {error}"
+        );
+
+        let error = parse_error("[rules.SnakeCase]\ncase = \"kebab-case\"\n");
+        assert!(
+            error.contains("unknown variant `kebab-case`"),
+            "unexpected error: {error}"
+        );
+
+        let error = parse_error("[rules.SnakeCase]\nseverity = \"fatal\"\n");
+        assert!(
+            error.starts_with("invalid lint configuration"),
+            "unexpected error: {error}"
+        );
+
+        assert_eq!(
+            parse_error("[[overrides]]\npaths = []\n"),
+            "override 1 must specify at least one path"
+        );
+
+        assert_eq!(
+            parse_error("[[overrides]]\npaths = [\"a\"]\n[overrides.rules.Foo]\n"),
+            "unknown lint rule `Foo` in override 1"
+        );
+    }
+
+    #[test]
+    fn it_disables_rules() {
+        let mut config = LintConfig::parse(
+            "[rules.SnakeCase]\nseverity = \"error\"\n\n[[overrides]]\npaths = [\"**\"]\n\n[overrides.rules.SnakeCase]\nseverity = \"warning\"\n",
+            ".",
+        )
+        .expect("configuration should be valid");
+        let enabled = |config: &LintConfig| {
+            config
+                .configured_rules(&[0])
+                .iter()
+                .any(|(r, _)| r.id() == "SnakeCase")
+        };
+
+        assert!(enabled(&config));
+        config.disable_rule("SnakeCase");
+        assert!(!enabled(&config));
+    }
+
+    #[tokio::test]
+    async fn it_lints_with_configuration() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        fs::write(
+            dir.path().join(CONFIG_FILE_NAME),
+            r#"
+[rules.SnakeCase]
+case = "camelCase"
+severity = "error"
+
+[rules.LineWidth]
+max_width = 40
+
+[[overrides]]
+paths = ["legacy/**"]
+
+[overrides.rules.LineWidth]
+severity = "off"
+
+[overrides.rules.SnakeCase]
+case = "snake_case"
+"#,
+        )
+        .unwrap();
+
+        let source = r#"#@ except: MetaSections, OutputSection, DeclarationName
+version 1.1
+
+task my_task {
+    command <<<>>>
+
+    output {
+        String a_very_long_output_name = "a very long string literal"
+    }
+}
+"#;
+        fs::write(dir.path().join("source.wdl"), source).unwrap();
+        fs::create_dir(dir.path().join("legacy")).unwrap();
+        fs::write(dir.path().join("legacy").join("source.wdl"), source).unwrap();
+
+        let config = Arc::new(
+            LintConfig::discover(dir.path().join("legacy"))
+                .unwrap()
+                .expect("should discover a configuration"),
+        );
+
+        let analyzer = Analyzer::new_with_validator(
+            DiagnosticsConfig::except_all(),
+            |_, _, _, _| async {},
+            move || {
+                let mut validator = Validator::default();
+                validator.add_visitor(Linter::with_config(config.clone()));
+                validator
+            },
+        );
+        analyzer
+            .add_directory(dir.path().to_path_buf())
+            .await
+            .unwrap();
+        let results = analyzer.analyze(()).await.unwrap();
+        assert_eq!(results.len(), 2);
+
+        let diagnostics = |path: &Path| {
+            let result = results
+                .iter()
+                .find(|r| r.document().uri().to_file_path().unwrap() == path)
+                .expect("should have result");
+            let mut diagnostics = result
+                .document()
+                .diagnostics()
+                .iter()
+                .filter(|d| matches!(d.rule(), Some("SnakeCase") | Some("LineWidth")))
+                .map(|d| format!("{:?}: {}", d.severity(), d.message()))
+                .collect::<Vec<_>>();
+            diagnostics.sort();
+            diagnostics
+        };
+
+        assert_eq!(
+            diagnostics(&dir.path().join("source.wdl")),
+            [
+                "Error: output name `a_very_long_output_name` is not camelCase",
+                "Error: task name `my_task` is not camelCase",
+                "Note: line exceeds maximum width of 40",
+                "Note: line exceeds maximum width of 40",
+            ]
+        );
+        assert_eq!(
+            diagnostics(&dir.path().join("legacy").join("source.wdl")),
+            Vec::<String>::new()
+        );
+    }
+}
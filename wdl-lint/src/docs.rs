@@ -0,0 +1,378 @@
+//! Generation of rule documentation.
+//!
+//! The documentation of a rule is generated from the metadata the rule
+//! declares: its description, explanation, tags, related rules, and the
+//! examples of documents that violate and conform to the rule. Documentation
+//! is generated for both the lint rules of this crate and the analysis rules of
+//! `wdl-analysis`.
+
+use std::fmt;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use anyhow::Result;
+
+use crate::Rule;
+use crate::TagSet;
+
+/// The preamble of the generated `RULES.md` file.
+const RULES_PREAMBLE: &str = "# Rules
+
+These tables document all `wdl` lint and analysis rules implemented on the
+`main` branch of the `stjude-rust-labs/wdl` repository. Note that the
+information may be out of sync with released packages.
+";
+
+/// Represents the kind of a documented rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleKind {
+    /// The rule is a lint rule.
+    Lint,
+    /// The rule is an analysis rule.
+    Analysis,
+}
+
+impl fmt::Display for RuleKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Lint => write!(f, "lint"),
+            Self::Analysis => write!(f, "analysis"),
+        }
+    }
+}
+
+/// Represents the documentation of a rule.
+#[derive(Debug, Clone)]
+pub struct RuleDocumentation {
+    /// The identifier of the rule.
+    id: &'static str,
+    /// The kind of the rule.
+    kind: RuleKind,
+    /// The short description of the rule.
+    description: &'static str,
+    /// The explanation of the rule, with the indentation of its lines removed.
+    explanation: String,
+    /// The tags of the rule.
+    ///
+    /// This is `None` for analysis rules.
+    tags: Option<TagSet>,
+    /// The URL of the rule.
+    url: Option<&'static str>,
+    /// The identifiers of the rules related to the rule.
+    related_rules: Vec<&'static str>,
+    /// The examples of documents that violate the rule.
+    bad_examples: Vec<&'static str>,
+    /// The examples of documents that conform to the rule.
+    good_examples: Vec<&'static str>,
+    /// The options with which the examples are linted.
+    example_options: Option<&'static str>,
+}
+
+impl RuleDocumentation {
+    /// Creates the documentation of a lint rule.
+    pub fn from_lint_rule(rule: &dyn Rule) -> Self {
+        Self {
+            id: rule.id(),
+            kind: RuleKind::Lint,
+            description: rule.description(),
+            explanation: normalize_explanation(rule.explanation()),
+            tags: Some(rule.tags()),
+            url: rule.url(),
+            related_rules: rule.related_rules().to_vec(),
+            bad_examples: rule.bad_examples().to_vec(),
+            good_examples: rule.good_examples().to_vec(),
+            example_options: rule.example_options(),
+        }
+    }
+
+    /// Creates the documentation of an analysis rule.
+    pub fn from_analysis_rule(rule: &dyn wdl_analysis::Rule) -> Self {
+        Self {
+            id: rule.id(),
+            kind: RuleKind::Analysis,
+            description: rule.description(),
+            explanation: normalize_explanation(rule.explanation()),
+            tags: None,
+            url: None,
+            related_rules: Vec::new(),
+            bad_examples: rule.bad_examples().to_vec(),
+            good_examples: rule.good_examples().to_vec(),
+            example_options: None,
+        }
+    }
+
+    /// Gets the identifier of the rule.
+    pub fn id(&self) -> &'static str {
+        self.id
+    }
+
+    /// Gets the kind of the rule.
+    pub fn kind(&self) -> RuleKind {
+        self.kind
+    }
+
+    /// Gets the short description of the rule.
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Gets the explanation of the rule as Markdown.
+    pub fn explanation(&self) -> &str {
+        &self.explanation
+    }
+
+    /// Gets the names of the tags of the rule, sorted alphabetically.
+    ///
+    /// Analysis rules have no tags.
+    pub fn tags(&self) -> Vec<String> {
+        let mut tags = self
+            .tags
+            .iter()
+            .flat_map(|t| t.iter())
+            .map(|t| t.to_string())
+            .collect::<Vec<_>>();
+        tags.sort();
+        tags
+    }
+
+    /// Gets the URL of the rule.
+    pub fn url(&self) -> Option<&'static str> {
+        self.url
+    }
+
+    /// Gets the identifiers of the rules related to the rule.
+    pub fn related_rules(&self) -> &[&'static str] {
+        &self.related_rules
+    }
+
+    /// Gets the examples of documents that violate the rule.
+    pub fn bad_examples(&self) -> &[&'static str] {
+        &self.bad_examples
+    }
+
+    /// Gets the examples of documents that conform to the rule.
+    pub fn good_examples(&self) -> &[&'static str] {
+        &self.good_examples
+    }
+
+    /// Gets the options, as the contents of a TOML table, with which the
+    /// examples of the rule are linted.
+    pub fn example_options(&self) -> Option<&'static str> {
+        self.example_options
+    }
+
+    /// Renders the documentation of the rule as a Markdown page.
+    ///
+    /// Related rules link to the pages of the rules in the same directory
+    /// (e.g. `ShellCheck.md`).
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        writeln!(markdown, "# `{id}`\n", id = self.id).unwrap();
+        writeln!(markdown, "{description}\n", description = self.description).unwrap();
+        write!(markdown, "* **Kind:** {kind}", kind = self.kind).unwrap();
+        let tags = self.tags();
+        if !tags.is_empty() {
+            write!(markdown, "\n* **Tags:** {tags}", tags = tags.join(", ")).unwrap();
+        }
+
+        if let Some(url) = self.url {
+            write!(markdown, "\n* **More information:** <{url}>").unwrap();
+        }
+
+        writeln!(markdown, "\n\n## Explanation\n").unwrap();
+        writeln!(markdown, "{explanation}", explanation = self.explanation).unwrap();
+
+        if let Some(options) = self.example_options {
+            writeln!(
+                markdown,
+                "\n## Options\n\nThe examples below are linted with the following options:\n\n\
+                 ```toml\n[rules.{id}]\n{options}\n```",
+                id = self.id,
+                options = options.trim()
+            )
+            .unwrap();
+        }
+
+        for (heading, examples) in [
+            ("Bad examples", &self.bad_examples),
+            ("Good examples", &self.good_examples),
+        ] {
+            if examples.is_empty() {
+                continue;
+            }
+
+            writeln!(markdown, "\n## {heading}").unwrap();
+            for example in examples {
+                writeln!(
+                    markdown,
+                    "\n```wdl\n{example}\n```",
+                    example = example.trim()
+                )
+                .unwrap();
+            }
+        }
+
+        if !self.related_rules.is_empty() {
+            writeln!(markdown, "\n## Related rules\n").unwrap();
+            for id in &self.related_rules {
+                writeln!(markdown, "* [`{id}`]({id}.md)").unwrap();
+            }
+        }
+
+        markdown
+    }
+}
+
+/// Removes the indentation of the lines of a rule's explanation.
+///
+/// Explanations are written as string literals continued over multiple lines;
+/// each paragraph after the first is indented by the source code.
+fn normalize_explanation(explanation: &str) -> String {
+    explanation
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Gets the documentation of all lint and analysis rules.
+///
+/// The documentation is sorted by rule identifier.
+pub fn documentation() -> Vec<RuleDocumentation> {
+    let mut docs = crate::rules()
+        .iter()
+        .map(|r| RuleDocumentation::from_lint_rule(r.as_ref()))
+        .chain(
+            wdl_analysis::rules()
+                .iter()
+                .map(|r| RuleDocumentation::from_analysis_rule(r.as_ref())),
+        )
+        .collect::<Vec<_>>();
+    docs.sort_by_key(|d| d.id);
+    docs
+}
+
+/// Writes a Markdown table with the given header and rows.
+///
+/// The columns are padded to the width of their widest cell.
+fn write_table(markdown: &mut String, header: &[&str], rows: &[Vec<String>]) {
+    let widths = header
+        .iter()
+        .enumerate()
+        .map(|(i, h)| {
+            rows.iter()
+                .map(|r| r[i].chars().count())
+                .chain([h.len(), 2])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let header = header.iter().map(|h| h.to_string()).collect::<Vec<_>>();
+    let separator = widths
+        .iter()
+        .map(|w| format!(":{dashes}", dashes = "-".repeat(w - 1)))
+        .collect::<Vec<_>>();
+    for row in [&header, &separator].into_iter().chain(rows) {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect::<Vec<_>>();
+        writeln!(markdown, "| {cells} |", cells = cells.join(" | ")).unwrap();
+    }
+}
+
+/// Generates the contents of the `RULES.md` file that documents the lint and
+/// analysis rules in tables.
+pub fn rules_markdown() -> String {
+    let docs = documentation();
+    let mut markdown = String::from(RULES_PREAMBLE);
+
+    markdown.push_str("\n## Lint Rules\n\n");
+    let rows = docs
+        .iter()
+        .filter(|d| d.kind == RuleKind::Lint)
+        .map(|d| {
+            vec![
+                format!("`{id}`", id = d.id),
+                d.tags().join(", "),
+                d.description.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    write_table(&mut markdown, &["Name", "Tags", "Description"], &rows);
+
+    markdown.push_str("\n## Analysis Rules\n\n");
+    let rows = docs
+        .iter()
+        .filter(|d| d.kind == RuleKind::Analysis)
+        .map(|d| vec![format!("`{id}`", id = d.id), d.description.to_string()])
+        .collect::<Vec<_>>();
+    write_table(&mut markdown, &["Name", "Description"], &rows);
+    markdown
+}
+
+/// Writes a Markdown page for each lint and analysis rule to the given
+/// directory.
+///
+/// Each page is named after the identifier of its rule (e.g.
+/// `ShellCheck.md`); the directory is created if it does not exist.
+pub fn write_markdown(dir: impl AsRef<Path>) -> Result<()> {
+    let dir = dir.as_ref();
+    fs::create_dir_all(dir)
+        .with_context(|| format!("failed to create directory `{dir}`", dir = dir.display()))?;
+
+    for doc in documentation() {
+        let path = dir.join(format!("{id}.md", id = doc.id));
+        fs::write(&path, doc.to_markdown())
+            .with_context(|| format!("failed to write `{path}`", path = path.display()))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::rules::ShellCheckRule;
+
+    #[test]
+    fn it_renders_markdown() {
+        let doc = RuleDocumentation::from_lint_rule(&ShellCheckRule::default());
+        let markdown = doc.to_markdown();
+        assert!(markdown.starts_with(
+            "# `ShellCheck`\n\nEnsures that command blocks are free of ShellCheck \
+             violations.\n\n* **Kind:** lint\n* **Tags:** Correctness, Portability\n\n## \
+             Explanation\n\n"
+        ));
+        assert!(markdown.contains("\n## Bad examples\n\n```wdl\nversion 1.2\n"));
+        assert!(markdown.contains("\n## Good examples\n\n```wdl\nversion 1.2\n"));
+        assert!(markdown.ends_with(
+            "\n## Related rules\n\n* [`UnquotedPlaceholder`](UnquotedPlaceholder.md)\n"
+        ));
+
+        // Explanations are not indented
+        assert!(!doc.explanation().lines().any(|l| l.starts_with(' ')));
+    }
+
+    #[test]
+    fn it_documents_all_rules() {
+        let docs = documentation();
+        assert_eq!(
+            docs.len(),
+            crate::rules().len() + wdl_analysis::rules().len()
+        );
+        assert!(docs.windows(2).all(|w| w[0].id() < w[1].id()));
+        assert_eq!(
+            docs.iter()
+                .find(|d| d.id() == "UnusedImport")
+                .map(|d| d.kind()),
+            Some(RuleKind::Analysis)
+        );
+    }
+}
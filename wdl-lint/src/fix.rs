@@ -1,9 +1,28 @@
 //! Module for applying fixes for diagnostics.
+//!
+//! Lint rules may attach machine-applicable [edits](wdl_ast::Edit) to their
+//! diagnostics. [`apply_fixes`] applies the non-conflicting edits of a set of
+//! diagnostics to a document's source and [`fix_document`] repeatedly applies
+//! fixes and re-lints a document with an analyzer until no more fixes can be
+//! applied.
 
 use std::ops::Range;
 
+use anyhow::Context as _;
+use anyhow::Result;
 use ftree::FenwickTree;
 use serde::Deserialize;
+use url::Url;
+use wdl_analysis::Analyzer;
+use wdl_analysis::IncrementalChange;
+use wdl_ast::Applicability;
+use wdl_ast::AstNode;
+use wdl_ast::Diagnostic;
+use wdl_ast::Span;
+
+/// The maximum number of times a document is fixed and re-linted by
+/// [`fix_document`].
+const MAX_FIX_PASSES: usize = 10;
 
 /// An insertion point.
 #[derive(Copy, Clone, Debug, Deserialize)]
@@ -98,7 +117,6 @@ pub struct Fixer {
     tree: FenwickTree<i32>,
 }
 
-#[allow(unused)]
 impl Fixer {
     /// Create a new Fixer from a String.
     pub fn new(value: String) -> Self {
@@ -180,13 +198,229 @@ impl Fixer {
     }
 }
 
+/// Determines if two edit spans conflict.
+///
+/// Spans conflict if they overlap or if either is an insertion at the start of
+/// the other (as the order of the edits would then be ambiguous).
+fn conflicts(a: Span, b: Span) -> bool {
+    if a.start() == b.start() {
+        return true;
+    }
+
+    a.start() < b.end() && b.start() < a.end()
+}
+
+/// Represents the result of applying fixes to a document's source.
+#[derive(Clone, Debug)]
+pub struct FixPass {
+    /// The fixed source.
+    source: String,
+    /// The diagnostics whose fixes were applied.
+    applied: Vec<Diagnostic>,
+    /// The diagnostics whose fixes were skipped because they overlap the fix of
+    /// another diagnostic.
+    skipped: Vec<Diagnostic>,
+}
+
+impl FixPass {
+    /// Gets the fixed source.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Gets the diagnostics whose fixes were applied.
+    pub fn applied(&self) -> &[Diagnostic] {
+        &self.applied
+    }
+
+    /// Gets the diagnostics whose fixes were skipped because they overlap the
+    /// fix of another diagnostic.
+    pub fn skipped(&self) -> &[Diagnostic] {
+        &self.skipped
+    }
+}
+
+/// Applies the non-conflicting fixes of the given diagnostics to the given
+/// source.
+///
+/// Only diagnostics with [`Applicability::Safe`] edits are fixed unless
+/// `unsafe_fixes` is `true`.
+///
+/// The fixes are considered in source order; a fix with an edit that overlaps
+/// an edit of an already accepted fix is skipped. Fixes are all-or-nothing: a
+/// diagnostic's edits are either all applied or all skipped.
+pub fn apply_fixes(source: &str, diagnostics: &[Diagnostic], unsafe_fixes: bool) -> FixPass {
+    let mut candidates = diagnostics
+        .iter()
+        .filter(|d| match d.applicability() {
+            Some(Applicability::Safe) => true,
+            Some(Applicability::Unsafe) => unsafe_fixes,
+            None => false,
+        })
+        .filter(|d| d.edits().iter().all(|e| e.span().end() <= source.len()))
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|d| d.edits().iter().map(|e| e.span().start()).min());
+
+    let mut accepted: Vec<Span> = Vec::new();
+    let mut applied = Vec::new();
+    let mut skipped = Vec::new();
+    let mut replacements = Vec::new();
+    for diagnostic in candidates {
+        if diagnostic
+            .edits()
+            .iter()
+            .any(|e| accepted.iter().any(|a| conflicts(*a, e.span())))
+        {
+            skipped.push(diagnostic.clone());
+            continue;
+        }
+
+        for edit in diagnostic.edits() {
+            accepted.push(edit.span());
+            replacements.push(Replacement::new(
+                edit.span().start(),
+                edit.span().end(),
+                InsertionPoint::BeforeStart,
+                edit.replacement().to_string(),
+                0,
+            ));
+        }
+
+        applied.push(diagnostic.clone());
+    }
+
+    // As the replacements don't overlap, applying them in source order with the
+    // fixer adjusts each one for the replacements that came before it
+    replacements.sort_by_key(|r| r.start());
+    let mut fixer = Fixer::new(source.to_string());
+    for replacement in &replacements {
+        fixer.apply_replacement(replacement);
+    }
+
+    FixPass {
+        source: fixer.value,
+        applied,
+        skipped,
+    }
+}
+
+/// Represents the result of fixing a document with [`fix_document`].
+#[derive(Clone, Debug)]
+pub struct FixReport {
+    /// The fixed source of the document.
+    source: String,
+    /// The passes that applied fixes to the document.
+    passes: Vec<FixPass>,
+    /// Whether or not fixing stopped because no more fixes could be applied.
+    converged: bool,
+}
+
+impl FixReport {
+    /// Gets the fixed source of the document.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Gets the passes that applied fixes to the document, in order.
+    ///
+    /// The spans of the diagnostics of each pass refer to the source of the
+    /// document prior to that pass.
+    pub fn passes(&self) -> &[FixPass] {
+        &self.passes
+    }
+
+    /// Gets the diagnostics whose fixes were applied over all passes.
+    pub fn applied(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.passes.iter().flat_map(|p| p.applied.iter())
+    }
+
+    /// Gets the diagnostics whose fixes were skipped over all passes because
+    /// they overlapped another fix.
+    ///
+    /// A fix skipped in one pass is typically applied in a later pass once the
+    /// document has been re-linted.
+    pub fn skipped(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.passes.iter().flat_map(|p| p.skipped.iter())
+    }
+
+    /// Whether or not fixing stopped because no more fixes could be applied.
+    ///
+    /// This is `false` if fixing stopped after the maximum number of passes.
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+}
+
+/// Fixes a document by repeatedly applying the non-conflicting fixes of its
+/// diagnostics and re-linting it with the given analyzer until no more fixes
+/// can be applied.
+///
+/// The analyzer is expected to have been created with a validator that lints
+/// and must already know of the document. The document is changed in the
+/// analyzer's memory only; writing the fixed source back is left to the
+/// caller.
+///
+/// Only diagnostics with [`Applicability::Safe`] edits are fixed unless
+/// `unsafe_fixes` is `true`.
+pub async fn fix_document<Context>(
+    analyzer: &Analyzer<Context>,
+    context: Context,
+    document: &Url,
+    unsafe_fixes: bool,
+) -> Result<FixReport>
+where
+    Context: Send + Clone + 'static,
+{
+    let mut passes = Vec::new();
+    loop {
+        let results = analyzer
+            .analyze_document(context.clone(), document.clone())
+            .await?;
+        let result = results
+            .iter()
+            .find(|r| r.document().uri().as_ref() == document)
+            .with_context(|| format!("document `{document}` was not analyzed"))?;
+
+        let source = result.document().root().text().to_string();
+        let pass = apply_fixes(&source, result.document().diagnostics(), unsafe_fixes);
+        if pass.applied.is_empty() || passes.len() == MAX_FIX_PASSES {
+            return Ok(FixReport {
+                source,
+                converged: pass.applied.is_empty(),
+                passes,
+            });
+        }
+
+        analyzer.notify_incremental_change(
+            document.clone(),
+            IncrementalChange {
+                version: result.version().unwrap_or_default() + 1,
+                start: Some(pass.source.clone()),
+                edits: Vec::new(),
+            },
+        )?;
+        passes.push(pass);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
 
+    use wdl_analysis::Analyzer;
+    use wdl_analysis::DiagnosticsConfig;
+    use wdl_analysis::Validator;
+    use wdl_ast::Applicability;
+    use wdl_ast::Diagnostic;
+    use wdl_ast::Edit;
+    use wdl_ast::Span;
+
+    use crate::Linter;
     use crate::fix::Fixer;
     use crate::fix::InsertionPoint;
     use crate::fix::Replacement;
+    use crate::fix::apply_fixes;
+    use crate::fix::fix_document;
 
     #[test]
     fn test_fixer_insertion() {
@@ -260,4 +494,95 @@ mod tests {
         let mut fixer = Fixer::new(value);
         fixer.apply_replacement(&rep);
     }
+
+    #[test]
+    fn test_apply_fixes() {
+        let source = "hello  world";
+        let diagnostics = [
+            Diagnostic::note("remove a space")
+                .with_edits(Applicability::Safe, [Edit::delete(Span::new(5, 1))]),
+            Diagnostic::note("replace the spaces")
+                .with_edits(Applicability::Safe, [Edit::new(Span::new(5, 2), ", ")]),
+            Diagnostic::note("capitalize").with_edits(
+                Applicability::Unsafe,
+                [
+                    Edit::new(Span::new(0, 1), "H"),
+                    Edit::new(Span::new(7, 1), "W"),
+                ],
+            ),
+            Diagnostic::note("add punctuation")
+                .with_edits(Applicability::Safe, [Edit::insert(12, "!")]),
+            Diagnostic::note("no edits"),
+        ];
+
+        let pass = apply_fixes(source, &diagnostics, false);
+        assert_eq!(pass.source(), "hello world!");
+        assert_eq!(
+            pass.applied()
+                .iter()
+                .map(|d| d.message())
+                .collect::<Vec<_>>(),
+            ["remove a space", "add punctuation"]
+        );
+        assert_eq!(
+            pass.skipped()
+                .iter()
+                .map(|d| d.message())
+                .collect::<Vec<_>>(),
+            ["replace the spaces"]
+        );
+
+        let pass = apply_fixes(source, &diagnostics, true);
+        assert_eq!(pass.source(), "Hello World!");
+        assert_eq!(
+            pass.applied()
+                .iter()
+                .map(|d| d.message())
+                .collect::<Vec<_>>(),
+            ["capitalize", "remove a space", "add punctuation"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fix_document() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = dir.path().join("source.wdl");
+        std::fs::write(
+            &path,
+            "#@ except: MetaSections, RuntimeSection, ContainerUri, RequirementsSection, \
+             MetaDescription, InputName\nversion 1.1\n\nworkflow test {   \n    input {\n        \
+             String b = 'b'\n        String a\n    }\n  \n\n  \n\n    output {\n        \
+             Array[String] values = [\n            a,\n            b\n        ]\n        String \
+             joined = \"~{sep=\",\" values}\"\n    }\n}",
+        )
+        .unwrap();
+
+        let analyzer = Analyzer::new_with_validator(
+            DiagnosticsConfig::except_all(),
+            |_, _, _, _| async {},
+            || {
+                let mut validator = Validator::default();
+                validator.add_visitor(Linter::default());
+                validator
+            },
+        );
+        analyzer
+            .add_directory(dir.path().to_path_buf())
+            .await
+            .unwrap();
+
+        let uri = url::Url::from_file_path(&path).unwrap();
+        let report = fix_document(&analyzer, (), &uri, false).await.unwrap();
+        assert!(report.converged());
+        assert!(report.passes().len() > 1);
+        assert!(report.skipped().next().is_some());
+        assert_eq!(
+            report.source(),
+            "#@ except: MetaSections, RuntimeSection, ContainerUri, RequirementsSection, \
+             MetaDescription, InputName\nversion 1.1\n\nworkflow test {\n    input {\n        \
+             String a\n        String b = \"b\"\n    }\n\n    output {\n        Array[String] \
+             values = [\n            a,\n            b,\n        ]\n        String joined = \
+             \"~{sep(\",\", values)}\"\n    }\n}\n"
+        );
+    }
 }
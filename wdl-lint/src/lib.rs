@@ -23,15 +23,22 @@
 #![warn(clippy::missing_docs_in_private_items)]
 #![warn(rustdoc::broken_intra_doc_links)]
 
+use anyhow::Result;
+use anyhow::bail;
 use wdl_analysis::Visitor;
 use wdl_ast::SyntaxKind;
 
-pub(crate) mod fix;
+pub mod baseline;
+pub mod config;
+pub mod docs;
+pub mod fix;
 mod linter;
 pub mod rules;
+mod shell;
 mod tags;
 pub(crate) mod util;
 
+pub use config::LintConfig;
 pub use linter::*;
 pub use tags::*;
 pub use util::find_nearest_rule;
@@ -57,6 +64,31 @@ pub trait Rule: Visitor {
     /// Get the long-form explanation of the lint rule.
     fn explanation(&self) -> &'static str;
 
+    /// Gets examples of WDL documents that violate the lint rule.
+    ///
+    /// Each example is a complete document for which the lint rule emits at
+    /// least one diagnostic.
+    fn bad_examples(&self) -> &[&'static str] {
+        &[]
+    }
+
+    /// Gets examples of WDL documents that conform to the lint rule.
+    ///
+    /// Each example is a complete document for which the lint rule emits no
+    /// diagnostics.
+    fn good_examples(&self) -> &[&'static str] {
+        &[]
+    }
+
+    /// Gets the options, as the contents of a TOML table, with which the
+    /// examples of the lint rule are linted.
+    ///
+    /// This is used by lint rules that do nothing unless configured (e.g.
+    /// `required = ["memory"]`).
+    fn example_options(&self) -> Option<&'static str> {
+        None
+    }
+
     /// Get the tags of the lint rule.
     fn tags(&self) -> TagSet;
 
@@ -76,6 +108,24 @@ pub trait Rule: Visitor {
     /// relevant rules to the user based on potential logical connections or
     /// common co-occurrences of issues.
     fn related_rules(&self) -> &[&'static str];
+
+    /// Configures the lint rule with the given options.
+    ///
+    /// The options are the contents of the rule's table in a configuration
+    /// file, excluding its `severity`.
+    ///
+    /// By default, a lint rule does not accept any options and an error is
+    /// returned if any are given.
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        if let Some(key) = options.keys().next() {
+            bail!(
+                "lint rule `{id}` does not accept options, but option `{key}` was given",
+                id = self.id()
+            );
+        }
+
+        Ok(())
+    }
 }
 
 /// Gets the default rule set.
@@ -116,6 +166,7 @@ pub fn rules() -> Vec<Box<dyn Rule>> {
         Box::<rules::OutputNameRule>::default(),
         Box::<rules::DeclarationNameRule>::default(),
         Box::<rules::ContainerUriRule>::default(),
+        Box::<rules::ContainerPolicyRule>::default(),
         Box::<rules::RequirementsSectionRule>::default(),
         Box::<rules::KnownRulesRule>::default(),
         Box::<rules::LintDirectiveValidRule>::default(),
@@ -124,6 +175,11 @@ pub fn rules() -> Vec<Box<dyn Rule>> {
         Box::<rules::LintDirectiveFormattedRule>::default(),
         Box::<rules::ConciseInputRule>::default(),
         Box::<rules::ShellCheckRule>::default(),
+        Box::<rules::UnquotedPlaceholderRule>::default(),
+        Box::<rules::ResourceBoundsRule>::default(),
+        Box::<rules::DisksFormatRule>::default(),
+        Box::<rules::RequiredResourcesRule>::default(),
+        Box::<rules::ComplexityRule>::default(),
     ];
 
     // Ensure all the rule IDs are unique and pascal case and that related rules are
@@ -1,6 +1,8 @@
 //! Implementation of the linter.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
+use std::sync::Arc;
 
 use indexmap::IndexMap;
 use wdl_analysis::Diagnostics;
@@ -10,12 +12,14 @@ use wdl_analysis::VisitReason;
 use wdl_analysis::Visitor;
 use wdl_ast::AstNode;
 use wdl_ast::Comment;
+use wdl_ast::Severity;
 use wdl_ast::SupportedVersion;
 use wdl_ast::VersionStatement;
 use wdl_ast::Whitespace;
 use wdl_ast::v1;
 
 use crate::Rule;
+use crate::config::LintConfig;
 use crate::rules;
 
 /// A visitor that runs linting rules.
@@ -32,10 +36,21 @@ use crate::rules;
 ///
 /// Otherwise, `#@ except` comments disable the rule for the immediately
 /// following AST node.
+///
+/// A linter created with [`Linter::with_config`] constructs its rules from a
+/// [`LintConfig`], reconfiguring them for each document based on the
+/// overrides that match the document's path.
 #[allow(missing_debug_implementations)]
 pub struct Linter {
     /// The map of rule name to rule.
     rules: IndexMap<&'static str, Box<dyn Rule>>,
+    /// The map of rule name to the severity its diagnostics are remapped to.
+    severities: HashMap<&'static str, Severity>,
+    /// The lint configuration, if there is one.
+    config: Option<Arc<LintConfig>>,
+    /// The indexes of the configuration overrides the current rules were
+    /// constructed with.
+    applied_overrides: Vec<usize>,
     /// The set of rule ids that are disabled for the current document.
     document_exceptions: HashSet<String>,
 }
@@ -45,10 +60,42 @@ impl Linter {
     pub fn new(rules: impl IntoIterator<Item = Box<dyn Rule>>) -> Self {
         Self {
             rules: rules.into_iter().map(|r| (r.id(), r)).collect(),
+            severities: HashMap::default(),
+            config: None,
+            applied_overrides: Vec::new(),
             document_exceptions: HashSet::default(),
         }
     }
 
+    /// Creates a new linter with rules constructed from the given lint
+    /// configuration.
+    pub fn with_config(config: impl Into<Arc<LintConfig>>) -> Self {
+        let mut linter = Self::new([]);
+        linter.config = Some(config.into());
+        linter.configure(Vec::new());
+        linter
+    }
+
+    /// Reconstructs the rules from the lint configuration with the given
+    /// overrides applied.
+    fn configure(&mut self, overrides: Vec<usize>) {
+        let Some(config) = &self.config else {
+            return;
+        };
+
+        self.rules.clear();
+        self.severities.clear();
+        for (rule, severity) in config.configured_rules(&overrides) {
+            if let Some(severity) = severity {
+                self.severities.insert(rule.id(), severity);
+            }
+
+            self.rules.insert(rule.id(), rule);
+        }
+
+        self.applied_overrides = overrides;
+    }
+
     /// Invokes a callback on each rule
     fn each_enabled_rule<F>(&mut self, diagnostics: &mut Diagnostics, mut cb: F)
     where
@@ -58,17 +105,20 @@ impl Linter {
             if self.document_exceptions.contains(id.to_owned()) {
                 continue;
             }
+
+            let start = diagnostics.len();
             cb(diagnostics, rule.as_mut());
+
+            if let Some(severity) = self.severities.get(id) {
+                diagnostics.set_severity_from(start, *severity);
+            }
         }
     }
 }
 
 impl Default for Linter {
     fn default() -> Self {
-        Self {
-            rules: rules().into_iter().map(|r| (r.id(), r)).collect(),
-            document_exceptions: HashSet::default(),
-        }
+        Self::new(rules())
     }
 }
 
@@ -91,6 +141,16 @@ impl Visitor for Linter {
         version: SupportedVersion,
     ) {
         if reason == VisitReason::Enter {
+            // Reconfigure the rules if the overrides that apply to this document
+            // differ from those of the previous document
+            if let Some(config) = &self.config {
+                let path = doc.uri().to_file_path().ok();
+                let overrides = config.matching_overrides(path.as_deref());
+                if overrides != self.applied_overrides {
+                    self.configure(overrides);
+                }
+            }
+
             self.document_exceptions.extend(
                 doc.root()
                     .version_statement()
@@ -150,6 +210,17 @@ impl Visitor for Linter {
         });
     }
 
+    fn enum_definition(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        def: &v1::EnumDefinition,
+    ) {
+        self.each_enabled_rule(diagnostics, |diagnostics, rule| {
+            rule.enum_definition(diagnostics, reason, def)
+        });
+    }
+
     fn task_definition(
         &mut self,
         diagnostics: &mut Diagnostics,
@@ -377,6 +448,17 @@ impl Visitor for Linter {
         });
     }
 
+    fn conditional_else_clause(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        clause: &v1::ConditionalElseClause,
+    ) {
+        self.each_enabled_rule(diagnostics, |diagnostics, rule| {
+            rule.conditional_else_clause(diagnostics, reason, clause)
+        });
+    }
+
     fn scatter_statement(
         &mut self,
         diagnostics: &mut Diagnostics,
@@ -3,12 +3,15 @@
 mod call_input_spacing;
 mod command_section_indentation;
 mod comment_whitespace;
+mod complexity;
 mod concise_input;
 mod consistent_newlines;
+mod container_policy;
 mod container_uri;
 mod declaration_name;
 mod deprecated_object;
 mod deprecated_placeholder;
+mod disks_format;
 mod double_quotes;
 mod element_spacing;
 mod ending_newline;
@@ -34,25 +37,31 @@ mod parameter_meta_matched;
 mod pascal_case;
 mod preamble_comment_placement;
 mod preamble_formatted;
+mod required_resources;
 mod requirements_section;
+mod resource_bounds;
 mod runtime_section;
 mod section_order;
 mod shellcheck;
 mod snake_case;
 mod todo_comment;
 mod trailing_comma;
+mod unquoted_placeholder;
 mod version_statement_formatted;
 mod whitespace;
 
 pub use call_input_spacing::*;
 pub use command_section_indentation::*;
 pub use comment_whitespace::*;
+pub use complexity::*;
 pub use concise_input::*;
 pub use consistent_newlines::*;
+pub use container_policy::*;
 pub use container_uri::*;
 pub use declaration_name::*;
 pub use deprecated_object::*;
 pub use deprecated_placeholder::*;
+pub use disks_format::*;
 pub use double_quotes::*;
 pub use element_spacing::*;
 pub use ending_newline::*;
@@ -78,12 +87,15 @@ pub use parameter_meta_matched::*;
 pub use pascal_case::*;
 pub use preamble_comment_placement::*;
 pub use preamble_formatted::*;
+pub use required_resources::*;
 pub use requirements_section::*;
+pub use resource_bounds::*;
 pub use runtime_section::*;
 pub use section_order::*;
 pub use shellcheck::*;
 pub use snake_case::*;
 pub use todo_comment::*;
 pub use trailing_comma::*;
+pub use unquoted_placeholder::*;
 pub use version_statement_formatted::*;
 pub use whitespace::*;
@@ -72,6 +72,53 @@ impl Rule for CallInputSpacingRule {
          it is permissable to keep the input on the same line as the call."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task add {
+    input {
+        Int x
+        Int y
+    }
+
+    command <<<>>>
+
+    output {
+        Int sum = x + y
+    }
+}
+
+workflow calculate {
+    call add {x = 1, y = 2}
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task add {
+    input {
+        Int x
+        Int y
+    }
+
+    command <<<>>>
+
+    output {
+        Int sum = x + y
+    }
+}
+
+workflow calculate {
+    call add { input:
+        x = 1,
+        y = 2,
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Style, Tag::Clarity, Tag::Spacing])
     }
@@ -91,6 +91,30 @@ impl Rule for CommandSectionIndentationRule {
          the whitespace stripping step may cause unexpected behavior."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &["version 1.2
+
+task say_hello {
+    command <<<
+        echo \"Hello\"
+\techo \"World\"
+    >>>
+}
+"]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        echo "Hello"
+        echo "World"
+    >>>
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Correctness, Tag::Spacing, Tag::Clarity])
     }
@@ -86,6 +86,26 @@ impl Rule for CommentWhitespaceRule {
          comments must be kept below the 90 character width limit."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+#This comment is missing a space
+workflow hello {
+    String greeting = "hello" # This inline comment needs two spaces before it
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+# This comment is spaced correctly
+workflow hello {
+    String greeting = "hello"  # This inline comment is spaced correctly
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Spacing])
     }
@@ -0,0 +1,313 @@
+//! A lint rule for limiting the size and complexity of tasks and workflows.
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use wdl_analysis::Diagnostics;
+use wdl_analysis::VisitReason;
+use wdl_analysis::Visitor;
+use wdl_analysis::metrics::TaskMetrics;
+use wdl_analysis::metrics::WorkflowMetrics;
+use wdl_ast::AstNode;
+use wdl_ast::Diagnostic;
+use wdl_ast::Span;
+use wdl_ast::SyntaxElement;
+use wdl_ast::SyntaxKind;
+use wdl_ast::v1::ImportStatement;
+use wdl_ast::v1::TaskDefinition;
+use wdl_ast::v1::WorkflowDefinition;
+
+use crate::Rule;
+use crate::Tag;
+use crate::TagSet;
+use crate::config::parse_options;
+
+/// The identifier for the complexity rule.
+const ID: &str = "Complexity";
+
+/// Creates a diagnostic for a metric that exceeds its configured maximum.
+fn metric_exceeded(item: &str, metric: &str, value: usize, max: usize, span: Span) -> Diagnostic {
+    Diagnostic::note(format!(
+        "{item} has {metric} of {value}, which exceeds the maximum of {max}"
+    ))
+    .with_rule(ID)
+    .with_highlight(span)
+    .with_fix("split the item into smaller tasks or workflows")
+}
+
+/// Creates a diagnostic for an import that exceeds the maximum number of
+/// imports of a document.
+fn too_many_imports(max: usize, span: Span) -> Diagnostic {
+    Diagnostic::note(format!(
+        "document has more than the maximum of {max} import{s}",
+        s = if max == 1 { "" } else { "s" }
+    ))
+    .with_rule(ID)
+    .with_highlight(span)
+    .with_fix("split the document into smaller documents")
+}
+
+/// The configurable options of the complexity rule.
+///
+/// Each option is the maximum value of a metric; metrics without a maximum
+/// are not checked. By default, no metrics are checked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ComplexityOptions {
+    /// The maximum number of imports of a document.
+    pub max_imports: Option<usize>,
+    /// The maximum number of inputs of a task.
+    pub max_task_inputs: Option<usize>,
+    /// The maximum number of outputs of a task.
+    pub max_task_outputs: Option<usize>,
+    /// The maximum number of non-blank lines of a task's command.
+    pub max_command_lines: Option<usize>,
+    /// The maximum number of placeholders in a task's command.
+    pub max_placeholders: Option<usize>,
+    /// The maximum number of distinct `runtime` and `requirements` keys of a
+    /// task.
+    pub max_runtime_keys: Option<usize>,
+    /// The maximum number of calls in a workflow.
+    pub max_calls: Option<usize>,
+    /// The maximum nesting depth of scatter and conditional statements in a
+    /// workflow.
+    pub max_nesting_depth: Option<usize>,
+    /// The maximum number of calls in a workflow that directly depend on a
+    /// single call.
+    pub max_fan_out: Option<usize>,
+    /// The maximum number of calls in the longest chain of dependent calls in
+    /// a workflow.
+    pub max_critical_path_length: Option<usize>,
+}
+
+/// Detects tasks, workflows, and documents whose metrics exceed the configured
+/// maximums.
+#[derive(Debug, Default, Clone)]
+pub struct ComplexityRule {
+    /// The options of the rule.
+    options: ComplexityOptions,
+    /// The number of import statements visited in the document.
+    imports: usize,
+}
+
+impl ComplexityRule {
+    /// Constructs a new complexity rule with the given options.
+    pub fn with_options(options: ComplexityOptions) -> Self {
+        Self {
+            options,
+            imports: 0,
+        }
+    }
+
+    /// Adds a diagnostic for each of the given metrics that exceeds its
+    /// maximum.
+    fn check(
+        &self,
+        diagnostics: &mut Diagnostics,
+        item: &str,
+        span: Span,
+        element: SyntaxElement,
+        metrics: &[(Option<usize>, usize, &str)],
+    ) {
+        for (max, value, metric) in metrics {
+            let Some(max) = *max else {
+                continue;
+            };
+
+            if *value > max {
+                diagnostics.exceptable_add(
+                    metric_exceeded(item, metric, *value, max, span),
+                    element.clone(),
+                    &self.exceptable_nodes(),
+                );
+            }
+        }
+    }
+}
+
+impl Rule for ComplexityRule {
+    fn id(&self) -> &'static str {
+        ID
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures that tasks and workflows do not exceed the configured size and complexity limits."
+    }
+
+    fn explanation(&self) -> &'static str {
+        "Large tasks and deeply nested workflows are difficult to review, test, and reuse. \
+         Splitting them into smaller tasks and sub-workflows keeps each piece understandable.
+
+         This rule checks the metrics of each document against the configured maximums: the \
+         number of imports of a document; the number of inputs, outputs, non-blank command lines, \
+         command placeholders, and distinct `runtime` and `requirements` keys of a task; and the \
+         number of calls, the nesting depth of scatter and conditional statements, the maximum \
+         fan-out of a call, and the length of the longest chain of dependent calls of a workflow. \
+         No maximums are configured by default."
+    }
+
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task greet {
+    input {
+        String greeting
+        String name
+        String punctuation
+    }
+
+    command <<<
+        echo "~{greeting}, ~{name}~{punctuation}"
+    >>>
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task greet {
+    input {
+        String greeting
+        String name
+    }
+
+    command <<<
+        echo "~{greeting}, ~{name}!"
+    >>>
+}
+"#]
+    }
+
+    fn example_options(&self) -> Option<&'static str> {
+        Some(r#"max_task_inputs = 2"#)
+    }
+
+    fn tags(&self) -> TagSet {
+        TagSet::new(&[Tag::Clarity])
+    }
+
+    fn exceptable_nodes(&self) -> Option<&'static [SyntaxKind]> {
+        Some(&[
+            SyntaxKind::VersionStatementNode,
+            SyntaxKind::TaskDefinitionNode,
+            SyntaxKind::WorkflowDefinitionNode,
+        ])
+    }
+
+    fn related_rules(&self) -> &[&'static str] {
+        &["LineWidth"]
+    }
+
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        *self = Self::with_options(parse_options(self.id(), options)?);
+        Ok(())
+    }
+}
+
+impl Visitor for ComplexityRule {
+    fn reset(&mut self) {
+        *self = Self::with_options(self.options);
+    }
+
+    fn import_statement(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        stmt: &ImportStatement,
+    ) {
+        if reason == VisitReason::Exit {
+            return;
+        }
+
+        self.imports += 1;
+
+        // Only the first import over the maximum is reported
+        if let Some(max) = self.options.max_imports {
+            if self.imports == max + 1 {
+                diagnostics.exceptable_add(
+                    too_many_imports(max, stmt.span()),
+                    SyntaxElement::from(stmt.inner().clone()),
+                    &self.exceptable_nodes(),
+                );
+            }
+        }
+    }
+
+    fn task_definition(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        task: &TaskDefinition,
+    ) {
+        if reason == VisitReason::Exit {
+            return;
+        }
+
+        let metrics = TaskMetrics::new(task);
+        let options = &self.options;
+        self.check(
+            diagnostics,
+            &format!("task `{name}`", name = metrics.name()),
+            metrics.span(),
+            SyntaxElement::from(task.inner().clone()),
+            &[
+                (options.max_task_inputs, metrics.inputs(), "an input count"),
+                (
+                    options.max_task_outputs,
+                    metrics.outputs(),
+                    "an output count",
+                ),
+                (
+                    options.max_command_lines,
+                    metrics.command_lines(),
+                    "a command line count",
+                ),
+                (
+                    options.max_placeholders,
+                    metrics.placeholders(),
+                    "a placeholder count",
+                ),
+                (
+                    options.max_runtime_keys,
+                    metrics.runtime_keys(),
+                    "a runtime key count",
+                ),
+            ],
+        );
+    }
+
+    fn workflow_definition(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        workflow: &WorkflowDefinition,
+    ) {
+        if reason == VisitReason::Exit {
+            return;
+        }
+
+        let metrics = WorkflowMetrics::new(workflow);
+        let options = &self.options;
+        self.check(
+            diagnostics,
+            &format!("workflow `{name}`", name = metrics.name()),
+            metrics.span(),
+            SyntaxElement::from(workflow.inner().clone()),
+            &[
+                (options.max_calls, metrics.calls(), "a call count"),
+                (
+                    options.max_nesting_depth,
+                    metrics.max_nesting_depth(),
+                    "a nesting depth",
+                ),
+                (options.max_fan_out, metrics.max_fan_out(), "a call fan-out"),
+                (
+                    options.max_critical_path_length,
+                    metrics.critical_path_length(),
+                    "a critical path length",
+                ),
+            ],
+        );
+    }
+}
@@ -47,6 +47,52 @@ impl Rule for ConciseInputRule {
          binding. For example, `{ input: a = a }` can be shortened to `{ input: a }`."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    input {
+        String name
+    }
+
+    command <<<
+        echo "Hello, ~{name}!"
+    >>>
+}
+
+workflow hello {
+    input {
+        String name
+    }
+
+    call say_hello { name = name }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    input {
+        String name
+    }
+
+    command <<<
+        echo "Hello, ~{name}!"
+    >>>
+}
+
+workflow hello {
+    input {
+        String name
+    }
+
+    call say_hello { name }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Style])
     }
@@ -57,6 +57,22 @@ impl Rule for ConsistentNewlinesRule {
          your project."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &["version 1.2\r
+\r
+workflow hello {
+}
+"]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Style, Tag::Clarity])
     }
@@ -0,0 +1,388 @@
+//! A lint rule for enforcing a container image policy.
+
+use anyhow::Context;
+use anyhow::Result;
+use glob::Pattern;
+use serde::Deserialize;
+use serde::Serialize;
+use wdl_analysis::Diagnostics;
+use wdl_analysis::VisitReason;
+use wdl_analysis::Visitor;
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::Diagnostic;
+use wdl_ast::Span;
+use wdl_ast::SyntaxElement;
+use wdl_ast::SyntaxKind;
+use wdl_ast::v1::RequirementsSection;
+use wdl_ast::v1::RuntimeSection;
+use wdl_ast::v1::TaskDefinition;
+use wdl_ast::v1::common::container::value::Value;
+use wdl_ast::v1::common::container::value::uri::ANY_CONTAINER_VALUE;
+
+use crate::Rule;
+use crate::Tag;
+use crate::TagSet;
+use crate::config::parse_options;
+
+/// The identifier for the container policy rule.
+const ID: &str = "ContainerPolicy";
+
+/// The registry of container images that do not specify one.
+const DEFAULT_REGISTRY: &str = "docker.io";
+
+/// Creates a diagnostic for the use of the "any" container value.
+fn any_container(span: Span) -> Diagnostic {
+    Diagnostic::warning(format!(
+        "container URI `{ANY_CONTAINER_VALUE}` allows any container to be used"
+    ))
+    .with_rule(ID)
+    .with_highlight(span)
+    .with_fix("replace the value with an explicit container image")
+}
+
+/// Creates a diagnostic for a container URI that is not pinned to a digest.
+fn missing_digest(span: Span) -> Diagnostic {
+    Diagnostic::warning(String::from(
+        "container URI is not pinned to an image digest",
+    ))
+    .with_rule(ID)
+    .with_highlight(span)
+    .with_fix(
+        "pin the container URI to its SHA256 digest (e.g., `ubuntu@sha256:foobar` instead of \
+         `ubuntu:24.04`)",
+    )
+}
+
+/// Creates a diagnostic for a container URI from a registry that is not
+/// allowed.
+fn disallowed_registry(registry: &str, allowed: &[String], span: Span) -> Diagnostic {
+    Diagnostic::warning(format!(
+        "container registry `{registry}` is not allowed by the container policy"
+    ))
+    .with_rule(ID)
+    .with_highlight(span)
+    .with_fix(format!(
+        "use a container image from an allowed registry: {allowed}",
+        allowed = format_list(allowed)
+    ))
+}
+
+/// Creates a diagnostic for a container URI with a repository that is not
+/// allowed.
+fn disallowed_repository(image: &str, allowed: &[String], span: Span) -> Diagnostic {
+    Diagnostic::warning(format!(
+        "container image `{image}` is not allowed by the container policy"
+    ))
+    .with_rule(ID)
+    .with_highlight(span)
+    .with_fix(format!(
+        "use a container image matching an allowed repository: {allowed}",
+        allowed = format_list(allowed)
+    ))
+}
+
+/// Creates a diagnostic for a task that does not specify a container.
+fn missing_container(task: &str, span: Span) -> Diagnostic {
+    Diagnostic::warning(format!("task `{task}` does not specify a container"))
+        .with_rule(ID)
+        .with_highlight(span)
+        .with_fix("add a `container` key to the task's `runtime` or `requirements` section")
+}
+
+/// Formats a list of allowed values for a diagnostic.
+fn format_list(items: &[String]) -> String {
+    items
+        .iter()
+        .map(|i| format!("`{i}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Gets the registry of the given container image.
+///
+/// The first component of the image is the registry if it contains a `.` or a
+/// `:` or if it is `localhost`; otherwise, the image is from Docker Hub.
+fn registry(image: &str) -> &str {
+    match image.split_once('/') {
+        Some((first, _)) if first.contains(['.', ':']) || first == "localhost" => first,
+        _ => DEFAULT_REGISTRY,
+    }
+}
+
+/// The configurable options of the container policy rule.
+///
+/// By default, only the use of the "any" container value (`*`) is flagged.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ContainerPolicyOptions {
+    /// Whether or not container URIs are required to be pinned to an image
+    /// digest (i.e., `@sha256:`).
+    pub require_digest: bool,
+    /// Whether or not tasks are required to specify a container.
+    pub require_container: bool,
+    /// Whether or not the "any" container value (`*`) is allowed.
+    pub allow_any: bool,
+    /// The registries that container images may come from.
+    ///
+    /// Images without an explicit registry are from `docker.io`.
+    ///
+    /// If empty, images may come from any registry.
+    pub allowed_registries: Vec<String>,
+    /// The glob patterns of the container images that are allowed, matched
+    /// against the image as written without its tag or digest (e.g.,
+    /// `quay.io/biocontainers/*`).
+    ///
+    /// If empty, any image is allowed.
+    pub allowed_repositories: Vec<String>,
+}
+
+/// Enforces a container image policy on tasks.
+#[derive(Debug, Default, Clone)]
+pub struct ContainerPolicyRule {
+    /// The options of the rule.
+    options: ContainerPolicyOptions,
+    /// The compiled patterns of the allowed repositories.
+    repositories: Vec<Pattern>,
+    /// Whether or not the current task specifies a container.
+    has_container: bool,
+}
+
+impl ContainerPolicyRule {
+    /// Constructs a new container policy rule with the given options.
+    ///
+    /// Returns an error if an allowed repository is not a valid glob pattern.
+    pub fn with_options(options: ContainerPolicyOptions) -> Result<Self> {
+        let repositories = options
+            .allowed_repositories
+            .iter()
+            .map(|r| Pattern::new(r).with_context(|| format!("invalid repository pattern `{r}`")))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            options,
+            repositories,
+            has_container: false,
+        })
+    }
+
+    /// Checks the value of a `container` item against the policy.
+    fn check_container_value(
+        &self,
+        diagnostics: &mut Diagnostics,
+        value: Value,
+        syntax: SyntaxElement,
+    ) {
+        let exceptable_nodes = self.exceptable_nodes();
+        for uri in value.uris() {
+            let span = uri.literal_string().span();
+            let Some(entry) = uri.kind().as_entry() else {
+                if !self.options.allow_any {
+                    diagnostics.exceptable_add(
+                        any_container(span),
+                        syntax.clone(),
+                        &exceptable_nodes,
+                    );
+                }
+
+                continue;
+            };
+
+            if self.options.require_digest && !entry.immutable() {
+                diagnostics.exceptable_add(missing_digest(span), syntax.clone(), &exceptable_nodes);
+            }
+
+            let image = entry.image();
+            let registry = registry(image);
+            if !self.options.allowed_registries.is_empty()
+                && !self
+                    .options
+                    .allowed_registries
+                    .iter()
+                    .any(|r| r.eq_ignore_ascii_case(registry))
+            {
+                diagnostics.exceptable_add(
+                    disallowed_registry(registry, &self.options.allowed_registries, span),
+                    syntax.clone(),
+                    &exceptable_nodes,
+                );
+            } else if !self.repositories.is_empty()
+                && !self.repositories.iter().any(|p| p.matches(image))
+            {
+                diagnostics.exceptable_add(
+                    disallowed_repository(image, &self.options.allowed_repositories, span),
+                    syntax.clone(),
+                    &exceptable_nodes,
+                );
+            }
+        }
+    }
+}
+
+impl Rule for ContainerPolicyRule {
+    fn id(&self) -> &'static str {
+        ID
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures that task containers conform to the configured container policy."
+    }
+
+    fn explanation(&self) -> &'static str {
+        "Container images determine the software a task runs with, so organizations often \
+         restrict which images may be used. This rule checks the values of `container` (or \
+         `docker`) keys within `runtime` and `requirements` sections, including each entry of \
+         an array of container URIs, against a configurable policy:
+
+         - `allow_any`: whether the 'any' container value (`*`) may be used; it is not allowed by \
+         default.
+         - `require_digest`: whether container URIs must be pinned to an image digest (e.g., \
+         `ubuntu@sha256:foobar`).
+         - `require_container`: whether every task must specify a container.
+         - `allowed_registries`: the registries images may come from; images without an explicit \
+         registry come from `docker.io`.
+         - `allowed_repositories`: glob patterns that images (without their tag or digest) must \
+         match, such as `quay.io/biocontainers/*`.
+
+         Container values that are not string literals cannot be checked against the policy."
+    }
+
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        echo "Hello"
+    >>>
+
+    requirements {
+        container: "*"
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        echo "Hello"
+    >>>
+
+    requirements {
+        container: "ubuntu:22.04"
+    }
+}
+"#]
+    }
+
+    fn tags(&self) -> TagSet {
+        TagSet::new(&[Tag::Portability])
+    }
+
+    fn exceptable_nodes(&self) -> Option<&'static [SyntaxKind]> {
+        Some(&[
+            SyntaxKind::VersionStatementNode,
+            SyntaxKind::TaskDefinitionNode,
+            SyntaxKind::RuntimeSectionNode,
+            SyntaxKind::RequirementsSectionNode,
+        ])
+    }
+
+    fn related_rules(&self) -> &[&'static str] {
+        &["ContainerUri", "RuntimeSection", "RequirementsSection"]
+    }
+
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        let id = self.id();
+        *self = Self::with_options(parse_options(id, options)?)
+            .with_context(|| format!("invalid options for lint rule `{id}`"))?;
+        Ok(())
+    }
+}
+
+impl Visitor for ContainerPolicyRule {
+    fn reset(&mut self) {
+        self.has_container = false;
+    }
+
+    fn task_definition(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        task: &TaskDefinition,
+    ) {
+        if reason == VisitReason::Enter {
+            self.has_container = false;
+            return;
+        }
+
+        if self.options.require_container && !self.has_container {
+            let name = task.name();
+            diagnostics.exceptable_add(
+                missing_container(name.text(), name.span()),
+                SyntaxElement::from(task.inner().clone()),
+                &self.exceptable_nodes(),
+            );
+        }
+    }
+
+    fn runtime_section(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        section: &RuntimeSection,
+    ) {
+        if reason == VisitReason::Exit {
+            return;
+        }
+
+        if let Some(container) = section.container() {
+            self.has_container = true;
+            if let Ok(value) = container.value() {
+                self.check_container_value(
+                    diagnostics,
+                    value,
+                    SyntaxElement::from(section.inner().clone()),
+                );
+            }
+        }
+    }
+
+    fn requirements_section(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        section: &RequirementsSection,
+    ) {
+        if reason == VisitReason::Exit {
+            return;
+        }
+
+        if let Some(container) = section.container() {
+            self.has_container = true;
+            if let Ok(value) = container.value() {
+                self.check_container_value(
+                    diagnostics,
+                    value,
+                    SyntaxElement::from(section.inner().clone()),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_determines_registries() {
+        assert_eq!(registry("ubuntu"), "docker.io");
+        assert_eq!(registry("stjudecloud/samtools"), "docker.io");
+        assert_eq!(registry("quay.io/biocontainers/samtools"), "quay.io");
+        assert_eq!(registry("localhost/foo"), "localhost");
+        assert_eq!(registry("ghcr.io/org/image"), "ghcr.io");
+    }
+}
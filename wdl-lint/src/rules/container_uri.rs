@@ -118,6 +118,36 @@ impl Rule for ContainerUriRule {
          value."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        echo "Hello"
+    >>>
+
+    requirements {
+        container: "ubuntu"
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        echo "Hello"
+    >>>
+
+    requirements {
+        container: "ubuntu@sha256:0000000000000000000000000000000000000000000000000000000000000000"
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         // NOTE: these are the justification for these tags:
         //
@@ -139,7 +169,7 @@ impl Rule for ContainerUriRule {
     }
 
     fn related_rules(&self) -> &[&'static str] {
-        &[]
+        &["ContainerPolicy"]
     }
 }
 
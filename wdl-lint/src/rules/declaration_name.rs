@@ -51,6 +51,26 @@ impl Rule for DeclarationNameRule {
          struct types, which are not flagged by this rule."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow count {
+    Int counter_int = 0
+    Boolean is_active_bool = true
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow count {
+    Int counter = 0
+    Boolean is_active = true
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Style, Tag::Clarity])
     }
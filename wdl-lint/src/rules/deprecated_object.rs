@@ -49,6 +49,33 @@ impl Rule for DeprecatedObjectRule {
          See this issue for more details: https://github.com/openwdl/wdl/pull/228."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.1
+
+workflow describe {
+    input {
+        Object metadata
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.1
+
+struct Metadata {
+    String name
+    Int size
+}
+
+workflow describe {
+    input {
+        Metadata metadata
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Deprecated])
     }
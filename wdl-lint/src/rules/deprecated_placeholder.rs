@@ -4,8 +4,10 @@ use wdl_analysis::Diagnostics;
 use wdl_analysis::Document;
 use wdl_analysis::VisitReason;
 use wdl_analysis::Visitor;
+use wdl_ast::Applicability;
 use wdl_ast::AstNode;
 use wdl_ast::Diagnostic;
+use wdl_ast::Edit;
 use wdl_ast::Span;
 use wdl_ast::SupportedVersion;
 use wdl_ast::SyntaxElement;
@@ -23,7 +25,10 @@ const ID: &str = "DeprecatedPlaceholder";
 
 /// Creates a diagnostic for the use of the deprecated `default` placeholder
 /// option.
-fn deprecated_default_placeholder_option(span: Span) -> Diagnostic {
+///
+/// The edit is unsafe as the type of the default value may not be compatible
+/// with the type of the placeholder expression.
+fn deprecated_default_placeholder_option(span: Span, edit: Edit) -> Diagnostic {
     Diagnostic::note(String::from(
         "use of the deprecated `default` placeholder option",
     ))
@@ -33,10 +38,11 @@ fn deprecated_default_placeholder_option(span: Span) -> Diagnostic {
         "replace the `default` placeholder option with a call to the `select_first()` standard \
          library function",
     )
+    .with_edits(Applicability::Unsafe, [edit])
 }
 
 /// Creates a diagnostic for the use of the deprecated `sep` placeholder option.
-fn deprecated_sep_placeholder_option(span: Span) -> Diagnostic {
+fn deprecated_sep_placeholder_option(span: Span, edit: Edit) -> Diagnostic {
     Diagnostic::note(String::from(
         "use of the deprecated `sep` placeholder option",
     ))
@@ -45,17 +51,19 @@ fn deprecated_sep_placeholder_option(span: Span) -> Diagnostic {
     .with_fix(
         "replace the `sep` placeholder option with a call to the `sep()` standard library function",
     )
+    .with_edits(Applicability::Safe, [edit])
 }
 
 /// Creates a diagnostic for the use of the deprecated `true`/`false`
 /// placeholder option.
-fn deprecated_true_false_placeholder_option(span: Span) -> Diagnostic {
+fn deprecated_true_false_placeholder_option(span: Span, edit: Edit) -> Diagnostic {
     Diagnostic::note(String::from(
         "use of the deprecated `true`/`false` placeholder option",
     ))
     .with_rule(ID)
     .with_highlight(span)
     .with_fix("replace the `true`/`false` placeholder option with an `if`/`else` expression")
+    .with_edits(Applicability::Safe, [edit])
 }
 
 /// Detects the use of a deprecated placeholder option.
@@ -87,6 +95,36 @@ impl Rule for DeprecatedPlaceholderRule {
          was the version where the deprecation was introduced."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task greet {
+    input {
+        Array[String] names
+    }
+
+    command <<<
+        echo ~{sep=" " names}
+    >>>
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task greet {
+    input {
+        Array[String] names
+    }
+
+    command <<<
+        echo ~{sep(" ", names)}
+    >>>
+}
+"#]
+    }
+
     fn exceptable_nodes(&self) -> Option<&'static [wdl_ast::SyntaxKind]> {
         Some(&[
             SyntaxKind::VersionStatementNode,
@@ -144,13 +182,48 @@ impl Visitor for DeprecatedPlaceholderRule {
         };
 
         if let Some(option) = placeholder.option() {
+            // The edit replaces both the option and the placeholder expression
+            let expr = placeholder.expr();
+            let text = expr.text().to_string();
+            let span = Span::new(
+                option.span().start(),
+                expr.span().end() - option.span().start(),
+            );
+
             let diagnostic = match option {
-                PlaceholderOption::Sep(option) => deprecated_sep_placeholder_option(option.span()),
-                PlaceholderOption::Default(option) => {
-                    deprecated_default_placeholder_option(option.span())
-                }
+                PlaceholderOption::Sep(option) => deprecated_sep_placeholder_option(
+                    option.span(),
+                    Edit::new(
+                        span,
+                        format!(
+                            "sep({separator}, {text})",
+                            separator = option.separator().inner().text()
+                        ),
+                    ),
+                ),
+                PlaceholderOption::Default(option) => deprecated_default_placeholder_option(
+                    option.span(),
+                    Edit::new(
+                        span,
+                        format!(
+                            "select_first([{text}, {value}])",
+                            value = option.value().inner().text()
+                        ),
+                    ),
+                ),
                 PlaceholderOption::TrueFalse(option) => {
-                    deprecated_true_false_placeholder_option(option.span())
+                    let (t, f) = option.values();
+                    deprecated_true_false_placeholder_option(
+                        option.span(),
+                        Edit::new(
+                            span,
+                            format!(
+                                "if {text} then {t} else {f}",
+                                t = t.inner().text(),
+                                f = f.inner().text()
+                            ),
+                        ),
+                    )
                 }
             };
             diagnostics.exceptable_add(
@@ -0,0 +1,152 @@
+//! A lint rule for checking the format of `disks` specifications.
+
+use wdl_analysis::Diagnostics;
+use wdl_analysis::VisitReason;
+use wdl_analysis::Visitor;
+use wdl_analysis::eval::v1::ConstantValue;
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::Diagnostic;
+use wdl_ast::Span;
+use wdl_ast::SyntaxKind;
+use wdl_ast::v1::TASK_REQUIREMENT_DISKS;
+use wdl_ast::v1::TaskDefinition;
+
+use crate::Rule;
+use crate::Tag;
+use crate::TagSet;
+use crate::util::parse_disk_spec;
+use crate::util::resource_items;
+use crate::util::task_evaluator;
+
+/// The identifier for the disks format rule.
+const ID: &str = "DisksFormat";
+
+/// Creates a diagnostic for a `disks` specification that could not be parsed.
+fn invalid_disk_spec(spec: &str, span: Span) -> Diagnostic {
+    Diagnostic::warning(format!("disk specification `{spec}` could not be parsed"))
+        .with_rule(ID)
+        .with_highlight(span)
+        .with_fix(
+            "specify a size with an optional unit, optionally preceded by a mount point (e.g., \
+             `10 GiB` or `/mnt/outputs 10 GiB`)",
+        )
+}
+
+/// Detects `disks` specifications that could not be parsed.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DisksFormatRule;
+
+impl Rule for DisksFormatRule {
+    fn id(&self) -> &'static str {
+        ID
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures that `disks` specifications are well-formed."
+    }
+
+    fn explanation(&self) -> &'static str {
+        "A `disks` specification is a size, with an optional storage unit that defaults to GiB, \
+         optionally preceded by an absolute mount point (e.g., `10 GiB` or `/mnt/outputs 500 \
+         GiB`). The legacy format of `local-disk <size> <type>` (e.g., `local-disk 100 SSD`) is \
+         also accepted. Specifications that cannot be parsed are either rejected by execution \
+         engines or silently ignored, leaving the task without the disk space it needs.
+
+         This rule checks the `disks` key of `runtime`, `requirements`, and `hints` sections \
+         whose values are literals or can be evaluated from constant declarations, including each element of \
+         an array of specifications."
+    }
+
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        echo "Hello"
+    >>>
+
+    requirements {
+        disks: "100 gigabytes"
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        echo "Hello"
+    >>>
+
+    requirements {
+        disks: "/mnt/outputs 100 GiB"
+    }
+}
+"#]
+    }
+
+    fn tags(&self) -> TagSet {
+        TagSet::new(&[Tag::Resources, Tag::Correctness])
+    }
+
+    fn exceptable_nodes(&self) -> Option<&'static [SyntaxKind]> {
+        Some(&[
+            SyntaxKind::VersionStatementNode,
+            SyntaxKind::TaskDefinitionNode,
+            SyntaxKind::RuntimeSectionNode,
+            SyntaxKind::RequirementsSectionNode,
+            SyntaxKind::TaskHintsSectionNode,
+        ])
+    }
+
+    fn related_rules(&self) -> &[&'static str] {
+        &["ResourceBounds", "RequiredResources"]
+    }
+}
+
+impl Visitor for DisksFormatRule {
+    fn reset(&mut self) {}
+
+    fn task_definition(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        task: &TaskDefinition,
+    ) {
+        if reason == VisitReason::Exit {
+            return;
+        }
+
+        let mut evaluator = task_evaluator(task);
+        for (name, expr, syntax) in resource_items(task, true) {
+            if name.text() != TASK_REQUIREMENT_DISKS {
+                continue;
+            }
+
+            let specs = match evaluator.evaluate(&expr) {
+                Some(ConstantValue::String(s)) => vec![s],
+                Some(ConstantValue::Array(elements)) => elements
+                    .iter()
+                    .filter_map(|e| match e {
+                        ConstantValue::String(s) => Some(s.clone()),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => continue,
+            };
+
+            for spec in specs {
+                if parse_disk_spec(&spec).is_none() {
+                    diagnostics.exceptable_add(
+                        invalid_disk_spec(&spec, expr.span()),
+                        syntax.clone(),
+                        &self.exceptable_nodes(),
+                    );
+                }
+            }
+        }
+    }
+}
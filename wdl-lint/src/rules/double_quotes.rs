@@ -3,14 +3,19 @@
 use wdl_analysis::Diagnostics;
 use wdl_analysis::VisitReason;
 use wdl_analysis::Visitor;
+use wdl_ast::Applicability;
 use wdl_ast::AstNode;
+use wdl_ast::AstToken;
 use wdl_ast::Diagnostic;
+use wdl_ast::Edit;
 use wdl_ast::Span;
 use wdl_ast::SyntaxElement;
 use wdl_ast::SyntaxKind;
 use wdl_ast::v1::Expr;
 use wdl_ast::v1::LiteralExpr;
+use wdl_ast::v1::LiteralString;
 use wdl_ast::v1::LiteralStringKind;
+use wdl_ast::v1::StringPart;
 
 use crate::Rule;
 use crate::Tag;
@@ -20,11 +25,59 @@ use crate::TagSet;
 const ID: &str = "DoubleQuotes";
 
 /// Creates a "use double quotes" diagnostic.
-fn use_double_quotes(span: Span) -> Diagnostic {
+fn use_double_quotes(span: Span, edits: Vec<Edit>) -> Diagnostic {
     Diagnostic::note("string defined with single quotes")
         .with_rule(ID)
         .with_highlight(span)
         .with_fix("change the string to use double quotes")
+        .with_edits(Applicability::Safe, edits)
+}
+
+/// Escapes the text of a single quoted string for use in a double quoted
+/// string.
+///
+/// Returns `None` if the text does not need to change.
+fn escape_for_double_quotes(text: &str) -> Option<String> {
+    if !text.contains(['"', '\\']) {
+        return None;
+    }
+
+    let mut escaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                // An escaped single quote no longer needs escaping
+                Some('\'') => escaped.push('\''),
+                Some(next) => {
+                    escaped.push('\\');
+                    escaped.push(next);
+                }
+                None => escaped.push('\\'),
+            },
+            '"' => escaped.push_str("\\\""),
+            c => escaped.push(c),
+        }
+    }
+
+    if escaped == text { None } else { Some(escaped) }
+}
+
+/// Gets the edits that convert a single quoted string to a double quoted
+/// string.
+fn double_quote_edits(s: &LiteralString) -> Vec<Edit> {
+    let span = s.span();
+    let mut edits = vec![Edit::new(Span::new(span.start(), 1), "\"")];
+    for part in s.parts() {
+        if let StringPart::Text(text) = part {
+            if let Some(escaped) = escape_for_double_quotes(text.text()) {
+                edits.push(Edit::new(text.span(), escaped));
+            }
+        }
+    }
+
+    edits.push(Edit::new(Span::new(span.end() - 1, 1), "\""));
+    edits
 }
 
 /// Detects strings that are not defined with double quotes.
@@ -46,6 +99,24 @@ impl Rule for DoubleQuotesRule {
          ensure consistency and avoid any confusion."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    String greeting = 'hello'
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    String greeting = "hello"
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Clarity, Tag::Style])
     }
@@ -81,7 +152,7 @@ impl Visitor for DoubleQuotesRule {
         if let Expr::Literal(LiteralExpr::String(s)) = expr {
             if s.kind() == LiteralStringKind::SingleQuoted {
                 diagnostics.exceptable_add(
-                    use_double_quotes(s.span()),
+                    use_double_quotes(s.span(), double_quote_edits(s)),
                     SyntaxElement::from(expr.inner().clone()),
                     &self.exceptable_nodes(),
                 );
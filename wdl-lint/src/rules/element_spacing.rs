@@ -98,6 +98,39 @@ impl Rule for ElementSpacingRule {
          individual elements may optionally be separated by a blank line."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+
+    input {
+        String name
+
+        String greeting
+    }
+    output {
+        String message = "~{greeting}, ~{name}!"
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    input {
+        String name
+        String greeting
+    }
+
+    output {
+        String message = "~{greeting}, ~{name}!"
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Spacing])
     }
@@ -4,8 +4,10 @@ use wdl_analysis::Diagnostics;
 use wdl_analysis::Document;
 use wdl_analysis::VisitReason;
 use wdl_analysis::Visitor;
+use wdl_ast::Applicability;
 use wdl_ast::AstNode;
 use wdl_ast::Diagnostic;
+use wdl_ast::Edit;
 use wdl_ast::Span;
 use wdl_ast::SupportedVersion;
 use wdl_ast::SyntaxKind;
@@ -24,10 +26,13 @@ fn missing_ending_newline(span: Span) -> Diagnostic {
         .with_rule(ID)
         .with_label("expected a newline to follow this", span)
         .with_fix("add a newline at the end of the file")
+        .with_edits(Applicability::Safe, [Edit::insert(span.end(), "\n")])
 }
 
 /// Creates a "multiple ending newline" diagnostic.
-fn multiple_ending_newline(span: Span, count: usize) -> Diagnostic {
+///
+/// The `extra` span is the span of the newlines to remove.
+fn multiple_ending_newline(span: Span, extra: Span, count: usize) -> Diagnostic {
     Diagnostic::note("multiple empty lines at the end of file")
         .with_rule(ID)
         .with_label(
@@ -39,6 +44,7 @@ fn multiple_ending_newline(span: Span, count: usize) -> Diagnostic {
             span,
         )
         .with_fix("remove all but one empty line at the end of the file")
+        .with_edits(Applicability::Safe, [Edit::delete(extra)])
 }
 
 /// Detects missing newline at the end of the document.
@@ -58,6 +64,29 @@ impl Rule for EndingNewlineRule {
         "The file should end with one and only one newline character to conform to POSIX standards. See https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap03.html#tag_03_206."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[
+            r#"version 1.2
+
+workflow hello {
+}"#,
+            r#"version 1.2
+
+workflow hello {
+}
+
+"#,
+        ]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Spacing, Tag::Style])
     }
@@ -108,8 +137,11 @@ impl Visitor for EndingNewlineRule {
                             // Since this rule can only be excepted in a document-wide fashion,
                             // if the rule is running we can directly add the diagnostic
                             // without checking for the exceptable nodes
+                            let last_newline_len =
+                                if last.text().ends_with("\r\n") { 2 } else { 1 };
                             diagnostics.add(multiple_ending_newline(
                                 Span::new(start + text.len(), len - text.len() - 1),
+                                Span::new(start + text.len(), len - text.len() - last_newline_len),
                                 extra,
                             ));
                         }
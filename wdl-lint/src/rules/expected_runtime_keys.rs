@@ -8,6 +8,9 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::OnceLock;
 
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
 use wdl_analysis::Diagnostics;
 use wdl_analysis::Document;
 use wdl_analysis::VisitReason;
@@ -43,6 +46,7 @@ use wdl_ast::version::V1;
 use crate::Rule;
 use crate::Tag;
 use crate::TagSet;
+use crate::config::parse_options;
 use crate::util::serialize_oxford_comma;
 
 /// The identifier for the runtime section rule.
@@ -249,9 +253,24 @@ fn report_missing_recommended_keys(
         .with_fix(fix)
 }
 
+/// The configurable options of the expected runtime keys rule.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExpectedRuntimeKeysOptions {
+    /// The keys that are recommended to be included in a `runtime` section.
+    ///
+    /// If `None`, the keys recommended by the specification are used.
+    pub recommended: Option<Vec<String>>,
+    /// The additional non-reserved keys that are permitted in a `runtime`
+    /// section.
+    pub allowed: Vec<String>,
+}
+
 /// Detects the use of deprecated, unknown, or missing runtime keys.
 #[derive(Debug, Default, Clone)]
 pub struct ExpectedRuntimeKeysRule {
+    /// The options of the rule.
+    options: ExpectedRuntimeKeysOptions,
     /// The detected version of the current document.
     version: Option<SupportedVersion>,
     /// The span of the first `runtime` section encountered within the current
@@ -266,6 +285,16 @@ pub struct ExpectedRuntimeKeysRule {
     non_reserved_keys: HashSet<TokenText>,
 }
 
+impl ExpectedRuntimeKeysRule {
+    /// Constructs a new expected runtime keys rule with the given options.
+    pub fn with_options(options: ExpectedRuntimeKeysOptions) -> Self {
+        Self {
+            options,
+            ..Default::default()
+        }
+    }
+}
+
 impl Rule for ExpectedRuntimeKeysRule {
     fn id(&self) -> &'static str {
         ID
@@ -292,7 +321,47 @@ impl Rule for ExpectedRuntimeKeysRule {
          section. As such, any non-reserved keys will be flagged for removal.
 
          For WDL v1.2 documents and later, this rule does not evaluate because `runtime` sections \
-         were deprecated in this version."
+         were deprecated in this version.
+
+         The recommended keys may be replaced with the `recommended` option and additional \
+         non-reserved keys may be permitted with the `allowed` option."
+    }
+
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.1
+
+task say_hello {
+    command <<<
+        echo "Hello"
+    >>>
+
+    runtime {
+        container: "ubuntu:22.04"
+        disk_size: "10 GiB"
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.1
+
+task say_hello {
+    command <<<
+        echo "Hello"
+    >>>
+
+    runtime {
+        container: "ubuntu:22.04"
+        cpu: 1
+        memory: "2 GiB"
+        gpu: false
+        disks: "10 GiB"
+        maxRetries: 0
+        returnCodes: 0
+    }
+}
+"#]
     }
 
     fn tags(&self) -> crate::TagSet {
@@ -309,6 +378,11 @@ impl Rule for ExpectedRuntimeKeysRule {
     fn related_rules(&self) -> &[&'static str] {
         &["DeprecatedObject", "DeprecatedPlaceholder"]
     }
+
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        *self = Self::with_options(parse_options(self.id(), options)?);
+        Ok(())
+    }
 }
 
 /// A utility method to parse the recommended keys from a static set of runtime
@@ -387,15 +461,26 @@ impl Visitor for ExpectedRuntimeKeysRule {
                         );
                     }
 
-                    let recommended_keys = match minor_version {
-                        V1::Zero => recommended_keys(keys_v1_0()),
-                        V1::One => recommended_keys(keys_v1_1()),
+                    let keys = match minor_version {
+                        V1::Zero => keys_v1_0(),
+                        V1::One => keys_v1_1(),
                         _ => return,
                     };
 
+                    let (recommended_keys, recommended_by) = match &self.options.recommended {
+                        Some(recommended) => (
+                            recommended.iter().map(String::as_str).collect::<Vec<_>>(),
+                            "the lint configuration",
+                        ),
+                        None => (
+                            recommended_keys(keys).map(|(key, _)| key).collect(),
+                            specification.as_str(),
+                        ),
+                    };
+
                     let missing_keys = recommended_keys
-                        .filter(|(key, _)| !self.encountered_keys.iter().any(|s| s.text() == *key))
-                        .map(|(key, _)| key)
+                        .into_iter()
+                        .filter(|key| !self.encountered_keys.iter().any(|s| s.text() == *key))
                         .collect::<Vec<_>>();
 
                     if !missing_keys.is_empty() {
@@ -403,7 +488,7 @@ impl Visitor for ExpectedRuntimeKeysRule {
                             report_missing_recommended_keys(
                                 missing_keys,
                                 runtime_span,
-                                &specification,
+                                recommended_by,
                             ),
                             SyntaxElement::from(runtime_node),
                             &self.exceptable_nodes(),
@@ -495,8 +580,11 @@ impl Visitor for ExpectedRuntimeKeysRule {
                     }
                     None => {
                         // If the key was _not_ found in the map, that means the
-                        // key was not one of the permitted values for WDL v1.1.
-                        self.non_reserved_keys.insert(key_name.hashable());
+                        // key was not one of the permitted values for WDL v1.1
+                        // (unless it was explicitly allowed by the options).
+                        if !self.options.allowed.iter().any(|k| k == key_name.text()) {
+                            self.non_reserved_keys.insert(key_name.hashable());
+                        }
                     }
                 }
             }
@@ -193,6 +193,26 @@ impl Rule for ExpressionSpacingRule {
          choose a more compact or a more spaced out expression."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow calculate {
+    Int total = 1+2*3
+    Boolean is_large = total>5
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow calculate {
+    Int total = 1 + 2 * 3
+    Boolean is_large = total > 5
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Spacing])
     }
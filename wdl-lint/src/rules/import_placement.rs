@@ -52,6 +52,26 @@ impl Rule for ImportPlacementRule {
          between the version and the first import statement."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+}
+
+import "lib.wdl"
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+import "lib.wdl"
+
+workflow hello {
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Clarity])
     }
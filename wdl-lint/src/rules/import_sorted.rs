@@ -4,9 +4,11 @@ use wdl_analysis::Diagnostics;
 use wdl_analysis::Document;
 use wdl_analysis::VisitReason;
 use wdl_analysis::Visitor;
+use wdl_ast::Applicability;
 use wdl_ast::AstNode;
 use wdl_ast::AstToken;
 use wdl_ast::Diagnostic;
+use wdl_ast::Edit;
 use wdl_ast::Span;
 use wdl_ast::SupportedVersion;
 use wdl_ast::SyntaxKind;
@@ -20,7 +22,12 @@ use crate::TagSet;
 const ID: &str = "ImportSorted";
 
 /// Creates an import not sorted diagnostic.
-fn import_not_sorted(span: Span, sorted_imports: String) -> Diagnostic {
+fn import_not_sorted(
+    span: Span,
+    sorted_imports: String,
+    applicability: Applicability,
+    edits: Vec<Edit>,
+) -> Diagnostic {
     Diagnostic::note("imports are not sorted lexicographically")
         .with_rule(ID)
         .with_label("imports must be sorted", span)
@@ -28,6 +35,7 @@ fn import_not_sorted(span: Span, sorted_imports: String) -> Diagnostic {
             "sort the imports lexicographically:\n{}",
             sorted_imports
         ))
+        .with_edits(applicability, edits)
 }
 /// Creates an improper comment diagnostic.
 fn improper_comment(span: Span) -> Diagnostic {
@@ -57,6 +65,28 @@ impl Rule for ImportSortedRule {
          are permitted within an import statement."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+import "utils.wdl"
+import "lib.wdl"
+
+workflow hello {
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+import "lib.wdl"
+import "utils.wdl"
+
+workflow hello {
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Style, Tag::Clarity, Tag::Sorting])
     }
@@ -125,6 +155,27 @@ impl Visitor for ImportSortedRule {
                 .expect("node should have a first token")
                 .text_range()
                 .into();
+            // Each import is replaced with the import that sorts into its position;
+            // comments between or within the imports are left in place, so the edits
+            // are only safe if there are none
+            let first = imports.first().expect("should have imports").text_range();
+            let last = imports.last().expect("should have imports").text_range();
+            let has_comments = doc
+                .root()
+                .inner()
+                .descendants_with_tokens()
+                .filter(|e| e.kind() == SyntaxKind::Comment)
+                .any(|e| {
+                    e.text_range().start() > first.start() && e.text_range().end() < last.end()
+                });
+
+            let edits = imports
+                .iter()
+                .zip(&sorted_imports)
+                .filter(|(import, sorted)| import != sorted)
+                .map(|(import, sorted)| Edit::new(import.text_range(), sorted.text().to_string()))
+                .collect();
+
             diagnostics.add(import_not_sorted(
                 span,
                 sorted_imports
@@ -132,6 +183,12 @@ impl Visitor for ImportSortedRule {
                     .map(|i| i.text().to_string())
                     .collect::<Vec<_>>()
                     .join("\n"),
+                if has_comments {
+                    Applicability::Unsafe
+                } else {
+                    Applicability::Safe
+                },
+                edits,
             ));
         }
     }
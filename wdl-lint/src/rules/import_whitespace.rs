@@ -69,6 +69,29 @@ impl Rule for ImportWhitespaceRule {
          understand."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+import  "lib.wdl"
+
+import "utils.wdl"  as  utils
+
+workflow hello {
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+import "lib.wdl"
+import "utils.wdl" as utils
+
+workflow hello {
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Style, Tag::Clarity, Tag::Spacing])
     }
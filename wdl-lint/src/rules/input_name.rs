@@ -69,6 +69,32 @@ impl Rule for InputNameRule {
          content of an input. Input names should be at least 3 characters long."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    input {
+        String in_name
+        String input_greeting
+        Int n
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    input {
+        String name
+        String greeting
+        Int count
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Naming])
     }
@@ -5,9 +5,11 @@ use std::cmp::Ordering;
 use wdl_analysis::Diagnostics;
 use wdl_analysis::VisitReason;
 use wdl_analysis::Visitor;
+use wdl_ast::Applicability;
 use wdl_ast::AstNode;
 use wdl_ast::AstToken;
 use wdl_ast::Diagnostic;
+use wdl_ast::Edit;
 use wdl_ast::Span;
 use wdl_ast::SyntaxElement;
 use wdl_ast::SyntaxKind;
@@ -22,11 +24,17 @@ use crate::TagSet;
 const ID: &str = "InputSorted";
 
 /// Creates a "input not sorted" diagnostic.
-fn input_not_sorted(span: Span, sorted_inputs: String) -> Diagnostic {
+fn input_not_sorted(
+    span: Span,
+    sorted_inputs: String,
+    applicability: Applicability,
+    edits: Vec<Edit>,
+) -> Diagnostic {
     Diagnostic::note("input not sorted")
         .with_rule(ID)
         .with_label("input section must be sorted".to_string(), span)
         .with_fix(format!("sort input statements as: \n{}", sorted_inputs))
+        .with_edits(applicability, edits)
 }
 
 /// Define an ordering for declarations.
@@ -208,6 +216,34 @@ impl Rule for InputSortedRule {
          the developer for final order of inputs of the same type."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow align {
+    input {
+        String sample_name
+        File reads
+        Int threads = 1
+        String? read_group
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow align {
+    input {
+        File reads
+        String sample_name
+        String? read_group
+        Int threads = 1
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Style, Tag::Clarity, Tag::Sorting])
     }
@@ -249,17 +285,32 @@ impl Visitor for InputSortedRule {
             .into_iter()
             .map(|decl| decl.inner().text().to_string() + "\n")
             .collect::<String>();
-        let mut errors = 0;
-
-        decls
+        // Each declaration is replaced with the declaration that sorts into its
+        // position
+        let edits = decls
             .into_iter()
             .zip(sorted_decls)
-            .for_each(|(decl, sorted_decl)| {
-                if decl != sorted_decl {
-                    errors += 1;
-                }
-            });
-        if errors > 0 {
+            .filter(|(decl, sorted_decl)| decl != sorted_decl)
+            .map(|(decl, sorted_decl)| {
+                Edit::new(
+                    decl.inner().text_range(),
+                    sorted_decl.inner().text().to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        if !edits.is_empty() {
+            // Comments between declarations are left in place, so the edits are
+            // only safe if there are none
+            let applicability = if input
+                .inner()
+                .descendants_with_tokens()
+                .any(|e| e.kind() == SyntaxKind::Comment)
+            {
+                Applicability::Unsafe
+            } else {
+                Applicability::Safe
+            };
+
             let span = input
                 .inner()
                 .first_token()
@@ -267,7 +318,7 @@ impl Visitor for InputSortedRule {
                 .text_range()
                 .into();
             diagnostics.exceptable_add(
-                input_not_sorted(span, input_string),
+                input_not_sorted(span, input_string, applicability, edits),
                 SyntaxElement::from(input.inner().clone()),
                 &self.exceptable_nodes(),
             );
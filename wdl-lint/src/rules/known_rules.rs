@@ -65,6 +65,24 @@ impl Rule for KnownRulesRule {
          mistakes."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+#@ except: NotARule
+workflow hello {
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+#@ except: MetaSections
+workflow hello {
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         // TODO: Is there another tag that would be appropriate?
         TagSet::new(&[Tag::Clarity])
@@ -1,5 +1,8 @@
 //! Ensures that lines do not exceed a certain width.
 
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
 use wdl_analysis::Diagnostics;
 use wdl_analysis::VisitReason;
 use wdl_analysis::Visitor;
@@ -13,10 +16,30 @@ use wdl_ast::v1;
 use crate::Rule;
 use crate::Tag;
 use crate::TagSet;
+use crate::config::parse_options;
 
 /// The identifier for the line width rule.
 const ID: &str = "LineWidth";
 
+/// The default maximum width of a line.
+const DEFAULT_MAX_WIDTH: usize = 90;
+
+/// The configurable options of the line width rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LineWidthOptions {
+    /// The maximum width of a line.
+    pub max_width: usize,
+}
+
+impl Default for LineWidthOptions {
+    fn default() -> Self {
+        Self {
+            max_width: DEFAULT_MAX_WIDTH,
+        }
+    }
+}
+
 /// Creates a diagnostic for when a line exceeds the maximum width.
 fn line_too_long(span: Span, max_width: usize) -> Diagnostic {
     Diagnostic::note(format!("line exceeds maximum width of {}", max_width))
@@ -45,6 +68,11 @@ impl LineWidthRule {
         }
     }
 
+    /// Constructs a new line width rule with the given options.
+    pub fn with_options(options: LineWidthOptions) -> Self {
+        Self::new(options.max_width)
+    }
+
     /// Detects lines that exceed a certain width.
     fn detect_line_too_long(
         &mut self,
@@ -82,7 +110,7 @@ impl LineWidthRule {
 impl Default for LineWidthRule {
     fn default() -> Self {
         Self {
-            max_width: 90,
+            max_width: DEFAULT_MAX_WIDTH,
             previous_newline_offset: None,
             ignored_section: false,
         }
@@ -101,7 +129,26 @@ impl Rule for LineWidthRule {
     fn explanation(&self) -> &'static str {
         "Lines should not exceed a certain width to make it easier to read and understand the \
          code. Code within the either the meta or parameter meta sections is not checked. Comments \
-         are included in the line width check. The current maximum width is 90 characters."
+         are included in the line width check. The default maximum width is 90 characters and \
+         may be changed with the `max_width` option."
+    }
+
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    String greeting = "This greeting is far too long to fit comfortably on a single line of a WDL document"
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    String greeting = "This greeting fits on a single line"
+}
+"#]
     }
 
     fn tags(&self) -> TagSet {
@@ -115,11 +162,16 @@ impl Rule for LineWidthRule {
     fn related_rules(&self) -> &[&'static str] {
         &["ExpressionSpacing"]
     }
+
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        *self = Self::with_options(parse_options(self.id(), options)?);
+        Ok(())
+    }
 }
 
 impl Visitor for LineWidthRule {
     fn reset(&mut self) {
-        *self = Self::default();
+        *self = Self::new(self.max_width);
     }
 
     fn whitespace(&mut self, diagnostics: &mut Diagnostics, whitespace: &Whitespace) {
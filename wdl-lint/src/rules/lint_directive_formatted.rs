@@ -88,6 +88,31 @@ impl Rule for LintDirectiveFormattedRule {
          For example, `#@ except: LintDirectiveFormatted`."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[
+            r#"version 1.2
+
+#@except: MetaSections
+workflow hello {
+}
+"#,
+            r#"version 1.2
+
+workflow hello {  #@ except: MetaSections
+}
+"#,
+        ]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+#@ except: MetaSections
+workflow hello {
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Clarity, Tag::Correctness])
     }
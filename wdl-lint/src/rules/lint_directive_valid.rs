@@ -78,6 +78,30 @@ impl Rule for LintDirectiveValidRule {
          they are in the correct location."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    #@ except: OutputSection
+    input {
+        String name
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+#@ except: OutputSection
+workflow hello {
+    input {
+        String name
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Clarity, Tag::Correctness])
     }
@@ -124,6 +124,53 @@ impl Rule for MatchingOutputMetaRule<'_> {
          is up to the developer to decide). No extraneous `meta.outputs` entries are allowed."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    meta {
+        description: "Says hello"
+        outputs: {
+            greeting: "The greeting",
+        }
+    }
+
+    command <<<
+        echo "Hello"
+    >>>
+
+    output {
+        String greeting = read_string(stdout())
+        String name = "world"
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    meta {
+        description: "Says hello"
+        outputs: {
+            greeting: "The greeting",
+            name: "The name that was greeted",
+        }
+    }
+
+    command <<<
+        echo "Hello"
+    >>>
+
+    output {
+        String greeting = read_string(stdout())
+        String name = "world"
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Completeness])
     }
@@ -64,6 +64,29 @@ impl Rule for MetaDescriptionRule {
          `help` key."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    meta {
+        author: "Jane Doe"
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    meta {
+        description: "Says hello"
+        author: "Jane Doe"
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Completeness])
     }
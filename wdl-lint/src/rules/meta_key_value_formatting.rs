@@ -86,6 +86,33 @@ impl Rule for MetaKeyValueFormattingRule {
          the closing bracket on its own line at the same indentation level of the key."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    meta {
+        description: "Says hello"
+        authors: ["Jane Doe", "John Doe"]
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    meta {
+        description: "Says hello"
+        authors: [
+            "Jane Doe",
+            "John Doe",
+        ]
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Style])
     }
@@ -111,6 +111,36 @@ impl Rule for MetaSectionsRule {
          skip the `parameter_meta` section."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    input {
+        String name
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    meta {
+        description: "Says hello"
+    }
+
+    parameter_meta {
+        name: "The name to greet"
+    }
+
+    input {
+        String name
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Completeness, Tag::Clarity])
     }
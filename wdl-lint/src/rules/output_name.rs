@@ -69,6 +69,29 @@ impl Rule for OutputNameRule {
          content of an output. Output names should be at least 3 characters long."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    output {
+        String out = "Hello"
+        String output_greeting = "Hello"
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    output {
+        String greeting = "Hello"
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Naming])
     }
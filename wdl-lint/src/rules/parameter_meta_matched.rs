@@ -115,6 +115,36 @@ impl Rule for ParameterMetaMatchedRule {
          the `parameter_meta` block."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    parameter_meta {
+        greeting: "The greeting to use"
+    }
+
+    input {
+        String name
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    parameter_meta {
+        name: "The name to greet"
+    }
+
+    input {
+        String name
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Completeness, Tag::Sorting])
     }
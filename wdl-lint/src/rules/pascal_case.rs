@@ -1,8 +1,8 @@
 //! A lint rule that ensures structs are defined with pascal case names.
 
-use convert_case::Boundary;
-use convert_case::Case;
-use convert_case::Converter;
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
 use wdl_analysis::Diagnostics;
 use wdl_analysis::VisitReason;
 use wdl_analysis::Visitor;
@@ -17,21 +17,54 @@ use wdl_ast::v1::StructDefinition;
 use crate::Rule;
 use crate::Tag;
 use crate::TagSet;
+use crate::config::NamingCase;
+use crate::config::parse_options;
 
 /// The identifier for the pascal case rule.
 const ID: &str = "PascalCase";
 
 /// Creates a "use pascal case" diagnostic.
-fn use_pascal_case(name: &str, properly_cased_name: &str, span: Span) -> Diagnostic {
-    Diagnostic::warning(format!("struct name `{name}` is not PascalCase"))
+fn use_pascal_case(
+    case: NamingCase,
+    name: &str,
+    properly_cased_name: &str,
+    span: Span,
+) -> Diagnostic {
+    Diagnostic::warning(format!("struct name `{name}` is not {case}"))
         .with_rule(ID)
-        .with_label("this name must be PascalCase", span)
+        .with_label(format!("this name must be {case}"), span)
         .with_fix(format!("replace `{name}` with `{properly_cased_name}`"))
 }
 
+/// The configurable options of the pascal case rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PascalCaseOptions {
+    /// The case that struct names are expected to be in.
+    pub case: NamingCase,
+}
+
+impl Default for PascalCaseOptions {
+    fn default() -> Self {
+        Self {
+            case: NamingCase::Pascal,
+        }
+    }
+}
+
 /// Detects structs defined without a pascal case name.
 #[derive(Default, Debug, Clone, Copy)]
-pub struct PascalCaseRule;
+pub struct PascalCaseRule {
+    /// The options of the rule.
+    options: PascalCaseOptions,
+}
+
+impl PascalCaseRule {
+    /// Constructs a new pascal case rule with the given options.
+    pub fn with_options(options: PascalCaseOptions) -> Self {
+        Self { options }
+    }
+}
 
 impl Rule for PascalCaseRule {
     fn id(&self) -> &'static str {
@@ -44,7 +77,26 @@ impl Rule for PascalCaseRule {
 
     fn explanation(&self) -> &'static str {
         "Struct names should be in PascalCase. Maintaining a consistent naming convention makes \
-         the code easier to read and understand."
+         the code easier to read and understand. The expected case may be changed with the `case` \
+         option."
+    }
+
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+struct sample_info {
+    String name
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+struct SampleInfo {
+    String name
+}
+"#]
     }
 
     fn tags(&self) -> TagSet {
@@ -61,24 +113,27 @@ impl Rule for PascalCaseRule {
     fn related_rules(&self) -> &[&'static str] {
         &["SnakeCase"]
     }
+
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        *self = Self::with_options(parse_options(self.id(), options)?);
+        Ok(())
+    }
 }
 
-/// Checks if the given name is pascal case, and if not adds a warning to the
-/// diagnostics.
+/// Checks if the given name is in the expected case, and if not adds a warning
+/// to the diagnostics.
 fn check_name(
+    case: NamingCase,
     name: &str,
     span: Span,
     diagnostics: &mut Diagnostics,
     element: SyntaxElement,
     exceptable_nodes: &Option<&'static [SyntaxKind]>,
 ) {
-    let converter = Converter::new()
-        .remove_boundaries(&[Boundary::DIGIT_LOWER, Boundary::LOWER_DIGIT])
-        .to_case(Case::Pascal);
-    let properly_cased_name = converter.convert(name);
+    let properly_cased_name = case.convert(name);
     if name != properly_cased_name {
         diagnostics.exceptable_add(
-            use_pascal_case(name, &properly_cased_name, span),
+            use_pascal_case(case, name, &properly_cased_name, span),
             element,
             exceptable_nodes,
         );
@@ -87,7 +142,7 @@ fn check_name(
 
 impl Visitor for PascalCaseRule {
     fn reset(&mut self) {
-        *self = Self;
+        *self = Self::with_options(self.options);
     }
 
     fn struct_definition(
@@ -102,6 +157,7 @@ impl Visitor for PascalCaseRule {
 
         let name = def.name();
         check_name(
+            self.options.case,
             name.text(),
             name.span(),
             diagnostics,
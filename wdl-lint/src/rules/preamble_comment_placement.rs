@@ -51,6 +51,25 @@ impl Rule for PreambleCommentPlacementRule {
          ensures that double-pound comments (`##`) are not used after the version statement."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+## Says hello
+workflow hello {
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"## Says hello
+
+version 1.2
+
+workflow hello {
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Clarity])
     }
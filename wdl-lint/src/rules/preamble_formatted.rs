@@ -144,6 +144,26 @@ impl Rule for PreambleFormattedRule {
          there should be no comments or whitespace before the version declaration."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"# This comment is not a preamble comment
+##This preamble comment is missing a space
+version 1.2
+
+workflow hello {
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"## This is a preamble comment
+
+version 1.2
+
+workflow hello {
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Spacing, Tag::Style, Tag::Clarity])
     }
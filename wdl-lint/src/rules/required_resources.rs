@@ -0,0 +1,195 @@
+//! A lint rule for requiring resource keys in tasks.
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use wdl_analysis::Diagnostics;
+use wdl_analysis::VisitReason;
+use wdl_analysis::Visitor;
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::Diagnostic;
+use wdl_ast::Span;
+use wdl_ast::SyntaxElement;
+use wdl_ast::SyntaxKind;
+use wdl_ast::v1::TASK_REQUIREMENT_CONTAINER;
+use wdl_ast::v1::TASK_REQUIREMENT_CONTAINER_ALIAS;
+use wdl_ast::v1::TASK_REQUIREMENT_MAX_RETRIES;
+use wdl_ast::v1::TASK_REQUIREMENT_MAX_RETRIES_ALIAS;
+use wdl_ast::v1::TASK_REQUIREMENT_RETURN_CODES;
+use wdl_ast::v1::TASK_REQUIREMENT_RETURN_CODES_ALIAS;
+use wdl_ast::v1::TaskDefinition;
+
+use crate::Rule;
+use crate::Tag;
+use crate::TagSet;
+use crate::config::parse_options;
+use crate::util::resource_items;
+use crate::util::serialize_oxford_comma;
+
+/// The identifier for the required resources rule.
+const ID: &str = "RequiredResources";
+
+/// Creates a diagnostic for a task that is missing required resource keys.
+fn missing_resources(task: &str, missing: &[&str], span: Span) -> Diagnostic {
+    let keys: Vec<_> = missing.iter().map(|k| format!("`{k}`")).collect();
+    let keys = serialize_oxford_comma(&keys).expect("should have at least one key");
+    Diagnostic::warning(format!(
+        "task `{task}` does not specify the required {keys} key{s}",
+        s = if missing.len() == 1 { "" } else { "s" }
+    ))
+    .with_rule(ID)
+    .with_highlight(span)
+    .with_fix(format!(
+        "add {keys} to the task's `runtime` or `requirements` section"
+    ))
+}
+
+/// Gets the canonical name of a `runtime` or `requirements` key.
+fn canonical_key(key: &str) -> &str {
+    match key {
+        TASK_REQUIREMENT_CONTAINER_ALIAS => TASK_REQUIREMENT_CONTAINER,
+        TASK_REQUIREMENT_MAX_RETRIES_ALIAS => TASK_REQUIREMENT_MAX_RETRIES,
+        TASK_REQUIREMENT_RETURN_CODES_ALIAS => TASK_REQUIREMENT_RETURN_CODES,
+        key => key,
+    }
+}
+
+/// The configurable options of the required resources rule.
+///
+/// By default, no keys are required.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RequiredResourcesOptions {
+    /// The keys every task must specify in its `runtime` or `requirements`
+    /// section (e.g. `cpu` and `memory`).
+    pub required: Vec<String>,
+}
+
+/// Detects tasks that do not specify required resource keys.
+#[derive(Debug, Default, Clone)]
+pub struct RequiredResourcesRule {
+    /// The options of the rule.
+    options: RequiredResourcesOptions,
+}
+
+impl RequiredResourcesRule {
+    /// Constructs a new required resources rule with the given options.
+    pub fn with_options(options: RequiredResourcesOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl Rule for RequiredResourcesRule {
+    fn id(&self) -> &'static str {
+        ID
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures that tasks specify the configured resource keys."
+    }
+
+    fn explanation(&self) -> &'static str {
+        "Tasks that do not request resources such as `memory` receive an execution engine's \
+         defaults, which may be too small for the task (or needlessly large). Teams often require \
+         every task to state its resource needs explicitly.
+
+         This rule checks that every task specifies the keys given by the `required` option in \
+         its `runtime` or `requirements` section; aliases such as `docker` for `container` and \
+         `maxRetries` for `max_retries` are accepted. No keys are required by default."
+    }
+
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        echo "Hello"
+    >>>
+
+    requirements {
+        container: "ubuntu:22.04"
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        echo "Hello"
+    >>>
+
+    requirements {
+        container: "ubuntu:22.04"
+        memory: "2 GiB"
+    }
+}
+"#]
+    }
+
+    fn example_options(&self) -> Option<&'static str> {
+        Some(r#"required = ["memory"]"#)
+    }
+
+    fn tags(&self) -> TagSet {
+        TagSet::new(&[Tag::Resources, Tag::Completeness])
+    }
+
+    fn exceptable_nodes(&self) -> Option<&'static [SyntaxKind]> {
+        Some(&[
+            SyntaxKind::VersionStatementNode,
+            SyntaxKind::TaskDefinitionNode,
+        ])
+    }
+
+    fn related_rules(&self) -> &[&'static str] {
+        &["ResourceBounds", "ExpectedRuntimeKeys", "RuntimeSection"]
+    }
+
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        *self = Self::with_options(parse_options(self.id(), options)?);
+        Ok(())
+    }
+}
+
+impl Visitor for RequiredResourcesRule {
+    fn reset(&mut self) {}
+
+    fn task_definition(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        task: &TaskDefinition,
+    ) {
+        if reason == VisitReason::Exit || self.options.required.is_empty() {
+            return;
+        }
+
+        let items = resource_items(task, false);
+        let missing: Vec<_> = self
+            .options
+            .required
+            .iter()
+            .map(|k| k.as_str())
+            .filter(|required| {
+                !items
+                    .iter()
+                    .any(|(name, ..)| canonical_key(name.text()) == canonical_key(required))
+            })
+            .collect();
+
+        if missing.is_empty() {
+            return;
+        }
+
+        let name = task.name();
+        diagnostics.exceptable_add(
+            missing_resources(name.text(), &missing, name.span()),
+            SyntaxElement::from(task.inner().clone()),
+            &self.exceptable_nodes(),
+        );
+    }
+}
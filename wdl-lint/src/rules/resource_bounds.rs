@@ -0,0 +1,408 @@
+//! A lint rule for checking task resource requests against bounds.
+
+use anyhow::Context;
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use wdl_analysis::Diagnostics;
+use wdl_analysis::VisitReason;
+use wdl_analysis::Visitor;
+use wdl_analysis::eval::v1::ConstantValue;
+use wdl_analysis::units::StorageUnit;
+use wdl_analysis::units::convert_unit_string;
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::Diagnostic;
+use wdl_ast::Span;
+use wdl_ast::SyntaxKind;
+use wdl_ast::v1::TASK_REQUIREMENT_CPU;
+use wdl_ast::v1::TASK_REQUIREMENT_DISKS;
+use wdl_ast::v1::TASK_REQUIREMENT_GPU;
+use wdl_ast::v1::TASK_REQUIREMENT_MAX_RETRIES;
+use wdl_ast::v1::TASK_REQUIREMENT_MAX_RETRIES_ALIAS;
+use wdl_ast::v1::TASK_REQUIREMENT_MEMORY;
+use wdl_ast::v1::TaskDefinition;
+
+use crate::Rule;
+use crate::Tag;
+use crate::TagSet;
+use crate::config::parse_options;
+use crate::util::parse_disk_spec;
+use crate::util::resource_items;
+use crate::util::task_evaluator;
+
+/// The identifier for the resource bounds rule.
+const ID: &str = "ResourceBounds";
+
+/// Creates a diagnostic for a resource request that is not positive.
+fn not_positive(key: &str, value: &str, span: Span) -> Diagnostic {
+    Diagnostic::warning(format!(
+        "`{key}` requests `{value}`, which is not greater than zero"
+    ))
+    .with_rule(ID)
+    .with_highlight(span)
+    .with_fix(format!("request a positive `{key}` value"))
+}
+
+/// Creates a diagnostic for a resource request that is negative.
+fn negative(key: &str, value: i64, span: Span) -> Diagnostic {
+    Diagnostic::warning(format!("`{key}` requests `{value}`, which is negative"))
+        .with_rule(ID)
+        .with_highlight(span)
+        .with_fix(format!("request a `{key}` value of zero or more"))
+}
+
+/// Creates a diagnostic for a resource request below the configured minimum.
+fn below_minimum(key: &str, value: &str, min: &str, span: Span) -> Diagnostic {
+    Diagnostic::warning(format!(
+        "`{key}` requests `{value}`, which is less than the minimum of `{min}`"
+    ))
+    .with_rule(ID)
+    .with_highlight(span)
+    .with_fix(format!("request at least `{min}` for `{key}`"))
+}
+
+/// Creates a diagnostic for a resource request above the configured maximum.
+fn above_maximum(key: &str, value: &str, max: &str, span: Span) -> Diagnostic {
+    Diagnostic::warning(format!(
+        "`{key}` requests `{value}`, which exceeds the maximum of `{max}`"
+    ))
+    .with_rule(ID)
+    .with_highlight(span)
+    .with_fix(format!("request at most `{max}` for `{key}`"))
+}
+
+/// Parses a storage bound (e.g. `1 TiB`) into bytes.
+fn parse_bound(name: &str, bound: Option<&str>) -> Result<Option<u64>> {
+    bound
+        .map(|b| convert_unit_string(b).with_context(|| format!("invalid `{name}` value `{b}`")))
+        .transpose()
+}
+
+/// The configurable options of the resource bounds rule.
+///
+/// Memory and disk bounds are storage sizes with a unit (e.g. `512 GiB`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ResourceBoundsOptions {
+    /// The minimum number of CPUs a task may request.
+    pub min_cpu: Option<f64>,
+    /// The maximum number of CPUs a task may request.
+    pub max_cpu: Option<f64>,
+    /// The minimum amount of memory a task may request.
+    pub min_memory: Option<String>,
+    /// The maximum amount of memory a task may request.
+    pub max_memory: Option<String>,
+    /// The minimum size of a disk a task may request.
+    pub min_disks: Option<String>,
+    /// The maximum size of a disk a task may request.
+    pub max_disks: Option<String>,
+    /// The maximum number of GPUs a task may request.
+    pub max_gpu: Option<i64>,
+    /// The maximum number of retries a task may request.
+    pub max_retries: Option<i64>,
+}
+
+impl Default for ResourceBoundsOptions {
+    fn default() -> Self {
+        Self {
+            min_cpu: None,
+            max_cpu: Some(128.0),
+            min_memory: None,
+            max_memory: Some(String::from("1 TiB")),
+            min_disks: None,
+            max_disks: Some(String::from("16 TiB")),
+            max_gpu: Some(8),
+            max_retries: Some(10),
+        }
+    }
+}
+
+/// Checks constant task resource requests against configurable bounds.
+#[derive(Debug, Clone)]
+pub struct ResourceBoundsRule {
+    /// The options of the rule.
+    options: ResourceBoundsOptions,
+    /// The minimum memory in bytes.
+    min_memory: Option<u64>,
+    /// The maximum memory in bytes.
+    max_memory: Option<u64>,
+    /// The minimum disk size in bytes.
+    min_disks: Option<u64>,
+    /// The maximum disk size in bytes.
+    max_disks: Option<u64>,
+}
+
+impl Default for ResourceBoundsRule {
+    fn default() -> Self {
+        Self::with_options(ResourceBoundsOptions::default())
+            .expect("default options should be valid")
+    }
+}
+
+impl ResourceBoundsRule {
+    /// Constructs a new resource bounds rule with the given options.
+    ///
+    /// Returns an error if a memory or disk bound is not a valid storage size.
+    pub fn with_options(options: ResourceBoundsOptions) -> Result<Self> {
+        Ok(Self {
+            min_memory: parse_bound("min_memory", options.min_memory.as_deref())?,
+            max_memory: parse_bound("max_memory", options.max_memory.as_deref())?,
+            min_disks: parse_bound("min_disks", options.min_disks.as_deref())?,
+            max_disks: parse_bound("max_disks", options.max_disks.as_deref())?,
+            options,
+        })
+    }
+
+    /// Checks a storage request in bytes against the given bounds.
+    fn check_storage(
+        &self,
+        key: &str,
+        bytes: u64,
+        display: &str,
+        min: (Option<u64>, Option<&String>),
+        max: (Option<u64>, Option<&String>),
+        span: Span,
+    ) -> Option<Diagnostic> {
+        if bytes == 0 {
+            return Some(not_positive(key, display, span));
+        }
+
+        if let (Some(min), Some(text)) = min {
+            if bytes < min {
+                return Some(below_minimum(key, display, text, span));
+            }
+        }
+
+        if let (Some(max), Some(text)) = max {
+            if bytes > max {
+                return Some(above_maximum(key, display, text, span));
+            }
+        }
+
+        None
+    }
+
+    /// Checks the constant value of a resource request.
+    fn check(&self, key: &str, value: &ConstantValue, span: Span) -> Vec<Diagnostic> {
+        let options = &self.options;
+        let mut diagnostics = Vec::new();
+        match key {
+            TASK_REQUIREMENT_CPU => {
+                let Some(cpu) = value.as_float() else {
+                    return diagnostics;
+                };
+
+                let display = cpu.to_string();
+                if cpu <= 0.0 {
+                    diagnostics.push(not_positive(key, &display, span));
+                } else if let Some(min) = options.min_cpu.filter(|min| cpu < *min) {
+                    diagnostics.push(below_minimum(key, &display, &min.to_string(), span));
+                } else if let Some(max) = options.max_cpu.filter(|max| cpu > *max) {
+                    diagnostics.push(above_maximum(key, &display, &max.to_string(), span));
+                }
+            }
+            TASK_REQUIREMENT_MEMORY => {
+                let (bytes, display) = match value {
+                    ConstantValue::Integer(v) => match u64::try_from(*v) {
+                        Ok(bytes) => (bytes, format!("{v} B")),
+                        Err(_) => {
+                            diagnostics.push(not_positive(key, &v.to_string(), span));
+                            return diagnostics;
+                        }
+                    },
+                    ConstantValue::String(s) => match convert_unit_string(s) {
+                        Some(bytes) => (bytes, s.to_string()),
+                        // Invalid memory strings are reported by analysis
+                        None => return diagnostics,
+                    },
+                    _ => return diagnostics,
+                };
+
+                diagnostics.extend(self.check_storage(
+                    key,
+                    bytes,
+                    &display,
+                    (self.min_memory, options.min_memory.as_ref()),
+                    (self.max_memory, options.max_memory.as_ref()),
+                    span,
+                ));
+            }
+            TASK_REQUIREMENT_DISKS => {
+                let specs: Vec<_> = match value {
+                    ConstantValue::Integer(v) => {
+                        match u64::try_from(*v)
+                            .ok()
+                            .and_then(|v| StorageUnit::Gibibytes.bytes(v))
+                        {
+                            Some(bytes) => vec![(bytes, format!("{v} GiB"))],
+                            None => {
+                                diagnostics.push(not_positive(key, &v.to_string(), span));
+                                return diagnostics;
+                            }
+                        }
+                    }
+                    ConstantValue::String(s) => parse_disk_spec(s)
+                        .map(|bytes| (bytes, s.to_string()))
+                        .into_iter()
+                        .collect(),
+                    ConstantValue::Array(elements) => elements
+                        .iter()
+                        .filter_map(|e| e.as_string())
+                        .filter_map(|s| parse_disk_spec(s).map(|bytes| (bytes, s.to_string())))
+                        .collect(),
+                    _ => return diagnostics,
+                };
+
+                for (bytes, display) in specs {
+                    diagnostics.extend(self.check_storage(
+                        key,
+                        bytes,
+                        &display,
+                        (self.min_disks, options.min_disks.as_ref()),
+                        (self.max_disks, options.max_disks.as_ref()),
+                        span,
+                    ));
+                }
+            }
+            TASK_REQUIREMENT_GPU
+            | TASK_REQUIREMENT_MAX_RETRIES
+            | TASK_REQUIREMENT_MAX_RETRIES_ALIAS => {
+                let Some(count) = value.as_integer() else {
+                    return diagnostics;
+                };
+
+                let max = if key == TASK_REQUIREMENT_GPU {
+                    options.max_gpu
+                } else {
+                    options.max_retries
+                };
+
+                if count < 0 {
+                    diagnostics.push(negative(key, count, span));
+                } else if let Some(max) = max.filter(|max| count > *max) {
+                    diagnostics.push(above_maximum(
+                        key,
+                        &count.to_string(),
+                        &max.to_string(),
+                        span,
+                    ));
+                }
+            }
+            _ => {}
+        }
+
+        diagnostics
+    }
+}
+
+impl Rule for ResourceBoundsRule {
+    fn id(&self) -> &'static str {
+        ID
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures that tasks request resources within configured bounds."
+    }
+
+    fn explanation(&self) -> &'static str {
+        "Requesting too few resources causes a task to fail or run slowly, while requesting too \
+         many wastes compute or makes the task impossible to schedule; requests such as `cpu: 0` \
+         or `memory: \"4000 GB\"` are almost always mistakes.
+
+         This rule checks the `cpu`, `memory`, `disks`, `gpu`, and `max_retries` (or \
+         `maxRetries`) keys of `runtime`, `requirements`, and `hints` sections whose values are \
+         literals or can be evaluated from constant declarations; a `gpu` value is checked only \
+         when it is a count. Inputs are assumed to have their default \
+         values. Requests that are not positive (or, for counts, that are negative) are always \
+         flagged; the bounds are configured with the `min_cpu`, `max_cpu`, `min_memory`, \
+         `max_memory`, `min_disks`, `max_disks`, `max_gpu`, and `max_retries` options. Memory and \
+         disk bounds are storage sizes such as `512 GiB`.
+
+         By default, at most 128 CPUs, 1 TiB of memory, 16 TiB per disk, 8 GPUs, and 10 retries \
+         may be requested."
+    }
+
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        echo "Hello"
+    >>>
+
+    requirements {
+        cpu: 1024
+        memory: "0 GiB"
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        echo "Hello"
+    >>>
+
+    requirements {
+        cpu: 4
+        memory: "8 GiB"
+    }
+}
+"#]
+    }
+
+    fn tags(&self) -> TagSet {
+        TagSet::new(&[Tag::Resources, Tag::Correctness])
+    }
+
+    fn exceptable_nodes(&self) -> Option<&'static [SyntaxKind]> {
+        Some(&[
+            SyntaxKind::VersionStatementNode,
+            SyntaxKind::TaskDefinitionNode,
+            SyntaxKind::RuntimeSectionNode,
+            SyntaxKind::RequirementsSectionNode,
+            SyntaxKind::TaskHintsSectionNode,
+        ])
+    }
+
+    fn related_rules(&self) -> &[&'static str] {
+        &["DisksFormat", "RequiredResources", "ExpectedRuntimeKeys"]
+    }
+
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        let id = self.id();
+        *self = Self::with_options(parse_options(id, options)?)
+            .with_context(|| format!("invalid options for lint rule `{id}`"))?;
+        Ok(())
+    }
+}
+
+impl Visitor for ResourceBoundsRule {
+    fn reset(&mut self) {}
+
+    fn task_definition(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        task: &TaskDefinition,
+    ) {
+        if reason == VisitReason::Exit {
+            return;
+        }
+
+        let mut evaluator = task_evaluator(task);
+        for (name, expr, syntax) in resource_items(task, true) {
+            let Some(value) = evaluator.evaluate(&expr) else {
+                continue;
+            };
+
+            for diagnostic in self.check(name.text(), &value, expr.span()) {
+                diagnostics.exceptable_add(diagnostic, syntax.clone(), &self.exceptable_nodes());
+            }
+        }
+    }
+}
@@ -46,6 +46,32 @@ impl Rule for RuntimeSectionRule {
         "Tasks that don't declare `runtime` sections are unlikely to be portable."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.1
+
+task say_hello {
+    command <<<
+        echo "Hello"
+    >>>
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.1
+
+task say_hello {
+    command <<<
+        echo "Hello"
+    >>>
+
+    runtime {
+        container: "ubuntu:22.04"
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Completeness, Tag::Portability])
     }
@@ -83,6 +83,36 @@ impl Rule for SectionOrderingRule {
          parameter_meta, members."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    output {
+        String greeting = "Hello, ~{name}!"
+    }
+
+    input {
+        String name
+    }
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    input {
+        String name
+    }
+
+    output {
+        String greeting = "Hello, ~{name}!"
+    }
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Style, Tag::Sorting])
     }
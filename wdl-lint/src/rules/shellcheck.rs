@@ -5,6 +5,8 @@ use std::io::Write;
 use std::process;
 use std::process::Stdio;
 use std::sync::OnceLock;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 
 use anyhow::Context;
 use anyhow::Result;
@@ -14,6 +16,7 @@ use rand::distr::Alphanumeric;
 use rand::distr::SampleString;
 use rowan::ast::support;
 use serde::Deserialize;
+use serde::Serialize;
 use serde_json;
 use tracing::debug;
 use wdl_analysis::Diagnostics;
@@ -40,12 +43,15 @@ use wdl_ast::v1::StrippedCommandPart;
 use crate::Rule;
 use crate::Tag;
 use crate::TagSet;
+use crate::config::parse_options;
 use crate::fix::Fixer;
 use crate::fix::InsertionPoint;
 use crate::fix::Replacement;
+use crate::shell::Script;
 use crate::util::is_quote_balanced;
 use crate::util::lines_with_offset;
 use crate::util::program_exists;
+use crate::util::serialize_oxford_comma;
 
 /// The shellcheck executable
 const SHELLCHECK_BIN: &str = "shellcheck";
@@ -77,9 +83,124 @@ const SHELLCHECK_WIKI: &str = "https://www.shellcheck.net/wiki";
 /// Whether or not shellcheck exists on the system
 static SHELLCHECK_EXISTS: OnceLock<bool> = OnceLock::new();
 
+/// Whether or not a missing shellcheck executable has been reported.
+static SHELLCHECK_MISSING_REPORTED: AtomicBool = AtomicBool::new(false);
+
 /// The identifier for the command section ShellCheck rule.
 const ID: &str = "ShellCheck";
 
+/// The engine used to analyze command sections.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShellCheckEngine {
+    /// Uses `shellcheck` if it is installed and the native analysis otherwise.
+    #[default]
+    Auto,
+    /// Always uses `shellcheck`, reporting if it is not installed.
+    Shellcheck,
+    /// Always uses the native analysis.
+    Native,
+}
+
+/// The configurable options of the ShellCheck rule.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShellCheckOptions {
+    /// The engine used to analyze command sections.
+    pub engine: ShellCheckEngine,
+}
+
+/// Creates a diagnostic for a reference to an unassigned shell variable.
+fn undefined_variable(name: &str, span: Span) -> Diagnostic {
+    Diagnostic::warning(format!(
+        "shell variable `{name}` is referenced but not assigned"
+    ))
+    .with_rule(ID)
+    .with_highlight(span)
+    .with_fix(format!(
+        "assign `{name}` before it is referenced or provide a default value (e.g., \
+         `${{{name}:-}}`)"
+    ))
+}
+
+/// Creates a diagnostic for an unterminated quoted string.
+fn unterminated_quote(span: Span) -> Diagnostic {
+    Diagnostic::warning("command section contains an unterminated quoted string")
+        .with_rule(ID)
+        .with_label("this quote is never closed", span)
+        .with_fix("add the matching closing quote")
+}
+
+/// Creates a diagnostic for a command section that does not enable the
+/// strict mode shell options.
+fn missing_strict_mode(missing: &[&str], span: Span) -> Diagnostic {
+    let options: Vec<_> = missing.iter().map(|o| format!("`{o}`")).collect();
+    Diagnostic::note(format!(
+        "command section does not enable the {options} shell option{s}",
+        options = serialize_oxford_comma(&options).expect("should have at least one option"),
+        s = if missing.len() == 1 { "" } else { "s" }
+    ))
+    .with_rule(ID)
+    .with_highlight(span)
+    .with_fix("add `set -euo pipefail` to the start of the command section")
+}
+
+/// Creates a diagnostic for a placeholder whose value is executed as a
+/// command.
+fn executed_placeholder(span: Span) -> Diagnostic {
+    Diagnostic::note("the value of the placeholder is executed as a shell command")
+        .with_rule(ID)
+        .with_highlight(span)
+        .with_fix(
+            "use the placeholder as an argument to a command (e.g., `echo`) or remove it if it \
+             is unused",
+        )
+}
+
+/// Determines which of the `errexit`, `nounset`, and `pipefail` shell options
+/// are not enabled by `set` commands in the script.
+fn missing_strict_options(script: &Script) -> Vec<&'static str> {
+    let mut enabled = [false; 3];
+    for command in script.commands() {
+        let Some((name, args)) = command.name_and_args() else {
+            continue;
+        };
+
+        if name.literal() != Some("set") {
+            continue;
+        }
+
+        let mut args = args.iter().filter_map(|w| w.literal());
+        while let Some(arg) = args.next() {
+            let Some(flags) = arg.strip_prefix('-') else {
+                continue;
+            };
+
+            for flag in flags.chars() {
+                let option = match flag {
+                    'e' => "errexit",
+                    'u' => "nounset",
+                    'o' => args.next().unwrap_or_default(),
+                    _ => continue,
+                };
+
+                match option {
+                    "errexit" => enabled[0] = true,
+                    "nounset" => enabled[1] = true,
+                    "pipefail" => enabled[2] = true,
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    ["errexit", "nounset", "pipefail"]
+        .into_iter()
+        .zip(enabled)
+        .filter_map(|(o, e)| (!e).then_some(o))
+        .collect()
+}
+
 /// Suggested fix for a ShellCheck diagnostic.
 #[derive(Clone, Debug, Deserialize)]
 struct ShellCheckFix {
@@ -213,10 +334,81 @@ fn run_shellcheck(command: &str) -> Result<Vec<ShellCheckDiagnostic>> {
 /// Runs ShellCheck on a command section and reports diagnostics.
 #[derive(Default, Debug, Clone)]
 pub struct ShellCheckRule {
+    /// The options of the rule.
+    options: ShellCheckOptions,
     /// The document being linted.
     document: Option<Document>,
 }
 
+impl ShellCheckRule {
+    /// Constructs a new ShellCheck rule with the given options.
+    pub fn with_options(options: ShellCheckOptions) -> Self {
+        Self {
+            options,
+            document: None,
+        }
+    }
+
+    /// Analyzes a command section without `shellcheck`.
+    ///
+    /// Reports references to unassigned shell variables, unterminated quoted
+    /// strings, a missing `set -euo pipefail`, and placeholders whose values
+    /// are executed as commands.
+    fn check_natively(&self, diagnostics: &mut Diagnostics, section: &CommandSection) {
+        let script = Script::new(section);
+        let syntax = SyntaxElement::from(section.inner().clone());
+        let exceptable_nodes = self.exceptable_nodes();
+
+        let mut reported = HashSet::new();
+        for reference in script.references() {
+            let name = reference.name();
+            // Names without lowercase letters are conventionally environment
+            // variables, which are not assigned by the script
+            if !name.chars().any(|c| c.is_ascii_lowercase())
+                || script.is_assigned(name)
+                || !reported.insert(name)
+            {
+                continue;
+            }
+
+            diagnostics.exceptable_add(
+                undefined_variable(name, reference.span()),
+                syntax.clone(),
+                &exceptable_nodes,
+            );
+        }
+
+        if let Some(span) = script.unterminated_quote() {
+            diagnostics.exceptable_add(unterminated_quote(span), syntax.clone(), &exceptable_nodes);
+        }
+
+        for command in script.commands() {
+            if let [word] = command.words() {
+                if word.is_placeholder_only() && !command.is_nested() {
+                    diagnostics.exceptable_add(
+                        executed_placeholder(word.span()),
+                        syntax.clone(),
+                        &exceptable_nodes,
+                    );
+                }
+            }
+        }
+
+        if !script.commands().is_empty() {
+            let missing = missing_strict_options(&script);
+            if !missing.is_empty() {
+                let command_keyword = support::token(section.inner(), SyntaxKind::CommandKeyword)
+                    .expect("should have a command keyword token");
+                diagnostics.exceptable_add(
+                    missing_strict_mode(&missing, command_keyword.text_range().into()),
+                    syntax,
+                    &exceptable_nodes,
+                );
+            }
+        }
+    }
+}
+
 impl Rule for ShellCheckRule {
     fn id(&self) -> &'static str {
         ID
@@ -229,7 +421,38 @@ impl Rule for ShellCheckRule {
     fn explanation(&self) -> &'static str {
         "ShellCheck (https://shellcheck.net) is a static analysis tool and linter for sh / bash. \
          The lints provided by ShellCheck help prevent common errors and pitfalls in your scripts. \
-         Following its recommendations will increase the robustness of your command sections."
+         Following its recommendations will increase the robustness of your command sections.
+
+         When `shellcheck` is not installed, command sections are analyzed by a built-in parser \
+         for a subset of bash that treats placeholders as opaque words. It reports references to \
+         unassigned shell variables, unterminated quoted strings, command sections that do not \
+         enable `set -euo pipefail`, and placeholders whose values are executed as commands. The \
+         `engine` option selects between `auto` (the default), `shellcheck`, and `native`."
+    }
+
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        echo "Hello, $name!"
+    >>>
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task say_hello {
+    command <<<
+        set -euo pipefail
+
+        name="world"
+        echo "Hello, $name!"
+    >>>
+}
+"#]
     }
 
     fn tags(&self) -> TagSet {
@@ -244,7 +467,12 @@ impl Rule for ShellCheckRule {
     }
 
     fn related_rules(&self) -> &[&'static str] {
-        &[]
+        &["UnquotedPlaceholder"]
+    }
+
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        *self = Self::with_options(parse_options(self.id(), options)?);
+        Ok(())
     }
 }
 
@@ -517,7 +745,7 @@ fn calculate_span(diagnostic: &ShellCheckDiagnostic, line_map: &HashMap<usize, S
 
 impl Visitor for ShellCheckRule {
     fn reset(&mut self) {
-        *self = Default::default();
+        *self = Self::with_options(self.options);
     }
 
     fn document(
@@ -544,13 +772,19 @@ impl Visitor for ShellCheckRule {
             return;
         }
 
-        if !SHELLCHECK_EXISTS.get_or_init(|| {
-            if !program_exists(SHELLCHECK_BIN) {
+        if self.options.engine == ShellCheckEngine::Native {
+            return self.check_natively(diagnostics, section);
+        }
+
+        if !SHELLCHECK_EXISTS.get_or_init(|| program_exists(SHELLCHECK_BIN)) {
+            if self.options.engine == ShellCheckEngine::Auto {
+                debug!("`shellcheck` was not found; analyzing the command section natively");
+                return self.check_natively(diagnostics, section);
+            }
+
+            if !SHELLCHECK_MISSING_REPORTED.swap(true, Ordering::Relaxed) {
                 let command_keyword = support::token(section.inner(), SyntaxKind::CommandKeyword)
-                    .expect(
-                        "should have a
-                command keyword token",
-                    );
+                    .expect("should have a command keyword token");
                 diagnostics.exceptable_add(
                     Diagnostic::note("running `shellcheck` on command section")
                         .with_label(
@@ -564,10 +798,8 @@ impl Visitor for ShellCheckRule {
                     SyntaxElement::from(section.inner().clone()),
                     &self.exceptable_nodes(),
                 );
-                return false;
             }
-            true
-        }) {
+
             return;
         }
 
@@ -3,9 +3,9 @@
 
 use std::fmt;
 
-use convert_case::Boundary;
-use convert_case::Case;
-use convert_case::Converter;
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
 use wdl_analysis::Diagnostics;
 use wdl_analysis::VisitReason;
 use wdl_analysis::Visitor;
@@ -26,6 +26,8 @@ use wdl_ast::v1::WorkflowDefinition;
 use crate::Rule;
 use crate::Tag;
 use crate::TagSet;
+use crate::config::NamingCase;
+use crate::config::parse_options;
 
 /// Represents context of an warning.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -61,36 +63,58 @@ impl fmt::Display for Context {
 const ID: &str = "SnakeCase";
 
 /// Creates a "snake case" diagnostic.
-fn snake_case(context: Context, name: &str, properly_cased_name: &str, span: Span) -> Diagnostic {
-    Diagnostic::warning(format!("{context} name `{name}` is not snake_case"))
+fn snake_case(
+    context: Context,
+    case: NamingCase,
+    name: &str,
+    properly_cased_name: &str,
+    span: Span,
+) -> Diagnostic {
+    Diagnostic::warning(format!("{context} name `{name}` is not {case}"))
         .with_rule(ID)
-        .with_label("this name must be snake_case", span)
+        .with_label(format!("this name must be {case}"), span)
         .with_fix(format!("replace `{name}` with `{properly_cased_name}`"))
 }
 
-/// Checks if the given name is snake case, and if not adds a warning to the
-/// diagnostics.
+/// Checks if the given name is in the expected case, and if not adds a warning
+/// to the diagnostics.
 fn check_name(
     context: Context,
+    case: NamingCase,
     name: &str,
     span: Span,
     diagnostics: &mut Diagnostics,
     element: SyntaxElement,
     exceptable_nodes: &Option<&'static [SyntaxKind]>,
 ) {
-    let converter = Converter::new()
-        .remove_boundaries(&[Boundary::DIGIT_LOWER, Boundary::LOWER_DIGIT])
-        .to_case(Case::Snake);
-    let properly_cased_name = converter.convert(name);
+    let properly_cased_name = case.convert(name);
     if name != properly_cased_name {
-        let warning = snake_case(context, name, &properly_cased_name, span);
+        let warning = snake_case(context, case, name, &properly_cased_name, span);
         diagnostics.exceptable_add(warning, element, exceptable_nodes);
     }
 }
 
+/// The configurable options of the snake case rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SnakeCaseOptions {
+    /// The case that task, workflow, and variable names are expected to be in.
+    pub case: NamingCase,
+}
+
+impl Default for SnakeCaseOptions {
+    fn default() -> Self {
+        Self {
+            case: NamingCase::Snake,
+        }
+    }
+}
+
 /// Detects non-snake_cased identifiers.
 #[derive(Default, Debug, Clone, Copy)]
 pub struct SnakeCaseRule {
+    /// The options of the rule.
+    options: SnakeCaseOptions,
     /// Whether the visitor is currently within a struct.
     within_struct: bool,
     /// Whether the visitor is currently within an input section.
@@ -100,6 +124,14 @@ pub struct SnakeCaseRule {
 }
 
 impl SnakeCaseRule {
+    /// Constructs a new snake case rule with the given options.
+    pub fn with_options(options: SnakeCaseOptions) -> Self {
+        Self {
+            options,
+            ..Default::default()
+        }
+    }
+
     /// Determines current declaration context.
     fn determine_decl_context(&self) -> Context {
         if self.within_struct {
@@ -125,7 +157,26 @@ impl Rule for SnakeCaseRule {
 
     fn explanation(&self) -> &'static str {
         "Workflow, task, and variable names should be in snake case. Maintaining a consistent \
-         naming convention makes the code easier to read and understand."
+         naming convention makes the code easier to read and understand. The expected case may be \
+         changed with the `case` option."
+    }
+
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow sayHello {
+    String greetingMessage = "Hello"
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow say_hello {
+    String greeting_message = "Hello"
+}
+"#]
     }
 
     fn tags(&self) -> TagSet {
@@ -148,11 +199,16 @@ impl Rule for SnakeCaseRule {
     fn related_rules(&self) -> &[&'static str] {
         &["PascalCase"]
     }
+
+    fn configure(&mut self, options: &toml::Table) -> Result<()> {
+        *self = Self::with_options(parse_options(self.id(), options)?);
+        Ok(())
+    }
 }
 
 impl Visitor for SnakeCaseRule {
     fn reset(&mut self) {
-        *self = Self::default();
+        *self = Self::with_options(self.options);
     }
 
     fn struct_definition(
@@ -216,6 +272,7 @@ impl Visitor for SnakeCaseRule {
         let name = task.name();
         check_name(
             Context::Task,
+            self.options.case,
             name.text(),
             name.span(),
             diagnostics,
@@ -237,6 +294,7 @@ impl Visitor for SnakeCaseRule {
         let name = workflow.name();
         check_name(
             Context::Workflow,
+            self.options.case,
             name.text(),
             name.span(),
             diagnostics,
@@ -254,6 +312,7 @@ impl Visitor for SnakeCaseRule {
         let context = self.determine_decl_context();
         check_name(
             context,
+            self.options.case,
             name.text(),
             name.span(),
             diagnostics,
@@ -276,6 +335,7 @@ impl Visitor for SnakeCaseRule {
         let context = self.determine_decl_context();
         check_name(
             context,
+            self.options.case,
             name.text(),
             name.span(),
             diagnostics,
@@ -3,8 +3,10 @@
 use wdl_analysis::Diagnostics;
 use wdl_analysis::VisitReason;
 use wdl_analysis::Visitor;
+use wdl_ast::Applicability;
 use wdl_ast::AstNode;
 use wdl_ast::Diagnostic;
+use wdl_ast::Edit;
 use wdl_ast::Span;
 use wdl_ast::SyntaxElement;
 use wdl_ast::SyntaxKind;
@@ -26,14 +28,26 @@ fn missing_trailing_comma(span: Span) -> Diagnostic {
         .with_rule(ID)
         .with_highlight(span)
         .with_fix("add a trailing comma")
+        .with_edits(Applicability::Safe, [Edit::insert(span.end(), ",")])
 }
 
 /// Diagnostic message for extraneous content before trailing comma.
+///
+/// The span is expected to end immediately before the trailing comma.
 fn extraneous_content(span: Span) -> Diagnostic {
+    // Move the comma to the end of the item rather than removing the content, as it
+    // may contain comments
     Diagnostic::note("extraneous whitespace and/or comments before trailing comma")
         .with_rule(ID)
         .with_highlight(span)
         .with_fix("remove the extraneous content before the trailing comma")
+        .with_edits(
+            Applicability::Safe,
+            [
+                Edit::insert(span.start(), ","),
+                Edit::delete(Span::new(span.end(), 1)),
+            ],
+        )
 }
 
 /// Detects missing trailing commas.
@@ -59,6 +73,30 @@ impl Rule for TrailingCommaRule {
          `call` input blocks as well as `Array`, `Map`, `Object`, and `Struct` literals."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    Array[String] names = [
+        "Jane",
+        "John"
+    ]
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+    Array[String] names = [
+        "Jane",
+        "John",
+    ]
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Style])
     }
@@ -0,0 +1,278 @@
+//! A lint rule for flagging unquoted string placeholders in command sections.
+
+use wdl_analysis::Diagnostics;
+use wdl_analysis::Document;
+use wdl_analysis::VisitReason;
+use wdl_analysis::Visitor;
+use wdl_analysis::types::CompoundType;
+use wdl_analysis::types::PrimitiveType;
+use wdl_analysis::types::Type;
+use wdl_ast::Applicability;
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::Diagnostic;
+use wdl_ast::Edit;
+use wdl_ast::Span;
+use wdl_ast::SupportedVersion;
+use wdl_ast::SyntaxElement;
+use wdl_ast::SyntaxKind;
+use wdl_ast::v1::CommandPart;
+use wdl_ast::v1::CommandSection;
+use wdl_ast::v1::Expr;
+use wdl_ast::v1::Placeholder;
+use wdl_ast::v1::PlaceholderOption;
+use wdl_ast::version::V1;
+
+use crate::Rule;
+use crate::Tag;
+use crate::TagSet;
+use crate::shell::PlaceholderContext;
+use crate::shell::Script;
+
+/// The identifier for the unquoted placeholder rule.
+const ID: &str = "UnquotedPlaceholder";
+
+/// Creates a diagnostic for an unquoted placeholder.
+///
+/// The edit is unsafe as the command may rely on the placeholder's value being
+/// split into multiple shell words.
+fn unquoted_placeholder(ty: &Type, span: Span, edit: Edit) -> Diagnostic {
+    Diagnostic::warning(format!(
+        "placeholder of type `{ty}` is not quoted in the command"
+    ))
+    .with_rule(ID)
+    .with_highlight(span)
+    .with_fix("wrap the placeholder in double quotes")
+    .with_edits(Applicability::Unsafe, [edit])
+}
+
+/// Creates a diagnostic for an array placeholder whose elements are joined
+/// without being quoted.
+///
+/// An edit is only present if the `quote()` standard library function is
+/// available to the document.
+fn unquoted_array_elements(ty: &Type, span: Span, edit: Option<Edit>) -> Diagnostic {
+    let diagnostic = Diagnostic::warning(format!(
+        "elements of `{ty}` are joined without being quoted in the command"
+    ))
+    .with_rule(ID)
+    .with_highlight(span);
+
+    match edit {
+        Some(edit) => diagnostic
+            .with_fix("pass the array through the `quote()` standard library function")
+            .with_edits(Applicability::Unsafe, [edit]),
+        None => diagnostic.with_fix(
+            "quote the elements of the array before joining them or write the array to a file",
+        ),
+    }
+}
+
+/// Determines if the given type is a string-like type whose values may contain
+/// whitespace or shell metacharacters.
+fn is_string_like(ty: &Type) -> bool {
+    matches!(
+        ty.as_primitive(),
+        Some(PrimitiveType::String | PrimitiveType::File | PrimitiveType::Directory)
+    )
+}
+
+/// Determines if the given type is an array of a string-like type.
+fn is_string_like_array(ty: &Type) -> bool {
+    match ty.as_compound() {
+        Some(CompoundType::Array(ty)) => is_string_like(ty.element_type()),
+        _ => false,
+    }
+}
+
+/// Determines if the given expression is a call to `quote()` or `squote()`.
+fn is_quote_call(expr: &Expr) -> bool {
+    expr.as_call()
+        .map(|c| matches!(c.target().text(), "quote" | "squote"))
+        .unwrap_or(false)
+}
+
+/// Detects unquoted string placeholders in command sections.
+#[derive(Debug, Default, Clone)]
+pub struct UnquotedPlaceholderRule {
+    /// The document being linted.
+    document: Option<Document>,
+}
+
+impl UnquotedPlaceholderRule {
+    /// Checks an unquoted placeholder in a command section.
+    fn check(&self, placeholder: &Placeholder) -> Option<Diagnostic> {
+        let document = self.document.as_ref().expect("should have a document");
+        let quote_available =
+            matches!(document.version(), Some(SupportedVersion::V1(v)) if v >= V1::One);
+        let quote = |expr: &Expr| {
+            quote_available.then(|| Edit::new(expr.span(), format!("quote({})", expr.text())))
+        };
+
+        let expr = placeholder.expr();
+        match placeholder.option() {
+            Some(PlaceholderOption::TrueFalse(_)) => return None,
+            Some(PlaceholderOption::Sep(_)) => {
+                let ty = document.expr_type(expr.span())?;
+                if !is_string_like_array(ty) || is_quote_call(&expr) {
+                    return None;
+                }
+
+                return Some(unquoted_array_elements(ty, expr.span(), quote(&expr)));
+            }
+            Some(PlaceholderOption::Default(_)) | None => {}
+        }
+
+        if let Some(call) = expr.as_call() {
+            match call.target().text() {
+                "sep" => {
+                    let array = call.arguments().nth(1)?;
+                    let ty = document.expr_type(array.span())?;
+                    if !is_string_like_array(ty) || is_quote_call(&array) {
+                        return None;
+                    }
+
+                    return Some(unquoted_array_elements(ty, array.span(), quote(&array)));
+                }
+                "quote" | "squote" => return None,
+                _ => {}
+            }
+        }
+
+        let ty = document.expr_type(expr.span())?;
+        if !is_string_like(ty) {
+            return None;
+        }
+
+        let span = placeholder.span();
+        Some(unquoted_placeholder(
+            ty,
+            span,
+            Edit::new(span, format!("\"{text}\"", text = placeholder.text())),
+        ))
+    }
+}
+
+impl Rule for UnquotedPlaceholderRule {
+    fn id(&self) -> &'static str {
+        ID
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures that string placeholders in command sections are quoted."
+    }
+
+    fn explanation(&self) -> &'static str {
+        "Placeholders of type `String`, `File`, or `Directory` that are interpolated into a \
+         command section outside of shell quotes are split into multiple words by the shell if \
+         their values contain whitespace. Values containing shell metacharacters may also execute \
+         unintended commands.
+
+         Such placeholders should be wrapped in double quotes. Arrays of strings joined with the \
+         `sep()` standard library function (or the deprecated `sep` placeholder option) should \
+         have their elements quoted with the `quote()` or `squote()` standard library functions \
+         before they are joined.
+
+         Placeholders within shell comments and heredoc bodies are not checked."
+    }
+
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task copy {
+    input {
+        File source
+        String destination
+    }
+
+    command <<<
+        cp ~{source} ~{destination}
+    >>>
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+task copy {
+    input {
+        File source
+        String destination
+    }
+
+    command <<<
+        cp "~{source}" "~{destination}"
+    >>>
+}
+"#]
+    }
+
+    fn tags(&self) -> TagSet {
+        TagSet::new(&[Tag::Correctness])
+    }
+
+    fn exceptable_nodes(&self) -> Option<&'static [SyntaxKind]> {
+        Some(&[
+            SyntaxKind::VersionStatementNode,
+            SyntaxKind::TaskDefinitionNode,
+            SyntaxKind::CommandSectionNode,
+            SyntaxKind::PlaceholderNode,
+        ])
+    }
+
+    fn related_rules(&self) -> &[&'static str] {
+        &["ShellCheck", "DeprecatedPlaceholder"]
+    }
+}
+
+impl Visitor for UnquotedPlaceholderRule {
+    fn reset(&mut self) {
+        *self = Default::default();
+    }
+
+    fn document(
+        &mut self,
+        _: &mut Diagnostics,
+        reason: VisitReason,
+        document: &Document,
+        _: SupportedVersion,
+    ) {
+        if reason == VisitReason::Exit {
+            return;
+        }
+
+        self.document = Some(document.clone());
+    }
+
+    fn command_section(
+        &mut self,
+        diagnostics: &mut Diagnostics,
+        reason: VisitReason,
+        section: &CommandSection,
+    ) {
+        if reason == VisitReason::Exit {
+            return;
+        }
+
+        let script = Script::new(section);
+        for part in section.parts() {
+            let CommandPart::Placeholder(placeholder) = part else {
+                continue;
+            };
+
+            if script.placeholder_context(placeholder.span()) != Some(PlaceholderContext::Unquoted)
+            {
+                continue;
+            }
+
+            if let Some(diagnostic) = self.check(&placeholder) {
+                diagnostics.exceptable_add(
+                    diagnostic,
+                    SyntaxElement::from(placeholder.inner().clone()),
+                    &self.exceptable_nodes(),
+                );
+            }
+        }
+    }
+}
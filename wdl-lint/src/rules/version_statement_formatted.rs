@@ -85,6 +85,21 @@ impl Rule for VersionStatementFormattedRule {
          comments, there should be no whitespace before the version statement."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version  1.2
+workflow hello {
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Style])
     }
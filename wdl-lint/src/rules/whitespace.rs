@@ -3,8 +3,10 @@
 use wdl_analysis::Diagnostics;
 use wdl_analysis::VisitReason;
 use wdl_analysis::Visitor;
+use wdl_ast::Applicability;
 use wdl_ast::AstToken;
 use wdl_ast::Diagnostic;
+use wdl_ast::Edit;
 use wdl_ast::Span;
 use wdl_ast::SyntaxElement;
 use wdl_ast::SyntaxKind;
@@ -25,6 +27,7 @@ fn only_whitespace(span: Span) -> Diagnostic {
         .with_rule(ID)
         .with_highlight(span)
         .with_fix("remove the whitespace")
+        .with_edits(Applicability::Safe, [Edit::delete(span)])
 }
 
 /// Creates a "trailing whitespace" diagnostic.
@@ -33,14 +36,27 @@ fn trailing_whitespace(span: Span) -> Diagnostic {
         .with_rule(ID)
         .with_highlight(span)
         .with_fix("remove the trailing whitespace")
+        .with_edits(Applicability::Safe, [Edit::delete(span)])
 }
 
 /// Creates a "more than one blank line" diagnostic.
-fn more_than_one_blank_line(span: Span) -> Diagnostic {
+///
+/// The `replacement` is the whitespace with the extra blank lines removed.
+fn more_than_one_blank_line(span: Span, replacement: String) -> Diagnostic {
     Diagnostic::note("more than one blank line in a row")
         .with_rule(ID)
         .with_highlight(span)
         .with_fix("remove the extra blank lines")
+        .with_edits(Applicability::Safe, [Edit::new(span, replacement)])
+}
+
+/// Collapses the given whitespace to contain at most one blank line.
+///
+/// The indentation of the last line is preserved.
+fn collapse_blank_lines(text: &str) -> String {
+    let newline = if text.contains("\r\n") { "\r\n" } else { "\n" };
+    let indentation = text.rsplit('\n').next().unwrap_or_default();
+    format!("{newline}{newline}{indentation}")
 }
 
 /// Detects undesired whitespace.
@@ -67,6 +83,24 @@ impl Rule for WhitespaceRule {
          newlines. There should be at most one empty line in a row."
     }
 
+    fn bad_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+
+
+workflow hello {   
+}
+"#]
+    }
+
+    fn good_examples(&self) -> &[&'static str] {
+        &[r#"version 1.2
+
+workflow hello {
+}
+"#]
+    }
+
     fn tags(&self) -> TagSet {
         TagSet::new(&[Tag::Style, Tag::Spacing])
     }
@@ -169,7 +203,7 @@ impl Visitor for WhitespaceRule {
         // The "ending newline" rule will catch blank lines at the end of the file
         if !is_last && blank_start.is_some() {
             diagnostics.exceptable_add(
-                more_than_one_blank_line(span),
+                more_than_one_blank_line(span, collapse_blank_lines(text)),
                 SyntaxElement::from(whitespace.inner().clone()),
                 &self.exceptable_nodes(),
             );
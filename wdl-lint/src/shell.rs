@@ -0,0 +1,988 @@
+//! A lexer for the subset of shell syntax found in command sections.
+//!
+//! Placeholders are treated as opaque parts of shell words; their values are
+//! not known until the command is evaluated.
+//!
+//! The lexer is intentionally forgiving: it never fails, and constructs it
+//! does not understand are scanned as ordinary words.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::Span;
+use wdl_ast::v1::CommandPart;
+use wdl_ast::v1::CommandSection;
+
+/// The character used within a word's text to stand in for a placeholder or a
+/// shell expansion.
+const OPAQUE: char = '\0';
+
+/// Shell keywords that may precede a command name.
+const KEYWORDS: &[&str] = &[
+    "!", "{", "}", "if", "then", "elif", "else", "while", "until", "do", "time",
+];
+
+/// The shell context of a placeholder in a command section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PlaceholderContext {
+    /// The placeholder is part of an unquoted shell word.
+    Unquoted,
+    /// The placeholder is within single or double quotes.
+    Quoted,
+    /// The placeholder is within a comment, a heredoc body, or an arithmetic
+    /// expression.
+    Ignored,
+}
+
+/// A shell word.
+#[derive(Debug, Clone)]
+pub(crate) struct Word {
+    /// The span of the word.
+    span: Span,
+    /// The text of the word with quotes removed.
+    ///
+    /// Placeholders and shell expansions are represented by [`OPAQUE`].
+    text: String,
+    /// The length of the leading part of the text that is unquoted and free
+    /// of placeholders and expansions.
+    unquoted_prefix: usize,
+    /// Whether or not any part of the word is quoted.
+    quoted: bool,
+    /// Whether or not the word consists solely of placeholders.
+    placeholder_only: bool,
+}
+
+impl Word {
+    /// Gets the span of the word.
+    pub(crate) fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Gets the literal text of the word.
+    ///
+    /// Returns `None` if the word contains a placeholder or a shell expansion.
+    pub(crate) fn literal(&self) -> Option<&str> {
+        if self.text.contains(OPAQUE) {
+            return None;
+        }
+
+        Some(&self.text)
+    }
+
+    /// Determines if the word consists solely of placeholders.
+    pub(crate) fn is_placeholder_only(&self) -> bool {
+        self.placeholder_only
+    }
+
+    /// Gets the name of the variable assigned by the word if it is an
+    /// assignment (e.g. `name=value` or `name+=value`).
+    fn assignment(&self) -> Option<&str> {
+        let prefix = &self.text[..self.unquoted_prefix];
+        let (name, _) = prefix.split_once('=')?;
+        let name = name.strip_suffix('+').unwrap_or(name);
+        let name = name.split_once('[').map(|(n, _)| n).unwrap_or(name);
+        is_name(name).then_some(name)
+    }
+}
+
+/// A simple shell command.
+#[derive(Debug, Clone)]
+pub(crate) struct SimpleCommand {
+    /// The words of the command, including any leading assignments.
+    words: Vec<Word>,
+    /// Whether or not the command is nested within a command substitution.
+    nested: bool,
+}
+
+impl SimpleCommand {
+    /// Gets the words of the command.
+    pub(crate) fn words(&self) -> &[Word] {
+        &self.words
+    }
+
+    /// Determines if the command is nested within a command substitution.
+    pub(crate) fn is_nested(&self) -> bool {
+        self.nested
+    }
+
+    /// Gets the word naming the command and the words of its arguments.
+    ///
+    /// Leading assignments and shell keywords are skipped.
+    pub(crate) fn name_and_args(&self) -> Option<(&Word, &[Word])> {
+        let start = self.words.iter().position(|w| {
+            w.assignment().is_none() && !w.literal().is_some_and(|t| KEYWORDS.contains(&t))
+        })?;
+        Some((&self.words[start], &self.words[start + 1..]))
+    }
+}
+
+/// A reference to a shell variable.
+#[derive(Debug, Clone)]
+pub(crate) struct Reference {
+    /// The name of the variable.
+    name: String,
+    /// The span of the reference.
+    span: Span,
+}
+
+impl Reference {
+    /// Gets the name of the referenced variable.
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the span of the reference.
+    pub(crate) fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// The result of lexing a command section.
+#[derive(Debug, Default)]
+pub(crate) struct Script {
+    /// The simple commands of the script, in order.
+    commands: Vec<SimpleCommand>,
+    /// The references to shell variables, in order.
+    references: Vec<Reference>,
+    /// The names of the shell variables assigned by the script.
+    assigned: HashSet<String>,
+    /// The shell contexts of placeholders, keyed by the start of the
+    /// placeholder's span.
+    placeholders: HashMap<usize, PlaceholderContext>,
+    /// The span of the opening quote of an unterminated quoted string.
+    unterminated_quote: Option<Span>,
+}
+
+impl Script {
+    /// Lexes the given command section.
+    pub(crate) fn new(section: &CommandSection) -> Self {
+        let mut items = Vec::new();
+        for part in section.parts() {
+            match part {
+                CommandPart::Text(text) => {
+                    let start = text.span().start();
+                    items.extend(
+                        text.text()
+                            .char_indices()
+                            .map(|(i, c)| (start + i, Item::Char(c))),
+                    );
+                }
+                CommandPart::Placeholder(placeholder) => {
+                    let span = placeholder.span();
+                    items.push((span.start(), Item::Placeholder(span)));
+                }
+            }
+        }
+
+        let end = match items.last() {
+            Some((offset, Item::Char(c))) => offset + c.len_utf8(),
+            Some((_, Item::Placeholder(span))) => span.end(),
+            None => section.span().end(),
+        };
+
+        let mut lexer = Lexer {
+            items,
+            end,
+            pos: 0,
+            nesting: 0,
+            heredocs: Vec::new(),
+            script: Script::default(),
+        };
+        lexer.commands(None);
+        lexer.script
+    }
+
+    /// Gets the simple commands of the script.
+    pub(crate) fn commands(&self) -> &[SimpleCommand] {
+        &self.commands
+    }
+
+    /// Gets the references to shell variables in the script.
+    pub(crate) fn references(&self) -> &[Reference] {
+        &self.references
+    }
+
+    /// Determines if the given shell variable is assigned by the script.
+    pub(crate) fn is_assigned(&self, name: &str) -> bool {
+        self.assigned.contains(name)
+    }
+
+    /// Gets the shell context of the placeholder with the given span.
+    pub(crate) fn placeholder_context(&self, span: Span) -> Option<PlaceholderContext> {
+        self.placeholders.get(&span.start()).copied()
+    }
+
+    /// Gets the span of the opening quote of an unterminated quoted string.
+    pub(crate) fn unterminated_quote(&self) -> Option<Span> {
+        self.unterminated_quote
+    }
+
+    /// Records the variables assigned by a simple command.
+    fn record_assignments(&mut self, command: &SimpleCommand) {
+        for word in &command.words {
+            match word.assignment() {
+                Some(name) => {
+                    self.assigned.insert(name.to_string());
+                }
+                None => break,
+            }
+        }
+
+        let Some((name, args)) = command.name_and_args() else {
+            return;
+        };
+
+        let args: Vec<&str> = args.iter().filter_map(Word::literal).collect();
+        let names: Vec<&str> = match name.literal() {
+            Some("for" | "select") => args.first().copied().into_iter().collect(),
+            Some("export" | "local" | "declare" | "typeset" | "readonly" | "let") => args
+                .iter()
+                .filter(|a| !a.starts_with('-'))
+                .map(|a| {
+                    a.split(['=', '+', '['])
+                        .next()
+                        .expect("should have a first element")
+                })
+                .collect(),
+            Some("read") => option_operands(&args, &["d", "i", "n", "N", "p", "t", "u"], "a"),
+            Some("mapfile" | "readarray") => {
+                option_operands(&args, &["d", "n", "O", "s", "u", "C", "c"], "")
+            }
+            Some("getopts") => args.get(1).copied().into_iter().collect(),
+            Some("printf") => match args.as_slice() {
+                ["-v", name, ..] => vec![name],
+                _ => Vec::new(),
+            },
+            _ => Vec::new(),
+        };
+
+        self.assigned.extend(
+            names
+                .into_iter()
+                .filter(|n| is_name(n))
+                .map(ToString::to_string),
+        );
+    }
+}
+
+/// Gets the operands of a command that are not options or option arguments.
+///
+/// `with_args` are the options that take an argument; `assigns` is an option
+/// whose argument is also an assigned name (e.g. `read -a`).
+fn option_operands<'a>(args: &[&'a str], with_args: &[&str], assigns: &str) -> Vec<&'a str> {
+    let mut names = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.strip_prefix('-') {
+            Some(option) if !assigns.is_empty() && option == assigns => {
+                names.extend(iter.next());
+            }
+            Some(option) if with_args.contains(&option) => {
+                iter.next();
+            }
+            Some(_) => {}
+            None => names.push(*arg),
+        }
+    }
+
+    names
+}
+
+/// Determines if the given string is a valid shell variable name.
+fn is_name(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// An item of command section input.
+#[derive(Debug, Clone, Copy)]
+enum Item {
+    /// A character of command text.
+    Char(char),
+    /// A placeholder with the given span.
+    Placeholder(Span),
+}
+
+/// A heredoc whose body has yet to be scanned.
+#[derive(Debug)]
+struct Heredoc {
+    /// The delimiter of the heredoc.
+    delimiter: String,
+    /// Whether or not the delimiter was quoted, which disables expansions in
+    /// the body.
+    quoted: bool,
+}
+
+/// Implements the lexer of command sections.
+#[derive(Debug)]
+struct Lexer {
+    /// The items being lexed, paired with their offsets in the source.
+    items: Vec<(usize, Item)>,
+    /// The offset of the end of the input.
+    end: usize,
+    /// The index of the current item.
+    pos: usize,
+    /// The current depth of command substitutions.
+    nesting: usize,
+    /// The heredocs whose bodies start on the next line.
+    heredocs: Vec<Heredoc>,
+    /// The script being built.
+    script: Script,
+}
+
+impl Lexer {
+    /// Peeks at the current item.
+    fn peek(&self) -> Option<Item> {
+        self.items.get(self.pos).map(|(_, i)| *i)
+    }
+
+    /// Peeks at the character `n` items from the current item.
+    fn peek_char(&self, n: usize) -> Option<char> {
+        match self.items.get(self.pos + n) {
+            Some((_, Item::Char(c))) => Some(*c),
+            _ => None,
+        }
+    }
+
+    /// Gets the source offset of the current item.
+    fn offset(&self) -> usize {
+        self.items
+            .get(self.pos)
+            .map(|(o, _)| *o)
+            .unwrap_or(self.end)
+    }
+
+    /// Consumes a placeholder, recording its context.
+    fn placeholder(&mut self, span: Span, context: PlaceholderContext) {
+        self.pos += 1;
+        self.script.placeholders.insert(span.start(), context);
+    }
+
+    /// Consumes a run of characters that satisfy the given predicate.
+    fn take_while(&mut self, mut predicate: impl FnMut(char) -> bool) -> String {
+        let mut s = String::new();
+        while let Some(c) = self.peek_char(0).filter(|c| predicate(*c)) {
+            s.push(c);
+            self.pos += 1;
+        }
+
+        s
+    }
+
+    /// Records an unterminated quoted string opened at the given offset.
+    fn unterminated(&mut self, start: usize) {
+        self.script
+            .unterminated_quote
+            .get_or_insert(Span::new(start, 1));
+    }
+
+    /// Records a variable reference.
+    fn reference(&mut self, name: String, start: usize) {
+        let span = Span::new(start, self.offset() - start);
+        self.script.references.push(Reference { name, span });
+    }
+
+    /// Finishes the current simple command.
+    fn finish(&mut self, words: &mut Vec<Word>) {
+        if words.is_empty() {
+            return;
+        }
+
+        let command = SimpleCommand {
+            words: std::mem::take(words),
+            nested: self.nesting > 0,
+        };
+        self.script.record_assignments(&command);
+        self.script.commands.push(command);
+    }
+
+    /// Lexes a list of commands until the given terminator or the end of
+    /// input.
+    fn commands(&mut self, terminator: Option<char>) {
+        let mut words = Vec::new();
+        loop {
+            self.take_while(|c| c == ' ' || c == '\t');
+            match self.peek() {
+                None => break,
+                Some(Item::Char(c)) if Some(c) == terminator => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(Item::Char('\\')) if self.peek_char(1) == Some('\n') => self.pos += 2,
+                Some(Item::Char('#')) => self.comment(),
+                Some(Item::Char('\n')) => {
+                    self.pos += 1;
+                    self.finish(&mut words);
+                    self.heredoc_bodies();
+                }
+                Some(Item::Char(';' | '&' | '|')) => {
+                    self.take_while(|c| matches!(c, ';' | '&' | '|'));
+                    self.finish(&mut words);
+                }
+                Some(Item::Char('(')) => {
+                    self.finish(&mut words);
+                    self.pos += 1;
+                    if self.peek_char(0) == Some('(') {
+                        self.pos += 1;
+                        self.arithmetic();
+                    } else {
+                        self.commands(Some(')'));
+                    }
+                }
+                Some(Item::Char(')')) => {
+                    self.pos += 1;
+                    self.finish(&mut words);
+                }
+                Some(Item::Char('<' | '>')) => self.redirection(),
+                _ => {
+                    let word = self.word();
+                    words.push(word);
+                }
+            }
+        }
+
+        self.finish(&mut words);
+    }
+
+    /// Skips a comment, ignoring any placeholders within it.
+    fn comment(&mut self) {
+        loop {
+            match self.peek() {
+                None | Some(Item::Char('\n')) => break,
+                Some(Item::Placeholder(span)) => {
+                    self.placeholder(span, PlaceholderContext::Ignored)
+                }
+                Some(Item::Char(_)) => self.pos += 1,
+            }
+        }
+    }
+
+    /// Lexes a redirection operator and its target.
+    fn redirection(&mut self) {
+        let heredoc = self.peek_char(0) == Some('<')
+            && self.peek_char(1) == Some('<')
+            && self.peek_char(2) != Some('<');
+        self.take_while(|c| matches!(c, '<' | '>' | '&' | '|'));
+        if heredoc && self.peek_char(0) == Some('-') {
+            self.pos += 1;
+        }
+
+        self.take_while(|c| c == ' ' || c == '\t');
+        match self.peek() {
+            None | Some(Item::Char('\n' | ';' | '&' | '|' | '(' | ')' | '<' | '>')) => {}
+            Some(_) => {
+                let word = self.word();
+                // A delimiter of only digits is likely an arithmetic shift
+                if heredoc
+                    && !word.text.contains(OPAQUE)
+                    && !word.text.chars().all(|c| c.is_ascii_digit())
+                {
+                    self.heredocs.push(Heredoc {
+                        delimiter: word.text,
+                        quoted: word.quoted,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Scans the bodies of any pending heredocs.
+    fn heredoc_bodies(&mut self) {
+        for heredoc in std::mem::take(&mut self.heredocs) {
+            loop {
+                let mut line = String::new();
+                loop {
+                    match self.peek() {
+                        None => return,
+                        Some(Item::Char('\n')) => {
+                            self.pos += 1;
+                            break;
+                        }
+                        Some(Item::Placeholder(span)) => {
+                            self.placeholder(span, PlaceholderContext::Ignored);
+                            line.push(OPAQUE);
+                        }
+                        Some(Item::Char('$')) if !heredoc.quoted => {
+                            if !self.dollar() {
+                                line.push('$');
+                            }
+                        }
+                        Some(Item::Char('\\')) if !heredoc.quoted => {
+                            self.pos += 1;
+                            if self.peek_char(0).is_some() {
+                                self.pos += 1;
+                            }
+                        }
+                        Some(Item::Char(c)) => {
+                            self.pos += 1;
+                            line.push(c);
+                        }
+                    }
+                }
+
+                if line.trim() == heredoc.delimiter {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Lexes a shell word.
+    fn word(&mut self) -> Word {
+        let start = self.offset();
+        let mut text = String::new();
+        let mut unquoted_prefix = None;
+        let mut quoted = false;
+        let mut placeholders = 0;
+        let mut only_placeholders = true;
+
+        loop {
+            match self.peek() {
+                None => break,
+                Some(Item::Placeholder(span)) => {
+                    unquoted_prefix.get_or_insert(text.len());
+                    self.placeholder(span, PlaceholderContext::Unquoted);
+                    text.push(OPAQUE);
+                    placeholders += 1;
+                }
+                Some(Item::Char(c)) => {
+                    match c {
+                        ' ' | '\t' | '\n' | ';' | '&' | '|' | '(' | ')' | '<' | '>' => break,
+                        '\\' => {
+                            self.pos += 1;
+                            match self.peek() {
+                                Some(Item::Char('\n')) => self.pos += 1,
+                                Some(Item::Char(c)) => {
+                                    self.pos += 1;
+                                    text.push(c);
+                                }
+                                Some(Item::Placeholder(span)) => {
+                                    unquoted_prefix.get_or_insert(text.len());
+                                    self.placeholder(span, PlaceholderContext::Unquoted);
+                                    text.push(OPAQUE);
+                                }
+                                None => {}
+                            }
+                        }
+                        '\'' => {
+                            unquoted_prefix.get_or_insert(text.len());
+                            quoted = true;
+                            self.single_quoted(&mut text, false);
+                        }
+                        '"' => {
+                            unquoted_prefix.get_or_insert(text.len());
+                            quoted = true;
+                            self.double_quoted(&mut text);
+                        }
+                        '$' if self.peek_char(1) == Some('\'') => {
+                            unquoted_prefix.get_or_insert(text.len());
+                            quoted = true;
+                            self.pos += 1;
+                            self.single_quoted(&mut text, true);
+                        }
+                        '$' => {
+                            unquoted_prefix.get_or_insert(text.len());
+                            text.push(if self.dollar() { OPAQUE } else { '$' });
+                        }
+                        '`' => {
+                            unquoted_prefix.get_or_insert(text.len());
+                            self.backticks();
+                            text.push(OPAQUE);
+                        }
+                        _ => {
+                            self.pos += 1;
+                            text.push(c);
+                        }
+                    }
+
+                    only_placeholders = false;
+                }
+            }
+        }
+
+        Word {
+            span: Span::new(start, self.offset() - start),
+            unquoted_prefix: unquoted_prefix.unwrap_or(text.len()),
+            text,
+            quoted,
+            placeholder_only: only_placeholders && placeholders > 0,
+        }
+    }
+
+    /// Lexes a single quoted string.
+    ///
+    /// If `escapes` is `true`, the string is an ANSI-C quoted string (i.e.
+    /// `$'...'`) in which backslash escapes are recognized.
+    fn single_quoted(&mut self, text: &mut String, escapes: bool) {
+        let start = self.offset();
+        self.pos += 1;
+        loop {
+            match self.peek() {
+                None => return self.unterminated(start),
+                Some(Item::Char('\'')) => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(Item::Char('\\')) if escapes => {
+                    self.pos += 2;
+                    text.push(OPAQUE);
+                }
+                Some(Item::Char(c)) => {
+                    self.pos += 1;
+                    text.push(c);
+                }
+                Some(Item::Placeholder(span)) => {
+                    self.placeholder(span, PlaceholderContext::Quoted);
+                    text.push(OPAQUE);
+                }
+            }
+        }
+    }
+
+    /// Lexes a double quoted string.
+    fn double_quoted(&mut self, text: &mut String) {
+        let start = self.offset();
+        self.pos += 1;
+        loop {
+            match self.peek() {
+                None => return self.unterminated(start),
+                Some(Item::Char('"')) => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(Item::Char('\\')) => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some(Item::Char('\n')) => self.pos += 1,
+                        Some(Item::Char(c)) => {
+                            self.pos += 1;
+                            if !matches!(c, '$' | '`' | '"' | '\\') {
+                                text.push('\\');
+                            }
+
+                            text.push(c);
+                        }
+                        Some(Item::Placeholder(span)) => {
+                            self.placeholder(span, PlaceholderContext::Quoted);
+                            text.push(OPAQUE);
+                        }
+                        None => {}
+                    }
+                }
+                Some(Item::Char('$')) => {
+                    text.push(if self.dollar() { OPAQUE } else { '$' });
+                }
+                Some(Item::Char('`')) => {
+                    self.backticks();
+                    text.push(OPAQUE);
+                }
+                Some(Item::Char(c)) => {
+                    self.pos += 1;
+                    text.push(c);
+                }
+                Some(Item::Placeholder(span)) => {
+                    self.placeholder(span, PlaceholderContext::Quoted);
+                    text.push(OPAQUE);
+                }
+            }
+        }
+    }
+
+    /// Lexes a shell expansion starting with `$`.
+    ///
+    /// Returns `false` if the `$` is a literal character.
+    fn dollar(&mut self) -> bool {
+        let start = self.offset();
+        self.pos += 1;
+        match self.peek_char(0) {
+            Some('(') => {
+                self.pos += 1;
+                if self.peek_char(0) == Some('(') {
+                    self.pos += 1;
+                    self.arithmetic();
+                } else {
+                    self.substitution(Some(')'));
+                }
+            }
+            Some('{') => {
+                self.pos += 1;
+                self.parameter(start);
+            }
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+                let name = self.take_while(|c| c.is_ascii_alphanumeric() || c == '_');
+                self.reference(name, start);
+            }
+            Some(c) if c.is_ascii_digit() || "@*#?$!-".contains(c) => self.pos += 1,
+            Some('"') => {}
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Lexes the body of a command substitution.
+    fn substitution(&mut self, terminator: Option<char>) {
+        self.nesting += 1;
+        self.commands(terminator);
+        self.nesting -= 1;
+    }
+
+    /// Lexes a backtick command substitution.
+    ///
+    /// Only variable references within the substitution are recognized.
+    fn backticks(&mut self) {
+        let start = self.offset();
+        self.pos += 1;
+        loop {
+            match self.peek() {
+                None => return self.unterminated(start),
+                Some(Item::Char('`')) => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(Item::Char('\\')) => self.pos += 2,
+                Some(Item::Char('$')) => {
+                    self.dollar();
+                }
+                Some(Item::Char(_)) => self.pos += 1,
+                Some(Item::Placeholder(span)) => {
+                    self.placeholder(span, PlaceholderContext::Unquoted)
+                }
+            }
+        }
+    }
+
+    /// Lexes a parameter expansion following `${`.
+    fn parameter(&mut self, start: usize) {
+        let prefix = self.peek_char(0).filter(|c| matches!(c, '#' | '!'));
+        if prefix.is_some() && self.peek_char(1) != Some('}') {
+            self.pos += 1;
+        }
+
+        let name = self.take_while(|c| c.is_ascii_alphanumeric() || c == '_');
+        let mut reference = None;
+        if is_name(&name) {
+            // Expansions that provide a default value do not reference an
+            // unassigned variable
+            let operator = match self.peek_char(0) {
+                Some(':') => self.peek_char(1),
+                c => c,
+            };
+            match operator {
+                Some('=') => {
+                    self.script.assigned.insert(name);
+                }
+                Some('-' | '+' | '?') => {}
+                _ => reference = Some(name),
+            }
+        } else if name.is_empty() {
+            // A special parameter (e.g. `${@}`)
+            self.take_while(|c| "@*#?$!-".contains(c));
+        }
+
+        let mut depth = 0;
+        loop {
+            match self.peek() {
+                None => break,
+                Some(Item::Char('}')) if depth == 0 => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(Item::Char('}')) => {
+                    depth -= 1;
+                    self.pos += 1;
+                }
+                Some(Item::Char('{')) => {
+                    depth += 1;
+                    self.pos += 1;
+                }
+                Some(Item::Char('\\')) => self.pos += 2,
+                Some(Item::Char('$')) => {
+                    self.dollar();
+                }
+                Some(Item::Char('\'')) => self.single_quoted(&mut String::new(), false),
+                Some(Item::Char('"')) => self.double_quoted(&mut String::new()),
+                Some(Item::Char(_)) => self.pos += 1,
+                Some(Item::Placeholder(span)) => {
+                    self.placeholder(span, PlaceholderContext::Ignored)
+                }
+            }
+        }
+
+        if let Some(name) = reference {
+            self.reference(name, start);
+        }
+    }
+
+    /// Lexes an arithmetic expression following `((` or `$((`.
+    fn arithmetic(&mut self) {
+        let mut depth = 0;
+        let mut increment = false;
+        loop {
+            match self.peek() {
+                None => break,
+                Some(Item::Char(')')) if depth == 0 => {
+                    self.pos += 1;
+                    if self.peek_char(0) == Some(')') {
+                        self.pos += 1;
+                    }
+
+                    break;
+                }
+                Some(Item::Char(')')) => {
+                    depth -= 1;
+                    self.pos += 1;
+                }
+                Some(Item::Char('(')) => {
+                    depth += 1;
+                    self.pos += 1;
+                }
+                Some(Item::Char('$')) => {
+                    self.dollar();
+                }
+                Some(Item::Char(c)) if c.is_ascii_alphabetic() || c == '_' => {
+                    let name = self.take_while(|c| c.is_ascii_alphanumeric() || c == '_');
+                    self.take_while(|c| c == ' ' || c == '\t');
+                    let assigned = increment
+                        || matches!(
+                            (self.peek_char(0), self.peek_char(1)),
+                            (Some('='), c) if c != Some('=')
+                        )
+                        || matches!(
+                            (self.peek_char(0), self.peek_char(1)),
+                            (Some('+'), Some('+' | '='))
+                                | (Some('-'), Some('-' | '='))
+                                | (Some('*' | '/' | '%' | '&' | '|' | '^'), Some('='))
+                        );
+                    if assigned {
+                        self.script.assigned.insert(name);
+                    }
+
+                    increment = false;
+                }
+                Some(Item::Char(c @ ('+' | '-'))) => {
+                    increment = self.peek_char(1) == Some(c);
+                    self.pos += if increment { 2 } else { 1 };
+                }
+                Some(Item::Char(c)) => {
+                    if !c.is_whitespace() {
+                        increment = false;
+                    }
+
+                    self.pos += 1;
+                }
+                Some(Item::Placeholder(span)) => {
+                    self.placeholder(span, PlaceholderContext::Ignored);
+                    increment = false;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use wdl_ast::Document;
+
+    use super::*;
+
+    /// Lexes the command section of the first task in the given source.
+    fn lex(command: &str) -> Script {
+        let source =
+            format!("version 1.1\n\ntask test {{\n    command <<<\n{command}\n    >>>\n}}\n");
+        let (document, diagnostics) = Document::parse(&source);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        let section = document
+            .ast()
+            .as_v1()
+            .expect("should be a V1 AST")
+            .tasks()
+            .next()
+            .expect("should have a task")
+            .command()
+            .expect("should have a command section");
+        Script::new(&section)
+    }
+
+    /// Gets the placeholder contexts of a script in source order.
+    fn contexts(script: &Script) -> Vec<PlaceholderContext> {
+        let mut placeholders: Vec<_> = script.placeholders.iter().collect();
+        placeholders.sort_by_key(|(s, _)| **s);
+        placeholders.into_iter().map(|(_, c)| *c).collect()
+    }
+
+    #[test]
+    fn it_determines_placeholder_contexts() {
+        use PlaceholderContext::*;
+
+        let script = lex(r#"
+            echo ~{a} "~{b}" '~{c}' x~{d} $'~{e}' # ~{f}
+            cat <<EOF
+            ~{g}
+            EOF
+            (( i = ~{h} ))
+            echo "$(cat ~{i})"
+        "#);
+        assert_eq!(
+            contexts(&script),
+            [
+                Unquoted, Quoted, Quoted, Unquoted, Quoted, Ignored, Ignored, Ignored, Unquoted
+            ]
+        );
+    }
+
+    #[test]
+    fn it_records_references_and_assignments() {
+        let script = lex(r#"
+            a=1 b+=2 cmd "$c" ${d} ${e:-x} ${f:=y} $((g++)) $1 $@
+            for h in x; do read -r i j; done
+            export k=1; declare -a l; printf -v m x; getopts n o
+        "#);
+        let references: Vec<_> = script.references().iter().map(|r| r.name()).collect();
+        assert_eq!(references, ["c", "d"]);
+        for name in ["a", "b", "f", "g", "h", "i", "j", "k", "l", "m", "o"] {
+            assert!(script.is_assigned(name), "`{name}` should be assigned");
+        }
+
+        assert!(!script.is_assigned("e"));
+        assert!(!script.is_assigned("n"));
+        assert!(script.unterminated_quote().is_none());
+    }
+
+    #[test]
+    fn it_lexes_commands() {
+        let script = lex(r#"
+            FOO=bar if ~{cmd} | tee "out file"; then echo 'a b' \
+                c; fi
+            echo "unterminated
+        "#);
+        let commands: Vec<Vec<_>> = script
+            .commands()
+            .iter()
+            .map(|c| c.words().iter().map(|w| w.literal()).collect())
+            .collect();
+        assert_eq!(
+            commands[..4],
+            [
+                vec![Some("FOO=bar"), Some("if"), None],
+                vec![Some("tee"), Some("out file")],
+                vec![Some("then"), Some("echo"), Some("a b"), Some("c")],
+                vec![Some("fi")],
+            ]
+        );
+        assert_eq!(commands.len(), 5);
+
+        let (name, args) = script.commands()[0]
+            .name_and_args()
+            .expect("should have a name");
+        assert!(name.is_placeholder_only());
+        assert!(args.is_empty());
+        assert!(script.unterminated_quote().is_some());
+    }
+}
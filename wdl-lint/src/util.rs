@@ -4,10 +4,19 @@ use std::process::Command;
 use std::process::Stdio;
 
 use strsim::levenshtein;
+use wdl_analysis::eval::v1::ConstantEvaluator;
 use wdl_analysis::rules as analysis_rules;
+use wdl_analysis::units::StorageUnit;
+use wdl_analysis::units::convert_unit_string;
+use wdl_ast::AstNode;
 use wdl_ast::AstToken;
 use wdl_ast::Comment;
+use wdl_ast::Ident;
+use wdl_ast::SyntaxElement;
 use wdl_ast::SyntaxKind;
+use wdl_ast::v1::Decl;
+use wdl_ast::v1::Expr;
+use wdl_ast::v1::TaskDefinition;
 
 use crate::rules::RULE_MAP;
 
@@ -168,6 +177,95 @@ pub fn serialize_oxford_comma<T: std::fmt::Display>(items: &[T]) -> Option<Strin
     }
 }
 
+/// The disk types of the legacy (Cromwell) disk specification format (e.g.
+/// `local-disk 100 SSD`).
+const LEGACY_DISK_TYPES: &[&str] = &["HDD", "SSD", "LOCAL"];
+
+/// Parses a `disks` specification string and returns the requested size in
+/// bytes.
+///
+/// A specification is a size with an optional storage unit (defaulting to
+/// GiB), optionally preceded by a mount point (e.g. `/mnt/outputs 10 GiB`).
+/// The legacy format of `local-disk <size> <type>` is also accepted.
+///
+/// Returns `None` if the specification could not be parsed.
+pub fn parse_disk_spec(spec: &str) -> Option<u64> {
+    let parts: Vec<_> = spec.split_whitespace().collect();
+    let parts = match parts.as_slice() {
+        [mount, rest @ ..] if mount.starts_with('/') || *mount == "local-disk" => rest,
+        parts => parts,
+    };
+
+    match parts {
+        [size] => match size.parse() {
+            Ok(size) => StorageUnit::Gibibytes.bytes(size),
+            Err(_) => convert_unit_string(size),
+        },
+        [size, unit] => {
+            let size = size.parse().ok()?;
+            let unit = if LEGACY_DISK_TYPES.contains(unit) {
+                StorageUnit::Gibibytes
+            } else {
+                unit.parse().ok()?
+            };
+
+            unit.bytes(size)
+        }
+        _ => None,
+    }
+}
+
+/// Gets the items of a task's `runtime` and `requirements` sections and,
+/// optionally, its `hints` section.
+///
+/// Each item is returned as its name, its expression, and its syntax element.
+pub fn resource_items(task: &TaskDefinition, hints: bool) -> Vec<(Ident, Expr, SyntaxElement)> {
+    let mut items = Vec::new();
+    if let Some(section) = task.runtime() {
+        items.extend(
+            section
+                .items()
+                .map(|i| (i.name(), i.expr(), SyntaxElement::from(i.inner().clone()))),
+        );
+    }
+
+    if let Some(section) = task.requirements() {
+        items.extend(
+            section
+                .items()
+                .map(|i| (i.name(), i.expr(), SyntaxElement::from(i.inner().clone()))),
+        );
+    }
+
+    if let Some(section) = task.hints().filter(|_| hints) {
+        items.extend(
+            section
+                .items()
+                .map(|i| (i.name(), i.expr(), SyntaxElement::from(i.inner().clone()))),
+        );
+    }
+
+    items
+}
+
+/// Constructs a constant evaluator for the declarations of a task.
+///
+/// Inputs are assumed to have their default values.
+pub fn task_evaluator(task: &TaskDefinition) -> ConstantEvaluator {
+    let mut evaluator = ConstantEvaluator::new();
+    if let Some(section) = task.input() {
+        for decl in section.declarations() {
+            evaluator.add_input(&decl);
+        }
+    }
+
+    for decl in task.declarations() {
+        evaluator.add_decl(&Decl::Bound(decl));
+    }
+
+    evaluator
+}
+
 #[cfg(test)]
 mod test {
     use pretty_assertions::assert_eq;
@@ -322,4 +420,21 @@ task foo {  # an in-line comment
             Some(String::from("hello, there, and world"))
         );
     }
+
+    #[test]
+    fn test_parse_disk_spec() {
+        const GIB: u64 = 1 << 30;
+
+        assert_eq!(parse_disk_spec("10"), Some(10 * GIB));
+        assert_eq!(parse_disk_spec("10 GiB"), Some(10 * GIB));
+        assert_eq!(parse_disk_spec("10GB"), Some(10_000_000_000));
+        assert_eq!(parse_disk_spec("/mnt/outputs 500 MiB"), Some(500 << 20));
+        assert_eq!(parse_disk_spec("local-disk 100 SSD"), Some(100 * GIB));
+        assert_eq!(parse_disk_spec("local-disk 100"), Some(100 * GIB));
+        assert_eq!(parse_disk_spec(""), None);
+        assert_eq!(parse_disk_spec("local-disk"), None);
+        assert_eq!(parse_disk_spec("10 parsecs"), None);
+        assert_eq!(parse_disk_spec("1.5 GiB"), None);
+        assert_eq!(parse_disk_spec("mnt 10 GiB"), None);
+    }
 }
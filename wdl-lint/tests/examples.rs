@@ -0,0 +1,143 @@
+//! The rule documentation tests.
+//!
+//! This test lints the examples declared by each lint and analysis rule: every
+//! "bad" example must trigger its rule and no "good" example may trigger its
+//! rule. Examples may import `lib.wdl`, a document defining a `greet` task
+//! that is written alongside them.
+//!
+//! It also checks that `RULES.md` is generated from the current rule metadata;
+//! the file may be regenerated by setting the `BLESS` environment variable
+//! when running this test.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::sync::Arc;
+
+use pretty_assertions::StrComparison;
+use tempfile::TempDir;
+use wdl_analysis::Analyzer;
+use wdl_analysis::DiagnosticsConfig;
+use wdl_analysis::Validator;
+use wdl_lint::LintConfig;
+use wdl_lint::Linter;
+use wdl_lint::docs::documentation;
+use wdl_lint::docs::rules_markdown;
+
+/// The source of the `lib.wdl` document that examples may import.
+const LIB_SOURCE: &str = r#"version 1.2
+
+task greet {
+    input {
+        String name
+    }
+
+    command <<<
+        echo "Hello, ~{name}!"
+    >>>
+
+    output {
+        String greeting = read_string(stdout())
+    }
+}
+"#;
+
+#[tokio::test]
+async fn examples_trigger_their_rules() {
+    let dir = TempDir::new().expect("failed to create temporary directory");
+    let docs = documentation();
+
+    let mut config = String::new();
+    let mut expected = Vec::new();
+    let mut errors = Vec::new();
+    for doc in &docs {
+        if doc.bad_examples().is_empty() || doc.good_examples().is_empty() {
+            errors.push(format!(
+                "rule `{id}` does not have both bad and good examples",
+                id = doc.id()
+            ));
+        }
+
+        let rule_dir = dir.path().join(doc.id());
+        fs::create_dir(&rule_dir).expect("failed to create directory");
+        fs::write(rule_dir.join("lib.wdl"), LIB_SOURCE).expect("failed to write `lib.wdl`");
+        for (good, examples) in [(false, doc.bad_examples()), (true, doc.good_examples())] {
+            for (i, example) in examples.iter().enumerate() {
+                let path = rule_dir.join(format!(
+                    "{kind}-{i}.wdl",
+                    kind = if good { "good" } else { "bad" }
+                ));
+                fs::write(&path, example).expect("failed to write example");
+                expected.push((path, doc.id(), good));
+            }
+        }
+
+        if let Some(options) = doc.example_options() {
+            write!(
+                config,
+                "[[overrides]]\npaths = [\"{id}/**\"]\n\n[overrides.rules.{id}]\n{options}\n",
+                id = doc.id()
+            )
+            .unwrap();
+        }
+    }
+
+    let config =
+        Arc::new(LintConfig::parse(&config, dir.path()).expect("failed to parse configuration"));
+    let analyzer = Analyzer::new_with_validator(
+        DiagnosticsConfig::new(wdl_analysis::rules()),
+        |_: (), _, _, _| async {},
+        move || {
+            let mut validator = Validator::default();
+            validator.add_visitor(Linter::with_config(config.clone()));
+            validator
+        },
+    );
+    analyzer
+        .add_directory(dir.path().to_path_buf())
+        .await
+        .expect("failed to add directory");
+    let results = analyzer.analyze(()).await.expect("failed to analyze");
+
+    for (path, id, good) in expected {
+        let result = results
+            .iter()
+            .find(|r| r.document().uri().to_file_path().ok().as_ref() == Some(&path))
+            .expect("failed to find example result");
+        let triggered = result
+            .document()
+            .diagnostics()
+            .iter()
+            .any(|d| d.rule() == Some(id));
+        let name = path.file_name().unwrap().to_str().unwrap();
+        if good && triggered {
+            errors.push(format!(
+                "good example `{name}` of rule `{id}` triggers the rule"
+            ));
+        } else if !good && !triggered {
+            errors.push(format!(
+                "bad example `{name}` of rule `{id}` does not trigger the rule"
+            ));
+        }
+    }
+
+    assert!(errors.is_empty(), "{errors}", errors = errors.join("\n"));
+}
+
+#[test]
+fn rules_markdown_is_up_to_date() {
+    let generated = rules_markdown();
+    if env::var_os("BLESS").is_some() {
+        fs::write("RULES.md", &generated).expect("failed to write `RULES.md`");
+        return;
+    }
+
+    let expected = fs::read_to_string("RULES.md")
+        .expect("failed to read `RULES.md`")
+        .replace("\r\n", "\n");
+    assert!(
+        expected == generated,
+        "`RULES.md` is out of date (run with `BLESS=1` to regenerate):\n{diff}",
+        diff = StrComparison::new(&expected, &generated)
+    );
+}
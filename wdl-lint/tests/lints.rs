@@ -10,6 +10,9 @@
 //!
 //! The `source.errors` file may be automatically generated or updated by
 //! setting the `BLESS` environment variable when running this test.
+//!
+//! Rule options for individual tests may be given with overrides in the
+//! `tests/lints/wdl-lint.toml` lint configuration file.
 
 use std::collections::HashSet;
 use std::env;
@@ -18,6 +21,7 @@ use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
 use std::path::absolute;
+use std::sync::Arc;
 
 use codespan_reporting::files::SimpleFile;
 use codespan_reporting::term;
@@ -31,7 +35,9 @@ use wdl_analysis::DiagnosticsConfig;
 use wdl_analysis::Validator;
 use wdl_ast::AstNode;
 use wdl_ast::Diagnostic;
+use wdl_lint::LintConfig;
 use wdl_lint::Linter;
+use wdl_lint::config::CONFIG_FILE_NAME;
 
 /// Finds tests for this package.
 fn find_tests() -> Vec<PathBuf> {
@@ -122,12 +128,16 @@ async fn main() {
     let tests = find_tests();
     println!("\nrunning {} tests\n", tests.len());
 
+    let config = Arc::new(
+        LintConfig::from_file(Path::new("tests/lints").join(CONFIG_FILE_NAME))
+            .expect("failed to load lint configuration"),
+    );
     let analyzer = Analyzer::new_with_validator(
         DiagnosticsConfig::except_all(),
         |_, _, _, _| async {},
-        || {
+        move || {
             let mut validator = Validator::default();
-            validator.add_visitor(Linter::default());
+            validator.add_visitor(Linter::with_config(config.clone()));
             validator
         },
     );
@@ -0,0 +1,418 @@
+//! Implementation of code lenses and the commands they execute.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::anyhow;
+use anyhow::bail;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+use tower_lsp::lsp_types::CodeLens;
+use tower_lsp::lsp_types::Command;
+use url::Url;
+use wdl_analysis::AnalysisResult;
+use wdl_analysis::Document;
+use wdl_analysis::document::Input;
+use wdl_analysis::eval::v1::WorkflowGraphExport;
+use wdl_analysis::types::CompoundType;
+use wdl_analysis::types::Optional;
+use wdl_analysis::types::PrimitiveType;
+use wdl_analysis::types::Type;
+use wdl_ast::AstToken;
+use wdl_ast::v1::DocumentItem;
+use wdl_engine::EvaluationError;
+use wdl_engine::v1::ProgressKind;
+
+use crate::proto::range_from_span;
+
+/// The command for running a task or workflow.
+pub const RUN_COMMAND: &str = "wdl.run";
+
+/// The command for generating an inputs file for a task or workflow.
+pub const GENERATE_INPUTS_COMMAND: &str = "wdl.generateInputs";
+
+/// The command for showing the call graph of a workflow.
+pub const SHOW_CALL_GRAPH_COMMAND: &str = "wdl.showCallGraph";
+
+/// The commands supported by the server.
+pub const COMMANDS: &[&str] = &[
+    RUN_COMMAND,
+    GENERATE_INPUTS_COMMAND,
+    SHOW_CALL_GRAPH_COMMAND,
+];
+
+/// Gets the code lenses for an analysis result.
+///
+/// Each task and workflow has lenses for running it and for generating its
+/// inputs file; workflows additionally have a lens for showing the call graph.
+///
+/// Returns `None` if the document could not be parsed or is not a supported
+/// version.
+pub fn code_lenses(result: &AnalysisResult) -> Option<Vec<CodeLens>> {
+    let index = result.lines()?;
+    let document = result.document();
+    let ast = document.root().ast().into_v1()?;
+    let uri = document.uri().to_string();
+
+    let mut lenses = Vec::new();
+    for item in ast.items() {
+        let (name, commands): (_, &[_]) = match item {
+            DocumentItem::Task(definition) => (
+                definition.name(),
+                &[
+                    ("Run", RUN_COMMAND),
+                    ("Generate inputs JSON", GENERATE_INPUTS_COMMAND),
+                ],
+            ),
+            DocumentItem::Workflow(definition) => (
+                definition.name(),
+                &[
+                    ("Run", RUN_COMMAND),
+                    ("Generate inputs JSON", GENERATE_INPUTS_COMMAND),
+                    ("Show call graph", SHOW_CALL_GRAPH_COMMAND),
+                ],
+            ),
+            _ => continue,
+        };
+
+        let range = range_from_span(index, name.span()).ok()?;
+        for (title, command) in commands {
+            lenses.push(CodeLens {
+                range,
+                command: Some(Command {
+                    title: title.to_string(),
+                    command: command.to_string(),
+                    arguments: Some(vec![json!(uri), json!(name.text())]),
+                }),
+                data: None,
+            });
+        }
+    }
+
+    Some(lenses)
+}
+
+/// Parses the arguments of a command.
+///
+/// The arguments are the URI of the document and the name of the task or
+/// workflow.
+pub fn parse_arguments(arguments: &[Value]) -> Result<(Url, String)> {
+    match arguments {
+        [Value::String(uri), Value::String(name)] => Ok((
+            uri.parse()
+                .with_context(|| format!("invalid document URI `{uri}`"))?,
+            name.clone(),
+        )),
+        _ => bail!("expected a document URI and the name of a task or workflow"),
+    }
+}
+
+/// Gets the path of the inputs file for a task or workflow.
+///
+/// The inputs file is located next to the document.
+pub fn inputs_path(document: &Path, name: &str) -> PathBuf {
+    document.with_file_name(format!("{name}.inputs.json"))
+}
+
+/// Gets the output directory for running a task or workflow.
+///
+/// The output directory is located next to the document.
+pub fn output_dir(document: &Path, name: &str) -> PathBuf {
+    document.with_file_name("runs").join(name)
+}
+
+/// Gets the inputs of the task or workflow with the given name.
+fn callable_inputs<'a>(
+    document: &'a Document,
+    name: &str,
+) -> Result<impl Iterator<Item = (&'a String, &'a Input)>> {
+    if let Some(task) = document.task_by_name(name) {
+        return Ok(task.inputs().iter());
+    }
+
+    match document.workflow() {
+        Some(workflow) if workflow.name() == name => Ok(workflow.inputs().iter()),
+        _ => Err(anyhow!(
+            "document does not contain a task or workflow named `{name}`"
+        )),
+    }
+}
+
+/// Creates a placeholder JSON value for an input of the given type.
+fn placeholder(ty: &Type) -> Value {
+    if ty.is_optional() {
+        return Value::Null;
+    }
+
+    match ty {
+        Type::Primitive(PrimitiveType::Boolean, _) => json!(false),
+        Type::Primitive(PrimitiveType::Integer, _) => json!(0),
+        Type::Primitive(PrimitiveType::Float, _) => json!(0.0),
+        Type::Primitive(
+            PrimitiveType::String | PrimitiveType::File | PrimitiveType::Directory,
+            _,
+        ) => json!(""),
+        Type::Compound(CompoundType::Array(ty), _) => {
+            if ty.is_non_empty() {
+                json!([placeholder(ty.element_type())])
+            } else {
+                json!([])
+            }
+        }
+        Type::Compound(CompoundType::Pair(ty), _) => json!({
+            "left": placeholder(ty.left_type()),
+            "right": placeholder(ty.right_type()),
+        }),
+        Type::Compound(CompoundType::Struct(ty), _) => Value::Object(
+            ty.members()
+                .iter()
+                .map(|(name, ty)| (name.clone(), placeholder(ty)))
+                .collect(),
+        ),
+        Type::Compound(CompoundType::Enum(ty), _) => {
+            json!(ty.variants().first().cloned().unwrap_or_default())
+        }
+        Type::Compound(CompoundType::Map(_), _) | Type::Object => json!({}),
+        _ => Value::Null,
+    }
+}
+
+/// Generates the inputs file for the task or workflow with the given name.
+///
+/// The file contains a placeholder value for each required input; if the file
+/// already exists, only the missing required inputs are added to it.
+///
+/// Returns the path to the inputs file.
+pub fn generate_inputs(document: &Document, path: &Path, name: &str) -> Result<PathBuf> {
+    let inputs_path = inputs_path(path, name);
+    let mut inputs = if inputs_path.exists() {
+        let contents = std::fs::read_to_string(&inputs_path).with_context(|| {
+            format!(
+                "failed to read inputs file `{path}`",
+                path = inputs_path.display()
+            )
+        })?;
+
+        match serde_json::from_str(&contents).with_context(|| {
+            format!(
+                "failed to parse inputs file `{path}`",
+                path = inputs_path.display()
+            )
+        })? {
+            Value::Object(map) => map,
+            _ => bail!(
+                "expected inputs file `{path}` to contain a JSON object",
+                path = inputs_path.display()
+            ),
+        }
+    } else {
+        Map::new()
+    };
+
+    for (input, ty) in callable_inputs(document, name)?
+        .filter(|(_, i)| i.required())
+        .map(|(n, i)| (n, i.ty()))
+    {
+        inputs
+            .entry(format!("{name}.{input}"))
+            .or_insert_with(|| placeholder(ty));
+    }
+
+    let contents = serde_json::to_string_pretty(&inputs)?;
+    std::fs::write(&inputs_path, format!("{contents}\n")).with_context(|| {
+        format!(
+            "failed to write inputs file `{path}`",
+            path = inputs_path.display()
+        )
+    })?;
+
+    Ok(inputs_path)
+}
+
+/// Generates the call graph of the workflow with the given name.
+///
+/// The call graph is the workflow's exported evaluation graph rendered as a
+/// Mermaid flowchart, where an edge from one node to another denotes that the
+/// latter depends on the former.
+pub fn call_graph(document: &Document, name: &str) -> Result<String> {
+    let export = WorkflowGraphExport::new(document, false)
+        .filter(|e| e.workflow() == name)
+        .with_context(|| format!("document does not contain a workflow named `{name}`"))?;
+    Ok(export.to_mermaid())
+}
+
+/// Formats a progress event of an evaluation as a progress message.
+pub fn progress_message(kind: &ProgressKind<'_>) -> String {
+    match kind {
+        ProgressKind::TaskStarted { id } => format!("task `{id}` started"),
+        ProgressKind::TaskRetried { id, retry } => {
+            format!("task `{id}` retried (retry {retry})", retry = retry + 1)
+        }
+        ProgressKind::TaskExecutionStarted { id } => format!("task `{id}` executing"),
+        ProgressKind::TaskExecutionCompleted { id, result } => match result {
+            Ok(_) => format!("task `{id}` executed"),
+            Err(_) => format!("task `{id}` failed to execute"),
+        },
+        ProgressKind::TaskCompleted { id, result } => match result {
+            Ok(_) => format!("task `{id}` completed"),
+            Err(_) => format!("task `{id}` failed"),
+        },
+        ProgressKind::WorkflowStarted { id } => format!("workflow `{id}` started"),
+        ProgressKind::WorkflowCompleted { id, result } => match result {
+            Ok(_) => format!("workflow `{id}` completed"),
+            Err(_) => format!("workflow `{id}` failed"),
+        },
+    }
+}
+
+/// Formats an evaluation error as a message.
+pub fn evaluation_error_message(error: &EvaluationError) -> String {
+    match error {
+        EvaluationError::Source(e) => format!(
+            "{path}: {message}",
+            path = e.document.path(),
+            message = e.diagnostic.message()
+        ),
+        EvaluationError::Other(e) => format!("{e:#}"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::test::analyze;
+
+    /// The source of the document used by the tests.
+    const SOURCE: &str = r#"version 1.1
+
+struct Point {
+    Int x
+    Int? y
+}
+
+task t {
+    input {
+        String name
+        Array[File]+ files
+        Point point
+        Int count = 1
+        Boolean? flag
+    }
+
+    command <<<>>>
+
+    output {
+        String out = name
+    }
+}
+
+workflow w {
+    call t as a { input: name = "a", files = ["a"], point = Point { x: 1 } }
+    call t as b { input: name = a.out, files = ["b"], point = Point { x: 1 } }
+    call t as c { input: name = "c", files = ["c"], point = Point { x: 1 } }
+    String d = b.out + c.out
+    call t as e { input: name = d, files = ["e"], point = Point { x: 1 } }
+}
+"#;
+
+    #[tokio::test]
+    async fn it_creates_code_lenses() {
+        let result = analyze(SOURCE).await;
+        let lenses = code_lenses(&result)
+            .expect("should have lenses")
+            .into_iter()
+            .map(|l| {
+                let command = l.command.expect("should have command");
+                (l.range.start.line, command.title, command.command)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lenses,
+            [
+                (7, "Run".to_string(), RUN_COMMAND.to_string()),
+                (
+                    7,
+                    "Generate inputs JSON".to_string(),
+                    GENERATE_INPUTS_COMMAND.to_string()
+                ),
+                (23, "Run".to_string(), RUN_COMMAND.to_string()),
+                (
+                    23,
+                    "Generate inputs JSON".to_string(),
+                    GENERATE_INPUTS_COMMAND.to_string()
+                ),
+                (
+                    23,
+                    "Show call graph".to_string(),
+                    SHOW_CALL_GRAPH_COMMAND.to_string()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_generates_inputs() {
+        let result = analyze(SOURCE).await;
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = dir.path().join("source.wdl");
+
+        let inputs = generate_inputs(result.document(), &path, "t").expect("should generate");
+        assert_eq!(inputs, dir.path().join("t.inputs.json"));
+
+        let contents = std::fs::read_to_string(&inputs).expect("should read inputs");
+        let value: Value = serde_json::from_str(&contents).expect("should parse inputs");
+        assert_eq!(
+            value,
+            json!({
+                "t.name": "",
+                "t.files": [""],
+                "t.point": { "x": 0, "y": null },
+            })
+        );
+
+        // Existing values are preserved
+        std::fs::write(&inputs, r#"{ "t.name": "foo" }"#).expect("should write inputs");
+        generate_inputs(result.document(), &path, "t").expect("should generate");
+        let contents = std::fs::read_to_string(&inputs).expect("should read inputs");
+        let value: Value = serde_json::from_str(&contents).expect("should parse inputs");
+        assert_eq!(value["t.name"], json!("foo"));
+
+        assert!(generate_inputs(result.document(), &path, "missing").is_err());
+    }
+
+    #[tokio::test]
+    async fn it_generates_call_graphs() {
+        let result = analyze(SOURCE).await;
+        let graph = call_graph(result.document(), "w").expect("should generate");
+        let id = |label: &str| {
+            graph
+                .lines()
+                .find_map(|l| {
+                    l.trim()
+                        .strip_suffix(&format!("(\"{label}\")"))
+                        .map(ToString::to_string)
+                })
+                .expect("should have node")
+        };
+
+        let (a, b, c, e) = (
+            id("call t as a"),
+            id("call t as b"),
+            id("call t as c"),
+            id("call t as e"),
+        );
+        assert!(graph.starts_with("flowchart TD\n"));
+        assert!(graph.contains(&format!("    {a} --> {b}\n")));
+        assert!(!graph.contains(&format!("    {b} --> {e}\n")));
+        assert!(!graph.contains(&format!("    {c} --> {e}\n")));
+        assert!(graph.contains("[\"String d\"]"));
+
+        assert!(call_graph(result.document(), "t").is_err());
+    }
+}
@@ -0,0 +1,184 @@
+//! Implementation of folding ranges.
+
+use tower_lsp::lsp_types::FoldingRange;
+use tower_lsp::lsp_types::FoldingRangeKind;
+use wdl_analysis::AnalysisResult;
+use wdl_ast::AstNode;
+use wdl_ast::SyntaxElement;
+use wdl_ast::SyntaxKind;
+use wdl_ast::TreeNode;
+use wdl_ast::TreeToken;
+
+/// Determines if a node of the given kind is a foldable block.
+///
+/// Foldable blocks are the document items, the sections of tasks and
+/// workflows, the workflow statements with bodies, and the compound metadata
+/// and literal values that may span multiple lines.
+fn is_foldable(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::StructDefinitionNode
+            | SyntaxKind::EnumDefinitionNode
+            | SyntaxKind::TaskDefinitionNode
+            | SyntaxKind::WorkflowDefinitionNode
+            | SyntaxKind::InputSectionNode
+            | SyntaxKind::OutputSectionNode
+            | SyntaxKind::CommandSectionNode
+            | SyntaxKind::RequirementsSectionNode
+            | SyntaxKind::TaskHintsSectionNode
+            | SyntaxKind::WorkflowHintsSectionNode
+            | SyntaxKind::RuntimeSectionNode
+            | SyntaxKind::MetadataSectionNode
+            | SyntaxKind::ParameterMetadataSectionNode
+            | SyntaxKind::MetadataObjectNode
+            | SyntaxKind::MetadataArrayNode
+            | SyntaxKind::WorkflowHintsObjectNode
+            | SyntaxKind::WorkflowHintsArrayNode
+            | SyntaxKind::ConditionalStatementNode
+            | SyntaxKind::ConditionalElseClauseNode
+            | SyntaxKind::ScatterStatementNode
+            | SyntaxKind::CallStatementNode
+            | SyntaxKind::LiteralArrayNode
+            | SyntaxKind::LiteralMapNode
+            | SyntaxKind::LiteralObjectNode
+            | SyntaxKind::LiteralStructNode
+            | SyntaxKind::LiteralHintsNode
+            | SyntaxKind::LiteralInputNode
+            | SyntaxKind::LiteralOutputNode
+    )
+}
+
+/// Creates a folding range from the given start and end lines.
+///
+/// Returns `None` if the range does not span multiple lines.
+fn folding_range(start: u32, end: u32, kind: Option<FoldingRangeKind>) -> Option<FoldingRange> {
+    if end <= start {
+        return None;
+    }
+
+    Some(FoldingRange {
+        start_line: start,
+        start_character: None,
+        end_line: end,
+        end_character: None,
+        kind,
+        collapsed_text: None,
+    })
+}
+
+/// Gets the folding ranges for an analysis result.
+///
+/// Returns `None` if the document could not be parsed.
+pub fn folding_ranges(result: &AnalysisResult) -> Option<Vec<FoldingRange>> {
+    let index = result.lines()?;
+    let root = result.document().root();
+    let line = |offset: usize| -> Option<u32> {
+        Some(index.line_col(u32::try_from(offset).ok()?.into()).line)
+    };
+
+    let mut ranges = Vec::new();
+    let mut imports: Option<(u32, u32)> = None;
+    let mut comments: Option<(u32, u32)> = None;
+
+    for element in root.inner().descendants_with_tokens() {
+        match element {
+            SyntaxElement::Node(node) => {
+                let span = node.span();
+                if node.kind() == SyntaxKind::ImportStatementNode {
+                    let (start, end) = (line(span.start())?, line(span.end())?);
+                    imports = match imports {
+                        Some((first, _)) => Some((first, end)),
+                        None => Some((start, end)),
+                    };
+                    continue;
+                }
+
+                if !is_foldable(node.kind()) {
+                    continue;
+                }
+
+                // Keep the line of the closing delimiter visible when folded
+                let start = line(span.start())?;
+                let end = line(span.end())?.saturating_sub(1);
+                ranges.extend(folding_range(start, end, Some(FoldingRangeKind::Region)));
+            }
+            SyntaxElement::Token(token) => {
+                if token.kind() != SyntaxKind::Comment {
+                    continue;
+                }
+
+                // Consecutive line comments are folded together
+                let current = line(token.span().start())?;
+                comments = match comments {
+                    Some((start, end)) if end + 1 == current => Some((start, current)),
+                    Some((start, end)) => {
+                        ranges.extend(folding_range(start, end, Some(FoldingRangeKind::Comment)));
+                        Some((current, current))
+                    }
+                    None => Some((current, current)),
+                };
+            }
+        }
+    }
+
+    if let Some((start, end)) = comments {
+        ranges.extend(folding_range(start, end, Some(FoldingRangeKind::Comment)));
+    }
+
+    if let Some((start, end)) = imports {
+        ranges.extend(folding_range(start, end, Some(FoldingRangeKind::Imports)));
+    }
+
+    Some(ranges)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::analyze;
+
+    #[tokio::test]
+    async fn it_creates_folding_ranges() {
+        let result = analyze(
+            r#"version 1.1
+
+import "a.wdl"
+import "b.wdl"
+
+# A comment
+# spanning lines
+task t {
+    command <<<
+        echo hi
+    >>>
+}
+
+workflow w {
+    scatter (i in [1, 2, 3]) {
+        call t
+    }
+}
+"#,
+        )
+        .await;
+
+        let mut ranges = folding_ranges(&result)
+            .expect("should have ranges")
+            .into_iter()
+            .map(|r| (r.start_line, r.end_line, r.kind))
+            .collect::<Vec<_>>();
+        ranges.sort_by_key(|(start, end, _)| (*start, *end));
+
+        assert_eq!(
+            ranges,
+            [
+                (2, 3, Some(FoldingRangeKind::Imports)),
+                (5, 6, Some(FoldingRangeKind::Comment)),
+                (7, 10, Some(FoldingRangeKind::Region)),
+                (8, 9, Some(FoldingRangeKind::Region)),
+                (13, 16, Some(FoldingRangeKind::Region)),
+                (14, 15, Some(FoldingRangeKind::Region)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,333 @@
+//! Implementation of call hierarchies.
+
+use std::collections::HashMap;
+
+use tower_lsp::lsp_types::CallHierarchyIncomingCall;
+use tower_lsp::lsp_types::CallHierarchyItem;
+use tower_lsp::lsp_types::CallHierarchyOutgoingCall;
+use tower_lsp::lsp_types::Position;
+use tower_lsp::lsp_types::Range;
+use tower_lsp::lsp_types::SymbolKind;
+use url::Url;
+use wdl_analysis::AnalysisResult;
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::v1::CallStatement;
+use wdl_ast::v1::DocumentItem;
+use wdl_ast::v1::WorkflowStatement;
+
+use crate::proto::offset;
+use crate::proto::range_from_span;
+
+/// Represents a call site within a workflow.
+struct CallSite {
+    /// The URI of the document containing the callee.
+    uri: Url,
+    /// The name of the called task or workflow.
+    name: String,
+    /// The range of the call target.
+    range: Range,
+}
+
+/// Finds the analysis result for the given document URI.
+fn find_result<'a>(results: &'a [AnalysisResult], uri: &Url) -> Option<&'a AnalysisResult> {
+    results.iter().find(|r| r.document().uri().as_ref() == uri)
+}
+
+/// Creates a call hierarchy item for the task or workflow with the given name.
+///
+/// Returns `None` if the document does not contain a task or workflow with the
+/// given name.
+fn item(result: &AnalysisResult, name: &str) -> Option<CallHierarchyItem> {
+    let index = result.lines()?;
+    let document = result.document();
+    let ast = document.root().ast().into_v1()?;
+    let (kind, detail, span, selection) = ast.items().find_map(|item| match item {
+        DocumentItem::Task(definition) if definition.name().text() == name => Some((
+            SymbolKind::FUNCTION,
+            "task",
+            definition.span(),
+            definition.name().span(),
+        )),
+        DocumentItem::Workflow(definition) if definition.name().text() == name => Some((
+            SymbolKind::MODULE,
+            "workflow",
+            definition.span(),
+            definition.name().span(),
+        )),
+        _ => None,
+    })?;
+
+    Some(CallHierarchyItem {
+        name: name.to_string(),
+        kind,
+        tags: None,
+        detail: Some(detail.to_string()),
+        uri: document.uri().as_ref().clone(),
+        range: range_from_span(index, span).ok()?,
+        selection_range: range_from_span(index, selection).ok()?,
+        data: None,
+    })
+}
+
+/// Collects the call statements of a workflow, including those nested in
+/// scatter and conditional statements.
+fn collect_calls(
+    statements: impl Iterator<Item = WorkflowStatement>,
+    calls: &mut Vec<CallStatement>,
+) {
+    for statement in statements {
+        match statement {
+            WorkflowStatement::Conditional(s) => {
+                collect_calls(s.statements(), calls);
+                for clause in s.else_clauses() {
+                    collect_calls(clause.statements(), calls);
+                }
+            }
+            WorkflowStatement::Scatter(s) => collect_calls(s.statements(), calls),
+            WorkflowStatement::Call(s) => calls.push(s),
+            WorkflowStatement::Declaration(_) => {}
+        }
+    }
+}
+
+/// Gets the call sites of the workflow in the given analysis result.
+///
+/// Calls are resolved with the analyzed calls of the workflow; calls through
+/// an import namespace resolve to the imported document.
+fn call_sites(result: &AnalysisResult) -> Vec<CallSite> {
+    let document = result.document();
+    let (Some(index), Some(workflow)) = (result.lines(), document.workflow()) else {
+        return Vec::new();
+    };
+
+    let Some(definition) = document
+        .root()
+        .ast()
+        .into_v1()
+        .and_then(|ast| ast.workflows().find(|w| w.name().text() == workflow.name()))
+    else {
+        return Vec::new();
+    };
+
+    let mut statements = Vec::new();
+    collect_calls(definition.statements(), &mut statements);
+
+    statements
+        .into_iter()
+        .filter_map(|statement| {
+            let target = statement.target();
+            let name = match statement.alias() {
+                Some(alias) => alias.name(),
+                None => target.names().last()?,
+            };
+
+            let ty = workflow.calls().get(name.text())?;
+            let uri = match ty.namespace() {
+                Some(ns) => document.namespace(ns)?.source().as_ref().clone(),
+                None => document.uri().as_ref().clone(),
+            };
+
+            Some(CallSite {
+                uri,
+                name: ty.name().to_string(),
+                range: range_from_span(index, target.span()).ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Prepares the call hierarchy at the given position of a document.
+///
+/// The position may be at the name of a task or workflow definition or at the
+/// target of a call statement.
+///
+/// Returns `None` if there is no task or workflow at the position.
+pub fn prepare(
+    results: &[AnalysisResult],
+    uri: &Url,
+    position: Position,
+) -> Option<Vec<CallHierarchyItem>> {
+    let result = find_result(results, uri)?;
+    let offset = offset(result.lines()?, position).ok()?;
+    let ast = result.document().root().ast().into_v1()?;
+
+    // Check for the name of a task or workflow definition
+    let name = ast.items().find_map(|item| {
+        let name = match item {
+            DocumentItem::Task(definition) => definition.name(),
+            DocumentItem::Workflow(definition) => definition.name(),
+            _ => return None,
+        };
+
+        name.span()
+            .contains(offset)
+            .then(|| name.text().to_string())
+    });
+
+    if let Some(name) = name {
+        return Some(vec![item(result, &name)?]);
+    }
+
+    // Otherwise, check for the target of a call
+    let site = call_sites(result).into_iter().find(|s| {
+        let start = s.range.start;
+        let end = s.range.end;
+        (start.line, start.character) <= (position.line, position.character)
+            && (position.line, position.character) <= (end.line, end.character)
+    })?;
+
+    Some(vec![item(find_result(results, &site.uri)?, &site.name)?])
+}
+
+/// Gets the incoming calls of a call hierarchy item.
+///
+/// An incoming call is a workflow that calls the item's task or workflow.
+pub fn incoming_calls(
+    results: &[AnalysisResult],
+    item: &CallHierarchyItem,
+) -> Vec<CallHierarchyIncomingCall> {
+    results
+        .iter()
+        .filter_map(|result| {
+            let ranges = call_sites(result)
+                .into_iter()
+                .filter(|s| s.uri == item.uri && s.name == item.name)
+                .map(|s| s.range)
+                .collect::<Vec<_>>();
+
+            if ranges.is_empty() {
+                return None;
+            }
+
+            let workflow = result.document().workflow()?;
+            Some(CallHierarchyIncomingCall {
+                from: self::item(result, workflow.name())?,
+                from_ranges: ranges,
+            })
+        })
+        .collect()
+}
+
+/// Gets the outgoing calls of a call hierarchy item.
+///
+/// Outgoing calls are the tasks and workflows called by the item's workflow;
+/// tasks have no outgoing calls.
+pub fn outgoing_calls(
+    results: &[AnalysisResult],
+    item: &CallHierarchyItem,
+) -> Vec<CallHierarchyOutgoingCall> {
+    let Some(result) = find_result(results, &item.uri) else {
+        return Vec::new();
+    };
+
+    match result.document().workflow() {
+        Some(workflow) if workflow.name() == item.name => {}
+        _ => return Vec::new(),
+    }
+
+    // Group the call sites by callee while preserving the order of the calls
+    let mut callees: Vec<(Url, String)> = Vec::new();
+    let mut ranges: HashMap<(Url, String), Vec<Range>> = HashMap::new();
+    for site in call_sites(result) {
+        let key = (site.uri, site.name);
+        if !ranges.contains_key(&key) {
+            callees.push(key.clone());
+        }
+
+        ranges.entry(key).or_default().push(site.range);
+    }
+
+    callees
+        .into_iter()
+        .filter_map(|key| {
+            let to = self::item(find_result(results, &key.0)?, &key.1)?;
+            Some(CallHierarchyOutgoingCall {
+                to,
+                from_ranges: ranges.remove(&key).unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::test::analyze_all;
+
+    #[tokio::test]
+    async fn it_creates_call_hierarchies() {
+        let (dir, results) = analyze_all(&[
+            (
+                "tasks.wdl",
+                r#"version 1.1
+
+task hello {
+    command <<<>>>
+}
+"#,
+            ),
+            (
+                "main.wdl",
+                r#"version 1.1
+
+import "tasks.wdl" as lib
+
+task local {
+    command <<<>>>
+}
+
+workflow main {
+    call lib.hello
+    scatter (i in [1, 2]) {
+        call lib.hello as again
+    }
+    call local
+}
+"#,
+            ),
+        ])
+        .await;
+
+        let main = Url::from_file_path(dir.path().join("main.wdl")).unwrap();
+        let tasks = Url::from_file_path(dir.path().join("tasks.wdl")).unwrap();
+
+        // Prepare from the call target through the import namespace
+        let items = prepare(&results, &main, Position::new(9, 15)).expect("should have item");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].name, "hello");
+        assert_eq!(items[0].uri, tasks);
+
+        let incoming = incoming_calls(&results, &items[0]);
+        assert_eq!(incoming.len(), 1);
+        assert_eq!(incoming[0].from.name, "main");
+        assert_eq!(incoming[0].from_ranges.len(), 2);
+
+        // Prepare from the workflow name
+        let items = prepare(&results, &main, Position::new(8, 10)).expect("should have item");
+        assert_eq!(items[0].name, "main");
+        assert_eq!(items[0].kind, SymbolKind::MODULE);
+
+        let outgoing = outgoing_calls(&results, &items[0])
+            .into_iter()
+            .map(|c| (c.to.uri, c.to.name, c.from_ranges.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outgoing,
+            [
+                (tasks.clone(), "hello".to_string(), 2),
+                (main.clone(), "local".to_string(), 1),
+            ]
+        );
+
+        // Tasks have no outgoing calls
+        let items = prepare(&results, &main, Position::new(4, 6)).expect("should have item");
+        assert_eq!(items[0].name, "local");
+        assert!(outgoing_calls(&results, &items[0]).is_empty());
+        assert_eq!(incoming_calls(&results, &items[0]).len(), 1);
+
+        assert!(prepare(&results, &main, Position::new(0, 0)).is_none());
+    }
+}
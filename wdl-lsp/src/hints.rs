@@ -0,0 +1,264 @@
+//! Implementation of inlay hints.
+
+use anyhow::Result;
+use line_index::LineIndex;
+use tower_lsp::lsp_types::InlayHint;
+use tower_lsp::lsp_types::InlayHintKind;
+use tower_lsp::lsp_types::InlayHintLabel;
+use tower_lsp::lsp_types::InlayHintTooltip;
+use tower_lsp::lsp_types::Range;
+use wdl_analysis::AnalysisResult;
+use wdl_analysis::Document;
+use wdl_analysis::document::ScopeRef;
+use wdl_analysis::types::Type;
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::Ident;
+use wdl_ast::v1::DocumentItem;
+use wdl_ast::v1::WorkflowStatement;
+
+use crate::proto::position;
+
+/// Collects the inlay hints of a workflow.
+struct Collector<'a> {
+    /// The line index of the document.
+    index: &'a LineIndex,
+    /// The range of the document to collect hints for.
+    range: Range,
+    /// The analyzed document.
+    document: &'a Document,
+    /// The top-level scope of the workflow.
+    scope: ScopeRef<'a>,
+    /// The collected hints.
+    hints: Vec<InlayHint>,
+}
+
+impl Collector<'_> {
+    /// Adds a type hint after the given name.
+    fn add(&mut self, name: &Ident, label: String, tooltip: String) -> Result<()> {
+        let position = position(self.index, name.span().end())?;
+        if position < self.range.start || position > self.range.end {
+            return Ok(());
+        }
+
+        self.hints.push(InlayHint {
+            position,
+            label: InlayHintLabel::String(label),
+            kind: Some(InlayHintKind::TYPE),
+            text_edits: None,
+            tooltip: Some(InlayHintTooltip::String(tooltip)),
+            padding_left: None,
+            padding_right: None,
+            data: None,
+        });
+
+        Ok(())
+    }
+
+    /// Gets the type of a name as seen from the top-level scope of the workflow.
+    ///
+    /// Returns `None` if the name in the top-level scope was not introduced by
+    /// the given identifier.
+    fn promoted(&self, name: &Ident) -> Option<&'_ Type> {
+        self.scope
+            .lookup(name.text())
+            .filter(|n| n.span() == name.span())
+            .map(|n| n.ty())
+    }
+
+    /// Visits the statements of a workflow.
+    ///
+    /// The `nested` flag is `true` when the statements are within the body of
+    /// a scatter or conditional statement; the types of the names declared in
+    /// such bodies are implicitly promoted outside of the statement.
+    fn visit(
+        &mut self,
+        statements: impl Iterator<Item = WorkflowStatement>,
+        nested: bool,
+    ) -> Result<()> {
+        for statement in statements {
+            match statement {
+                WorkflowStatement::Conditional(statement) => {
+                    self.visit(statement.statements(), true)?;
+                    for clause in statement.else_clauses() {
+                        self.visit(clause.statements(), true)?;
+                    }
+                }
+                WorkflowStatement::Scatter(statement) => {
+                    let variable = statement.variable();
+                    let ty = statement
+                        .braced_scope_span()
+                        .and_then(|s| self.document.find_scope_by_position(s.start()))
+                        .and_then(|s| s.local(variable.text()).map(|n| n.ty().clone()));
+                    if let Some(ty) = ty.filter(|ty| !ty.is_union()) {
+                        self.add(
+                            &variable,
+                            format!(": {ty}"),
+                            "type of the scatter variable".to_string(),
+                        )?;
+                    }
+
+                    self.visit(statement.statements(), true)?;
+                }
+                WorkflowStatement::Call(statement) if nested => {
+                    let name = match statement.alias() {
+                        Some(alias) => alias.name(),
+                        None => match statement.target().names().last() {
+                            Some(name) => name,
+                            None => continue,
+                        },
+                    };
+
+                    let Some(Type::Call(ty)) = self.promoted(&name) else {
+                        continue;
+                    };
+
+                    if ty.outputs().is_empty() {
+                        continue;
+                    }
+
+                    let outputs = ty
+                        .outputs()
+                        .iter()
+                        .map(|(name, output)| format!("{name}: {ty}", ty = output.ty()))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    self.add(
+                        &name,
+                        format!(" {{ {outputs} }}"),
+                        "call outputs as seen outside of the enclosing statement".to_string(),
+                    )?;
+                }
+                WorkflowStatement::Call(_) => {}
+                WorkflowStatement::Declaration(decl) if nested => {
+                    let name = decl.name();
+                    if let Some(ty) = self.promoted(&name).filter(|ty| !ty.is_union()) {
+                        let label = format!(": {ty}");
+                        self.add(
+                            &name,
+                            label,
+                            "type of the declaration as seen outside of the enclosing statement"
+                                .to_string(),
+                        )?;
+                    }
+                }
+                WorkflowStatement::Declaration(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Gets the inlay hints for an analysis result within the given range.
+///
+/// Type hints are provided for scatter variables and for the declarations and
+/// calls within scatter and conditional statements, whose types are
+/// implicitly promoted to `Array[T]` and `T?`, respectively, outside of the
+/// statement.
+///
+/// Returns `None` if the document could not be parsed or is not a supported
+/// version.
+pub fn inlay_hints(result: &AnalysisResult, range: Range) -> Option<Vec<InlayHint>> {
+    let index = result.lines()?;
+    let document = result.document();
+    let ast = document.root().ast().into_v1()?;
+    let Some(workflow) = document.workflow() else {
+        return Some(Vec::new());
+    };
+
+    let mut collector = Collector {
+        index,
+        range,
+        document,
+        scope: workflow.scope(),
+        hints: Vec::new(),
+    };
+
+    let Some(definition) = ast.items().find_map(|item| match item {
+        DocumentItem::Workflow(definition) if definition.name().text() == workflow.name() => {
+            Some(definition)
+        }
+        _ => None,
+    }) else {
+        return Some(Vec::new());
+    };
+
+    collector.visit(definition.statements(), false).ok()?;
+    Some(collector.hints)
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use tower_lsp::lsp_types::Position;
+
+    use super::*;
+    use crate::test::analyze;
+
+    #[tokio::test]
+    async fn it_creates_inlay_hints() {
+        let result = analyze(
+            r#"version 1.1
+
+task t {
+    command <<<>>>
+
+    output {
+        Int out = 1
+    }
+}
+
+workflow w {
+    input {
+        Boolean flag
+    }
+
+    scatter (i in [1, 2]) {
+        call t
+        Int x = i
+    }
+
+    if (flag) {
+        String y = "y"
+    }
+
+    Int z = 1
+}
+"#,
+        )
+        .await;
+
+        let hints = inlay_hints(
+            &result,
+            Range::new(Position::new(0, 0), Position::new(u32::MAX, 0)),
+        )
+        .expect("should have hints")
+        .into_iter()
+        .map(|h| {
+            let InlayHintLabel::String(label) = h.label else {
+                panic!("expected a string label");
+            };
+            (h.position.line, h.position.character, label)
+        })
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            hints,
+            [
+                (15, 14, ": Int".to_string()),
+                (16, 14, " { out: Array[Int] }".to_string()),
+                (17, 13, ": Array[Int]".to_string()),
+                (21, 16, ": String?".to_string()),
+            ]
+        );
+
+        // Only hints within the range are returned
+        let hints = inlay_hints(
+            &result,
+            Range::new(Position::new(20, 0), Position::new(22, 0)),
+        )
+        .expect("should have hints");
+        assert_eq!(hints.len(), 1);
+    }
+}
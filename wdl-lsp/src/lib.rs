@@ -7,7 +7,16 @@
 #![warn(clippy::missing_docs_in_private_items)]
 #![warn(rustdoc::broken_intra_doc_links)]
 
+mod commands;
+mod folding;
+mod hierarchy;
+mod hints;
 mod proto;
+mod semantic;
 mod server;
+mod signature;
+mod symbols;
+#[cfg(test)]
+mod test;
 
 pub use server::*;
@@ -6,6 +6,7 @@ use anyhow::Context;
 use anyhow::Result;
 use line_index::LineIndex;
 use line_index::WideEncoding;
+use line_index::WideLineCol;
 use tower_lsp::lsp_types::Diagnostic;
 use tower_lsp::lsp_types::DiagnosticRelatedInformation;
 use tower_lsp::lsp_types::DiagnosticSeverity;
@@ -48,6 +49,35 @@ pub fn position(index: &LineIndex, offset: usize) -> Result<Position> {
     Ok(Position::new(line_col.line, line_col.col))
 }
 
+/// Converts an LSP position to a file byte offset.
+pub fn offset(index: &LineIndex, position: Position) -> Result<usize> {
+    let line_col = index
+        .to_utf8(
+            WideEncoding::Utf16,
+            WideLineCol {
+                line: position.line,
+                col: position.character,
+            },
+        )
+        .with_context(|| {
+            format!(
+                "invalid position: {line}:{character}",
+                line = position.line,
+                character = position.character
+            )
+        })?;
+
+    let offset = index.offset(line_col).with_context(|| {
+        format!(
+            "invalid line column: {line}:{column}",
+            line = line_col.line,
+            column = line_col.col
+        )
+    })?;
+
+    Ok(offset.into())
+}
+
 /// Converts a diagnostic span into an LSP range.
 pub fn range_from_span(index: &LineIndex, span: Span) -> Result<Range> {
     Ok(Range::new(
@@ -0,0 +1,474 @@
+//! Implementation of semantic tokens.
+
+use anyhow::Result;
+use line_index::LineIndex;
+use tower_lsp::lsp_types::SemanticToken;
+use tower_lsp::lsp_types::SemanticTokenModifier;
+use tower_lsp::lsp_types::SemanticTokenType;
+use tower_lsp::lsp_types::SemanticTokensLegend;
+use wdl_analysis::AnalysisResult;
+use wdl_analysis::Document;
+use wdl_analysis::stdlib::STDLIB;
+use wdl_analysis::types::Type;
+use wdl_ast::AstNode;
+use wdl_ast::SyntaxKind;
+use wdl_ast::SyntaxNode;
+use wdl_ast::SyntaxToken;
+use wdl_ast::TreeNode;
+use wdl_ast::TreeToken;
+
+use crate::proto::position;
+
+/// The semantic token types reported by the server.
+///
+/// The index of a type in this list is its encoded value.
+const TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::KEYWORD,
+    SemanticTokenType::TYPE,
+    SemanticTokenType::STRUCT,
+    SemanticTokenType::ENUM,
+    SemanticTokenType::ENUM_MEMBER,
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::METHOD,
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::PROPERTY,
+    SemanticTokenType::NUMBER,
+    SemanticTokenType::STRING,
+    SemanticTokenType::COMMENT,
+    SemanticTokenType::OPERATOR,
+];
+
+/// The semantic token modifiers reported by the server.
+///
+/// The index of a modifier in this list is its bit in the encoded value.
+const TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
+    SemanticTokenModifier::DECLARATION,
+    SemanticTokenModifier::DEFAULT_LIBRARY,
+];
+
+/// Represents the type of a semantic token.
+///
+/// The discriminant of each variant is its index in [`TOKEN_TYPES`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenType {
+    /// The token is a keyword.
+    Keyword,
+    /// The token is a primitive or compound type keyword.
+    Type,
+    /// The token names a struct.
+    Struct,
+    /// The token names an enum.
+    Enum,
+    /// The token names an enum variant.
+    EnumMember,
+    /// The token names a task, workflow, or standard library function.
+    Function,
+    /// The token names a call.
+    Method,
+    /// The token names an import namespace.
+    Namespace,
+    /// The token names a task or workflow input.
+    Parameter,
+    /// The token names a declaration or scatter variable.
+    Variable,
+    /// The token names a member or a key of a section item.
+    Property,
+    /// The token is a numeric literal.
+    Number,
+    /// The token is part of a string literal.
+    String,
+    /// The token is a comment.
+    Comment,
+    /// The token is an operator or placeholder delimiter.
+    Operator,
+}
+
+/// The modifier bit for a token that declares a name.
+const DECLARATION: u32 = 1 << 0;
+
+/// The modifier bit for a token that names a standard library function.
+const DEFAULT_LIBRARY: u32 = 1 << 1;
+
+/// Gets the semantic tokens legend of the server.
+pub fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+/// Classifies a token that is not an identifier.
+///
+/// Returns `None` if the token is not highlighted.
+fn classify_token(token: &SyntaxToken) -> Option<TokenType> {
+    match token.kind() {
+        SyntaxKind::Comment => Some(TokenType::Comment),
+        SyntaxKind::Version | SyntaxKind::Integer | SyntaxKind::Float => Some(TokenType::Number),
+        SyntaxKind::SingleQuote | SyntaxKind::DoubleQuote | SyntaxKind::LiteralStringText => {
+            Some(TokenType::String)
+        }
+        SyntaxKind::ArrayTypeKeyword
+        | SyntaxKind::BooleanTypeKeyword
+        | SyntaxKind::DirectoryTypeKeyword
+        | SyntaxKind::FileTypeKeyword
+        | SyntaxKind::FloatTypeKeyword
+        | SyntaxKind::IntTypeKeyword
+        | SyntaxKind::MapTypeKeyword
+        | SyntaxKind::ObjectTypeKeyword
+        | SyntaxKind::PairTypeKeyword
+        | SyntaxKind::StringTypeKeyword => Some(TokenType::Type),
+        SyntaxKind::AfterKeyword
+        | SyntaxKind::AliasKeyword
+        | SyntaxKind::AsKeyword
+        | SyntaxKind::CallKeyword
+        | SyntaxKind::CommandKeyword
+        | SyntaxKind::ElseKeyword
+        | SyntaxKind::EnumKeyword
+        | SyntaxKind::EnvKeyword
+        | SyntaxKind::FalseKeyword
+        | SyntaxKind::HintsKeyword
+        | SyntaxKind::IfKeyword
+        | SyntaxKind::ImportKeyword
+        | SyntaxKind::InKeyword
+        | SyntaxKind::InputKeyword
+        | SyntaxKind::MetaKeyword
+        | SyntaxKind::NoneKeyword
+        | SyntaxKind::NullKeyword
+        | SyntaxKind::ObjectKeyword
+        | SyntaxKind::OutputKeyword
+        | SyntaxKind::ParameterMetaKeyword
+        | SyntaxKind::RequirementsKeyword
+        | SyntaxKind::RuntimeKeyword
+        | SyntaxKind::ScatterKeyword
+        | SyntaxKind::StructKeyword
+        | SyntaxKind::TaskKeyword
+        | SyntaxKind::ThenKeyword
+        | SyntaxKind::TrueKeyword
+        | SyntaxKind::VersionKeyword
+        | SyntaxKind::WorkflowKeyword => Some(TokenType::Keyword),
+        SyntaxKind::Assignment
+        | SyntaxKind::Exclamation
+        | SyntaxKind::Plus
+        | SyntaxKind::Minus
+        | SyntaxKind::LogicalOr
+        | SyntaxKind::LogicalAnd
+        | SyntaxKind::Asterisk
+        | SyntaxKind::Exponentiation
+        | SyntaxKind::Slash
+        | SyntaxKind::Percent
+        | SyntaxKind::Equal
+        | SyntaxKind::NotEqual
+        | SyntaxKind::LessEqual
+        | SyntaxKind::GreaterEqual
+        | SyntaxKind::Less
+        | SyntaxKind::Greater
+        | SyntaxKind::PlaceholderOpen => Some(TokenType::Operator),
+        // The closing brace of a placeholder is highlighted with its opening token
+        SyntaxKind::CloseBrace
+            if token.parent().map(|p| p.kind()) == Some(SyntaxKind::PlaceholderNode) =>
+        {
+            Some(TokenType::Operator)
+        }
+        _ => None,
+    }
+}
+
+/// Classifies a name reference using the scopes of the analyzed document.
+///
+/// References to calls are distinguished from references to inputs and other
+/// declarations.
+fn classify_name_ref(document: &Document, token: &SyntaxToken) -> TokenType {
+    let scope = document.find_scope_by_position(token.span().start());
+    let Some(name) = scope.as_ref().and_then(|s| s.lookup(token.text())) else {
+        // A reference to an enum is the target of a variant access
+        if document.enum_by_name(token.text()).is_some() {
+            return TokenType::Enum;
+        }
+
+        return TokenType::Variable;
+    };
+
+    if let Type::Call(_) = name.ty() {
+        return TokenType::Method;
+    }
+
+    // Determine if the name was declared in the input section of the enclosing task
+    // or workflow
+    let input = token
+        .parent_ancestors()
+        .find(|n| {
+            matches!(
+                n.kind(),
+                SyntaxKind::TaskDefinitionNode | SyntaxKind::WorkflowDefinitionNode
+            )
+        })
+        .and_then(|n| {
+            n.children()
+                .find(|c| c.kind() == SyntaxKind::InputSectionNode)
+        });
+
+    match input {
+        Some(section) if section.span().contains(name.span().start()) => TokenType::Parameter,
+        _ => TokenType::Variable,
+    }
+}
+
+/// Classifies an identifier token based on its parent node.
+///
+/// Returns `None` if the identifier is not highlighted.
+fn classify_ident(document: &Document, token: &SyntaxToken) -> Option<(TokenType, u32)> {
+    let parent = token.parent()?;
+    match parent.kind() {
+        SyntaxKind::StructDefinitionNode => Some((TokenType::Struct, DECLARATION)),
+        SyntaxKind::EnumDefinitionNode => Some((TokenType::Enum, DECLARATION)),
+        SyntaxKind::EnumVariantNode => Some((TokenType::EnumMember, DECLARATION)),
+        SyntaxKind::TaskDefinitionNode | SyntaxKind::WorkflowDefinitionNode => {
+            Some((TokenType::Function, DECLARATION))
+        }
+        SyntaxKind::UnboundDeclNode | SyntaxKind::BoundDeclNode => {
+            match parent.parent().map(|p| p.kind()) {
+                Some(SyntaxKind::StructDefinitionNode) => Some((TokenType::Property, DECLARATION)),
+                Some(SyntaxKind::InputSectionNode) => Some((TokenType::Parameter, DECLARATION)),
+                _ => Some((TokenType::Variable, DECLARATION)),
+            }
+        }
+        SyntaxKind::TypeRefNode if document.enum_by_name(token.text()).is_some() => {
+            Some((TokenType::Enum, 0))
+        }
+        SyntaxKind::TypeRefNode | SyntaxKind::ImportAliasNode | SyntaxKind::LiteralStructNode => {
+            Some((TokenType::Struct, 0))
+        }
+        SyntaxKind::ImportStatementNode => Some((TokenType::Namespace, DECLARATION)),
+        SyntaxKind::CallTargetNode => {
+            // Every name in the target except for the last is a namespace
+            if token
+                .siblings_with_tokens(wdl_ast::Direction::Next)
+                .skip(1)
+                .any(|e| e.kind() == SyntaxKind::Ident)
+            {
+                Some((TokenType::Namespace, 0))
+            } else {
+                Some((TokenType::Function, 0))
+            }
+        }
+        SyntaxKind::CallAliasNode => Some((TokenType::Method, DECLARATION)),
+        SyntaxKind::CallAfterNode => Some((TokenType::Method, 0)),
+        SyntaxKind::CallInputItemNode => Some((TokenType::Parameter, 0)),
+        SyntaxKind::ScatterStatementNode => Some((TokenType::Variable, DECLARATION)),
+        SyntaxKind::NameRefExprNode => Some((classify_name_ref(document, token), 0)),
+        SyntaxKind::CallExprNode => {
+            if STDLIB.function(token.text()).is_some() {
+                Some((TokenType::Function, DEFAULT_LIBRARY))
+            } else {
+                Some((TokenType::Function, 0))
+            }
+        }
+        SyntaxKind::AccessExprNode
+            if parent
+                .first_child()
+                .filter(|c| c.kind() == SyntaxKind::NameRefExprNode)
+                .and_then(|c| c.first_token())
+                .map(|t| classify_name_ref(document, &t) == TokenType::Enum)
+                .unwrap_or(false) =>
+        {
+            Some((TokenType::EnumMember, 0))
+        }
+        SyntaxKind::AccessExprNode
+        | SyntaxKind::RuntimeItemNode
+        | SyntaxKind::RequirementsItemNode
+        | SyntaxKind::TaskHintsItemNode
+        | SyntaxKind::WorkflowHintsItemNode
+        | SyntaxKind::WorkflowHintsObjectItemNode
+        | SyntaxKind::MetadataObjectItemNode
+        | SyntaxKind::LiteralStructItemNode
+        | SyntaxKind::LiteralObjectItemNode
+        | SyntaxKind::LiteralHintsItemNode
+        | SyntaxKind::LiteralInputItemNode
+        | SyntaxKind::LiteralOutputItemNode
+        | SyntaxKind::PlaceholderSepOptionNode
+        | SyntaxKind::PlaceholderDefaultOptionNode => Some((TokenType::Property, 0)),
+        _ => None,
+    }
+}
+
+/// Encodes semantic tokens relative to the previous token.
+struct Encoder<'a> {
+    /// The line index of the document.
+    index: &'a LineIndex,
+    /// The encoded tokens.
+    tokens: Vec<SemanticToken>,
+    /// The line of the previously encoded token.
+    line: u32,
+    /// The start character of the previously encoded token.
+    start: u32,
+}
+
+impl<'a> Encoder<'a> {
+    /// Creates a new encoder for the given line index.
+    fn new(index: &'a LineIndex) -> Self {
+        Self {
+            index,
+            tokens: Vec::new(),
+            line: 0,
+            start: 0,
+        }
+    }
+
+    /// Encodes a token.
+    ///
+    /// Tokens spanning multiple lines are encoded as a token per line.
+    fn push(&mut self, token: &SyntaxToken, ty: TokenType, modifiers: u32) -> Result<()> {
+        let mut offset = token.span().start();
+        for part in token.text().split_inclusive('\n') {
+            let text = part.trim_end_matches(['\r', '\n']);
+            if !text.is_empty() {
+                let start = position(self.index, offset)?;
+                let end = position(self.index, offset + text.len())?;
+                let delta_line = start.line - self.line;
+                let delta_start = if delta_line == 0 {
+                    start.character - self.start
+                } else {
+                    start.character
+                };
+
+                self.tokens.push(SemanticToken {
+                    delta_line,
+                    delta_start,
+                    length: end.character - start.character,
+                    token_type: ty as u32,
+                    token_modifiers_bitset: modifiers,
+                });
+
+                self.line = start.line;
+                self.start = start.character;
+            }
+
+            offset += part.len();
+        }
+
+        Ok(())
+    }
+}
+
+/// Gets the semantic tokens for an analysis result.
+///
+/// Returns `None` if the document could not be parsed.
+pub fn semantic_tokens(result: &AnalysisResult) -> Option<Vec<SemanticToken>> {
+    let index = result.lines()?;
+    let document = result.document();
+    let root: SyntaxNode = document.root().inner().clone();
+
+    let mut encoder = Encoder::new(index);
+    for token in root
+        .descendants_with_tokens()
+        .filter_map(|e| e.into_token())
+    {
+        let classified = if token.kind() == SyntaxKind::Ident {
+            classify_ident(document, &token)
+        } else {
+            classify_token(&token).map(|ty| (ty, 0))
+        };
+
+        if let Some((ty, modifiers)) = classified {
+            encoder.push(&token, ty, modifiers).ok()?;
+        }
+    }
+
+    Some(encoder.tokens)
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::test::analyze;
+
+    /// Decodes semantic tokens into absolute positions.
+    fn decode(tokens: &[SemanticToken], source: &str) -> Vec<(String, SemanticTokenType, u32)> {
+        let lines = source.lines().collect::<Vec<_>>();
+        let mut line = 0;
+        let mut start = 0;
+        tokens
+            .iter()
+            .map(|t| {
+                if t.delta_line > 0 {
+                    start = 0;
+                }
+                line += t.delta_line;
+                start += t.delta_start;
+                let text = &lines[line as usize][start as usize..(start + t.length) as usize];
+                (
+                    text.to_string(),
+                    TOKEN_TYPES[t.token_type as usize].clone(),
+                    t.token_modifiers_bitset,
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn it_creates_semantic_tokens() {
+        let source = r#"version 1.1
+
+task t {
+    input {
+        String name
+    }
+
+    command <<<
+        echo ~{name}
+    >>>
+
+    output {
+        Int out = length([name])
+    }
+}
+
+workflow w {
+    scatter (i in [1, 2]) {
+        call t { input: name = "~{i}" }
+    }
+
+    output {
+        Array[Int] outs = t.out
+    }
+}
+"#;
+        let result = analyze(source).await;
+        let tokens = semantic_tokens(&result).expect("should have tokens");
+        let tokens = decode(&tokens, source);
+
+        let find = |text: &str, index: usize| {
+            tokens
+                .iter()
+                .filter(|(t, ..)| t == text)
+                .nth(index)
+                .map(|(_, ty, modifiers)| (ty.clone(), *modifiers))
+                .unwrap_or_else(|| panic!("missing token `{text}`"))
+        };
+
+        assert_eq!(find("version", 0), (SemanticTokenType::KEYWORD, 0));
+        assert_eq!(find("1.1", 0), (SemanticTokenType::NUMBER, 0));
+        assert_eq!(find("t", 0), (SemanticTokenType::FUNCTION, DECLARATION));
+        assert_eq!(find("String", 0), (SemanticTokenType::TYPE, 0));
+        assert_eq!(find("name", 0), (SemanticTokenType::PARAMETER, DECLARATION));
+        assert_eq!(find("~{", 0), (SemanticTokenType::OPERATOR, 0));
+        assert_eq!(find("name", 1), (SemanticTokenType::PARAMETER, 0));
+        assert_eq!(find("}", 0), (SemanticTokenType::OPERATOR, 0));
+        assert_eq!(find("out", 0), (SemanticTokenType::VARIABLE, DECLARATION));
+        assert_eq!(
+            find("length", 0),
+            (SemanticTokenType::FUNCTION, DEFAULT_LIBRARY)
+        );
+        assert_eq!(find("i", 0), (SemanticTokenType::VARIABLE, DECLARATION));
+        assert_eq!(find("t", 1), (SemanticTokenType::FUNCTION, 0));
+        assert_eq!(find("name", 3), (SemanticTokenType::PARAMETER, 0));
+        assert_eq!(find("\"", 0), (SemanticTokenType::STRING, 0));
+        assert_eq!(find("i", 1), (SemanticTokenType::VARIABLE, 0));
+        assert_eq!(find("t", 2), (SemanticTokenType::METHOD, 0));
+        assert_eq!(find("out", 1), (SemanticTokenType::PROPERTY, 0));
+    }
+}
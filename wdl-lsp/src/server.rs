@@ -1,17 +1,25 @@
 //! Implementation of the LSP server.
 
+use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::mem;
 use std::path::Component;
+use std::path::Path;
 use std::path::PathBuf;
 use std::path::Prefix;
 use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 
 use anyhow::Result;
+use anyhow::bail;
 use notification::Progress;
+use parking_lot::Mutex;
 use parking_lot::RwLock;
 use request::WorkDoneProgressCreate;
+use serde_json::Value;
 use serde_json::to_value;
+use tokio_util::sync::CancellationToken;
 use tower_lsp::Client;
 use tower_lsp::LanguageServer;
 use tower_lsp::LspService;
@@ -23,8 +31,10 @@ use tracing::debug;
 use tracing::error;
 use tracing::info;
 use uuid::Uuid;
+use wdl_analysis::AnalysisResult;
 use wdl_analysis::Analyzer;
 use wdl_analysis::DiagnosticsConfig;
+use wdl_analysis::Document;
 use wdl_analysis::IncrementalChange;
 use wdl_analysis::SourceEdit;
 use wdl_analysis::SourcePosition;
@@ -32,9 +42,23 @@ use wdl_analysis::SourcePositionEncoding;
 use wdl_analysis::Validator;
 use wdl_analysis::path_to_uri;
 use wdl_analysis::rules;
+use wdl_cli::Evaluator;
+use wdl_cli::inputs::InputFile;
+use wdl_cli::inputs::OriginPaths;
+use wdl_engine::Inputs as EngineInputs;
+use wdl_engine::config::Config;
+use wdl_engine::v1::ProgressKind;
 use wdl_lint::Linter;
+use wdl_lint::config::LintConfig;
 
+use crate::commands;
+use crate::folding;
+use crate::hierarchy;
+use crate::hints;
 use crate::proto;
+use crate::semantic;
+use crate::signature;
+use crate::symbols;
 
 /// Normalizes the path of a URI.
 ///
@@ -149,12 +173,41 @@ impl ProgressToken {
         Self(Some(token))
     }
 
+    /// Gets the identifier of the progress token.
+    ///
+    /// Returns `None` for a no-op token.
+    pub fn id(&self) -> Option<&str> {
+        self.0.as_deref()
+    }
+
     /// Starts the work progress.
     pub async fn start(
         &self,
         client: &Client,
         title: impl Into<String>,
         message: impl Into<String>,
+    ) {
+        self.begin(client, title.into(), message.into(), None).await;
+    }
+
+    /// Starts the work progress that the client may request be cancelled.
+    pub async fn start_cancellable(
+        &self,
+        client: &Client,
+        title: impl Into<String>,
+        message: impl Into<String>,
+    ) {
+        self.begin(client, title.into(), message.into(), Some(true))
+            .await;
+    }
+
+    /// Sends the notification that begins the work progress.
+    async fn begin(
+        &self,
+        client: &Client,
+        title: String,
+        message: String,
+        cancellable: Option<bool>,
     ) {
         if let Some(token) = &self.0 {
             client
@@ -162,9 +215,9 @@ impl ProgressToken {
                     token: NumberOrString::String(token.clone()),
                     value: ProgressParamsValue::WorkDone(WorkDoneProgress::Begin(
                         WorkDoneProgressBegin {
-                            title: title.into(),
-                            cancellable: None,
-                            message: Some(message.into()),
+                            title,
+                            cancellable,
+                            message: Some(message),
                             percentage: Some(0),
                         },
                     )),
@@ -223,6 +276,12 @@ pub struct ServerOptions {
 
     /// Whether or not linting is enabled.
     pub lint: bool,
+
+    /// The lint configuration to use when linting is enabled.
+    ///
+    /// If `None`, a `wdl-lint.toml` file in the server's current directory or
+    /// one of its ancestors is used if it exists.
+    pub lint_config: Option<LintConfig>,
 }
 
 /// Represents an LSP server for analyzing WDL documents.
@@ -238,6 +297,9 @@ pub struct Server {
     client_support: Arc<RwLock<ClientSupport>>,
     /// The current set of workspace folders.
     folders: Arc<RwLock<Vec<WorkspaceFolder>>>,
+    /// The cancellation tokens of running evaluations, keyed by the identifier
+    /// of the evaluation's progress token.
+    cancellations: Arc<Mutex<HashMap<String, CancellationToken>>>,
 }
 
 impl Server {
@@ -245,7 +307,24 @@ impl Server {
     pub async fn run(options: ServerOptions) -> Result<()> {
         debug!("running LSP server: {options:#?}");
 
-        let (service, socket) = LspService::new(|client| {
+        let lint_config = match (options.lint, &options.lint_config) {
+            (false, _) => None,
+            (true, Some(config)) => Some(config.clone()),
+            (true, None) => match std::env::current_dir().map(LintConfig::discover) {
+                Ok(Ok(config)) => config,
+                Ok(Err(e)) => {
+                    error!("failed to load lint configuration: {e:#}");
+                    None
+                }
+                Err(e) => {
+                    error!("failed to determine the current directory: {e}");
+                    None
+                }
+            },
+        }
+        .map(Arc::new);
+
+        let (service, socket) = LspService::build(|client| {
             let lint = options.lint;
             let analyzer_client = client.clone();
 
@@ -268,15 +347,24 @@ impl Server {
                     move || {
                         let mut validator = Validator::default();
                         if lint {
-                            validator.add_visitor(Linter::default());
+                            validator.add_visitor(match &lint_config {
+                                Some(config) => Linter::with_config(config.clone()),
+                                None => Linter::default(),
+                            });
                         }
                         validator
                     },
                 ),
                 client_support: Default::default(),
                 folders: Default::default(),
+                cancellations: Default::default(),
             }
-        });
+        })
+        .custom_method(
+            "window/workDoneProgress/cancel",
+            Self::work_done_progress_cancel,
+        )
+        .finish();
 
         let stdin = tokio::io::stdin();
         let stdout = tokio::io::stdout();
@@ -303,6 +391,168 @@ impl Server {
             .unwrap_or(env!("CARGO_PKG_VERSION"))
     }
 
+    /// Analyzes the given document and gets its analysis result.
+    ///
+    /// Returns `Ok(None)` if the document is not known to the analyzer.
+    async fn analysis_result(&self, uri: &Url) -> RpcResult<Option<AnalysisResult>> {
+        let results = self
+            .analyzer
+            .analyze_document(ProgressToken::default(), uri.clone())
+            .await
+            .map_err(|e| RpcError {
+                code: ErrorCode::InternalError,
+                message: e.to_string().into(),
+                data: None,
+            })?;
+
+        Ok(results
+            .into_iter()
+            .find(|r| r.document().uri().as_ref() == uri))
+    }
+
+    /// Analyzes the workspace and gets the analysis results of all documents.
+    async fn analysis_results(&self) -> RpcResult<Vec<AnalysisResult>> {
+        self.analyzer
+            .analyze(ProgressToken::default())
+            .await
+            .map_err(|e| RpcError {
+                code: ErrorCode::InternalError,
+                message: e.to_string().into(),
+                data: None,
+            })
+    }
+
+    /// Runs a task or workflow in the given document.
+    ///
+    /// The inputs are read from the inputs file next to the document, if it
+    /// exists; evaluation progress is reported to the client as work done
+    /// progress.
+    ///
+    /// Returns the outputs of the evaluation.
+    async fn run_callable(&self, document: &Document, path: &Path, name: &str) -> Result<Value> {
+        if document.has_errors() {
+            bail!("cannot run `{name}` because the document has errors");
+        }
+
+        let inputs_path = commands::inputs_path(path, name);
+        let inputs = if inputs_path.exists() {
+            InputFile::read(&inputs_path)?.into_engine_inputs(document)?
+        } else {
+            None
+        };
+
+        let (inputs, origins) = match inputs {
+            Some((callee, inputs, origins)) => {
+                if callee != name {
+                    bail!(
+                        "inputs file `{path}` is for `{callee}` instead of `{name}`",
+                        path = inputs_path.display()
+                    );
+                }
+
+                (inputs, origins)
+            }
+            None => {
+                let inputs = if document.task_by_name(name).is_some() {
+                    EngineInputs::Task(Default::default())
+                } else {
+                    EngineInputs::Workflow(Default::default())
+                };
+
+                let dir = path.parent().unwrap_or(path).to_path_buf();
+                (inputs, OriginPaths::from(dir))
+            }
+        };
+
+        let work_done_progress = self.client_support.read().work_done_progress;
+        let progress = ProgressToken::new(&self.client, work_done_progress).await;
+        progress
+            .start_cancellable(&self.client, format!("Running `{name}`"), "evaluating...")
+            .await;
+
+        // Evaluation is cancelled when the client cancels the work done progress
+        let cancellation = CancellationToken::new();
+        if let Some(id) = progress.id() {
+            self.cancellations
+                .lock()
+                .insert(id.to_string(), cancellation.clone());
+        }
+
+        // The percentage is based on the number of tasks completed out of those started
+        let client = self.client.clone();
+        let token = progress.clone();
+        let started = Arc::new(AtomicUsize::new(0));
+        let completed = Arc::new(AtomicUsize::new(0));
+        let output_dir = commands::output_dir(path, name);
+        let result = Evaluator::new(
+            document,
+            name,
+            inputs,
+            origins,
+            Config::default(),
+            &output_dir,
+        )
+        .run(cancellation.clone(), move |kind| {
+            match kind {
+                ProgressKind::TaskStarted { .. } => {
+                    started.fetch_add(1, Ordering::SeqCst);
+                }
+                ProgressKind::TaskCompleted { .. } => {
+                    completed.fetch_add(1, Ordering::SeqCst);
+                }
+                _ => {}
+            }
+
+            let message = commands::progress_message(&kind);
+            let percentage = (completed.load(Ordering::SeqCst) * 100
+                / started.load(Ordering::SeqCst).max(1)) as u32;
+            let client = client.clone();
+            let token = token.clone();
+            async move { token.update(&client, message, percentage).await }
+        })
+        .await;
+
+        if let Some(id) = progress.id() {
+            self.cancellations.lock().remove(id);
+        }
+
+        match result {
+            Ok(outputs) => {
+                progress
+                    .complete(&self.client, format!("`{name}` completed"))
+                    .await;
+                Ok(to_value(outputs)?)
+            }
+            Err(_) if cancellation.is_cancelled() => {
+                progress
+                    .complete(&self.client, format!("`{name}` was cancelled"))
+                    .await;
+                bail!("evaluation of `{name}` was cancelled")
+            }
+            Err(e) => {
+                progress
+                    .complete(&self.client, format!("`{name}` failed"))
+                    .await;
+                bail!(commands::evaluation_error_message(&e))
+            }
+        }
+    }
+
+    /// Handles a `window/workDoneProgress/cancel` notification from the
+    /// client.
+    ///
+    /// If the progress token belongs to a running evaluation, the evaluation
+    /// is cancelled.
+    async fn work_done_progress_cancel(&self, params: WorkDoneProgressCancelParams) {
+        debug!("received `window/workDoneProgress/cancel` notification: {params:#?}");
+
+        if let NumberOrString::String(id) = &params.token {
+            if let Some(cancellation) = self.cancellations.lock().get(id) {
+                cancellation.cancel();
+            }
+        }
+    }
+
     /// Registers a generic watcher for all files/directories in the workspace.
     async fn register_watcher(&self) {
         self.client
@@ -383,6 +633,32 @@ impl LanguageServer for Server {
                     },
                 )),
                 document_formatting_provider: Some(OneOf::Left(true)),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+                semantic_tokens_provider: Some(
+                    SemanticTokensServerCapabilities::SemanticTokensOptions(
+                        SemanticTokensOptions {
+                            legend: semantic::legend(),
+                            full: Some(SemanticTokensFullOptions::Bool(true)),
+                            ..Default::default()
+                        },
+                    ),
+                ),
+                inlay_hint_provider: Some(OneOf::Left(true)),
+                call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
+                code_lens_provider: Some(CodeLensOptions {
+                    resolve_provider: Some(false),
+                }),
+                execute_command_provider: Some(ExecuteCommandOptions {
+                    commands: commands::COMMANDS.iter().map(ToString::to_string).collect(),
+                    ..Default::default()
+                }),
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec!["(".to_string(), "{".to_string()]),
+                    retrigger_characters: Some(vec![",".to_string()]),
+                    ..Default::default()
+                }),
                 ..Default::default()
             },
             server_info: Some(ServerInfo {
@@ -710,4 +986,197 @@ impl LanguageServer for Server {
 
         Ok(result)
     }
+
+    async fn document_symbol(
+        &self,
+        mut params: DocumentSymbolParams,
+    ) -> RpcResult<Option<DocumentSymbolResponse>> {
+        normalize_uri_path(&mut params.text_document.uri);
+
+        debug!("received `textDocument/documentSymbol` request: {params:#?}");
+
+        Ok(self
+            .analysis_result(&params.text_document.uri)
+            .await?
+            .and_then(|r| symbols::document_symbols(&r))
+            .map(DocumentSymbolResponse::Nested))
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> RpcResult<Option<Vec<SymbolInformation>>> {
+        debug!("received `workspace/symbol` request: {params:#?}");
+
+        let results = self.analysis_results().await?;
+        Ok(Some(symbols::workspace_symbols(&results, &params.query)))
+    }
+
+    async fn folding_range(
+        &self,
+        mut params: FoldingRangeParams,
+    ) -> RpcResult<Option<Vec<FoldingRange>>> {
+        normalize_uri_path(&mut params.text_document.uri);
+
+        debug!("received `textDocument/foldingRange` request: {params:#?}");
+
+        Ok(self
+            .analysis_result(&params.text_document.uri)
+            .await?
+            .and_then(|r| folding::folding_ranges(&r)))
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        mut params: SemanticTokensParams,
+    ) -> RpcResult<Option<SemanticTokensResult>> {
+        normalize_uri_path(&mut params.text_document.uri);
+
+        debug!("received `textDocument/semanticTokens/full` request: {params:#?}");
+
+        Ok(self
+            .analysis_result(&params.text_document.uri)
+            .await?
+            .and_then(|r| semantic::semantic_tokens(&r))
+            .map(|data| {
+                SemanticTokensResult::Tokens(SemanticTokens {
+                    result_id: None,
+                    data,
+                })
+            }))
+    }
+
+    async fn inlay_hint(&self, mut params: InlayHintParams) -> RpcResult<Option<Vec<InlayHint>>> {
+        normalize_uri_path(&mut params.text_document.uri);
+
+        debug!("received `textDocument/inlayHint` request: {params:#?}");
+
+        Ok(self
+            .analysis_result(&params.text_document.uri)
+            .await?
+            .and_then(|r| hints::inlay_hints(&r, params.range)))
+    }
+
+    async fn signature_help(
+        &self,
+        mut params: SignatureHelpParams,
+    ) -> RpcResult<Option<SignatureHelp>> {
+        normalize_uri_path(&mut params.text_document_position_params.text_document.uri);
+
+        debug!("received `textDocument/signatureHelp` request: {params:#?}");
+
+        Ok(self
+            .analysis_result(&params.text_document_position_params.text_document.uri)
+            .await?
+            .and_then(|r| {
+                signature::signature_help(&r, params.text_document_position_params.position)
+            }))
+    }
+
+    async fn code_lens(&self, mut params: CodeLensParams) -> RpcResult<Option<Vec<CodeLens>>> {
+        normalize_uri_path(&mut params.text_document.uri);
+
+        debug!("received `textDocument/codeLens` request: {params:#?}");
+
+        Ok(self
+            .analysis_result(&params.text_document.uri)
+            .await?
+            .and_then(|r| commands::code_lenses(&r)))
+    }
+
+    async fn execute_command(&self, params: ExecuteCommandParams) -> RpcResult<Option<Value>> {
+        debug!("received `workspace/executeCommand` request: {params:#?}");
+
+        let internal_error = |e: anyhow::Error| RpcError {
+            code: ErrorCode::InternalError,
+            message: format!("{e:#}").into(),
+            data: None,
+        };
+
+        let (mut uri, name) = commands::parse_arguments(&params.arguments)
+            .map_err(|e| RpcError::invalid_params(format!("{e:#}")))?;
+        normalize_uri_path(&mut uri);
+
+        let path = uri.to_file_path().map_err(|_| {
+            RpcError::invalid_params(format!("document `{uri}` is not a local file"))
+        })?;
+
+        let result = self.analysis_result(&uri).await?.ok_or_else(|| {
+            RpcError::invalid_params(format!("document `{uri}` is not in the workspace"))
+        })?;
+
+        match params.command.as_str() {
+            commands::RUN_COMMAND => match self.run_callable(result.document(), &path, &name).await
+            {
+                Ok(outputs) => Ok(Some(outputs)),
+                Err(e) => {
+                    self.client
+                        .show_message(MessageType::ERROR, format!("{e:#}"))
+                        .await;
+                    Err(internal_error(e))
+                }
+            },
+            commands::GENERATE_INPUTS_COMMAND => {
+                let inputs = commands::generate_inputs(result.document(), &path, &name)
+                    .map_err(internal_error)?;
+
+                // Open the inputs file in the client; failure to do so is not an error
+                if let Ok(uri) = Url::from_file_path(&inputs) {
+                    let _ = self
+                        .client
+                        .show_document(ShowDocumentParams {
+                            uri,
+                            external: None,
+                            take_focus: Some(true),
+                            selection: None,
+                        })
+                        .await;
+                }
+
+                Ok(Some(Value::String(inputs.display().to_string())))
+            }
+            commands::SHOW_CALL_GRAPH_COMMAND => Ok(Some(Value::String(
+                commands::call_graph(result.document(), &name).map_err(internal_error)?,
+            ))),
+            command => Err(RpcError::invalid_params(format!(
+                "unsupported command `{command}`"
+            ))),
+        }
+    }
+
+    async fn prepare_call_hierarchy(
+        &self,
+        mut params: CallHierarchyPrepareParams,
+    ) -> RpcResult<Option<Vec<CallHierarchyItem>>> {
+        normalize_uri_path(&mut params.text_document_position_params.text_document.uri);
+
+        debug!("received `textDocument/prepareCallHierarchy` request: {params:#?}");
+
+        let results = self.analysis_results().await?;
+        Ok(hierarchy::prepare(
+            &results,
+            &params.text_document_position_params.text_document.uri,
+            params.text_document_position_params.position,
+        ))
+    }
+
+    async fn incoming_calls(
+        &self,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> RpcResult<Option<Vec<CallHierarchyIncomingCall>>> {
+        debug!("received `callHierarchy/incomingCalls` request: {params:#?}");
+
+        let results = self.analysis_results().await?;
+        Ok(Some(hierarchy::incoming_calls(&results, &params.item)))
+    }
+
+    async fn outgoing_calls(
+        &self,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> RpcResult<Option<Vec<CallHierarchyOutgoingCall>>> {
+        debug!("received `callHierarchy/outgoingCalls` request: {params:#?}");
+
+        let results = self.analysis_results().await?;
+        Ok(Some(hierarchy::outgoing_calls(&results, &params.item)))
+    }
 }
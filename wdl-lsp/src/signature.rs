@@ -0,0 +1,422 @@
+//! Implementation of signature help.
+
+use tower_lsp::lsp_types::Documentation;
+use tower_lsp::lsp_types::ParameterInformation;
+use tower_lsp::lsp_types::ParameterLabel;
+use tower_lsp::lsp_types::Position;
+use tower_lsp::lsp_types::SignatureHelp;
+use tower_lsp::lsp_types::SignatureInformation;
+use wdl_analysis::AnalysisResult;
+use wdl_analysis::DiagnosticsConfig;
+use wdl_analysis::Document;
+use wdl_analysis::diagnostics::unknown_type;
+use wdl_analysis::document::ScopeRef;
+use wdl_analysis::document::Task;
+use wdl_analysis::stdlib::Function;
+use wdl_analysis::stdlib::FunctionSignature;
+use wdl_analysis::stdlib::STDLIB;
+use wdl_analysis::stdlib::TypeParameters;
+use wdl_analysis::types::Coercible;
+use wdl_analysis::types::Type;
+use wdl_analysis::types::v1::EvaluationContext;
+use wdl_analysis::types::v1::ExprTypeEvaluator;
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::Diagnostic;
+use wdl_ast::Span;
+use wdl_ast::SupportedVersion;
+use wdl_ast::SyntaxKind;
+use wdl_ast::SyntaxNode;
+use wdl_ast::TreeNode;
+use wdl_ast::TreeToken;
+use wdl_ast::v1::CallExpr;
+use wdl_ast::v1::CallStatement;
+
+use crate::proto::offset;
+
+/// The context for evaluating the types of call arguments.
+///
+/// Any diagnostics from the evaluation are ignored.
+struct Context<'a> {
+    /// The document containing the expression.
+    document: &'a Document,
+    /// The scope containing the expression.
+    scope: Option<ScopeRef<'a>>,
+}
+
+impl EvaluationContext for Context<'_> {
+    fn version(&self) -> SupportedVersion {
+        self.document
+            .version()
+            .expect("document should have a version")
+    }
+
+    fn resolve_name(&self, name: &str, _: Span) -> Option<Type> {
+        self.scope
+            .as_ref()
+            .and_then(|s| s.lookup(name))
+            .map(|n| n.ty().clone())
+    }
+
+    fn resolve_type_name(&mut self, name: &str, span: Span) -> Result<Type, Diagnostic> {
+        self.document
+            .struct_by_name(name)
+            .and_then(|s| s.ty().cloned())
+            .or_else(|| self.document.enum_by_name(name).map(|e| e.ty().clone()))
+            .ok_or_else(|| unknown_type(name, span))
+    }
+
+    fn task(&self) -> Option<&Task> {
+        None
+    }
+
+    fn diagnostics_config(&self) -> DiagnosticsConfig {
+        DiagnosticsConfig::except_all()
+    }
+
+    fn add_diagnostic(&mut self, _: Diagnostic) {}
+}
+
+/// Determines if the given offset is within the delimited portion of a node.
+///
+/// If the closing delimiter is missing, the portion extends to the end of the
+/// node.
+fn within(node: &SyntaxNode, open: SyntaxKind, close: SyntaxKind, offset: usize) -> bool {
+    let Some(open) = node.children_with_tokens().find(|c| c.kind() == open) else {
+        return false;
+    };
+
+    let end = node
+        .children_with_tokens()
+        .filter(|c| c.kind() == close)
+        .last()
+        .and_then(|c| c.into_token())
+        .map(|t| t.span().start())
+        .unwrap_or_else(|| node.span().end());
+
+    let start = match open {
+        wdl_ast::SyntaxElement::Node(n) => n.span().end(),
+        wdl_ast::SyntaxElement::Token(t) => t.span().end(),
+    };
+
+    offset >= start && offset <= end
+}
+
+/// Creates the signature information for a standard library function
+/// signature.
+///
+/// The parameter labels are offsets into the signature label.
+fn function_signature(name: &str, signature: &FunctionSignature) -> SignatureInformation {
+    let params = TypeParameters::new(signature.type_parameters());
+    let label = format!("{name}{signature}", signature = signature.display(&params));
+
+    let required = signature.required();
+    let mut start = name.len() + 1;
+    let parameters = signature
+        .parameters()
+        .iter()
+        .enumerate()
+        .map(|(i, parameter)| {
+            let text = parameter.display(&params).to_string();
+
+            // Optional parameters are displayed within angle brackets
+            let optional = i >= required;
+            if optional {
+                start += 1;
+            }
+
+            let end = start + text.len();
+            let information = ParameterInformation {
+                label: ParameterLabel::LabelOffsets([start as u32, end as u32]),
+                documentation: None,
+            };
+
+            start = end + if optional { 3 } else { 2 };
+            information
+        })
+        .collect();
+
+    SignatureInformation {
+        label,
+        documentation: None,
+        parameters: Some(parameters),
+        active_parameter: None,
+    }
+}
+
+/// Determines if a signature may bind to the given argument types.
+///
+/// Arguments of an indeterminate type and generic parameters are always
+/// considered to match.
+fn may_bind(signature: &FunctionSignature, arguments: &[Type]) -> bool {
+    if arguments.len() > signature.parameters().len() {
+        return false;
+    }
+
+    signature
+        .parameters()
+        .iter()
+        .zip(arguments)
+        .all(|(parameter, argument)| {
+            argument.is_union()
+                || parameter
+                    .concrete_type()
+                    .map(|ty| argument.is_coercible_to(ty))
+                    .unwrap_or(true)
+        })
+}
+
+/// Gets the signature help for a call to a standard library function.
+fn function_help(document: &Document, expr: CallExpr, offset: usize) -> Option<SignatureHelp> {
+    let version = document.version()?;
+    let target = expr.target();
+    let signatures = match STDLIB.function(target.text())? {
+        Function::Monomorphic(f) => vec![f.signature()],
+        Function::Polymorphic(f) => f.signatures().iter().collect(),
+    };
+
+    let signatures = signatures
+        .into_iter()
+        .filter(|s| s.minimum_version() <= version)
+        .collect::<Vec<_>>();
+
+    // The active parameter is the number of argument separators before the offset
+    let active = expr
+        .inner()
+        .children_with_tokens()
+        .filter(|c| c.kind() == SyntaxKind::Comma)
+        .filter_map(|c| c.into_token())
+        .filter(|t| t.span().end() <= offset)
+        .count();
+
+    // Evaluate the types of the arguments preceding the active argument
+    let mut context = Context {
+        document,
+        scope: document.find_scope_by_position(offset),
+    };
+    let mut evaluator = ExprTypeEvaluator::new(&mut context);
+    let arguments = expr
+        .arguments()
+        .take(active)
+        .map(|arg| evaluator.evaluate_expr(&arg).unwrap_or(Type::Union))
+        .collect::<Vec<_>>();
+
+    // Narrow the overloads to those that may bind to the arguments
+    let narrowed = signatures
+        .iter()
+        .copied()
+        .filter(|s| s.parameters().len() > active && may_bind(s, &arguments))
+        .collect::<Vec<_>>();
+    let signatures = if narrowed.is_empty() {
+        signatures
+    } else {
+        narrowed
+    };
+
+    Some(SignatureHelp {
+        signatures: signatures
+            .into_iter()
+            .map(|s| function_signature(target.text(), s))
+            .collect(),
+        active_signature: Some(0),
+        active_parameter: Some(active as u32),
+    })
+}
+
+/// Gets the signature help for the inputs of a call statement.
+fn call_help(
+    document: &Document,
+    statement: CallStatement,
+    offset: usize,
+) -> Option<SignatureHelp> {
+    let name = match statement.alias() {
+        Some(alias) => alias.name(),
+        None => statement.target().names().last()?,
+    };
+
+    let ty = document.workflow()?.calls().get(name.text())?;
+
+    let mut label = match ty.namespace() {
+        Some(ns) => format!("{ns}.{name}(", name = ty.name()),
+        None => format!("{name}(", name = ty.name()),
+    };
+
+    let mut parameters = Vec::new();
+    for (i, (name, input)) in ty.inputs().iter().enumerate() {
+        if i > 0 {
+            label.push_str(", ");
+        }
+
+        // Optional inputs are displayed within angle brackets
+        if !input.required() {
+            label.push('<');
+        }
+
+        let start = label.len();
+        label.push_str(&format!("{name}: {ty}", ty = input.ty()));
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start as u32, label.len() as u32]),
+            documentation: Some(Documentation::String(
+                if input.required() {
+                    "required input"
+                } else {
+                    "optional input"
+                }
+                .to_string(),
+            )),
+        });
+
+        if !input.required() {
+            label.push('>');
+        }
+    }
+
+    label.push(')');
+
+    // The active parameter is the input being specified at the offset
+    let active = statement
+        .inputs()
+        .find(|i| {
+            let span = i.span();
+            offset >= span.start() && offset <= span.end()
+        })
+        .and_then(|i| ty.inputs().get_index_of(i.name().text()))
+        .map(|i| i as u32);
+
+    Some(SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label,
+            documentation: None,
+            parameters: Some(parameters),
+            active_parameter: None,
+        }],
+        active_signature: Some(0),
+        active_parameter: active,
+    })
+}
+
+/// Gets the signature help for an analysis result at the given position.
+///
+/// Signature help is provided for the arguments of a call to a standard
+/// library function and for the inputs of a call statement.
+///
+/// Returns `None` if there is no signature help at the position.
+pub fn signature_help(result: &AnalysisResult, position: Position) -> Option<SignatureHelp> {
+    let index = result.lines()?;
+    let offset = offset(index, position).ok()?;
+    let document = result.document();
+    let root = document.root();
+
+    // Find the innermost call containing the position
+    let node = root
+        .inner()
+        .descendants()
+        .filter(|n| match n.kind() {
+            SyntaxKind::CallExprNode => {
+                within(n, SyntaxKind::OpenParen, SyntaxKind::CloseParen, offset)
+            }
+            SyntaxKind::CallStatementNode => {
+                within(n, SyntaxKind::OpenBrace, SyntaxKind::CloseBrace, offset)
+            }
+            _ => false,
+        })
+        .last()?;
+
+    match node.kind() {
+        SyntaxKind::CallExprNode => function_help(document, CallExpr::cast(node)?, offset),
+        _ => call_help(document, CallStatement::cast(node)?, offset),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::test::analyze;
+
+    /// Gets the labels of the signatures and the active parameter.
+    fn labels(help: SignatureHelp) -> (Vec<String>, Option<u32>) {
+        (
+            help.signatures.into_iter().map(|s| s.label).collect(),
+            help.active_parameter,
+        )
+    }
+
+    #[tokio::test]
+    async fn it_provides_function_signature_help() {
+        let result = analyze(
+            r#"version 1.1
+
+workflow w {
+    Array[Int] a = [1, 2]
+    Int x = select_first([a[0], 1])
+    String y = sub("a", "b", "c")
+    Array[String] z = prefix("p", a)
+}
+"#,
+        )
+        .await;
+
+        let help = signature_help(&result, Position::new(4, 25)).expect("should have help");
+        assert_eq!(
+            labels(help),
+            (
+                vec!["select_first(Array[X], <X>) -> X".to_string()],
+                Some(0)
+            )
+        );
+
+        let help = signature_help(&result, Position::new(5, 24)).expect("should have help");
+        let help = labels(help);
+        assert_eq!(help.1, Some(1));
+        assert_eq!(help.0.len(), 1);
+
+        let help = signature_help(&result, Position::new(6, 34)).expect("should have help");
+        assert_eq!(help.active_parameter, Some(1));
+        let parameters = help.signatures[0]
+            .parameters
+            .as_ref()
+            .expect("should have parameters");
+        let ParameterLabel::LabelOffsets([start, end]) = parameters[1].label else {
+            panic!("expected label offsets");
+        };
+        assert_eq!(
+            &help.signatures[0].label[start as usize..end as usize],
+            "Array[P]"
+        );
+
+        assert!(signature_help(&result, Position::new(3, 20)).is_none());
+    }
+
+    #[tokio::test]
+    async fn it_provides_call_signature_help() {
+        let result = analyze(
+            r#"version 1.1
+
+task t {
+    input {
+        String name
+        Int count = 1
+    }
+
+    command <<<>>>
+}
+
+workflow w {
+    call t { input: name = "foo" }
+}
+"#,
+        )
+        .await;
+
+        let help = signature_help(&result, Position::new(12, 24)).expect("should have help");
+        assert_eq!(
+            labels(help),
+            (vec!["t(name: String, <count: Int>)".to_string()], Some(0))
+        );
+
+        let help = signature_help(&result, Position::new(12, 13)).expect("should have help");
+        assert_eq!(help.active_parameter, None);
+    }
+}
@@ -0,0 +1,586 @@
+//! Implementation of document and workspace symbols.
+
+use anyhow::Result;
+use line_index::LineIndex;
+use tower_lsp::lsp_types::DocumentSymbol;
+use tower_lsp::lsp_types::Location;
+use tower_lsp::lsp_types::SymbolInformation;
+use tower_lsp::lsp_types::SymbolKind;
+use url::Url;
+use wdl_analysis::AnalysisResult;
+use wdl_ast::AstNode;
+use wdl_ast::AstToken;
+use wdl_ast::Span;
+use wdl_ast::SyntaxNode;
+use wdl_ast::TreeNode;
+use wdl_ast::TreeToken;
+use wdl_ast::v1::BoundDecl;
+use wdl_ast::v1::CallStatement;
+use wdl_ast::v1::ConditionalStatement;
+use wdl_ast::v1::Decl;
+use wdl_ast::v1::DocumentItem;
+use wdl_ast::v1::EnumDefinition;
+use wdl_ast::v1::ScatterStatement;
+use wdl_ast::v1::StructDefinition;
+use wdl_ast::v1::TaskDefinition;
+use wdl_ast::v1::TaskItem;
+use wdl_ast::v1::WorkflowDefinition;
+use wdl_ast::v1::WorkflowItem;
+use wdl_ast::v1::WorkflowStatement;
+
+use crate::proto::range_from_span;
+
+/// Creates a new document symbol.
+#[allow(deprecated)]
+fn symbol(
+    index: &LineIndex,
+    name: impl Into<String>,
+    detail: Option<String>,
+    kind: SymbolKind,
+    span: Span,
+    selection: Span,
+    children: Vec<DocumentSymbol>,
+) -> Result<DocumentSymbol> {
+    Ok(DocumentSymbol {
+        name: name.into(),
+        detail,
+        kind,
+        tags: None,
+        deprecated: None,
+        range: range_from_span(index, span)?,
+        selection_range: range_from_span(index, selection)?,
+        children: if children.is_empty() {
+            None
+        } else {
+            Some(children)
+        },
+    })
+}
+
+/// Creates a document symbol for a declaration.
+fn decl_symbol(index: &LineIndex, decl: &Decl) -> Result<DocumentSymbol> {
+    let name = decl.name();
+    symbol(
+        index,
+        name.text(),
+        Some(decl.ty().to_string()),
+        SymbolKind::VARIABLE,
+        decl.inner().span(),
+        name.span(),
+        Vec::new(),
+    )
+}
+
+/// Creates a document symbol for a bound declaration.
+fn bound_decl_symbol(index: &LineIndex, decl: &BoundDecl) -> Result<DocumentSymbol> {
+    let name = decl.name();
+    symbol(
+        index,
+        name.text(),
+        Some(decl.ty().to_string()),
+        SymbolKind::VARIABLE,
+        decl.span(),
+        name.span(),
+        Vec::new(),
+    )
+}
+
+/// Creates a document symbol for a section of a task or workflow.
+///
+/// The selection range of a section is the section's keyword.
+fn section_symbol(
+    index: &LineIndex,
+    name: &str,
+    node: &impl AstNode<SyntaxNode>,
+    children: Vec<DocumentSymbol>,
+) -> Result<DocumentSymbol> {
+    let span = node.span();
+    let keyword = node.inner().first_token().map(|t| t.span()).unwrap_or(span);
+
+    symbol(
+        index,
+        name,
+        None,
+        SymbolKind::NAMESPACE,
+        span,
+        keyword,
+        children,
+    )
+}
+
+/// Creates a document symbol for a struct definition.
+fn struct_symbol(index: &LineIndex, definition: &StructDefinition) -> Result<DocumentSymbol> {
+    let name = definition.name();
+    let members = definition
+        .members()
+        .map(|m| {
+            let name = m.name();
+            symbol(
+                index,
+                name.text(),
+                Some(m.ty().to_string()),
+                SymbolKind::FIELD,
+                m.span(),
+                name.span(),
+                Vec::new(),
+            )
+        })
+        .collect::<Result<_>>()?;
+
+    symbol(
+        index,
+        name.text(),
+        Some("struct".to_string()),
+        SymbolKind::STRUCT,
+        definition.span(),
+        name.span(),
+        members,
+    )
+}
+
+/// Creates a document symbol for an enum definition.
+fn enum_symbol(index: &LineIndex, definition: &EnumDefinition) -> Result<DocumentSymbol> {
+    let name = definition.name();
+    let variants = definition
+        .variants()
+        .map(|v| {
+            let name = v.name();
+            symbol(
+                index,
+                name.text(),
+                None,
+                SymbolKind::ENUM_MEMBER,
+                v.span(),
+                name.span(),
+                Vec::new(),
+            )
+        })
+        .collect::<Result<_>>()?;
+
+    symbol(
+        index,
+        name.text(),
+        Some("enum".to_string()),
+        SymbolKind::ENUM,
+        definition.span(),
+        name.span(),
+        variants,
+    )
+}
+
+/// Creates a document symbol for a task definition.
+fn task_symbol(index: &LineIndex, definition: &TaskDefinition) -> Result<DocumentSymbol> {
+    let name = definition.name();
+    let mut children = Vec::new();
+    for item in definition.items() {
+        match item {
+            TaskItem::Input(section) => children.push(section_symbol(
+                index,
+                "input",
+                &section,
+                section
+                    .declarations()
+                    .map(|d| decl_symbol(index, &d))
+                    .collect::<Result<_>>()?,
+            )?),
+            TaskItem::Output(section) => children.push(section_symbol(
+                index,
+                "output",
+                &section,
+                section
+                    .declarations()
+                    .map(|d| bound_decl_symbol(index, &d))
+                    .collect::<Result<_>>()?,
+            )?),
+            TaskItem::Command(section) => {
+                children.push(section_symbol(index, "command", &section, Vec::new())?)
+            }
+            TaskItem::Requirements(section) => {
+                children.push(section_symbol(index, "requirements", &section, Vec::new())?)
+            }
+            TaskItem::Hints(section) => {
+                children.push(section_symbol(index, "hints", &section, Vec::new())?)
+            }
+            TaskItem::Runtime(section) => {
+                children.push(section_symbol(index, "runtime", &section, Vec::new())?)
+            }
+            TaskItem::Metadata(_) | TaskItem::ParameterMetadata(_) => {}
+            TaskItem::Declaration(decl) => children.push(bound_decl_symbol(index, &decl)?),
+        }
+    }
+
+    symbol(
+        index,
+        name.text(),
+        Some("task".to_string()),
+        SymbolKind::FUNCTION,
+        definition.span(),
+        name.span(),
+        children,
+    )
+}
+
+/// Creates a document symbol for a call statement.
+fn call_symbol(index: &LineIndex, stmt: &CallStatement) -> Result<DocumentSymbol> {
+    let target = stmt.target();
+    let (name, selection) = match stmt.alias() {
+        Some(alias) => {
+            let name = alias.name();
+            (name.text().to_string(), name.span())
+        }
+        None => match target.names().last() {
+            Some(name) => (name.text().to_string(), name.span()),
+            None => (target.text().to_string(), target.span()),
+        },
+    };
+
+    symbol(
+        index,
+        name,
+        Some(format!("call {target}", target = target.text())),
+        SymbolKind::METHOD,
+        stmt.span(),
+        selection,
+        Vec::new(),
+    )
+}
+
+/// Creates a document symbol for a scatter statement.
+fn scatter_symbol(index: &LineIndex, stmt: &ScatterStatement) -> Result<DocumentSymbol> {
+    let variable = stmt.variable();
+    symbol(
+        index,
+        format!(
+            "scatter ({variable} in {expr})",
+            variable = variable.text(),
+            expr = stmt.expr().text()
+        ),
+        None,
+        SymbolKind::OPERATOR,
+        stmt.span(),
+        variable.span(),
+        statement_symbols(index, stmt.statements())?,
+    )
+}
+
+/// Creates a document symbol for a conditional statement.
+///
+/// Any `else if` or `else` clauses are children of the statement's symbol.
+fn conditional_symbol(index: &LineIndex, stmt: &ConditionalStatement) -> Result<DocumentSymbol> {
+    let expr = stmt.expr();
+    let mut children = statement_symbols(index, stmt.statements())?;
+    for clause in stmt.else_clauses() {
+        let keyword = clause.keyword();
+        let name = match clause.expr() {
+            Some(expr) => format!("else if ({expr})", expr = expr.text()),
+            None => "else".to_string(),
+        };
+
+        children.push(symbol(
+            index,
+            name,
+            None,
+            SymbolKind::OPERATOR,
+            clause.span(),
+            keyword.span(),
+            statement_symbols(index, clause.statements())?,
+        )?);
+    }
+
+    symbol(
+        index,
+        format!("if ({expr})", expr = expr.text()),
+        None,
+        SymbolKind::OPERATOR,
+        stmt.span(),
+        expr.span(),
+        children,
+    )
+}
+
+/// Creates document symbols for a list of workflow statements.
+fn statement_symbols(
+    index: &LineIndex,
+    statements: impl Iterator<Item = WorkflowStatement>,
+) -> Result<Vec<DocumentSymbol>> {
+    statements
+        .map(|stmt| match stmt {
+            WorkflowStatement::Conditional(stmt) => conditional_symbol(index, &stmt),
+            WorkflowStatement::Scatter(stmt) => scatter_symbol(index, &stmt),
+            WorkflowStatement::Call(stmt) => call_symbol(index, &stmt),
+            WorkflowStatement::Declaration(decl) => bound_decl_symbol(index, &decl),
+        })
+        .collect()
+}
+
+/// Creates a document symbol for a workflow definition.
+fn workflow_symbol(index: &LineIndex, definition: &WorkflowDefinition) -> Result<DocumentSymbol> {
+    let name = definition.name();
+    let mut children = Vec::new();
+    for item in definition.items() {
+        match item {
+            WorkflowItem::Input(section) => children.push(section_symbol(
+                index,
+                "input",
+                &section,
+                section
+                    .declarations()
+                    .map(|d| decl_symbol(index, &d))
+                    .collect::<Result<_>>()?,
+            )?),
+            WorkflowItem::Output(section) => children.push(section_symbol(
+                index,
+                "output",
+                &section,
+                section
+                    .declarations()
+                    .map(|d| bound_decl_symbol(index, &d))
+                    .collect::<Result<_>>()?,
+            )?),
+            WorkflowItem::Conditional(stmt) => children.push(conditional_symbol(index, &stmt)?),
+            WorkflowItem::Scatter(stmt) => children.push(scatter_symbol(index, &stmt)?),
+            WorkflowItem::Call(stmt) => children.push(call_symbol(index, &stmt)?),
+            WorkflowItem::Hints(section) => {
+                children.push(section_symbol(index, "hints", &section, Vec::new())?)
+            }
+            WorkflowItem::Metadata(_) | WorkflowItem::ParameterMetadata(_) => {}
+            WorkflowItem::Declaration(decl) => children.push(bound_decl_symbol(index, &decl)?),
+        }
+    }
+
+    symbol(
+        index,
+        name.text(),
+        Some("workflow".to_string()),
+        SymbolKind::MODULE,
+        definition.span(),
+        name.span(),
+        children,
+    )
+}
+
+/// Gets the document symbols for an analysis result.
+///
+/// Returns `None` if the document could not be parsed or is not a supported
+/// version.
+pub fn document_symbols(result: &AnalysisResult) -> Option<Vec<DocumentSymbol>> {
+    let index = result.lines()?;
+    let ast = result.document().root().ast().into_v1()?;
+
+    ast.items()
+        .filter_map(|item| match item {
+            DocumentItem::Import(_) => None,
+            DocumentItem::Struct(definition) => Some(struct_symbol(index, &definition)),
+            DocumentItem::Enum(definition) => Some(enum_symbol(index, &definition)),
+            DocumentItem::Task(definition) => Some(task_symbol(index, &definition)),
+            DocumentItem::Workflow(definition) => Some(workflow_symbol(index, &definition)),
+        })
+        .collect::<Result<_>>()
+        .ok()
+}
+
+/// Determines if a symbol name matches a workspace symbol query.
+///
+/// A name matches if the characters of the query appear, in order, in the
+/// name; matching is case-insensitive.
+fn matches_query(name: &str, query: &str) -> bool {
+    let mut chars = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|q| chars.any(|c| c == q))
+}
+
+/// Gets the workspace symbols matching the given query.
+///
+/// Workspace symbols are the structs, enums, tasks, and workflows of every
+/// analyzed local document.
+#[allow(deprecated)]
+pub fn workspace_symbols(results: &[AnalysisResult], query: &str) -> Vec<SymbolInformation> {
+    let mut symbols = Vec::new();
+    for result in results {
+        // Only include symbols from local files
+        if result.document().uri().scheme() != "file" {
+            continue;
+        }
+
+        let index = match result.lines() {
+            Some(index) => index,
+            None => continue,
+        };
+
+        let ast = match result.document().root().ast().into_v1() {
+            Some(ast) => ast,
+            None => continue,
+        };
+
+        let uri: &Url = result.document().uri();
+        for item in ast.items() {
+            let (name, kind, span) = match item {
+                DocumentItem::Import(_) => continue,
+                DocumentItem::Struct(definition) => {
+                    (definition.name(), SymbolKind::STRUCT, definition.span())
+                }
+                DocumentItem::Enum(definition) => {
+                    (definition.name(), SymbolKind::ENUM, definition.span())
+                }
+                DocumentItem::Task(definition) => {
+                    (definition.name(), SymbolKind::FUNCTION, definition.span())
+                }
+                DocumentItem::Workflow(definition) => {
+                    (definition.name(), SymbolKind::MODULE, definition.span())
+                }
+            };
+
+            if !matches_query(name.text(), query) {
+                continue;
+            }
+
+            let range = match range_from_span(index, span) {
+                Ok(range) => range,
+                Err(_) => continue,
+            };
+
+            symbols.push(SymbolInformation {
+                name: name.text().to_string(),
+                kind,
+                tags: None,
+                deprecated: None,
+                location: Location::new(uri.clone(), range),
+                container_name: Some(result.document().path().into_owned()),
+            });
+        }
+    }
+
+    symbols
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::analyze;
+
+    #[tokio::test]
+    async fn it_creates_document_symbols() {
+        let result = analyze(
+            r#"version 1.1
+
+struct Foo {
+    String bar
+}
+
+task t {
+    input {
+        String x
+    }
+
+    command <<<>>>
+
+    output {
+        String y = x
+    }
+
+    runtime {}
+}
+
+workflow w {
+    scatter (i in [1, 2, 3]) {
+        if (i > 1) {
+            call t { input: x = "hi" }
+        }
+    }
+
+    call t as u { input: x = "there" }
+}
+"#,
+        )
+        .await;
+
+        /// Flattens the symbol tree into a list of (depth, name, kind).
+        fn flatten(
+            symbols: &[DocumentSymbol],
+            depth: usize,
+            out: &mut Vec<(usize, String, SymbolKind)>,
+        ) {
+            for symbol in symbols {
+                out.push((depth, symbol.name.clone(), symbol.kind));
+                flatten(
+                    symbol.children.as_deref().unwrap_or_default(),
+                    depth + 1,
+                    out,
+                );
+            }
+        }
+
+        let symbols = document_symbols(&result).expect("should have symbols");
+        let mut flattened = Vec::new();
+        flatten(&symbols, 0, &mut flattened);
+
+        let expected = [
+            (0, "Foo", SymbolKind::STRUCT),
+            (1, "bar", SymbolKind::FIELD),
+            (0, "t", SymbolKind::FUNCTION),
+            (1, "input", SymbolKind::NAMESPACE),
+            (2, "x", SymbolKind::VARIABLE),
+            (1, "command", SymbolKind::NAMESPACE),
+            (1, "output", SymbolKind::NAMESPACE),
+            (2, "y", SymbolKind::VARIABLE),
+            (1, "runtime", SymbolKind::NAMESPACE),
+            (0, "w", SymbolKind::MODULE),
+            (1, "scatter (i in [1, 2, 3])", SymbolKind::OPERATOR),
+            (2, "if (i > 1)", SymbolKind::OPERATOR),
+            (3, "t", SymbolKind::METHOD),
+            (1, "u", SymbolKind::METHOD),
+        ];
+
+        assert_eq!(
+            flattened,
+            expected
+                .iter()
+                .map(|(d, n, k)| (*d, n.to_string(), *k))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn it_creates_workspace_symbols() {
+        let result = analyze(
+            r#"version 1.1
+
+struct Foo {
+    String bar
+}
+
+task foo_task {
+    command <<<>>>
+}
+
+workflow bar_workflow {
+}
+"#,
+        )
+        .await;
+
+        let names = |query| {
+            workspace_symbols(std::slice::from_ref(&result), query)
+                .into_iter()
+                .map(|s| s.name)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(""), ["Foo", "foo_task", "bar_workflow"]);
+        assert_eq!(names("foo"), ["Foo", "foo_task"]);
+        assert_eq!(names("bw"), ["bar_workflow"]);
+        assert!(names("baz").is_empty());
+    }
+
+    #[test]
+    fn it_matches_queries() {
+        assert!(matches_query("hello_world", ""));
+        assert!(matches_query("hello_world", "hw"));
+        assert!(matches_query("hello_world", "HeLLo"));
+        assert!(matches_query("hello_world", "world"));
+        assert!(!matches_query("hello_world", "wh"));
+        assert!(!matches_query("hello_world", "goodbye"));
+    }
+}
@@ -0,0 +1,57 @@
+//! Utilities for testing the LSP implementation.
+
+use std::fs;
+
+use tempfile::TempDir;
+use wdl_analysis::AnalysisResult;
+use wdl_analysis::Analyzer;
+use wdl_analysis::DiagnosticsConfig;
+use wdl_analysis::path_to_uri;
+use wdl_analysis::rules;
+
+/// Analyzes the given source as the document `test.wdl` and returns its
+/// analysis result.
+pub async fn analyze(source: &str) -> AnalysisResult {
+    let dir = TempDir::new().expect("failed to create temporary directory");
+    let path = dir.path().join("test.wdl");
+    fs::write(&path, source).expect("failed to write test file");
+
+    let uri = path_to_uri(&path).expect("should convert to URI");
+    let analyzer = Analyzer::new(DiagnosticsConfig::new(rules()), |_: (), _, _, _| async {});
+    analyzer
+        .add_document(uri.clone())
+        .await
+        .expect("should add document");
+
+    analyzer
+        .analyze_document((), uri.clone())
+        .await
+        .expect("should analyze document")
+        .into_iter()
+        .find(|r| r.document().uri().as_ref() == &uri)
+        .expect("should have a result for the document")
+}
+
+/// Analyzes the given documents, keyed by file name, and returns the analysis
+/// results of all documents.
+///
+/// The temporary directory containing the documents is returned along with the
+/// results so that the documents may be referenced by the caller.
+pub async fn analyze_all(documents: &[(&str, &str)]) -> (TempDir, Vec<AnalysisResult>) {
+    let dir = TempDir::new().expect("failed to create temporary directory");
+    let analyzer = Analyzer::new(DiagnosticsConfig::new(rules()), |_: (), _, _, _| async {});
+    for (name, source) in documents {
+        let path = dir.path().join(name);
+        fs::write(&path, source).expect("failed to write test file");
+        analyzer
+            .add_document(path_to_uri(&path).expect("should convert to URI"))
+            .await
+            .expect("should add document");
+    }
+
+    let results = analyzer
+        .analyze(())
+        .await
+        .expect("should analyze documents");
+    (dir, results)
+}